@@ -43,21 +43,7 @@ impl ActivityManager {
     /// Initializes a new Activity Manager
     pub fn new(local_dir: &Path, ticks: Duration) -> Result<ActivityManager, HostError> {
         // Prepare Context
-        // Initialize configuration client
-        let (config_client, error_config): (ConfigClient, Option<String>) =
-            match Self::init_config_client() {
-                Ok(cli) => (cli, None),
-                Err(err) => {
-                    error!("Failed to initialize config client: {}", err);
-                    (ConfigClient::degraded(), Some(err))
-                }
-            };
-        let (bookmarks_client, error_bookmark) = match Self::init_bookmarks_client() {
-            Ok(cli) => (cli, None),
-            Err(err) => (None, Some(err)),
-        };
-        let error = error_config.or(error_bookmark);
-        let theme_provider: ThemeProvider = Self::init_theme_provider();
+        let (bookmarks_client, config_client, theme_provider, error) = Self::init_clients();
         let ctx: Context = Context::new(bookmarks_client, config_client, theme_provider, error);
         Ok(ActivityManager {
             context: Some(ctx),
@@ -95,20 +81,43 @@ impl ActivityManager {
         Ok(())
     }
 
+    /// Mark the local working directory as chosen by the user, so that it isn't replaced by the
+    /// directory of the last session with the host
+    pub fn set_explicit_local_dir(&mut self) {
+        let local_dir = self.local_dir.clone();
+        let ctx = self.context.as_mut().unwrap();
+        if let Some(params) = ctx.ft_params().cloned() {
+            ctx.set_ftparams(params.local_directory(Some(local_dir)));
+        }
+    }
+
     /// Resolve provided bookmark name and set it as file transfer params.
+    /// If `read_only` is true, the session is read-only even if the bookmark isn't.
     /// Returns error if bookmark is not found
     pub fn resolve_bookmark_name(
         &mut self,
         bookmark_name: &str,
         password: Option<&str>,
+        read_only: bool,
     ) -> Result<(), String> {
         if let Some(bookmarks_client) = self.context.as_mut().unwrap().bookmarks_client_mut() {
-            match bookmarks_client.get_bookmark(bookmark_name) {
+            // If there's no bookmark with this name, it may be a hotkey (e.g. `termscp -b 3`)
+            let bookmark = bookmarks_client.get_bookmark(bookmark_name).or_else(|| {
+                bookmark_name
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(|hotkey| bookmarks_client.get_bookmark_by_hotkey(hotkey))
+                    .and_then(|name| bookmarks_client.get_bookmark(name))
+            });
+            match bookmark {
                 None => Err(format!(
                     r#"Could not resolve bookmark name: "{}" no such bookmark"#,
                     bookmark_name
                 )),
-                Some(params) => self.set_filetransfer_params(params, password),
+                Some(params) => {
+                    let read_only = read_only || params.read_only;
+                    self.set_filetransfer_params(params.read_only(read_only), password)
+                }
             }
         } else {
             Err(String::from(
@@ -269,23 +278,79 @@ impl ActivityManager {
         };
         // Create activity
         activity.on_create(ctx);
+        // Prepare result
+        let result: Option<NextActivity>;
         loop {
             // Draw activity
             activity.on_draw();
             // Check if activity has terminated
-            if let Some(ExitReason::Quit) = activity.will_umount() {
-                info!("SetupActivity terminated due to 'Quit'");
-                break;
+            match activity.will_umount() {
+                Some(ExitReason::Quit) => {
+                    info!("SetupActivity terminated due to 'Quit'");
+                    // Return to AuthActivity
+                    result = Some(NextActivity::Authentication);
+                    break;
+                }
+                Some(ExitReason::SwitchProfile) => {
+                    info!("SetupActivity terminated due to 'SwitchProfile'");
+                    // Restart setup with the new profile
+                    result = Some(NextActivity::SetupActivity);
+                    break;
+                }
+                _ => { /* Nothing to do */ }
             }
         }
         // Destroy activity
         self.context = activity.on_destroy();
-        // This activity always returns to AuthActivity
-        Some(NextActivity::Authentication)
+        if matches!(result, Some(NextActivity::SetupActivity)) {
+            self.reload_context();
+        }
+        result
     }
 
     // -- misc
 
+    /// Initialize bookmarks client, configuration client and theme provider for the profile in use.
+    /// Returns the error occurred while initializing the clients, if any
+    fn init_clients() -> (
+        Option<BookmarksClient>,
+        ConfigClient,
+        ThemeProvider,
+        Option<String>,
+    ) {
+        // Initialize configuration client
+        let (config_client, error_config): (ConfigClient, Option<String>) =
+            match Self::init_config_client() {
+                Ok(cli) => (cli, None),
+                Err(err) => {
+                    error!("Failed to initialize config client: {}", err);
+                    (ConfigClient::degraded(), Some(err))
+                }
+            };
+        let (bookmarks_client, error_bookmark) = match Self::init_bookmarks_client() {
+            Ok(cli) => (cli, None),
+            Err(err) => (None, Some(err)),
+        };
+        let error = error_config.or(error_bookmark);
+        let theme_provider: ThemeProvider = Self::init_theme_provider();
+        (bookmarks_client, config_client, theme_provider, error)
+    }
+
+    /// Reload the clients in context for the profile in use
+    fn reload_context(&mut self) {
+        let (bookmarks_client, config_client, theme_provider, error) = Self::init_clients();
+        if let Some(ctx) = self.context.as_mut() {
+            info!(
+                "Reloading configuration for profile '{}'",
+                environment::get_profile().as_deref().unwrap_or("default")
+            );
+            ctx.reload(bookmarks_client, config_client, theme_provider);
+            if let Some(err) = error {
+                ctx.set_error(err);
+            }
+        }
+    }
+
     fn init_bookmarks_client() -> Result<Option<BookmarksClient>, String> {
         // Get config dir
         match environment::init_config_dir() {
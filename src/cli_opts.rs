@@ -13,7 +13,10 @@ use std::time::Duration;
 
 pub enum Task {
     Activity(NextActivity),
+    ExportConfig(PathBuf, bool),
+    ImportConfig(PathBuf),
     ImportTheme(PathBuf),
+    CheckUpdate,
     InstallUpdate,
 }
 
@@ -35,7 +38,7 @@ pub struct Args {
     #[argh(
         switch,
         short = 'b',
-        description = "resolve address argument as a bookmark name"
+        description = "resolve address argument as a bookmark name or hotkey (1-9)"
     )]
     pub address_as_bookmark: bool,
     #[argh(switch, short = 'c', description = "open termscp configuration")]
@@ -44,8 +47,25 @@ pub struct Args {
     pub debug: bool,
     #[argh(option, short = 'P', description = "provide password from CLI")]
     pub password: Option<String>,
+    #[argh(
+        switch,
+        description = "store configuration, bookmarks, themes and logs next to the executable"
+    )]
+    pub portable: bool,
+    #[argh(option, description = "use the specified configuration profile")]
+    pub profile: Option<String>,
     #[argh(switch, short = 'q', description = "disable logging")]
     pub quiet: bool,
+    #[argh(
+        switch,
+        description = "disable destructive operations (delete, rename, overwrite, exec) in the session"
+    )]
+    pub read_only: bool,
+    #[argh(
+        option,
+        description = "override configuration key (e.g. `--set user_interface.text_editor=vim`)"
+    )]
+    pub set: Vec<String>,
     #[argh(option, short = 't', description = "import specified theme")]
     pub theme: Option<String>,
     #[argh(
@@ -63,6 +83,8 @@ pub struct Args {
     pub ticks: u64,
     #[argh(switch, short = 'v', description = "print version")]
     pub version: bool,
+    #[argh(subcommand)]
+    pub nested: Option<ArgsSubcommands>,
     // -- positional
     #[argh(
         positional,
@@ -71,8 +93,76 @@ pub struct Args {
     pub positional: Vec<String>,
 }
 
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ArgsSubcommands {
+    Config(ConfigArgs),
+    Update(UpdateArgs),
+}
+
+#[derive(FromArgs)]
+#[argh(
+    subcommand,
+    name = "config",
+    description = "export or import termscp configuration"
+)]
+pub struct ConfigArgs {
+    #[argh(subcommand)]
+    pub nested: ConfigSubcommands,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ConfigSubcommands {
+    Export(ConfigExportArgs),
+    Import(ConfigImportArgs),
+}
+
+#[derive(FromArgs)]
+#[argh(
+    subcommand,
+    name = "export",
+    description = "export configuration, bookmarks and theme to an archive"
+)]
+pub struct ConfigExportArgs {
+    #[argh(
+        switch,
+        description = "exclude passwords, access keys and SSH keys from the archive"
+    )]
+    pub no_secrets: bool,
+    #[argh(positional, description = "path of the archive to create")]
+    pub path: PathBuf,
+}
+
+#[derive(FromArgs)]
+#[argh(
+    subcommand,
+    name = "import",
+    description = "import configuration, bookmarks and theme from an archive"
+)]
+pub struct ConfigImportArgs {
+    #[argh(positional, description = "path of the archive to import")]
+    pub path: PathBuf,
+}
+
+#[derive(FromArgs)]
+#[argh(
+    subcommand,
+    name = "update",
+    description = "update termscp to the latest version"
+)]
+pub struct UpdateArgs {
+    #[argh(
+        switch,
+        description = "only check whether a new version is available, without installing it"
+    )]
+    pub check: bool,
+}
+
 pub struct RunOpts {
     pub remote: Remote,
+    /// Local directory given as argument
+    pub local_dir: Option<PathBuf>,
     pub ticks: Duration,
     pub log_level: LogLevel,
     pub task: Task,
@@ -82,6 +172,7 @@ impl Default for RunOpts {
     fn default() -> Self {
         Self {
             remote: Remote::None,
+            local_dir: None,
             ticks: Duration::from_millis(10),
             log_level: LogLevel::Info,
             task: Task::Activity(NextActivity::Authentication),
@@ -99,6 +190,8 @@ pub enum Remote {
 pub struct BookmarkParams {
     pub name: String,
     pub password: Option<String>,
+    /// Whether to open a read-only session, even if the bookmark isn't read-only
+    pub read_only: bool,
 }
 
 pub struct HostParams {
@@ -111,8 +204,15 @@ impl BookmarkParams {
         Self {
             name: name.as_ref().to_string(),
             password: password.map(|x| x.as_ref().to_string()),
+            read_only: false,
         }
     }
+
+    /// Open a read-only session
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
 }
 
 impl HostParams {
@@ -12,10 +12,16 @@ use std::str::FromStr;
 
 /// UserHosts contains all the hosts saved by the user in the data storage
 /// It contains both `Bookmark`
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Clone, Deserialize, Serialize, Debug, Default)]
 pub struct UserHosts {
     pub bookmarks: HashMap<String, Bookmark>,
     pub recents: HashMap<String, Bookmark>,
+    /// Explorers state of the last session with each host
+    #[serde(default)]
+    pub sessions: HashMap<String, Session>, // @! Since 0.11.0
+    /// Name of the bookmark associated to each hotkey (1-9)
+    #[serde(default)]
+    pub hotkeys: HashMap<String, String>, // @! Since 0.11.0
 }
 
 /// Bookmark describes a single bookmark entry in the user hosts storage
@@ -36,10 +42,54 @@ pub struct Bookmark {
     pub password: Option<String>,
     /// Remote folder to connect to
     pub directory: Option<PathBuf>,
+    /// Whether to compress the SSH transport; if unset, the value from configuration is used
+    pub ssh_compression: Option<bool>, // @! Since 0.11.0
+    /// Encoding of the filenames on the server; if unset, UTF-8 is used
+    pub filename_encoding: Option<String>, // @! Since 0.11.0
+    /// Maximum upload speed in bytes per second
+    pub upload_limit: Option<u64>, // @! Since 0.11.0
+    /// Maximum download speed in bytes per second
+    pub download_limit: Option<u64>, // @! Since 0.11.0
+    /// Whether destructive operations are disabled in the sessions with this host
+    pub read_only: Option<bool>, // @! Since 0.11.0
+    /// Whether deletes on this host must be confirmed typing the amount of files to delete
+    pub production: Option<bool>, // @! Since 0.11.0
     /// S3 params; optional. When used other fields are empty for sure
     pub s3: Option<S3Params>,
 }
 
+/// Session describes the state of the explorers when the user disconnected from a host
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
+pub struct Session {
+    pub local_directory: PathBuf,
+    pub remote_directory: PathBuf,
+    /// When the session was saved, in seconds since the epoch; the oldest sessions are evicted first
+    #[serde(default)]
+    pub saved_at: u64,
+    /// Name of the file under the cursor in the local explorer
+    pub local_cursor: Option<String>,
+    /// Name of the file under the cursor in the remote explorer
+    pub remote_cursor: Option<String>,
+    /// Names of the files selected in the local explorer
+    #[serde(default)]
+    pub local_selection: Vec<String>,
+    /// Names of the files selected in the remote explorer
+    #[serde(default)]
+    pub remote_selection: Vec<String>,
+    /// Commands executed on the local host, from the oldest to the most recent
+    #[serde(default)]
+    pub local_commands: Vec<String>,
+    /// Commands executed on the remote host, from the oldest to the most recent
+    #[serde(default)]
+    pub remote_commands: Vec<String>,
+    /// Directories marked in the local explorer, by slot number
+    #[serde(default)]
+    pub local_marks: HashMap<String, PathBuf>,
+    /// Directories marked in the remote explorer, by slot number
+    #[serde(default)]
+    pub remote_marks: HashMap<String, PathBuf>,
+}
+
 /// Connection parameters for Aws s3 protocol
 #[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
 pub struct S3Params {
@@ -59,6 +109,10 @@ impl From<FileTransferParams> for Bookmark {
     fn from(params: FileTransferParams) -> Self {
         let protocol = params.protocol;
         let directory = params.entry_directory;
+        let upload_limit = params.upload_limit;
+        let download_limit = params.download_limit;
+        let read_only = Some(params.read_only);
+        let production = Some(params.production);
         // Create generic or others
         match params.params {
             ProtocolParams::Generic(params) => Self {
@@ -68,6 +122,12 @@ impl From<FileTransferParams> for Bookmark {
                 username: params.username,
                 password: params.password,
                 directory,
+                ssh_compression: params.compression,
+                filename_encoding: params.encoding,
+                upload_limit,
+                download_limit,
+                read_only,
+                production,
                 s3: None,
             },
             ProtocolParams::AwsS3(params) => Self {
@@ -77,6 +137,12 @@ impl From<FileTransferParams> for Bookmark {
                 username: None,
                 password: None,
                 directory,
+                ssh_compression: None,
+                filename_encoding: None,
+                upload_limit,
+                download_limit,
+                read_only,
+                production,
                 s3: Some(S3Params::from(params)),
             },
         }
@@ -99,11 +165,17 @@ impl From<Bookmark> for FileTransferParams {
                     .address(bookmark.address.unwrap_or_default())
                     .port(bookmark.port.unwrap_or(22))
                     .username(bookmark.username)
-                    .password(bookmark.password);
+                    .password(bookmark.password)
+                    .compression(bookmark.ssh_compression)
+                    .encoding(bookmark.filename_encoding);
                 Self::new(bookmark.protocol, ProtocolParams::Generic(params))
             }
         }
         .entry_directory(bookmark.directory) // Set entry directory
+        .upload_limit(bookmark.upload_limit)
+        .download_limit(bookmark.download_limit)
+        .read_only(bookmark.read_only.unwrap_or(false))
+        .production(bookmark.production.unwrap_or(false))
     }
 }
 
@@ -163,6 +235,7 @@ mod tests {
         let bookmarks: UserHosts = UserHosts::default();
         assert_eq!(bookmarks.bookmarks.len(), 0);
         assert_eq!(bookmarks.recents.len(), 0);
+        assert_eq!(bookmarks.sessions.len(), 0);
     }
 
     #[test]
@@ -174,6 +247,12 @@ mod tests {
             username: Some(String::from("root")),
             password: Some(String::from("password")),
             directory: Some(PathBuf::from("/tmp")),
+            ssh_compression: None,
+            filename_encoding: None,
+            upload_limit: None,
+            download_limit: None,
+            read_only: None,
+            production: None,
             s3: None,
         };
         let recent: Bookmark = Bookmark {
@@ -183,13 +262,24 @@ mod tests {
             username: Some(String::from("admin")),
             password: Some(String::from("password")),
             directory: Some(PathBuf::from("/home")),
+            ssh_compression: None,
+            filename_encoding: None,
+            upload_limit: None,
+            download_limit: None,
+            read_only: None,
+            production: None,
             s3: None,
         };
         let mut bookmarks: HashMap<String, Bookmark> = HashMap::with_capacity(1);
         bookmarks.insert(String::from("test"), bookmark);
         let mut recents: HashMap<String, Bookmark> = HashMap::with_capacity(1);
         recents.insert(String::from("ISO20201218T181432"), recent);
-        let hosts: UserHosts = UserHosts { bookmarks, recents };
+        let hosts: UserHosts = UserHosts {
+            bookmarks,
+            recents,
+            sessions: HashMap::new(),
+            hotkeys: HashMap::new(),
+        };
         // Verify
         let bookmark: &Bookmark = hosts.bookmarks.get(&String::from("test")).unwrap();
         assert_eq!(bookmark.address.as_deref().unwrap(), "192.168.1.1");
@@ -223,15 +313,26 @@ mod tests {
             port: 10222,
             username: Some(String::from("root")),
             password: Some(String::from("omar")),
+            compression: Some(true),
+            encoding: Some(String::from("latin1")),
         });
         let params: FileTransferParams = FileTransferParams::new(FileTransferProtocol::Scp, params)
-            .entry_directory(Some(PathBuf::from("/home")));
+            .entry_directory(Some(PathBuf::from("/home")))
+            .upload_limit(Some(5_000_000))
+            .read_only(true)
+            .production(true);
         let bookmark = Bookmark::from(params);
         assert_eq!(bookmark.protocol, FileTransferProtocol::Scp);
         assert_eq!(bookmark.address.as_deref().unwrap(), "127.0.0.1");
         assert_eq!(bookmark.port.unwrap(), 10222);
         assert_eq!(bookmark.username.as_deref().unwrap(), "root");
         assert_eq!(bookmark.password.as_deref().unwrap(), "omar");
+        assert_eq!(bookmark.ssh_compression, Some(true));
+        assert_eq!(bookmark.filename_encoding.as_deref(), Some("latin1"));
+        assert_eq!(bookmark.upload_limit, Some(5_000_000));
+        assert!(bookmark.download_limit.is_none());
+        assert_eq!(bookmark.read_only, Some(true));
+        assert_eq!(bookmark.production, Some(true));
         assert_eq!(
             bookmark.directory.as_deref().unwrap(),
             std::path::Path::new("/home")
@@ -271,6 +372,12 @@ mod tests {
             username: Some(String::from("root")),
             password: Some(String::from("password")),
             directory: Some(PathBuf::from("/tmp")),
+            ssh_compression: Some(false),
+            filename_encoding: Some(String::from("shift_jis")),
+            upload_limit: None,
+            download_limit: Some(1024),
+            read_only: Some(true),
+            production: Some(false),
             s3: None,
         };
         let params = FileTransferParams::from(bookmark);
@@ -284,6 +391,12 @@ mod tests {
         assert_eq!(gparams.port, 22);
         assert_eq!(gparams.username.as_deref().unwrap(), "root");
         assert_eq!(gparams.password.as_deref().unwrap(), "password");
+        assert_eq!(gparams.compression, Some(false));
+        assert_eq!(gparams.encoding.as_deref(), Some("shift_jis"));
+        assert!(params.upload_limit.is_none());
+        assert_eq!(params.download_limit, Some(1024));
+        assert!(params.read_only);
+        assert!(!params.production);
     }
 
     #[test]
@@ -295,6 +408,12 @@ mod tests {
             username: None,
             password: None,
             directory: Some(PathBuf::from("/tmp")),
+            ssh_compression: None,
+            filename_encoding: None,
+            upload_limit: None,
+            download_limit: None,
+            read_only: None,
+            production: None,
             s3: Some(S3Params {
                 bucket: String::from("veeso"),
                 region: Some(String::from("eu-west-1")),
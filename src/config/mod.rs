@@ -6,6 +6,8 @@
 pub use params::*;
 
 pub mod bookmarks;
+pub mod overrides;
 pub mod params;
 pub mod serialization;
+pub mod status_bar;
 pub mod themes;
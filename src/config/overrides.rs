@@ -0,0 +1,252 @@
+//! ## Overrides
+//!
+//! `overrides` provides the values which override the configuration keys without changing the
+//! configuration file, set through `TERMSCP_*` environment variables or the `--set` CLI option
+
+// Ext
+use std::sync::Mutex;
+use toml::Value;
+
+/// Prefix of the environment variables overriding configuration keys
+const ENV_PREFIX: &str = "TERMSCP_";
+/// Sections of the configuration whose keys can be overridden
+const SECTIONS: [&str; 2] = ["user_interface", "remote"];
+
+lazy_static! {
+    static ref CLI_OVERRIDES: Mutex<Vec<ConfigOverride>> = Mutex::new(Vec::new());
+}
+
+/// ConfigOverride describes the value which overrides a configuration key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    section: String,
+    key: String,
+    value: String,
+}
+
+impl ConfigOverride {
+    /// Parse override from `section.key=value` syntax (e.g. `user_interface.text_editor=vim`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("\"{}\": expected syntax is `section.key=value`", s))?;
+        let (section, key) = name
+            .trim()
+            .split_once('.')
+            .ok_or_else(|| format!("\"{}\": expected syntax is `section.key=value`", s))?;
+        if !SECTIONS.contains(&section) {
+            return Err(format!(
+                "\"{}\": unknown section \"{}\" (expected one of {})",
+                s,
+                section,
+                SECTIONS.join(", ")
+            ));
+        }
+        if key.is_empty() {
+            return Err(format!("\"{}\": key can't be empty", s));
+        }
+        Ok(Self {
+            section: section.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    /// Make override from environment variable named `TERMSCP_<SECTION>_<KEY>`.
+    /// Returns `None` if the variable doesn't refer to a configuration key
+    fn from_env(name: &str, value: &str) -> Option<Self> {
+        let name: String = name.strip_prefix(ENV_PREFIX)?.to_lowercase();
+        SECTIONS.iter().find_map(|section| {
+            name.strip_prefix(section)
+                .and_then(|x| x.strip_prefix('_'))
+                .filter(|key| !key.is_empty())
+                .map(|key| Self {
+                    section: section.to_string(),
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+        })
+    }
+
+    /// Returns the name of the overridden key (`section.key`)
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.section, self.key)
+    }
+
+    /// Set the value of the key into the serialized configuration.
+    /// The value is converted to the type of the current value of the key
+    pub fn apply(&self, config: &mut Value) -> Result<(), String> {
+        let section = config
+            .get_mut(self.section.as_str())
+            .and_then(|x| x.as_table_mut())
+            .ok_or_else(|| format!("{}: no such section", self.name()))?;
+        let value = self.make_value(section.get(self.key.as_str()))?;
+        section.insert(self.key.clone(), value);
+        Ok(())
+    }
+
+    /// Set the key in `config` to the value it has in `original`, or remove it if unset
+    pub fn restore(&self, config: &mut Value, original: &Value) {
+        let original = original
+            .get(self.section.as_str())
+            .and_then(|x| x.get(self.key.as_str()))
+            .cloned();
+        if let Some(section) = config
+            .get_mut(self.section.as_str())
+            .and_then(|x| x.as_table_mut())
+        {
+            match original {
+                Some(value) => {
+                    section.insert(self.key.clone(), value);
+                }
+                None => {
+                    section.remove(self.key.as_str());
+                }
+            }
+        }
+    }
+
+    /// Returns whether the key is set in `config`
+    pub fn is_set(&self, config: &Value) -> bool {
+        config
+            .get(self.section.as_str())
+            .and_then(|x| x.get(self.key.as_str()))
+            .is_some()
+    }
+
+    /// Convert the override value to the type of `current`.
+    /// If the key is unset, the type is guessed
+    fn make_value(&self, current: Option<&Value>) -> Result<Value, String> {
+        let value: &str = self.value.as_str();
+        match current {
+            Some(Value::String(_)) => Ok(Value::String(value.to_string())),
+            Some(Value::Boolean(_)) => value
+                .parse()
+                .map(Value::Boolean)
+                .map_err(|_| format!("{}: expected a boolean", self.name())),
+            Some(Value::Integer(_)) => value
+                .parse()
+                .map(Value::Integer)
+                .map_err(|_| format!("{}: expected an integer", self.name())),
+            Some(_) => Err(format!("{}: this key can't be overridden", self.name())),
+            None => Ok(value
+                .parse()
+                .map(Value::Boolean)
+                .or_else(|_| value.parse().map(Value::Integer))
+                .unwrap_or_else(|_| Value::String(value.to_string()))),
+        }
+    }
+}
+
+/// Set the overrides provided through CLI
+pub fn set_cli_overrides(overrides: Vec<ConfigOverride>) {
+    *CLI_OVERRIDES.lock().unwrap() = overrides;
+}
+
+/// Get overrides set through environment variables and CLI.
+/// CLI overrides come last, so they take precedence over environment variables
+pub fn get_overrides() -> Vec<ConfigOverride> {
+    let mut overrides: Vec<ConfigOverride> = std::env::vars()
+        .filter_map(|(name, value)| ConfigOverride::from_env(name.as_str(), value.as_str()))
+        .collect();
+    overrides.extend(CLI_OVERRIDES.lock().unwrap().iter().cloned());
+    overrides
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_parse_override() {
+        let o = ConfigOverride::parse("user_interface.text_editor=vim").unwrap();
+        assert_eq!(o.name().as_str(), "user_interface.text_editor");
+        assert_eq!(o.value.as_str(), "vim");
+        assert_eq!(
+            ConfigOverride::parse("remote.ssh_config=a=b")
+                .unwrap()
+                .value,
+            "a=b"
+        );
+        assert!(ConfigOverride::parse("user_interface.text_editor").is_err());
+        assert!(ConfigOverride::parse("text_editor=vim").is_err());
+        assert!(ConfigOverride::parse("misc.text_editor=vim").is_err());
+        assert!(ConfigOverride::parse("remote.=vim").is_err());
+    }
+
+    #[test]
+    fn should_make_override_from_env() {
+        assert_eq!(
+            ConfigOverride::from_env("TERMSCP_USER_INTERFACE_REMOTE_FILE_FMT", "{NAME}").unwrap(),
+            ConfigOverride::parse("user_interface.remote_file_fmt={NAME}").unwrap()
+        );
+        assert_eq!(
+            ConfigOverride::from_env("TERMSCP_REMOTE_SSH_CONFIG", "~/.ssh/config").unwrap(),
+            ConfigOverride::parse("remote.ssh_config=~/.ssh/config").unwrap()
+        );
+        assert!(ConfigOverride::from_env("TERMSCP_REMOTE", "1").is_none());
+        assert!(ConfigOverride::from_env("TERMSCP_PORTABLE", "1").is_none());
+        assert!(ConfigOverride::from_env("HOME", "/home/omar").is_none());
+    }
+
+    #[test]
+    fn should_apply_and_restore_override() {
+        let original: Value = toml::from_str(
+            r#"
+            [user_interface]
+            text_editor = "nano"
+            show_hidden_files = false
+            notification_threshold = 1024
+
+            [remote]
+            "#,
+        )
+        .unwrap();
+        let mut config = original.clone();
+        let editor = ConfigOverride::parse("user_interface.text_editor=vim").unwrap();
+        let hidden = ConfigOverride::parse("user_interface.show_hidden_files=true").unwrap();
+        let threshold = ConfigOverride::parse("user_interface.notification_threshold=64").unwrap();
+        let ssh_config = ConfigOverride::parse("remote.ssh_config=~/.ssh/config").unwrap();
+        for o in [&editor, &hidden, &threshold, &ssh_config] {
+            assert!(o.apply(&mut config).is_ok());
+            assert!(o.is_set(&config));
+        }
+        assert_eq!(
+            config["user_interface"]["text_editor"].as_str(),
+            Some("vim")
+        );
+        assert_eq!(
+            config["user_interface"]["show_hidden_files"].as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            config["user_interface"]["notification_threshold"].as_integer(),
+            Some(64)
+        );
+        assert_eq!(
+            config["remote"]["ssh_config"].as_str(),
+            Some("~/.ssh/config")
+        );
+        // Bad types
+        assert!(
+            ConfigOverride::parse("user_interface.show_hidden_files=maybe")
+                .unwrap()
+                .apply(&mut config)
+                .is_err()
+        );
+        assert!(
+            ConfigOverride::parse("user_interface.notification_threshold=big")
+                .unwrap()
+                .apply(&mut config)
+                .is_err()
+        );
+        // Restore
+        for o in [&editor, &hidden, &threshold, &ssh_config] {
+            o.restore(&mut config, &original);
+        }
+        assert_eq!(config, original);
+    }
+}
@@ -18,6 +18,15 @@ pub const DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD: u64 = 536870912; // 512MB
 pub struct UserConfig {
     pub user_interface: UserInterfaceConfig,
     pub remote: RemoteConfig,
+    /// View options remembered for directories; the most recently used comes last
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directory_views: Vec<DirectoryView>, // @! Since 0.11.0
+    /// View options of the local explorer changed by the user, restored the next time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_local_view: Option<LastView>, // @! Since 0.11.0
+    /// View options of the remote explorer changed by the user, restored the next time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_remote_view: Option<LastView>, // @! Since 0.11.0
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -29,11 +38,50 @@ pub struct UserInterfaceConfig {
     pub check_for_updates: Option<bool>,      // @! Since 0.3.3
     pub prompt_on_file_replace: Option<bool>, // @! Since 0.7.0; Default True
     pub group_dirs: Option<String>,
+    pub file_sorting: Option<String>, // @! Since 0.11.0; Default "by_name"
+    /// View options for remote host. If unset, the local host ones are used
+    pub remote_show_hidden_files: Option<bool>, // @! Since 0.11.0
+    pub remote_group_dirs: Option<String>, // @! Since 0.11.0
+    pub remote_file_sorting: Option<String>, // @! Since 0.11.0
     /// file fmt. Refers to local host (for backward compatibility)
     pub file_fmt: Option<String>,
     pub remote_file_fmt: Option<String>,     // @! Since 0.5.0
     pub notifications: Option<bool>,         // @! Since 0.7.0; Default true
     pub notification_threshold: Option<u64>, // @! Since 0.7.0; Default 512MB
+    pub symlink_policy: Option<String>,      // @! Since 0.11.0; Default "follow"
+    /// Mapping applied to the owner of transferred files. If unset, ownership is not preserved
+    pub ownership_map: Option<String>, // @! Since 0.11.0
+    /// Format of the explorers status bar. If unset, the default layout is used
+    pub status_bar_fmt: Option<String>, // @! Since 0.11.0
+    /// High contrast theme, text markers and reduced motion
+    pub accessibility: Option<bool>, // @! Since 0.11.0; Default false
+    /// Include/exclude patterns applied by default to recursive transfers
+    pub transfer_filter: Option<String>, // @! Since 0.11.0
+    /// Files larger than this size in bytes are uploaded as chunks. If unset, files are never split
+    pub split_size: Option<u64>, // @! Since 0.11.0
+    /// Upload files to a temporary `.part` file, renamed once the upload is complete
+    pub partial_uploads: Option<bool>, // @! Since 0.11.0; Default false
+    /// Recursive deletes of more files than this must be confirmed typing the amount of files
+    pub delete_confirmation_threshold: Option<usize>, // @! Since 0.11.0
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+/// View options remembered for a directory
+pub struct DirectoryView {
+    pub path: PathBuf,
+    /// Whether the directory is on the remote host
+    pub remote: bool,
+    pub file_sorting: String,
+    pub show_hidden_files: bool,
+    pub reverse_sorting: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+/// View options of an explorer when it was last closed
+pub struct LastView {
+    pub file_sorting: String,
+    pub show_hidden_files: bool,
+    pub reverse_sorting: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -41,6 +89,10 @@ pub struct UserInterfaceConfig {
 pub struct RemoteConfig {
     /// Ssh configuration path. If NONE, won't be read
     pub ssh_config: Option<String>,
+    /// Use active mode for FTP data connections. Passive if unset
+    pub ftp_active_mode: Option<bool>, // @! Since 0.11.0
+    /// Enable compression of the SSH transport for SFTP/SCP
+    pub ssh_compression: Option<bool>, // @! Since 0.11.0
     /// Association between host name and path to private key
     /// NOTE: this parameter must stay as last: <https://github.com/alexcrichton/toml-rs/issues/142>
     pub ssh_keys: HashMap<String, PathBuf>,
@@ -58,10 +110,22 @@ impl Default for UserInterfaceConfig {
             check_for_updates: Some(true),
             prompt_on_file_replace: Some(true),
             group_dirs: None,
+            file_sorting: None,
+            remote_show_hidden_files: None,
+            remote_group_dirs: None,
+            remote_file_sorting: None,
             file_fmt: None,
             remote_file_fmt: None,
             notifications: Some(true),
             notification_threshold: Some(DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD),
+            symlink_policy: None,
+            ownership_map: None,
+            status_bar_fmt: None,
+            accessibility: None,
+            transfer_filter: None,
+            split_size: None,
+            partial_uploads: None,
+            delete_confirmation_threshold: None,
         }
     }
 }
@@ -84,6 +148,8 @@ mod tests {
         let remote: RemoteConfig = RemoteConfig {
             ssh_keys: keys,
             ssh_config: Some(String::from("~/.ssh/config")),
+            ftp_active_mode: Some(true),
+            ssh_compression: Some(true),
         };
         let ui: UserInterfaceConfig = UserInterfaceConfig {
             default_protocol: String::from("SFTP"),
@@ -92,10 +158,22 @@ mod tests {
             check_for_updates: Some(true),
             prompt_on_file_replace: Some(true),
             group_dirs: Some(String::from("first")),
+            file_sorting: Some(String::from("by_natural_name")),
+            remote_show_hidden_files: Some(false),
+            remote_group_dirs: Some(String::from("last")),
+            remote_file_sorting: Some(String::from("by_mtime")),
             file_fmt: Some(String::from("{NAME}")),
             remote_file_fmt: Some(String::from("{USER}")),
             notifications: Some(true),
             notification_threshold: Some(DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD),
+            symlink_policy: Some(String::from("skip")),
+            ownership_map: Some(String::from("current, uid:1000=1001")),
+            status_bar_fmt: Some(String::from("{PATH} {DISK}")),
+            accessibility: Some(true),
+            transfer_filter: Some(String::from("target/, *.o")),
+            split_size: Some(1073741824),
+            partial_uploads: Some(true),
+            delete_confirmation_threshold: Some(100),
         };
         assert_eq!(ui.default_protocol, String::from("SFTP"));
         assert_eq!(ui.text_editor, PathBuf::from("nano"));
@@ -107,7 +185,26 @@ mod tests {
         let cfg: UserConfig = UserConfig {
             user_interface: ui,
             remote,
+            directory_views: vec![DirectoryView {
+                path: PathBuf::from("/var/log"),
+                remote: true,
+                file_sorting: String::from("by_mtime"),
+                show_hidden_files: false,
+                reverse_sorting: false,
+            }],
+            last_local_view: None,
+            last_remote_view: Some(LastView {
+                file_sorting: String::from("by_size"),
+                show_hidden_files: true,
+                reverse_sorting: true,
+            }),
         };
+        assert_eq!(cfg.directory_views.len(), 1);
+        assert!(cfg.last_local_view.is_none());
+        assert_eq!(
+            cfg.last_remote_view.as_ref().unwrap().file_sorting.as_str(),
+            "by_size"
+        );
         assert_eq!(
             *cfg.remote
                 .ssh_keys
@@ -119,18 +216,53 @@ mod tests {
             cfg.remote.ssh_config.as_deref().unwrap(),
             String::from("~/.ssh/config")
         );
+        assert_eq!(cfg.remote.ftp_active_mode, Some(true));
+        assert_eq!(cfg.remote.ssh_compression, Some(true));
         assert_eq!(cfg.user_interface.default_protocol, String::from("SFTP"));
         assert_eq!(cfg.user_interface.text_editor, PathBuf::from("nano"));
         assert_eq!(cfg.user_interface.show_hidden_files, true);
         assert_eq!(cfg.user_interface.check_for_updates, Some(true));
         assert_eq!(cfg.user_interface.prompt_on_file_replace, Some(true));
         assert_eq!(cfg.user_interface.group_dirs, Some(String::from("first")));
+        assert_eq!(
+            cfg.user_interface.file_sorting,
+            Some(String::from("by_natural_name"))
+        );
+        assert_eq!(cfg.user_interface.remote_show_hidden_files, Some(false));
+        assert_eq!(
+            cfg.user_interface.remote_group_dirs,
+            Some(String::from("last"))
+        );
+        assert_eq!(
+            cfg.user_interface.remote_file_sorting,
+            Some(String::from("by_mtime"))
+        );
         assert_eq!(cfg.user_interface.file_fmt, Some(String::from("{NAME}")));
         assert_eq!(
             cfg.user_interface.remote_file_fmt,
             Some(String::from("{USER}"))
         );
         assert_eq!(cfg.user_interface.notifications, Some(true));
+        assert_eq!(
+            cfg.user_interface.symlink_policy,
+            Some(String::from("skip"))
+        );
+        assert_eq!(
+            cfg.user_interface.ownership_map,
+            Some(String::from("current, uid:1000=1001"))
+        );
+        assert_eq!(
+            cfg.user_interface.status_bar_fmt,
+            Some(String::from("{PATH} {DISK}"))
+        );
+        assert_eq!(cfg.user_interface.accessibility, Some(true));
+        assert_eq!(
+            cfg.user_interface.transfer_filter,
+            Some(String::from("target/, *.o"))
+        );
+        assert_eq!(cfg.user_interface.split_size, Some(1073741824));
+        assert_eq!(cfg.user_interface.partial_uploads, Some(true));
+        assert_eq!(cfg.user_interface.delete_confirmation_threshold, Some(100));
         assert_eq!(
             cfg.user_interface.notification_threshold,
             Some(DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD)
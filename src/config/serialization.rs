@@ -111,8 +111,8 @@ mod tests {
 
     use super::*;
 
-    use crate::config::bookmarks::{Bookmark, S3Params, UserHosts};
-    use crate::config::params::UserConfig;
+    use crate::config::bookmarks::{Bookmark, S3Params, Session, UserHosts};
+    use crate::config::params::{DirectoryView, LastView, UserConfig};
     use crate::config::themes::Theme;
     use crate::filetransfer::FileTransferProtocol;
     use crate::utils::test_helpers::create_file_ioers;
@@ -173,12 +173,47 @@ mod tests {
         assert_eq!(cfg.user_interface.prompt_on_file_replace.unwrap(), false);
         assert_eq!(cfg.user_interface.notifications.unwrap(), false);
         assert_eq!(cfg.user_interface.notification_threshold.unwrap(), 1024);
+        assert_eq!(
+            cfg.user_interface.symlink_policy,
+            Some(String::from("link"))
+        );
+        assert_eq!(
+            cfg.user_interface.ownership_map,
+            Some(String::from("uid:1000=1001, gid:100=50"))
+        );
+        assert_eq!(
+            cfg.user_interface.status_bar_fmt,
+            Some(String::from("{HOST}: {PATH} | {RATE}"))
+        );
+        assert_eq!(cfg.user_interface.accessibility, Some(true));
+        assert_eq!(
+            cfg.user_interface.transfer_filter,
+            Some(String::from("target/, .git/, *.o"))
+        );
+        assert_eq!(cfg.user_interface.split_size, Some(4294967296));
+        assert_eq!(cfg.user_interface.partial_uploads, Some(true));
+        assert_eq!(cfg.user_interface.delete_confirmation_threshold, Some(50));
         assert_eq!(cfg.user_interface.group_dirs, Some(String::from("last")));
+        assert_eq!(
+            cfg.user_interface.file_sorting,
+            Some(String::from("by_natural_name"))
+        );
+        assert_eq!(cfg.user_interface.remote_show_hidden_files, Some(false));
+        assert_eq!(
+            cfg.user_interface.remote_group_dirs,
+            Some(String::from("first"))
+        );
+        assert_eq!(
+            cfg.user_interface.remote_file_sorting,
+            Some(String::from("by_mtime"))
+        );
         // Remote
         assert_eq!(
             cfg.remote.ssh_config.as_deref(),
             Some("/home/omar/.ssh/config")
         );
+        assert_eq!(cfg.remote.ftp_active_mode, Some(true));
+        assert_eq!(cfg.remote.ssh_compression, Some(true));
         assert_eq!(
             cfg.user_interface.file_fmt,
             Some(String::from("{NAME} {PEX}"))
@@ -203,6 +238,27 @@ mod tests {
             PathBuf::from("/home/omar/.ssh/beaglebone.key")
         );
         assert!(cfg.remote.ssh_keys.get(&String::from("1.1.1.1")).is_none());
+        // Directory views
+        assert_eq!(
+            cfg.directory_views,
+            vec![DirectoryView {
+                path: PathBuf::from("/var/log"),
+                remote: true,
+                file_sorting: String::from("by_mtime"),
+                show_hidden_files: false,
+                reverse_sorting: true,
+            }]
+        );
+        // Last views
+        assert!(cfg.last_local_view.is_none());
+        assert_eq!(
+            cfg.last_remote_view,
+            Some(LastView {
+                file_sorting: String::from("by_size"),
+                show_hidden_files: true,
+                reverse_sorting: false,
+            })
+        );
     }
 
     #[test]
@@ -220,13 +276,30 @@ mod tests {
         assert_eq!(cfg.user_interface.text_editor, PathBuf::from("vim"));
         assert_eq!(cfg.user_interface.show_hidden_files, true);
         assert_eq!(cfg.user_interface.group_dirs, None);
+        assert!(cfg.user_interface.file_sorting.is_none());
+        assert!(cfg.user_interface.remote_show_hidden_files.is_none());
+        assert!(cfg.user_interface.remote_group_dirs.is_none());
+        assert!(cfg.user_interface.remote_file_sorting.is_none());
         assert!(cfg.user_interface.check_for_updates.is_none());
         assert!(cfg.user_interface.prompt_on_file_replace.is_none());
         assert!(cfg.user_interface.file_fmt.is_none());
         assert!(cfg.user_interface.remote_file_fmt.is_none());
         assert!(cfg.user_interface.notifications.is_none());
+        assert!(cfg.directory_views.is_empty());
+        assert!(cfg.last_local_view.is_none());
+        assert!(cfg.last_remote_view.is_none());
         assert!(cfg.user_interface.notification_threshold.is_none());
+        assert!(cfg.user_interface.symlink_policy.is_none());
+        assert!(cfg.user_interface.ownership_map.is_none());
+        assert!(cfg.user_interface.status_bar_fmt.is_none());
+        assert!(cfg.user_interface.accessibility.is_none());
+        assert!(cfg.user_interface.transfer_filter.is_none());
+        assert!(cfg.user_interface.split_size.is_none());
+        assert!(cfg.user_interface.partial_uploads.is_none());
+        assert!(cfg.user_interface.delete_confirmation_threshold.is_none());
         assert!(cfg.remote.ssh_config.is_none());
+        assert!(cfg.remote.ftp_active_mode.is_none());
+        assert!(cfg.remote.ssh_compression.is_none());
         // Verify keys
         assert_eq!(
             *cfg.remote
@@ -263,6 +336,18 @@ mod tests {
             String::from("192.168.1.31"),
             PathBuf::from("/home/omar/.ssh/id_rsa"),
         );
+        cfg.directory_views.push(DirectoryView {
+            path: PathBuf::from("/var/log"),
+            remote: false,
+            file_sorting: String::from("by_mtime"),
+            show_hidden_files: true,
+            reverse_sorting: false,
+        });
+        cfg.last_local_view = Some(LastView {
+            file_sorting: String::from("by_name"),
+            show_hidden_files: true,
+            reverse_sorting: true,
+        });
         // Serialize
         let writer: Box<dyn Write> = Box::new(std::fs::File::create(toml_file.path()).unwrap());
         assert!(serialize(&cfg, writer).is_ok());
@@ -300,17 +385,43 @@ mod tests {
         check_for_updates = true
         prompt_on_file_replace = false
         group_dirs = "last"
+        file_sorting = "by_natural_name"
+        remote_show_hidden_files = false
+        remote_group_dirs = "first"
+        remote_file_sorting = "by_mtime"
         file_fmt = "{NAME} {PEX}"
         remote_file_fmt = "{NAME} {USER}"
         notifications = false
         notification_threshold = 1024
+        symlink_policy = "link"
+        ownership_map = "uid:1000=1001, gid:100=50"
+        status_bar_fmt = "{HOST}: {PATH} | {RATE}"
+        accessibility = true
+        transfer_filter = "target/, .git/, *.o"
+        split_size = 4294967296
+        partial_uploads = true
+        delete_confirmation_threshold = 50
 
         [remote]
         ssh_config = "/home/omar/.ssh/config"
+        ftp_active_mode = true
+        ssh_compression = true
 
         [remote.ssh_keys]
         "192.168.1.31" = "/home/omar/.ssh/raspberry.key"
         "192.168.1.32" = "/home/omar/.ssh/beaglebone.key"
+
+        [[directory_views]]
+        path = "/var/log"
+        remote = true
+        file_sorting = "by_mtime"
+        show_hidden_files = false
+        reverse_sorting = true
+
+        [last_remote_view]
+        file_sorting = "by_size"
+        show_hidden_files = true
+        reverse_sorting = false
         "#;
         tmpfile.write_all(file_content.as_bytes()).unwrap();
         tmpfile
@@ -391,6 +502,8 @@ mod tests {
         assert_eq!(host.protocol, FileTransferProtocol::Ftp(true));
         assert_eq!(host.username.as_deref().unwrap(), "aws001");
         assert_eq!(host.password, None);
+        assert_eq!(host.read_only, Some(true));
+        assert_eq!(host.production, Some(true));
         // Aws s3 bucket
         let host: &Bookmark = hosts.bookmarks.get("my-bucket").unwrap();
         assert_eq!(host.address, None);
@@ -406,6 +519,8 @@ mod tests {
         assert_eq!(s3.access_key.as_deref().unwrap(), "pippo");
         assert_eq!(s3.secret_access_key.as_deref().unwrap(), "pluto");
         assert_eq!(s3.new_path_style.unwrap(), true);
+        // Sessions are optional
+        assert!(hosts.sessions.is_empty());
     }
 
     #[test]
@@ -430,6 +545,12 @@ mod tests {
                 username: Some(String::from("root")),
                 password: None,
                 directory: None,
+                ssh_compression: None,
+                filename_encoding: None,
+                upload_limit: None,
+                download_limit: None,
+                read_only: None,
+                production: None,
                 s3: None,
             },
         );
@@ -442,6 +563,12 @@ mod tests {
                 username: Some(String::from("cvisintin")),
                 password: Some(String::from("password")),
                 directory: Some(PathBuf::from("/tmp")),
+                ssh_compression: None,
+                filename_encoding: None,
+                upload_limit: None,
+                download_limit: None,
+                read_only: None,
+                production: None,
                 s3: None,
             },
         );
@@ -454,6 +581,12 @@ mod tests {
                 username: None,
                 password: None,
                 directory: None,
+                ssh_compression: None,
+                filename_encoding: None,
+                upload_limit: None,
+                download_limit: None,
+                read_only: None,
+                production: None,
                 s3: Some(S3Params {
                     bucket: "veeso".to_string(),
                     region: Some("eu-west-1".to_string()),
@@ -475,12 +608,45 @@ mod tests {
                 username: Some(String::from("omar")),
                 password: Some(String::from("aaa")),
                 directory: Some(PathBuf::from("/tmp")),
+                ssh_compression: None,
+                filename_encoding: None,
+                upload_limit: None,
+                download_limit: None,
+                read_only: None,
+                production: None,
                 s3: None,
             },
         );
         let tmpfile: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
         // Serialize
-        let hosts: UserHosts = UserHosts { bookmarks, recents };
+        let mut sessions: HashMap<String, Session> = HashMap::with_capacity(1);
+        sessions.insert(
+            String::from("scp://omar@192.168.1.254:3022"),
+            Session {
+                local_directory: PathBuf::from("/home/omar"),
+                remote_directory: PathBuf::from("/tmp"),
+                local_cursor: Some(String::from("Downloads")),
+                remote_cursor: None,
+                local_selection: vec![],
+                remote_selection: vec![String::from("a.txt"), String::from("b.txt")],
+                local_commands: vec![],
+                remote_commands: vec![String::from("df -h")],
+                local_marks: HashMap::new(),
+                remote_marks: HashMap::from([
+                    (String::from("1"), PathBuf::from("/var/log")),
+                    (String::from("2"), PathBuf::from("/srv")),
+                ]),
+                saved_at: 1672531200,
+            },
+        );
+        let mut hotkeys: HashMap<String, String> = HashMap::new();
+        hotkeys.insert(String::from("1"), String::from("raspberry"));
+        let hosts: UserHosts = UserHosts {
+            bookmarks,
+            recents,
+            sessions,
+            hotkeys,
+        };
         assert!(serialize(&hosts, Box::new(tmpfile)).is_ok());
     }
 
@@ -515,7 +681,7 @@ mod tests {
         [bookmarks]
         raspberrypi2 = { address = "192.168.1.31", port = 22, protocol = "SFTP", username = "root", password = "mypassword" }
         msi-estrem = { address = "192.168.1.30", port = 22, protocol = "SFTP", username = "cvisintin", password = "mysecret", directory = "/tmp" }
-        aws-server-prod1 = { address = "51.23.67.12", port = 21, protocol = "FTPS", username = "aws001" }
+        aws-server-prod1 = { address = "51.23.67.12", port = 21, protocol = "FTPS", username = "aws001", read_only = true, production = true }
         
         [bookmarks.my-bucket]
         protocol = "S3"
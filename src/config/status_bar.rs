@@ -0,0 +1,158 @@
+//! ## Status bar
+//!
+//! `status_bar` provides the format of the status bars of the file explorers
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Values which can be displayed in a status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusBarToken {
+    /// Free and total space of the filesystem
+    Disk,
+    /// Whether hidden files are shown
+    Hidden,
+    /// Host name
+    Host,
+    /// Working directory
+    Path,
+    /// Speed of the last transfer
+    Rate,
+    /// File sorting criteria
+    Sorting,
+    /// Synchronized browsing state
+    Sync,
+    /// Watched paths and time of the last change synchronized by the file watcher
+    Watch,
+}
+
+impl StatusBarToken {
+    const ALL: [StatusBarToken; 8] = [
+        Self::Disk,
+        Self::Hidden,
+        Self::Host,
+        Self::Path,
+        Self::Rate,
+        Self::Sorting,
+        Self::Sync,
+        Self::Watch,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Disk => "DISK",
+            Self::Hidden => "HIDDEN",
+            Self::Host => "HOST",
+            Self::Path => "PATH",
+            Self::Rate => "RATE",
+            Self::Sorting => "SORTING",
+            Self::Sync => "SYNC",
+            Self::Watch => "WATCH",
+        }
+    }
+}
+
+/// A piece of the status bar: either text or a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusBarSegment {
+    Text(String),
+    Token(StatusBarToken),
+}
+
+/// Format of a status bar, written as text with `{TOKEN}` placeholders
+/// (e.g. `{PATH} | {SORTING} | {DISK}`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusBarFmt {
+    segments: Vec<StatusBarSegment>,
+}
+
+impl StatusBarFmt {
+    /// Returns the pieces of the status bar
+    pub fn segments(&self) -> &[StatusBarSegment] {
+        self.segments.as_slice()
+    }
+}
+
+impl fmt::Display for StatusBarFmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in self.segments.iter() {
+            match segment {
+                StatusBarSegment::Text(text) => write!(f, "{}", text)?,
+                StatusBarSegment::Token(token) => write!(f, "{{{}}}", token.name())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StatusBarFmt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                segments.push(StatusBarSegment::Text(rest[..start].to_string()));
+            }
+            let end = rest[start..]
+                .find('}')
+                .map(|x| start + x)
+                .ok_or_else(|| String::from("unterminated token"))?;
+            let name = &rest[start + 1..end];
+            let token = StatusBarToken::ALL
+                .iter()
+                .find(|x| x.name().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| format!("unknown token \"{}\"", name))?;
+            segments.push(StatusBarSegment::Token(*token));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            segments.push(StatusBarSegment::Text(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_parse_status_bar_fmt() {
+        let fmt = StatusBarFmt::from_str("{PATH} | {sorting}{DISK}").unwrap();
+        assert_eq!(
+            fmt.segments(),
+            &[
+                StatusBarSegment::Token(StatusBarToken::Path),
+                StatusBarSegment::Text(String::from(" | ")),
+                StatusBarSegment::Token(StatusBarToken::Sorting),
+                StatusBarSegment::Token(StatusBarToken::Disk),
+            ]
+        );
+        assert_eq!(fmt.to_string().as_str(), "{PATH} | {SORTING}{DISK}");
+        assert!(StatusBarFmt::from_str("").unwrap().segments().is_empty());
+        assert!(StatusBarFmt::from_str("{PATH").is_err());
+        assert!(StatusBarFmt::from_str("{FOO}").is_err());
+    }
+
+    #[test]
+    fn should_format_status_bar_fmt() {
+        let fmt = "{HOST}:{PATH} [{hidden}] {SYNC} | {RATE} | {Disk} free | {watch}";
+        assert_eq!(
+            StatusBarFmt::from_str(fmt).unwrap().to_string().as_str(),
+            "{HOST}:{PATH} [{HIDDEN}] {SYNC} | {RATE} | {DISK} free | {WATCH}"
+        );
+        let fmt = StatusBarFmt::from_str("Sorting: { SORTING }").unwrap();
+        assert_eq!(
+            fmt.segments(),
+            &[
+                StatusBarSegment::Text(String::from("Sorting: ")),
+                StatusBarSegment::Token(StatusBarToken::Sorting),
+            ]
+        );
+    }
+}
@@ -188,6 +188,41 @@ impl Default for Theme {
     }
 }
 
+impl Theme {
+    /// High contrast variant of the theme, used in accessibility mode
+    pub fn high_contrast() -> Self {
+        Self {
+            auth_address: Color::White,
+            auth_bookmarks: Color::Yellow,
+            auth_password: Color::White,
+            auth_port: Color::White,
+            auth_protocol: Color::White,
+            auth_recents: Color::Yellow,
+            auth_username: Color::White,
+            misc_error_dialog: Color::LightRed,
+            misc_info_dialog: Color::White,
+            misc_input_dialog: Color::White,
+            misc_keys: Color::Yellow,
+            misc_quit_dialog: Color::Yellow,
+            misc_save_dialog: Color::White,
+            misc_warn_dialog: Color::Yellow,
+            transfer_local_explorer_background: Color::Black,
+            transfer_local_explorer_foreground: Color::White,
+            transfer_local_explorer_highlighted: Color::Yellow,
+            transfer_log_background: Color::Black,
+            transfer_log_window: Color::White,
+            transfer_progress_bar_partial: Color::White,
+            transfer_progress_bar_full: Color::White,
+            transfer_remote_explorer_background: Color::Black,
+            transfer_remote_explorer_foreground: Color::White,
+            transfer_remote_explorer_highlighted: Color::Yellow,
+            transfer_status_hidden: Color::White,
+            transfer_status_sorting: Color::Yellow,
+            transfer_status_sync_browsing: Color::White,
+        }
+    }
+}
+
 // -- deserializer
 
 fn deserialize_color<'de, D>(deserializer: D) -> Result<Color, D::Error>
@@ -248,4 +283,13 @@ mod test {
         assert_eq!(theme.transfer_status_sorting, Color::LightYellow);
         assert_eq!(theme.transfer_status_sync_browsing, Color::LightGreen);
     }
+
+    #[test]
+    fn should_get_high_contrast_theme() {
+        let theme: Theme = Theme::high_contrast();
+        assert_eq!(theme.transfer_local_explorer_background, Color::Black);
+        assert_eq!(theme.transfer_local_explorer_foreground, Color::White);
+        assert_eq!(theme.transfer_local_explorer_highlighted, Color::Yellow);
+        assert_eq!(theme.misc_error_dialog, Color::LightRed);
+    }
 }
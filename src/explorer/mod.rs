@@ -6,6 +6,7 @@
 pub(crate) mod builder;
 mod formatter;
 // Locals
+use crate::utils::string::natural_cmp;
 use formatter::Formatter;
 // Ext
 use remotefs::fs::File;
@@ -21,6 +22,7 @@ bitflags! {
     /// ExplorerOpts are bit options which provides different behaviours to `FileExplorer`
     pub(crate) struct ExplorerOpts: u32 {
         const SHOW_HIDDEN_FILES = 0b00000001;
+        const REVERSE_SORTING = 0b00000010;
     }
 }
 
@@ -28,9 +30,21 @@ bitflags! {
 #[derive(Copy, Clone, PartialEq, Eq, std::fmt::Debug)]
 pub enum FileSorting {
     Name,
+    NaturalName,
     ModifyTime,
     CreationTime,
     Size,
+    Extension,
+    Owner,
+    Permissions,
+}
+
+/// ExplorerView describes the view options of a file explorer which can be changed while browsing
+#[derive(Copy, Clone, PartialEq, Eq, std::fmt::Debug)]
+pub struct ExplorerView {
+    pub file_sorting: FileSorting,
+    pub hidden_files: bool,
+    pub reverse_sorting: bool,
 }
 
 /// GroupDirs defines how directories should be grouped in sorting files
@@ -44,6 +58,7 @@ pub enum GroupDirs {
 pub struct FileExplorer {
     pub wrkdir: PathBuf,                      // Current directory
     pub(crate) dirstack: VecDeque<PathBuf>,   // Stack of visited directory (max 16)
+    pub(crate) fwdstack: VecDeque<PathBuf>,   // Stack of directories left going back
     pub(crate) stack_size: usize,             // Directory stack size
     pub(crate) file_sorting: FileSorting,     // File sorting criteria
     pub(crate) group_dirs: Option<GroupDirs>, // If Some, defines how to group directories
@@ -57,6 +72,7 @@ impl Default for FileExplorer {
         FileExplorer {
             wrkdir: PathBuf::from("/"),
             dirstack: VecDeque::with_capacity(16),
+            fwdstack: VecDeque::new(),
             stack_size: 16,
             file_sorting: FileSorting::Name,
             group_dirs: None,
@@ -68,14 +84,11 @@ impl Default for FileExplorer {
 }
 
 impl FileExplorer {
-    /// push directory to stack
+    /// push directory to stack.
+    /// Since this is a new navigation, the directories left going back are discarded
     pub fn pushd(&mut self, dir: &Path) {
-        // Check if stack would overflow the size
-        while self.dirstack.len() >= self.stack_size {
-            self.dirstack.pop_front(); // Start cleaning events from back
-        }
-        // Eventually push front the new record
-        self.dirstack.push_back(PathBuf::from(dir));
+        self.fwdstack.clear();
+        Self::push_stack(&mut self.dirstack, dir, self.stack_size);
     }
 
     /// Pop directory from the stack and return the directory
@@ -83,6 +96,71 @@ impl FileExplorer {
         self.dirstack.pop_back()
     }
 
+    /// Go back in history: pop the previous directory from the stack and keep the working directory
+    /// to go forward to it later
+    pub fn back(&mut self) -> Option<PathBuf> {
+        let prev = self.popd()?;
+        Self::push_stack(&mut self.fwdstack, self.wrkdir.as_path(), self.stack_size);
+        Some(prev)
+    }
+
+    /// Go forward in history: returns the directory left with the last `back()`
+    pub fn forward(&mut self) -> Option<PathBuf> {
+        let next = self.fwdstack.pop_back()?;
+        Self::push_stack(&mut self.dirstack, self.wrkdir.as_path(), self.stack_size);
+        Some(next)
+    }
+
+    /// Returns the visited directories, from the oldest to the newest; the working directory is at `history_index()`
+    pub fn history(&self) -> Vec<&Path> {
+        self.dirstack
+            .iter()
+            .chain(std::iter::once(&self.wrkdir))
+            .chain(self.fwdstack.iter().rev())
+            .map(|x| x.as_path())
+            .collect()
+    }
+
+    /// Returns the position of the working directory in `history()`
+    pub fn history_index(&self) -> usize {
+        self.dirstack.len()
+    }
+
+    /// Move back or forward in history to the directory at `index` in `history()` and return it.
+    /// Returns `None` if `index` is the working directory or it is out of range
+    pub fn jump(&mut self, index: usize) -> Option<PathBuf> {
+        let current = self.history_index();
+        if index < current {
+            let wrkdir = self.wrkdir.clone();
+            Self::push_stack(&mut self.fwdstack, wrkdir.as_path(), self.stack_size);
+            while self.dirstack.len() > index + 1 {
+                let dir = self.dirstack.pop_back().unwrap();
+                Self::push_stack(&mut self.fwdstack, dir.as_path(), self.stack_size);
+            }
+            self.dirstack.pop_back()
+        } else if index > current && index - current <= self.fwdstack.len() {
+            let wrkdir = self.wrkdir.clone();
+            Self::push_stack(&mut self.dirstack, wrkdir.as_path(), self.stack_size);
+            for _ in 1..(index - current) {
+                let dir = self.fwdstack.pop_back().unwrap();
+                Self::push_stack(&mut self.dirstack, dir.as_path(), self.stack_size);
+            }
+            self.fwdstack.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Push `dir` to `stack`, removing the oldest records if `size` is exceeded
+    fn push_stack(stack: &mut VecDeque<PathBuf>, dir: &Path, size: usize) {
+        // Check if stack would overflow the size
+        while stack.len() >= size {
+            stack.pop_front(); // Start cleaning events from back
+        }
+        // Eventually push front the new record
+        stack.push_back(PathBuf::from(dir));
+    }
+
     /// Set Explorer files
     /// This method will also sort entries based on current options
     /// Once all sorting have been performed, index is moved to first valid entry.
@@ -183,9 +261,16 @@ impl FileExplorer {
         // Choose sorting method
         match &self.file_sorting {
             FileSorting::Name => self.sort_files_by_name(),
+            FileSorting::NaturalName => self.sort_files_by_natural_name(),
             FileSorting::CreationTime => self.sort_files_by_creation_time(),
             FileSorting::ModifyTime => self.sort_files_by_mtime(),
             FileSorting::Size => self.sort_files_by_size(),
+            FileSorting::Extension => self.sort_files_by_extension(),
+            FileSorting::Owner => self.sort_files_by_owner(),
+            FileSorting::Permissions => self.sort_files_by_permissions(),
+        }
+        if self.opts.intersects(ExplorerOpts::REVERSE_SORTING) {
+            self.files.reverse();
         }
         // Directories first (NOTE: MUST COME AFTER OTHER SORTING)
         // Group directories if necessary
@@ -202,6 +287,12 @@ impl FileExplorer {
         self.files.sort_by_key(|x: &File| x.name().to_lowercase());
     }
 
+    /// Sort explorer files by their name in natural order (e.g. `file2` before `file10`)
+    fn sort_files_by_natural_name(&mut self) {
+        self.files
+            .sort_by(|a: &File, b: &File| natural_cmp(a.name().as_str(), b.name().as_str()));
+    }
+
     /// Sort files by mtime; the newest comes first
     fn sort_files_by_mtime(&mut self) {
         self.files
@@ -220,6 +311,28 @@ impl FileExplorer {
             .sort_by_key(|b: &File| Reverse(b.metadata().size));
     }
 
+    /// Sort files by extension; files with the same extension are sorted by name
+    fn sort_files_by_extension(&mut self) {
+        self.files.sort_by_key(|x: &File| {
+            (
+                x.extension().map(|x| x.to_lowercase()),
+                x.name().to_lowercase(),
+            )
+        });
+    }
+
+    /// Sort files by owner uid; files with the same owner are sorted by name
+    fn sort_files_by_owner(&mut self) {
+        self.files
+            .sort_by_key(|x: &File| (x.metadata().uid, x.name().to_lowercase()));
+    }
+
+    /// Sort files by permissions; files with the same permissions are sorted by name
+    fn sort_files_by_permissions(&mut self) {
+        self.files
+            .sort_by_key(|x: &File| (x.metadata().mode.map(u32::from), x.name().to_lowercase()));
+    }
+
     /// Sort files; directories come first
     fn sort_files_directories_first(&mut self) {
         self.files.sort_by_key(|x: &File| !x.is_dir());
@@ -230,11 +343,41 @@ impl FileExplorer {
         self.files.sort_by_key(|x: &File| x.is_dir());
     }
 
+    /// Get current view options
+    pub fn view(&self) -> ExplorerView {
+        ExplorerView {
+            file_sorting: self.file_sorting,
+            hidden_files: self.hidden_files_visible(),
+            reverse_sorting: self.reverse_sorting(),
+        }
+    }
+
+    /// Set view options; then sort files
+    pub fn set_view(&mut self, view: ExplorerView) {
+        self.file_sorting = view.file_sorting;
+        self.opts
+            .set(ExplorerOpts::SHOW_HIDDEN_FILES, view.hidden_files);
+        self.opts
+            .set(ExplorerOpts::REVERSE_SORTING, view.reverse_sorting);
+        self.sort();
+    }
+
     /// Enable/disable hidden files
     pub fn toggle_hidden_files(&mut self) {
         self.opts.toggle(ExplorerOpts::SHOW_HIDDEN_FILES);
     }
 
+    /// Reverse the order of files, regardless of the sorting method
+    pub fn toggle_reverse_sorting(&mut self) {
+        self.opts.toggle(ExplorerOpts::REVERSE_SORTING);
+        self.sort();
+    }
+
+    /// Returns whether sorting is reversed
+    pub fn reverse_sorting(&self) -> bool {
+        self.opts.intersects(ExplorerOpts::REVERSE_SORTING)
+    }
+
     /// Returns whether hidden files are visible
     pub fn hidden_files_visible(&self) -> bool {
         self.opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES)
@@ -249,7 +392,11 @@ impl ToString for FileSorting {
             FileSorting::CreationTime => "by_creation_time",
             FileSorting::ModifyTime => "by_mtime",
             FileSorting::Name => "by_name",
+            FileSorting::NaturalName => "by_natural_name",
             FileSorting::Size => "by_size",
+            FileSorting::Extension => "by_extension",
+            FileSorting::Owner => "by_owner",
+            FileSorting::Permissions => "by_permissions",
         })
     }
 }
@@ -261,7 +408,11 @@ impl FromStr for FileSorting {
             "by_creation_time" => Ok(FileSorting::CreationTime),
             "by_mtime" => Ok(FileSorting::ModifyTime),
             "by_name" => Ok(FileSorting::Name),
+            "by_natural_name" => Ok(FileSorting::NaturalName),
             "by_size" => Ok(FileSorting::Size),
+            "by_extension" => Ok(FileSorting::Extension),
+            "by_owner" => Ok(FileSorting::Owner),
+            "by_permissions" => Ok(FileSorting::Permissions),
             _ => Err(()),
         }
     }
@@ -339,6 +490,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fs_explorer_history() {
+        let mut explorer: FileExplorer = FileExplorer {
+            wrkdir: PathBuf::from("/home/omar"),
+            ..Default::default()
+        };
+        explorer.pushd(Path::new("/"));
+        explorer.pushd(Path::new("/tmp"));
+        assert_eq!(
+            explorer.history(),
+            vec![Path::new("/"), Path::new("/tmp"), Path::new("/home/omar")]
+        );
+        assert_eq!(explorer.history_index(), 2);
+        // Back
+        assert_eq!(explorer.back().unwrap(), PathBuf::from("/tmp"));
+        explorer.wrkdir = PathBuf::from("/tmp");
+        assert_eq!(explorer.history_index(), 1);
+        // Forward
+        assert_eq!(explorer.forward().unwrap(), PathBuf::from("/home/omar"));
+        explorer.wrkdir = PathBuf::from("/home/omar");
+        assert!(explorer.forward().is_none());
+        // Jump to first
+        assert_eq!(explorer.jump(0).unwrap(), PathBuf::from("/"));
+        explorer.wrkdir = PathBuf::from("/");
+        assert_eq!(
+            explorer.history(),
+            vec![Path::new("/"), Path::new("/tmp"), Path::new("/home/omar")]
+        );
+        assert_eq!(explorer.history_index(), 0);
+        assert!(explorer.jump(0).is_none());
+        assert!(explorer.jump(3).is_none());
+        // Jump to last
+        assert_eq!(explorer.jump(2).unwrap(), PathBuf::from("/home/omar"));
+        explorer.wrkdir = PathBuf::from("/home/omar");
+        assert_eq!(explorer.history_index(), 2);
+        // New navigation discards forward history
+        explorer.back();
+        explorer.wrkdir = PathBuf::from("/tmp");
+        explorer.pushd(Path::new("/tmp"));
+        explorer.wrkdir = PathBuf::from("/dev");
+        assert!(explorer.forward().is_none());
+        assert_eq!(
+            explorer.history(),
+            vec![Path::new("/"), Path::new("/tmp"), Path::new("/dev")]
+        );
+    }
+
     #[test]
     fn test_fs_explorer_files() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -391,6 +589,27 @@ mod tests {
         assert_eq!(explorer.files.get(8).unwrap().name(), "src");
     }
 
+    #[test]
+    fn test_fs_explorer_sort_by_natural_name() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("v1.10", true),
+            make_fs_entry("file10.txt", false),
+            make_fs_entry("v1.9", true),
+            make_fs_entry("File2.txt", false),
+            make_fs_entry("file1.txt", false),
+        ]);
+        explorer.sort_by(FileSorting::NaturalName);
+        assert_eq!(
+            explorer
+                .files
+                .iter()
+                .map(|x| x.name())
+                .collect::<Vec<String>>(),
+            vec!["file1.txt", "File2.txt", "file10.txt", "v1.9", "v1.10"]
+        );
+    }
+
     #[test]
     fn test_fs_explorer_sort_by_mtime() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -439,6 +658,110 @@ mod tests {
         assert_eq!(explorer.files.get(2).unwrap().name(), "CONTRIBUTING.md");
     }
 
+    #[test]
+    fn test_fs_explorer_sort_by_extension() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("README.md", false),
+            make_fs_entry("src", true),
+            make_fs_entry("Cargo.toml", false),
+            make_fs_entry("CHANGELOG.md", false),
+        ]);
+        explorer.sort_by(FileSorting::Extension);
+        assert_eq!(
+            explorer
+                .files
+                .iter()
+                .map(|x| x.name())
+                .collect::<Vec<String>>(),
+            vec!["src", "CHANGELOG.md", "README.md", "Cargo.toml"]
+        );
+    }
+
+    #[test]
+    fn test_fs_explorer_sort_by_owner() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        let mut entry = make_fs_entry("README.md", false);
+        entry.metadata.uid = Some(1000);
+        explorer.set_files(vec![
+            entry,
+            make_fs_entry("src", true),
+            make_fs_entry("Cargo.toml", false),
+        ]);
+        explorer.sort_by(FileSorting::Owner);
+        assert_eq!(explorer.files[0].name(), "Cargo.toml");
+        assert_eq!(explorer.files[1].name(), "src");
+        assert_eq!(explorer.files[2].name(), "README.md");
+    }
+
+    #[test]
+    fn test_fs_explorer_sort_by_permissions() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("src", true),
+            make_fs_entry("README.md", false),
+            make_fs_entry("Cargo.toml", false),
+        ]);
+        explorer.sort_by(FileSorting::Permissions);
+        assert_eq!(explorer.files[0].name(), "Cargo.toml");
+        assert_eq!(explorer.files[1].name(), "README.md");
+        assert_eq!(explorer.files[2].name(), "src");
+    }
+
+    #[test]
+    fn test_fs_explorer_reverse_sorting() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("README.md", false),
+            make_fs_entry("src", true),
+            make_fs_entry("docs", true),
+            make_fs_entry("Cargo.toml", false),
+        ]);
+        explorer.sort_by(FileSorting::Name);
+        assert!(!explorer.reverse_sorting());
+        explorer.toggle_reverse_sorting();
+        assert!(explorer.reverse_sorting());
+        assert_eq!(explorer.files[0].name(), "src");
+        assert_eq!(explorer.files[3].name(), "Cargo.toml");
+        // Reverse is kept when sorting method changes
+        explorer.sort_by(FileSorting::Permissions);
+        assert_eq!(explorer.files[0].name(), "src");
+        // Directories are still grouped
+        explorer.group_dirs_by(Some(GroupDirs::First));
+        assert_eq!(explorer.files[0].name(), "src");
+        assert_eq!(explorer.files[1].name(), "docs");
+        assert_eq!(explorer.files[2].name(), "README.md");
+        explorer.toggle_reverse_sorting();
+        assert_eq!(explorer.files[0].name(), "docs");
+    }
+
+    #[test]
+    fn test_fs_explorer_view() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("README.md", false),
+            make_fs_entry("src", true),
+            make_fs_entry(".git", true),
+        ]);
+        assert_eq!(
+            explorer.view(),
+            ExplorerView {
+                file_sorting: FileSorting::Name,
+                hidden_files: false,
+                reverse_sorting: false,
+            }
+        );
+        let view = ExplorerView {
+            file_sorting: FileSorting::Size,
+            hidden_files: true,
+            reverse_sorting: true,
+        };
+        explorer.set_view(view);
+        assert_eq!(explorer.view(), view);
+        assert_eq!(explorer.iter_files().count(), 3);
+        assert_eq!(explorer.files[0].name(), "src");
+    }
+
     #[test]
     fn test_fs_explorer_sort_by_name_and_dirs_first() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -536,7 +859,11 @@ mod tests {
         assert_eq!(FileSorting::CreationTime.to_string(), "by_creation_time");
         assert_eq!(FileSorting::ModifyTime.to_string(), "by_mtime");
         assert_eq!(FileSorting::Name.to_string(), "by_name");
+        assert_eq!(FileSorting::NaturalName.to_string(), "by_natural_name");
         assert_eq!(FileSorting::Size.to_string(), "by_size");
+        assert_eq!(FileSorting::Extension.to_string(), "by_extension");
+        assert_eq!(FileSorting::Owner.to_string(), "by_owner");
+        assert_eq!(FileSorting::Permissions.to_string(), "by_permissions");
         assert_eq!(
             FileSorting::from_str("by_creation_time").ok().unwrap(),
             FileSorting::CreationTime
@@ -549,10 +876,26 @@ mod tests {
             FileSorting::from_str("by_name").ok().unwrap(),
             FileSorting::Name
         );
+        assert_eq!(
+            FileSorting::from_str("by_natural_name").ok().unwrap(),
+            FileSorting::NaturalName
+        );
         assert_eq!(
             FileSorting::from_str("by_size").ok().unwrap(),
             FileSorting::Size
         );
+        assert_eq!(
+            FileSorting::from_str("by_extension").ok().unwrap(),
+            FileSorting::Extension
+        );
+        assert_eq!(
+            FileSorting::from_str("by_owner").ok().unwrap(),
+            FileSorting::Owner
+        );
+        assert_eq!(
+            FileSorting::from_str("by_permissions").ok().unwrap(),
+            FileSorting::Permissions
+        );
         assert!(FileSorting::from_str("omar").is_err());
         // Group dirs
         assert_eq!(GroupDirs::First.to_string(), "first");
@@ -2,7 +2,9 @@
 //!
 //! Remotefs client builder
 
+use super::encoding::{EncodedFs, FilenameEncoding};
 use super::params::{AwsS3Params, GenericProtocolParams};
+use super::ssh_config::SshConfigFs;
 use super::{FileTransferProtocol, ProtocolParams};
 use crate::system::config_client::ConfigClient;
 use crate::system::sshkey_storage::SshKeyStorage;
@@ -11,13 +13,19 @@ use remotefs::RemoteFs;
 use remotefs_aws_s3::AwsS3Fs;
 use remotefs_ftp::FtpFs;
 use remotefs_ssh::{ScpFs, SftpFs, SshOpts};
-use std::path::PathBuf;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
 
 /// Remotefs builder
 pub struct Builder;
 
 impl Builder {
     /// Build RemoteFs client from protocol and params.
+    /// If a filename encoding other than UTF-8 is set for SCP or SFTP, paths are converted by the client.
+    /// The encoding is ignored for FTP, whose client decodes paths and listings as UTF-8 before
+    /// they can be converted.
     ///
     /// if protocol and parameters are inconsistent, the function will panic.
     pub fn build(
@@ -25,18 +33,25 @@ impl Builder {
         params: ProtocolParams,
         config_client: &ConfigClient,
     ) -> Box<dyn RemoteFs> {
-        match (protocol, params) {
+        let encoding = match (protocol, &params) {
+            (
+                FileTransferProtocol::Scp | FileTransferProtocol::Sftp,
+                ProtocolParams::Generic(params),
+            ) => params.encoding.as_deref().and_then(FilenameEncoding::new),
+            _ => None,
+        };
+        let client: Box<dyn RemoteFs> = match (protocol, params) {
             (FileTransferProtocol::AwsS3, ProtocolParams::AwsS3(params)) => {
                 Box::new(Self::aws_s3_client(params))
             }
             (FileTransferProtocol::Ftp(secure), ProtocolParams::Generic(params)) => {
-                Box::new(Self::ftp_client(params, secure))
+                Box::new(Self::ftp_client(params, secure, config_client))
             }
             (FileTransferProtocol::Scp, ProtocolParams::Generic(params)) => {
-                Box::new(Self::scp_client(params, config_client))
+                Self::scp_client(params, config_client)
             }
             (FileTransferProtocol::Sftp, ProtocolParams::Generic(params)) => {
-                Box::new(Self::sftp_client(params, config_client))
+                Self::sftp_client(params, config_client)
             }
             (protocol, params) => {
                 error!("Invalid params for protocol '{:?}'", protocol);
@@ -45,6 +60,10 @@ impl Builder {
                     protocol, params
                 )
             }
+        };
+        match encoding {
+            Some(encoding) => Box::new(EncodedFs::new(client, encoding)),
+            None => client,
         }
     }
 
@@ -76,8 +95,16 @@ impl Builder {
     }
 
     /// Build ftp client from parameters
-    fn ftp_client(params: GenericProtocolParams, secure: bool) -> FtpFs {
-        let mut client = FtpFs::new(params.address, params.port).passive_mode();
+    fn ftp_client(
+        params: GenericProtocolParams,
+        secure: bool,
+        config_client: &ConfigClient,
+    ) -> FtpFs {
+        let mut client = FtpFs::new(params.address, params.port);
+        client = match config_client.get_ftp_active_mode() {
+            true => client.active_mode(),
+            false => client.passive_mode(),
+        };
         if let Some(username) = params.username {
             client = client.username(username);
         }
@@ -91,17 +118,44 @@ impl Builder {
     }
 
     /// Build scp client
-    fn scp_client(params: GenericProtocolParams, config_client: &ConfigClient) -> ScpFs {
-        Self::build_ssh_opts(params, config_client).into()
+    fn scp_client(
+        params: GenericProtocolParams,
+        config_client: &ConfigClient,
+    ) -> Box<dyn RemoteFs> {
+        let (opts, config) = Self::build_ssh_opts(params, config_client);
+        Self::with_ssh_config(Box::new(ScpFs::from(opts)), config)
     }
 
     /// Build sftp client
-    fn sftp_client(params: GenericProtocolParams, config_client: &ConfigClient) -> SftpFs {
-        Self::build_ssh_opts(params, config_client).into()
+    fn sftp_client(
+        params: GenericProtocolParams,
+        config_client: &ConfigClient,
+    ) -> Box<dyn RemoteFs> {
+        let (opts, config) = Self::build_ssh_opts(params, config_client);
+        Self::with_ssh_config(Box::new(SftpFs::from(opts)), config)
     }
 
-    /// Build ssh options from generic protocol params and client configuration
-    fn build_ssh_opts(params: GenericProtocolParams, config_client: &ConfigClient) -> SshOpts {
+    /// If an ssh configuration file has been generated for `client`, the client takes its ownership
+    fn with_ssh_config(
+        client: Box<dyn RemoteFs>,
+        config: Option<NamedTempFile>,
+    ) -> Box<dyn RemoteFs> {
+        match config {
+            Some(config) => Box::new(SshConfigFs::new(client, config)),
+            None => client,
+        }
+    }
+
+    /// Build ssh options from generic protocol params and client configuration.
+    /// Returns the configuration file generated for the options too, if any
+    fn build_ssh_opts(
+        params: GenericProtocolParams,
+        config_client: &ConfigClient,
+    ) -> (SshOpts, Option<NamedTempFile>) {
+        // Bookmark option takes precedence over configuration
+        let compression = params
+            .compression
+            .or_else(|| config_client.get_ssh_compression().then_some(true));
         let mut opts = SshOpts::new(params.address)
             .key_storage(Box::new(Self::make_ssh_storage(config_client)))
             .port(params.port);
@@ -111,10 +165,45 @@ impl Builder {
         if let Some(password) = params.password {
             opts = opts.password(password);
         }
-        if let Some(config_path) = config_client.get_ssh_config() {
-            opts = opts.config_file(PathBuf::from(config_path));
+        let mut config_path = config_client.get_ssh_config().map(PathBuf::from);
+        let mut config_file = None;
+        if let Some(compression) = compression {
+            match Self::make_ssh_config(config_path.as_deref(), compression) {
+                Ok(file) => {
+                    config_path = Some(file.path().to_path_buf());
+                    config_file = Some(file);
+                }
+                Err(err) => error!("Could not write ssh configuration: {}", err),
+            }
+        }
+        if let Some(config_path) = config_path {
+            opts = opts.config_file(config_path);
         }
-        opts
+        (opts, config_file)
+    }
+
+    /// Write a copy of the ssh configuration at `config_path` (if any), with the `Compression`
+    /// option set for all hosts, into a new temporary file, readable only by the user.
+    /// The file is removed once the returned handle is dropped.
+    ///
+    /// Since ssh2-config merges the matching hosts in order, the last entry overrides the user's options.
+    fn make_ssh_config(config_path: Option<&Path>, compression: bool) -> io::Result<NamedTempFile> {
+        let config = match config_path {
+            Some(p) => fs::read_to_string(p)?,
+            None => String::new(),
+        };
+        let mut file = tempfile::Builder::new()
+            .prefix("termscp-")
+            .suffix(".ssh_config")
+            .tempfile()?;
+        write!(
+            file,
+            "{}\nHost *\n    Compression {}\n",
+            config,
+            if compression { "yes" } else { "no" }
+        )?;
+        file.flush()?;
+        Ok(file)
     }
 
     /// Make ssh storage from `ConfigClient` if possible, empty otherwise (empty is implicit if degraded)
@@ -128,6 +217,9 @@ mod test {
 
     use super::*;
 
+    use pretty_assertions::assert_eq;
+    use ssh2_config::SshConfig;
+    use std::io::{BufReader, Write};
     use std::path::{Path, PathBuf};
     use tempfile::TempDir;
 
@@ -185,6 +277,51 @@ mod test {
         let _ = Builder::build(FileTransferProtocol::Sftp, params, &config_client);
     }
 
+    #[test]
+    fn should_build_sftp_fs_with_encoding() {
+        let params = ProtocolParams::Generic(
+            GenericProtocolParams::default()
+                .address("127.0.0.1")
+                .port(22)
+                .username(Some("omar"))
+                .encoding(Some("shift_jis")),
+        );
+        let config_client = get_config_client();
+        let _ = Builder::build(FileTransferProtocol::Sftp, params, &config_client);
+    }
+
+    #[test]
+    fn should_make_ssh_config_with_compression() {
+        let mut config = tempfile::NamedTempFile::new().unwrap();
+        writeln!(
+            config,
+            "Host test\n    HostName 127.0.0.1\n    Compression no"
+        )
+        .unwrap();
+        let file = Builder::make_ssh_config(Some(config.path()), true).unwrap();
+        let ssh_config = SshConfig::default()
+            .parse(&mut BufReader::new(fs::File::open(file.path()).unwrap()))
+            .unwrap();
+        let params = ssh_config.query("test");
+        assert_eq!(params.host_name.as_deref(), Some("127.0.0.1"));
+        assert_eq!(params.compression, Some(true));
+        let file = Builder::make_ssh_config(None, false).unwrap();
+        let ssh_config = SshConfig::default()
+            .parse(&mut BufReader::new(fs::File::open(file.path()).unwrap()))
+            .unwrap();
+        assert_eq!(ssh_config.query("test").compression, Some(false));
+        // File is readable only by the user and removed once dropped
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(file.path()).unwrap().permissions().mode();
+            assert_eq!(mode & 0o077, 0);
+        }
+        let path = file.path().to_path_buf();
+        drop(file);
+        assert!(!path.exists());
+    }
+
     #[test]
     #[should_panic]
     fn should_not_build_fs() {
@@ -0,0 +1,288 @@
+//! ## Encoding
+//!
+//! `encoding` provides a remotefs client wrapper which converts the filenames of servers
+//! using an encoding different from UTF-8 (e.g. Latin-1 or Shift-JIS)
+
+use encoding_rs::{Encoding, UTF_8};
+use remotefs::fs::{File, Metadata, ReadStream, UnixPex, Welcome, WriteStream};
+use remotefs::{RemoteFs, RemoteResult};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Returns the encoding with the provided label (e.g. `latin1`, `shift_jis`).
+/// Returns `None` if the label is unknown
+pub fn encoding_for_label(label: &str) -> Option<&'static Encoding> {
+    Encoding::for_label(label.trim().as_bytes())
+}
+
+/// Converts paths between UTF-8 and the filename encoding of the remote server
+#[derive(Debug, Clone, Copy)]
+pub struct FilenameEncoding {
+    encoding: &'static Encoding,
+}
+
+impl FilenameEncoding {
+    /// Instantiates a new `FilenameEncoding` from the encoding label.
+    /// Returns `None` if the label is unknown or if the encoding is UTF-8, since no conversion is needed
+    pub fn new(label: &str) -> Option<Self> {
+        encoding_for_label(label)
+            .filter(|x| *x != UTF_8)
+            .map(|encoding| Self { encoding })
+    }
+
+    /// Convert a path received from the server to UTF-8
+    #[cfg(target_family = "unix")]
+    pub fn decode(&self, path: &Path) -> PathBuf {
+        use std::os::unix::ffi::OsStrExt;
+
+        let (decoded, _) = self
+            .encoding
+            .decode_without_bom_handling(path.as_os_str().as_bytes());
+        PathBuf::from(decoded.as_ref())
+    }
+
+    /// Convert a UTF-8 path to the server encoding
+    #[cfg(target_family = "unix")]
+    pub fn encode(&self, path: &Path) -> PathBuf {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = path.to_string_lossy();
+        let (encoded, _, _) = self.encoding.encode(path.as_ref());
+        PathBuf::from(OsStr::from_bytes(encoded.as_ref()))
+    }
+
+    /// Convert a path received from the server to UTF-8.
+    /// Paths can't hold arbitrary bytes on Windows, so they're returned as they are
+    #[cfg(target_os = "windows")]
+    pub fn decode(&self, path: &Path) -> PathBuf {
+        path.to_path_buf()
+    }
+
+    /// Convert a UTF-8 path to the server encoding.
+    /// Paths can't hold arbitrary bytes on Windows, so they're returned as they are
+    #[cfg(target_os = "windows")]
+    pub fn encode(&self, path: &Path) -> PathBuf {
+        path.to_path_buf()
+    }
+
+    fn decode_file(&self, mut file: File) -> File {
+        file.path = self.decode(file.path.as_path());
+        file.metadata.symlink = file.metadata.symlink.map(|x| self.decode(x.as_path()));
+        file
+    }
+}
+
+/// Remotefs client wrapper which converts the paths sent to and received from the server
+pub struct EncodedFs {
+    client: Box<dyn RemoteFs>,
+    encoding: FilenameEncoding,
+}
+
+impl EncodedFs {
+    /// Wrap `client`, converting paths with `encoding`
+    pub fn new(client: Box<dyn RemoteFs>, encoding: FilenameEncoding) -> Self {
+        Self { client, encoding }
+    }
+
+    fn encode(&self, path: &Path) -> PathBuf {
+        self.encoding.encode(path)
+    }
+}
+
+impl RemoteFs for EncodedFs {
+    fn connect(&mut self) -> RemoteResult<Welcome> {
+        self.client.connect()
+    }
+
+    fn disconnect(&mut self) -> RemoteResult<()> {
+        self.client.disconnect()
+    }
+
+    fn is_connected(&mut self) -> bool {
+        self.client.is_connected()
+    }
+
+    fn pwd(&mut self) -> RemoteResult<PathBuf> {
+        self.client.pwd().map(|x| self.encoding.decode(x.as_path()))
+    }
+
+    fn change_dir(&mut self, dir: &Path) -> RemoteResult<PathBuf> {
+        let dir = self.encode(dir);
+        self.client
+            .change_dir(dir.as_path())
+            .map(|x| self.encoding.decode(x.as_path()))
+    }
+
+    fn list_dir(&mut self, path: &Path) -> RemoteResult<Vec<File>> {
+        let path = self.encode(path);
+        self.client.list_dir(path.as_path()).map(|files| {
+            files
+                .into_iter()
+                .map(|x| self.encoding.decode_file(x))
+                .collect()
+        })
+    }
+
+    fn stat(&mut self, path: &Path) -> RemoteResult<File> {
+        let path = self.encode(path);
+        self.client
+            .stat(path.as_path())
+            .map(|x| self.encoding.decode_file(x))
+    }
+
+    fn setstat(&mut self, path: &Path, metadata: Metadata) -> RemoteResult<()> {
+        let path = self.encode(path);
+        self.client.setstat(path.as_path(), metadata)
+    }
+
+    fn exists(&mut self, path: &Path) -> RemoteResult<bool> {
+        let path = self.encode(path);
+        self.client.exists(path.as_path())
+    }
+
+    fn remove_file(&mut self, path: &Path) -> RemoteResult<()> {
+        let path = self.encode(path);
+        self.client.remove_file(path.as_path())
+    }
+
+    fn remove_dir(&mut self, path: &Path) -> RemoteResult<()> {
+        let path = self.encode(path);
+        self.client.remove_dir(path.as_path())
+    }
+
+    fn remove_dir_all(&mut self, path: &Path) -> RemoteResult<()> {
+        let path = self.encode(path);
+        self.client.remove_dir_all(path.as_path())
+    }
+
+    fn create_dir(&mut self, path: &Path, mode: UnixPex) -> RemoteResult<()> {
+        let path = self.encode(path);
+        self.client.create_dir(path.as_path(), mode)
+    }
+
+    fn symlink(&mut self, path: &Path, target: &Path) -> RemoteResult<()> {
+        let path = self.encode(path);
+        let target = self.encode(target);
+        self.client.symlink(path.as_path(), target.as_path())
+    }
+
+    fn copy(&mut self, src: &Path, dest: &Path) -> RemoteResult<()> {
+        let src = self.encode(src);
+        let dest = self.encode(dest);
+        self.client.copy(src.as_path(), dest.as_path())
+    }
+
+    fn mov(&mut self, src: &Path, dest: &Path) -> RemoteResult<()> {
+        let src = self.encode(src);
+        let dest = self.encode(dest);
+        self.client.mov(src.as_path(), dest.as_path())
+    }
+
+    fn exec(&mut self, cmd: &str) -> RemoteResult<(u32, String)> {
+        self.client.exec(cmd)
+    }
+
+    fn append(&mut self, path: &Path, metadata: &Metadata) -> RemoteResult<WriteStream> {
+        let path = self.encode(path);
+        self.client.append(path.as_path(), metadata)
+    }
+
+    fn create(&mut self, path: &Path, metadata: &Metadata) -> RemoteResult<WriteStream> {
+        let path = self.encode(path);
+        self.client.create(path.as_path(), metadata)
+    }
+
+    fn open(&mut self, path: &Path) -> RemoteResult<ReadStream> {
+        let path = self.encode(path);
+        self.client.open(path.as_path())
+    }
+
+    fn on_written(&mut self, writable: WriteStream) -> RemoteResult<()> {
+        self.client.on_written(writable)
+    }
+
+    fn on_read(&mut self, readable: ReadStream) -> RemoteResult<()> {
+        self.client.on_read(readable)
+    }
+
+    fn append_file(
+        &mut self,
+        path: &Path,
+        metadata: &Metadata,
+        reader: Box<dyn Read>,
+    ) -> RemoteResult<u64> {
+        let path = self.encode(path);
+        self.client.append_file(path.as_path(), metadata, reader)
+    }
+
+    fn create_file(
+        &mut self,
+        path: &Path,
+        metadata: &Metadata,
+        reader: Box<dyn Read>,
+    ) -> RemoteResult<u64> {
+        let path = self.encode(path);
+        self.client.create_file(path.as_path(), metadata, reader)
+    }
+
+    fn open_file(&mut self, src: &Path, dest: Box<dyn Write + Send>) -> RemoteResult<u64> {
+        let src = self.encode(src);
+        self.client.open_file(src.as_path(), dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_get_filename_encoding() {
+        assert!(FilenameEncoding::new("latin1").is_some());
+        assert!(FilenameEncoding::new(" Shift_JIS ").is_some());
+        assert!(FilenameEncoding::new("utf-8").is_none());
+        assert!(FilenameEncoding::new("klingon").is_none());
+        assert!(encoding_for_label("utf-8").is_some());
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn should_convert_paths() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let encoding = FilenameEncoding::new("latin1").unwrap();
+        let remote = PathBuf::from(OsStr::from_bytes(b"/home/omar/caf\xe9.txt"));
+        let local = encoding.decode(remote.as_path());
+        assert_eq!(local, PathBuf::from("/home/omar/café.txt"));
+        assert_eq!(encoding.encode(local.as_path()), remote);
+        let encoding = FilenameEncoding::new("shift_jis").unwrap();
+        let remote = PathBuf::from(OsStr::from_bytes(b"/tmp/\x93\xfa\x96\x7b"));
+        let local = encoding.decode(remote.as_path());
+        assert_eq!(local, PathBuf::from("/tmp/日本"));
+        assert_eq!(encoding.encode(local.as_path()), remote);
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn should_decode_files() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let encoding = FilenameEncoding::new("latin1").unwrap();
+        let file = File {
+            path: PathBuf::from(OsStr::from_bytes(b"/tmp/\xe0")),
+            metadata: Metadata::default()
+                .symlink(PathBuf::from(OsStr::from_bytes(b"/tmp/\xe8")).as_path()),
+        };
+        let file = encoding.decode_file(file);
+        assert_eq!(file.path(), Path::new("/tmp/à"));
+        assert_eq!(
+            file.metadata().symlink.as_deref(),
+            Some(Path::new("/tmp/è"))
+        );
+    }
+}
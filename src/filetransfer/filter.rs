@@ -0,0 +1,218 @@
+//! ## Filter
+//!
+//! `filter` provides the include/exclude patterns applied to recursive transfers
+
+use std::fmt;
+use std::path::{Component, Path};
+use std::str::FromStr;
+use wildmatch::WildMatch;
+
+/// Describes which entries are transferred while recursing into a directory.
+///
+/// The filter is written as a comma separated list of glob patterns matched against the entry name:
+///
+/// - `<pattern>` or `-<pattern>`: entries matching the pattern are excluded
+/// - `+<pattern>`: only the entries matching one of the include patterns are transferred
+/// - a trailing `/` makes the pattern match directories only (e.g. `target/`)
+///
+/// Include patterns without the trailing `/` apply to files only, so directories are still
+/// traversed in order to look for the files to include.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TransferFilter {
+    rules: Vec<FilterRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FilterRule {
+    pattern: String,
+    include: bool,
+    dir_only: bool,
+}
+
+impl FilterRule {
+    fn applies_to(&self, is_dir: bool) -> bool {
+        self.dir_only == is_dir || (!self.dir_only && !self.include)
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        WildMatch::new(self.pattern.as_str()).matches(name)
+    }
+}
+
+impl TransferFilter {
+    /// Exclude the entries matching `pattern`
+    pub fn exclude<S: AsRef<str>>(self, pattern: S) -> Self {
+        self.rule(pattern.as_ref(), false)
+    }
+
+    /// Transfer only the entries matching `pattern` (or any other include pattern)
+    pub fn include<S: AsRef<str>>(self, pattern: S) -> Self {
+        self.rule(pattern.as_ref(), true)
+    }
+
+    fn rule(mut self, pattern: &str, include: bool) -> Self {
+        let (pattern, dir_only) = match pattern.strip_suffix('/') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
+        self.rules.push(FilterRule {
+            pattern: pattern.to_string(),
+            include,
+            dir_only,
+        });
+        self
+    }
+
+    /// Returns whether the filter has no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns whether the entry named `name` must be skipped
+    pub fn excludes(&self, name: &str, is_dir: bool) -> bool {
+        let (includes, excludes): (Vec<&FilterRule>, Vec<&FilterRule>) = self
+            .rules
+            .iter()
+            .filter(|x| x.applies_to(is_dir))
+            .partition(|x| x.include);
+        excludes.iter().any(|x| x.matches(name))
+            || (!includes.is_empty() && !includes.iter().any(|x| x.matches(name)))
+    }
+
+    /// Returns whether the entry at the relative `path` must be skipped, checking all the
+    /// directories it is placed in
+    pub fn excludes_path(&self, path: &Path, is_dir: bool) -> bool {
+        let names: Vec<String> = path
+            .components()
+            .filter_map(|x| match x {
+                Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect();
+        names.iter().enumerate().any(|(i, name)| {
+            let is_last = i + 1 == names.len();
+            self.excludes(name.as_str(), !is_last || is_dir)
+        })
+    }
+}
+
+impl fmt::Display for TransferFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<String> = self
+            .rules
+            .iter()
+            .map(|x| {
+                format!(
+                    "{}{}{}",
+                    if x.include { "+" } else { "" },
+                    x.pattern,
+                    if x.dir_only { "/" } else { "" }
+                )
+            })
+            .collect();
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+impl FromStr for TransferFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = TransferFilter::default();
+        for rule in s.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            filter = if let Some(pattern) = rule.strip_prefix('+') {
+                filter.include(pattern.trim())
+            } else if let Some(pattern) = rule.strip_prefix('-') {
+                filter.exclude(pattern.trim())
+            } else {
+                filter.exclude(rule)
+            };
+            if filter.rules.last().map(|x| x.pattern.is_empty()) == Some(true) {
+                return Err(format!("invalid pattern \"{}\"", rule));
+            }
+        }
+        Ok(filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_exclude_entries() {
+        let filter = TransferFilter::default()
+            .exclude("target/")
+            .exclude(".git")
+            .exclude("*.o");
+        assert!(filter.excludes("target", true));
+        assert!(!filter.excludes("target", false));
+        assert!(filter.excludes(".git", true));
+        assert!(filter.excludes(".git", false));
+        assert!(filter.excludes("main.o", false));
+        assert!(!filter.excludes("main.c", false));
+        assert!(!filter.excludes("src", true));
+        assert!(!TransferFilter::default().excludes("target", true));
+    }
+
+    #[test]
+    fn should_include_entries() {
+        let filter = TransferFilter::default()
+            .include("*.rs")
+            .include("*.toml")
+            .exclude("build.rs");
+        assert!(!filter.excludes("main.rs", false));
+        assert!(!filter.excludes("Cargo.toml", false));
+        assert!(filter.excludes("build.rs", false));
+        assert!(filter.excludes("README.md", false));
+        // Directories are still traversed
+        assert!(!filter.excludes("src", true));
+        let filter = TransferFilter::default().include("src/");
+        assert!(!filter.excludes("src", true));
+        assert!(filter.excludes("docs", true));
+        assert!(!filter.excludes("README.md", false));
+    }
+
+    #[test]
+    fn should_exclude_paths() {
+        let filter = TransferFilter::default().exclude("target/").exclude("*.o");
+        assert!(filter.excludes_path(Path::new("target/debug/termscp"), false));
+        assert!(filter.excludes_path(Path::new("src/main.o"), false));
+        assert!(filter.excludes_path(Path::new("./target"), true));
+        assert!(!filter.excludes_path(Path::new("./target"), false));
+        assert!(!filter.excludes_path(Path::new("src/main.rs"), false));
+    }
+
+    #[test]
+    fn should_parse_transfer_filter() {
+        assert_eq!(
+            TransferFilter::from_str("target/, .git/,*.o, -*.tmp, +*.rs").unwrap(),
+            TransferFilter::default()
+                .exclude("target/")
+                .exclude(".git/")
+                .exclude("*.o")
+                .exclude("*.tmp")
+                .include("*.rs")
+        );
+        assert!(TransferFilter::from_str("").unwrap().is_empty());
+        assert!(TransferFilter::from_str("+").is_err());
+        assert!(TransferFilter::from_str("*.o, -/").is_err());
+    }
+
+    #[test]
+    fn should_format_transfer_filter() {
+        let filter = TransferFilter::default()
+            .exclude("target/")
+            .exclude("*.o")
+            .include("*.rs");
+        assert_eq!(filter.to_string().as_str(), "target/, *.o, +*.rs");
+        assert_eq!(
+            TransferFilter::from_str(filter.to_string().as_str()).unwrap(),
+            filter
+        );
+        assert_eq!(TransferFilter::default().to_string().as_str(), "");
+    }
+}
@@ -3,10 +3,16 @@
 //! `filetransfer` is the module which provides the file transfer protocols and remotefs builders
 
 mod builder;
+pub mod encoding;
+mod filter;
+mod ownership;
 pub mod params;
+mod ssh_config;
 
 // -- export types
 pub use builder::Builder;
+pub use filter::TransferFilter;
+pub use ownership::OwnershipMap;
 pub use params::{FileTransferParams, ProtocolParams};
 
 /// This enum defines the different transfer protocol available in termscp
@@ -19,6 +25,17 @@ pub enum FileTransferProtocol {
     AwsS3,
 }
 
+/// Defines how symlinks are handled during recursive transfers
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SymlinkPolicy {
+    /// Transfer the file or directory the link points to
+    Follow,
+    /// Create a link pointing to the same target on the destination
+    CopyAsLink,
+    /// Don't transfer symlinks
+    Skip,
+}
+
 // Traits
 
 impl std::string::ToString for FileTransferProtocol {
@@ -49,6 +66,32 @@ impl std::str::FromStr for FileTransferProtocol {
     }
 }
 
+impl std::fmt::Display for SymlinkPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SymlinkPolicy::Follow => "follow",
+                SymlinkPolicy::CopyAsLink => "link",
+                SymlinkPolicy::Skip => "skip",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for SymlinkPolicy {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "follow" => Ok(SymlinkPolicy::Follow),
+            "link" => Ok(SymlinkPolicy::CopyAsLink),
+            "skip" => Ok(SymlinkPolicy::Skip),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
 // Tests
 
 #[cfg(test)]
@@ -126,4 +169,24 @@ mod tests {
         assert_eq!(FileTransferProtocol::Sftp.to_string(), String::from("SFTP"));
         assert_eq!(FileTransferProtocol::AwsS3.to_string(), String::from("S3"));
     }
+
+    #[test]
+    fn should_convert_symlink_policy() {
+        for policy in [
+            SymlinkPolicy::Follow,
+            SymlinkPolicy::CopyAsLink,
+            SymlinkPolicy::Skip,
+        ] {
+            assert_eq!(
+                SymlinkPolicy::from_str(policy.to_string().as_str()).unwrap(),
+                policy
+            );
+        }
+        assert_eq!(SymlinkPolicy::CopyAsLink.to_string().as_str(), "link");
+        assert_eq!(
+            SymlinkPolicy::from_str("SKIP").unwrap(),
+            SymlinkPolicy::Skip
+        );
+        assert!(SymlinkPolicy::from_str("copy").is_err());
+    }
 }
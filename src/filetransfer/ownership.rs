@@ -0,0 +1,179 @@
+//! ## Ownership
+//!
+//! `ownership` provides the mapping applied to the owner and group of the transferred files
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Describes how the owner and group of the transferred files are set on the destination.
+///
+/// The mapping is written as a comma separated list of rules:
+///
+/// - `preserve`: ids not in the table are kept as they are on the source (default)
+/// - `current`: ids not in the table are left to the current user on the destination
+/// - `uid:<src>=<dst>`: files owned by user `src` are owned by user `dst` on the destination
+/// - `gid:<src>=<dst>`: files of group `src` belong to group `dst` on the destination
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OwnershipMap {
+    uids: BTreeMap<u32, u32>,
+    gids: BTreeMap<u32, u32>,
+    /// Ids which are not in the table are left to the current user
+    current_user: bool,
+}
+
+impl OwnershipMap {
+    /// Map ids not in the table to the current user
+    pub fn current_user(mut self) -> Self {
+        self.current_user = true;
+        self
+    }
+
+    /// Map the user `src` to `dst`
+    pub fn uid(mut self, src: u32, dst: u32) -> Self {
+        self.uids.insert(src, dst);
+        self
+    }
+
+    /// Map the group `src` to `dst`
+    pub fn gid(mut self, src: u32, dst: u32) -> Self {
+        self.gids.insert(src, dst);
+        self
+    }
+
+    /// Returns the owner to set on the destination for a file owned by `uid`.
+    /// Returns `None` if the owner must be left to the current user
+    pub fn map_uid(&self, uid: Option<u32>) -> Option<u32> {
+        Self::map_id(&self.uids, uid, self.current_user)
+    }
+
+    /// Returns the group to set on the destination for a file of group `gid`.
+    /// Returns `None` if the group must be left to the current user
+    pub fn map_gid(&self, gid: Option<u32>) -> Option<u32> {
+        Self::map_id(&self.gids, gid, self.current_user)
+    }
+
+    fn map_id(table: &BTreeMap<u32, u32>, id: Option<u32>, current_user: bool) -> Option<u32> {
+        let id = id?;
+        match table.get(&id) {
+            Some(mapped) => Some(*mapped),
+            None if current_user => None,
+            None => Some(id),
+        }
+    }
+}
+
+impl fmt::Display for OwnershipMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rules: Vec<String> = vec![String::from(match self.current_user {
+            true => "current",
+            false => "preserve",
+        })];
+        rules.extend(
+            self.uids
+                .iter()
+                .map(|(src, dst)| format!("uid:{}={}", src, dst)),
+        );
+        rules.extend(
+            self.gids
+                .iter()
+                .map(|(src, dst)| format!("gid:{}={}", src, dst)),
+        );
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+impl FromStr for OwnershipMap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = OwnershipMap::default();
+        for rule in s.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            match rule.to_ascii_lowercase().as_str() {
+                "preserve" => map.current_user = false,
+                "current" => map = map.current_user(),
+                rule => {
+                    let (kind, ids) = rule
+                        .split_once(':')
+                        .ok_or_else(|| format!("invalid rule \"{}\"", rule))?;
+                    let (src, dst) = ids
+                        .split_once('=')
+                        .and_then(|(src, dst)| {
+                            Some((
+                                src.trim().parse::<u32>().ok()?,
+                                dst.trim().parse::<u32>().ok()?,
+                            ))
+                        })
+                        .ok_or_else(|| format!("invalid rule \"{}\"", rule))?;
+                    map = match kind.trim() {
+                        "uid" => map.uid(src, dst),
+                        "gid" => map.gid(src, dst),
+                        _ => return Err(format!("invalid rule \"{}\"", rule)),
+                    };
+                }
+            }
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_map_ids() {
+        let map = OwnershipMap::default().uid(1000, 1001).gid(100, 50);
+        assert_eq!(map.map_uid(Some(1000)), Some(1001));
+        assert_eq!(map.map_uid(Some(0)), Some(0));
+        assert_eq!(map.map_uid(None), None);
+        assert_eq!(map.map_gid(Some(100)), Some(50));
+        assert_eq!(map.map_gid(Some(1000)), Some(1000));
+        let map = map.current_user();
+        assert_eq!(map.map_uid(Some(1000)), Some(1001));
+        assert_eq!(map.map_uid(Some(0)), None);
+        assert_eq!(map.map_gid(Some(100)), Some(50));
+        assert_eq!(map.map_gid(Some(1000)), None);
+    }
+
+    #[test]
+    fn should_parse_ownership_map() {
+        assert_eq!(
+            OwnershipMap::from_str("uid:1000=1001, GID:100=50").unwrap(),
+            OwnershipMap::default().uid(1000, 1001).gid(100, 50)
+        );
+        assert_eq!(
+            OwnershipMap::from_str("current,uid:0=1000").unwrap(),
+            OwnershipMap::default().current_user().uid(0, 1000)
+        );
+        assert_eq!(
+            OwnershipMap::from_str("preserve").unwrap(),
+            OwnershipMap::default()
+        );
+        assert!(OwnershipMap::from_str("uid:1000").is_err());
+        assert!(OwnershipMap::from_str("uid:omar=1000").is_err());
+        assert!(OwnershipMap::from_str("pid:1=2").is_err());
+        assert!(OwnershipMap::from_str("everyone").is_err());
+    }
+
+    #[test]
+    fn should_format_ownership_map() {
+        let map = OwnershipMap::default()
+            .current_user()
+            .uid(1000, 1001)
+            .gid(100, 50)
+            .uid(0, 1000);
+        assert_eq!(
+            map.to_string().as_str(),
+            "current, uid:0=1000, uid:1000=1001, gid:100=50"
+        );
+        assert_eq!(
+            OwnershipMap::from_str(map.to_string().as_str()).unwrap(),
+            map
+        );
+        assert_eq!(OwnershipMap::default().to_string().as_str(), "preserve");
+    }
+}
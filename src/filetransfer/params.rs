@@ -14,6 +14,16 @@ pub struct FileTransferParams {
     pub protocol: FileTransferProtocol,
     pub params: ProtocolParams,
     pub entry_directory: Option<PathBuf>,
+    /// Local directory given by the user to start from; takes precedence over the last session
+    pub local_directory: Option<PathBuf>,
+    /// Maximum upload speed in bytes per second
+    pub upload_limit: Option<u64>,
+    /// Maximum download speed in bytes per second
+    pub download_limit: Option<u64>,
+    /// Whether destructive operations (delete, rename, overwrite, exec) are disabled
+    pub read_only: bool,
+    /// Whether the host is a production host, where deletes must be confirmed typing the amount of files
+    pub production: bool,
 }
 
 /// Container for protocol params
@@ -30,6 +40,10 @@ pub struct GenericProtocolParams {
     pub port: u16,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// Whether to compress the SSH transport; if unset, the value from configuration is used
+    pub compression: Option<bool>,
+    /// Encoding of the filenames on the server; if unset, UTF-8 is used
+    pub encoding: Option<String>,
 }
 
 /// Connection parameters for AWS S3 protocol
@@ -53,6 +67,11 @@ impl FileTransferParams {
             protocol,
             params,
             entry_directory: None,
+            local_directory: None,
+            upload_limit: None,
+            download_limit: None,
+            read_only: false,
+            production: false,
         }
     }
 
@@ -62,6 +81,36 @@ impl FileTransferParams {
         self
     }
 
+    /// Set local directory
+    pub fn local_directory<P: AsRef<Path>>(mut self, dir: Option<P>) -> Self {
+        self.local_directory = dir.map(|x| x.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set upload speed limit (bytes per second)
+    pub fn upload_limit(mut self, limit: Option<u64>) -> Self {
+        self.upload_limit = limit;
+        self
+    }
+
+    /// Set download speed limit (bytes per second)
+    pub fn download_limit(mut self, limit: Option<u64>) -> Self {
+        self.download_limit = limit;
+        self
+    }
+
+    /// Set whether destructive operations are disabled
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set whether the host is a production host
+    pub fn production(mut self, production: bool) -> Self {
+        self.production = production;
+        self
+    }
+
     /// Returns whether a password is supposed to be required for this protocol params.
     /// The result true is returned ONLY if the supposed secret is MISSING!!!
     pub fn password_missing(&self) -> bool {
@@ -130,6 +179,8 @@ impl Default for GenericProtocolParams {
             port: 22,
             username: None,
             password: None,
+            compression: None,
+            encoding: None,
         }
     }
 }
@@ -159,6 +210,18 @@ impl GenericProtocolParams {
         self
     }
 
+    /// Set SSH transport compression for params
+    pub fn compression(mut self, compression: Option<bool>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set filename encoding for params
+    pub fn encoding<S: AsRef<str>>(mut self, encoding: Option<S>) -> Self {
+        self.encoding = encoding.map(|x| x.as_ref().to_string());
+        self
+    }
+
     /// Returns whether a password is supposed to be required for this protocol params.
     /// The result true is returned ONLY if the supposed secret is MISSING!!!
     pub fn password_missing(&self) -> bool {
@@ -257,6 +320,19 @@ mod test {
             params.entry_directory.as_deref().unwrap(),
             Path::new("/tmp")
         );
+        assert!(params.local_directory.is_none());
+        assert!(!params.read_only);
+        assert!(!params.production);
+        let params = params
+            .local_directory(Some(&Path::new("/home/omar")))
+            .read_only(true)
+            .production(true);
+        assert_eq!(
+            params.local_directory.as_deref().unwrap(),
+            Path::new("/home/omar")
+        );
+        assert!(params.read_only);
+        assert!(params.production);
     }
 
     #[test]
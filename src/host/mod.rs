@@ -84,6 +84,15 @@ impl std::fmt::Display for HostError {
     }
 }
 
+/// Output of a command executed on localhost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecOutput {
+    /// Exit code of the command; `None` if it was terminated by a signal
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
 /// Localhost is the entity which holds the information about the current directory and host.
 /// It provides functions to navigate across the local host file system
 pub struct Localhost {
@@ -407,20 +416,22 @@ impl Localhost {
     }
 
     /// Execute a command on localhost
-    pub fn exec(&self, cmd: &str) -> Result<String, HostError> {
+    pub fn exec(&self, cmd: &str) -> Result<ExecOutput, HostError> {
         // Make command
         let args: Vec<&str> = cmd.split(' ').collect();
         let cmd: &str = args.first().unwrap();
         let argv: &[&str] = &args[1..];
         info!("Executing command: {} {:?}", cmd, argv);
         match std::process::Command::new(cmd).args(argv).output() {
-            Ok(output) => match std::str::from_utf8(&output.stdout) {
-                Ok(s) => {
-                    info!("Command output: {}", s);
-                    Ok(s.to_string())
-                }
-                Err(_) => Ok(String::new()),
-            },
+            Ok(output) => {
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                info!("Command output: {}", stdout);
+                Ok(ExecOutput {
+                    exit_code: output.status.code(),
+                    stdout,
+                    stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+                })
+            }
             Err(err) => {
                 error!("Failed to run command: {}", err);
                 Err(HostError::new(
@@ -1034,9 +1045,16 @@ mod tests {
         let host: Localhost = Localhost::new(PathBuf::from(tmpdir.path())).ok().unwrap();
         // Execute
         #[cfg(target_family = "unix")]
-        assert_eq!(host.exec("echo 5").ok().unwrap().as_str(), "5\n");
+        assert_eq!(host.exec("echo 5").ok().unwrap().stdout.as_str(), "5\n");
         #[cfg(target_os = "windows")]
-        assert_eq!(host.exec("echo 5").ok().unwrap().as_str(), "5\r\n");
+        assert_eq!(host.exec("echo 5").ok().unwrap().stdout.as_str(), "5\r\n");
+        #[cfg(target_family = "unix")]
+        {
+            let output = host.exec("ls /this/does/not/exist").ok().unwrap();
+            assert_ne!(output.exit_code, Some(0));
+            assert!(output.stdout.is_empty());
+            assert!(!output.stderr.is_empty());
+        }
     }
 
     #[test]
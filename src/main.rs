@@ -32,8 +32,12 @@ mod utils;
 
 // namespaces
 use activity_manager::{ActivityManager, NextActivity};
-use cli_opts::{Args, BookmarkParams, HostParams, Remote, RunOpts, Task};
+use cli_opts::{
+    Args, ArgsSubcommands, BookmarkParams, ConfigSubcommands, HostParams, Remote, RunOpts, Task,
+};
+use config::overrides::{self, ConfigOverride};
 use filetransfer::FileTransferParams;
+use system::environment;
 use system::logging::{self, LogLevel};
 
 fn main() {
@@ -81,6 +85,28 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
     } else if args.quiet {
         run_opts.log_level = LogLevel::Off;
     }
+    // Portable mode
+    if args.portable {
+        environment::set_portable(true);
+    }
+    // Profile
+    if let Some(profile) = args.profile.as_deref() {
+        if !environment::is_valid_profile_name(profile) {
+            return Err(format!(
+                "Bad profile name \"{}\": only alphanumeric characters, '-' and '_' are allowed",
+                profile
+            ));
+        }
+        environment::set_profile(Some(profile));
+    }
+    // Configuration overrides
+    let overrides = args
+        .set
+        .iter()
+        .map(|x| ConfigOverride::parse(x))
+        .collect::<Result<Vec<ConfigOverride>, String>>()
+        .map_err(|e| format!("Bad --set option: {}", e))?;
+    overrides::set_cli_overrides(overrides);
     // Match ticks
     run_opts.ticks = Duration::from_millis(args.ticks);
     // @! extra modes
@@ -90,6 +116,25 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
     if args.update {
         run_opts.task = Task::InstallUpdate;
     }
+    match &args.nested {
+        Some(ArgsSubcommands::Config(config)) => {
+            run_opts.task = match &config.nested {
+                ConfigSubcommands::Export(export) => {
+                    Task::ExportConfig(export.path.clone(), !export.no_secrets)
+                }
+                ConfigSubcommands::Import(import) => Task::ImportConfig(import.path.clone()),
+            };
+            return Ok(run_opts);
+        }
+        Some(ArgsSubcommands::Update(update)) => {
+            run_opts.task = match update.check {
+                true => Task::CheckUpdate,
+                false => Task::InstallUpdate,
+            };
+            return Ok(run_opts);
+        }
+        None => {}
+    }
     // @! Ordinary mode
     // Remote argument
     match parse_address_arg(&args) {
@@ -110,6 +155,7 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
         if let Err(err) = env::set_current_dir(localdir.as_path()) {
             return Err(format!("Bad working directory argument: {}", err));
         }
+        run_opts.local_dir = Some(localdir);
     }
     Ok(run_opts)
 }
@@ -118,14 +164,17 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
 fn parse_address_arg(args: &Args) -> Result<Remote, String> {
     if let Some(remote) = args.positional.get(0) {
         if args.address_as_bookmark {
-            Ok(Remote::Bookmark(BookmarkParams::new(
-                remote,
-                args.password.as_ref(),
-            )))
+            Ok(Remote::Bookmark(
+                BookmarkParams::new(remote, args.password.as_ref()).read_only(args.read_only),
+            ))
         } else {
             // Parse address
-            parse_remote_address(remote.as_str())
-                .map(|x| Remote::Host(HostParams::new(x, args.password.as_deref())))
+            parse_remote_address(remote.as_str()).map(|x| {
+                Remote::Host(HostParams::new(
+                    x.read_only(args.read_only),
+                    args.password.as_deref(),
+                ))
+            })
         }
     } else {
         Ok(Remote::None)
@@ -142,6 +191,28 @@ fn parse_remote_address(remote: &str) -> Result<FileTransferParams, String> {
 /// Run task and return rc
 fn run(run_opts: RunOpts) -> i32 {
     match run_opts.task {
+        Task::ExportConfig(path, secrets) => {
+            match support::export_config(path.as_path(), secrets) {
+                Ok(_) => {
+                    println!("Configuration has been exported to {}", path.display());
+                    0
+                }
+                Err(err) => {
+                    eprintln!("Could not export configuration: {}", err);
+                    1
+                }
+            }
+        }
+        Task::ImportConfig(path) => match support::import_config(path.as_path()) {
+            Ok(_) => {
+                println!("Configuration has been successfully imported!");
+                0
+            }
+            Err(err) => {
+                eprintln!("Could not import configuration: {}", err);
+                1
+            }
+        },
         Task::ImportTheme(theme) => match support::import_theme(theme.as_path()) {
             Ok(_) => {
                 println!("Theme has been successfully imported!");
@@ -152,6 +223,16 @@ fn run(run_opts: RunOpts) -> i32 {
                 1
             }
         },
+        Task::CheckUpdate => match support::check_for_updates() {
+            Ok(msg) => {
+                println!("{}", msg);
+                0
+            }
+            Err(err) => {
+                eprintln!("Could not check for updates: {}", err);
+                1
+            }
+        },
         Task::InstallUpdate => match support::install_update() {
             Ok(msg) => {
                 println!("{}", msg);
@@ -179,8 +260,14 @@ fn run(run_opts: RunOpts) -> i32 {
                 };
             // Set file transfer params if set
             match run_opts.remote {
-                Remote::Bookmark(BookmarkParams { name, password }) => {
-                    if let Err(err) = manager.resolve_bookmark_name(&name, password.as_deref()) {
+                Remote::Bookmark(BookmarkParams {
+                    name,
+                    password,
+                    read_only,
+                }) => {
+                    if let Err(err) =
+                        manager.resolve_bookmark_name(&name, password.as_deref(), read_only)
+                    {
                         eprintln!("{}", err);
                         return 1;
                     }
@@ -193,6 +280,10 @@ fn run(run_opts: RunOpts) -> i32 {
                 }
                 Remote::None => {}
             }
+            // The local directory argument takes precedence over the last session with the host
+            if run_opts.local_dir.is_some() {
+                manager.set_explicit_local_dir();
+            }
             manager.run(activity);
             0
         }
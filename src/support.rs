@@ -5,11 +5,13 @@
 // mod
 use crate::system::{
     auto_update::{Update, UpdateStatus},
+    config_archive::ConfigArchive,
     config_client::ConfigClient,
     environment,
     notifications::Notification,
     theme_provider::ThemeProvider,
 };
+use crate::utils::tty;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -34,6 +36,70 @@ pub fn import_theme(p: &Path) -> Result<(), String> {
         .map_err(|e| format!("Could not import theme: {}", e))
 }
 
+/// ### export_config
+///
+/// Export configuration, bookmarks and theme to an archive at provided path.
+/// If `secrets` is true, the user is asked for a passphrase to encrypt secrets with,
+/// otherwise secrets are excluded
+pub fn export_config(p: &Path, secrets: bool) -> Result<(), String> {
+    let passphrase: Option<String> = match secrets {
+        true => {
+            let passphrase = read_passphrase("Passphrase to encrypt secrets with: ")?;
+            if passphrase.is_some() && read_passphrase("Confirm passphrase: ")? != passphrase {
+                return Err(String::from("Passphrases don't match"));
+            }
+            passphrase
+        }
+        false => None,
+    };
+    if passphrase.is_none() {
+        println!("Secrets won't be exported");
+    }
+    let cfg_dir: PathBuf = get_config_dir()?;
+    ConfigArchive::collect(cfg_dir.as_path(), passphrase.as_deref())
+        .and_then(|archive| archive.write(p))
+        .map_err(|e| e.to_string())
+}
+
+/// ### import_config
+///
+/// Import configuration, bookmarks and theme from the archive at provided path.
+/// If the archive contains secrets, the user is asked for the passphrase to decrypt them with
+pub fn import_config(p: &Path) -> Result<(), String> {
+    let archive = ConfigArchive::open(p).map_err(|e| e.to_string())?;
+    if archive.is_from_newer_version() {
+        println!(
+            "The archive was created with termscp {}; options unknown to this version will be discarded",
+            archive.termscp_version()
+        );
+    }
+    let passphrase: Option<String> = match archive.has_secrets() {
+        true => read_passphrase("Passphrase (leave empty to skip secrets): ")?,
+        false => None,
+    };
+    let cfg_dir: PathBuf = get_config_dir()?;
+    archive
+        .install(cfg_dir.as_path(), passphrase.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+/// ### check_for_updates
+///
+/// Check whether a newer version of termscp is available, without installing it
+pub fn check_for_updates() -> Result<String, String> {
+    match Update::is_new_version_available() {
+        Ok(Some(release)) => Ok(format!(
+            "termscp {} is available; run `termscp update` to install it",
+            release.version
+        )),
+        Ok(None) => Ok(format!(
+            "termscp is up to date ({})",
+            env!("CARGO_PKG_VERSION")
+        )),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 /// ### install_update
 ///
 /// Install latest version of termscp if an update is available
@@ -81,6 +147,13 @@ fn get_config_dir() -> Result<PathBuf, String> {
     }
 }
 
+/// ### read_passphrase
+///
+/// Read passphrase from tty
+fn read_passphrase(prompt: &str) -> Result<Option<String>, String> {
+    tty::read_secret_from_tty(prompt).map_err(|e| format!("Could not read passphrase: {}", e))
+}
+
 /// ### get_config_client
 ///
 /// Get configuration client
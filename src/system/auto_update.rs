@@ -9,6 +9,20 @@ use self_update::{
     backends::github::Update as GithubUpdater, cargo_crate_version, update::Release as UpdRelease,
     Status,
 };
+use std::path::Path;
+
+/// Directories where termscp is installed by package managers, which must be used to update it
+const PACKAGE_MANAGER_DIRS: &[&str] = &[
+    "/bin",
+    "/sbin",
+    "/usr/bin",
+    "/usr/sbin",
+    "/nix/store",
+    "/snap",
+    "/opt/homebrew",
+    "/usr/local/Cellar",
+    "/home/linuxbrew",
+];
 
 /// ### UpdateStatus
 ///
@@ -51,6 +65,7 @@ impl Update {
 
     pub fn upgrade(self) -> Result<UpdateStatus, UpdateError> {
         info!("Updating termscp...");
+        Self::check_self_update()?;
         GithubUpdater::configure()
             // Set default options
             .repo_owner("veeso")
@@ -86,6 +101,25 @@ impl Update {
             .map(Self::check_version)
     }
 
+    /// Returns an error if termscp can't replace its own executable,
+    /// since it has been installed by a package manager
+    fn check_self_update() -> Result<(), UpdateError> {
+        let exe = std::env::current_exe().map_err(UpdateError::Io)?;
+        let exe = exe.canonicalize().unwrap_or(exe);
+        if Self::is_installed_by_package_manager(exe.as_path()) {
+            return Err(UpdateError::Update(format!(
+                "termscp has been installed at {} by a package manager; please update it with your package manager",
+                exe.display()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns whether the executable at `exe` has been installed by a package manager
+    fn is_installed_by_package_manager(exe: &Path) -> bool {
+        PACKAGE_MANAGER_DIRS.iter().any(|x| exe.starts_with(x))
+    }
+
     /// In case received version is newer than current one, version as Some is returned; otherwise None
     fn check_version(r: Release) -> Option<Release> {
         match parse_semver(r.version.as_str()) {
@@ -174,6 +208,28 @@ mod test {
         assert!(Update::is_new_version_available().is_ok());
     }
 
+    #[test]
+    fn should_tell_whether_installed_by_package_manager() {
+        assert!(Update::is_installed_by_package_manager(Path::new(
+            "/usr/bin/termscp"
+        )));
+        assert!(Update::is_installed_by_package_manager(Path::new(
+            "/opt/homebrew/Cellar/termscp/0.10.0/bin/termscp"
+        )));
+        assert!(Update::is_installed_by_package_manager(Path::new(
+            "/nix/store/abcdef-termscp-0.10.0/bin/termscp"
+        )));
+        assert!(!Update::is_installed_by_package_manager(Path::new(
+            "/usr/local/bin/termscp"
+        )));
+        assert!(!Update::is_installed_by_package_manager(Path::new(
+            "/home/user/.cargo/bin/termscp"
+        )));
+        assert!(!Update::is_installed_by_package_manager(Path::new(
+            "/usr/binaries/termscp"
+        )));
+    }
+
     #[test]
     fn update_status() {
         assert_eq!(
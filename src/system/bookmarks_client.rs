@@ -4,14 +4,16 @@
 
 // Crate
 #[cfg(feature = "with-keyring")]
+use super::environment;
+#[cfg(feature = "with-keyring")]
 use super::keys::keyringstorage::KeyringStorage;
 use super::keys::{filestorage::FileStorage, KeyStorage, KeyStorageError};
 // Local
 use crate::config::{
-    bookmarks::{Bookmark, UserHosts},
+    bookmarks::{Bookmark, Session, UserHosts},
     serialization::{deserialize, serialize, SerializerError, SerializerErrorKind},
 };
-use crate::filetransfer::FileTransferParams;
+use crate::filetransfer::{FileTransferParams, ProtocolParams};
 use crate::utils::crypto;
 use crate::utils::fmt::fmt_time;
 use crate::utils::random::random_alphanumeric_with_len;
@@ -19,7 +21,10 @@ use crate::utils::random::random_alphanumeric_with_len;
 use std::fs::OpenOptions;
 use std::path::{Path, PathBuf};
 use std::string::ToString;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum amount of sessions kept; the least recent ones are removed first
+const SESSIONS_SIZE: usize = 64;
 
 /// BookmarksClient provides a layer between the host system and the bookmarks module
 pub struct BookmarksClient {
@@ -52,7 +57,8 @@ impl BookmarksClient {
             let app_name: &str = "termscp";
             #[cfg(test)] // NOTE: when running test, add -test
             let app_name: &str = "termscp-test";
-            match storage.is_supported() {
+            // NOTE: in portable mode, the key must be stored along with bookmarks
+            match storage.is_supported() && !environment::is_portable() {
                 true => {
                     debug!("Using KeyringStorage");
                     (Box::new(storage), app_name)
@@ -212,8 +218,46 @@ impl BookmarksClient {
     /// Delete entry from bookmarks
     pub fn del_bookmark(&mut self, name: &str) {
         let _ = self.hosts.bookmarks.remove(name);
+        self.set_hotkey(name, None);
         info!("Removed bookmark {}", name);
     }
+
+    /// Get the hotkey (1-9) associated to the bookmark `name`
+    pub fn get_hotkey(&self, name: &str) -> Option<u8> {
+        self.hosts
+            .hotkeys
+            .iter()
+            .find(|(_, bookmark)| bookmark.as_str() == name)
+            .and_then(|(hotkey, _)| hotkey.parse::<u8>().ok())
+    }
+
+    /// Associate `hotkey` to the bookmark `name`, replacing its previous hotkey.
+    /// If another bookmark had the same hotkey, it's taken from it.
+    /// If `hotkey` is `None`, the hotkey is removed from the bookmark
+    pub fn set_hotkey(&mut self, name: &str, hotkey: Option<u8>) {
+        self.hosts
+            .hotkeys
+            .retain(|_, bookmark| bookmark.as_str() != name);
+        if let Some(hotkey) = hotkey {
+            if (1..=9).contains(&hotkey) {
+                info!("Assigned hotkey {} to bookmark {}", hotkey, name);
+                self.hosts
+                    .hotkeys
+                    .insert(hotkey.to_string(), name.to_string());
+            } else {
+                error!("Invalid bookmark hotkey {}", hotkey);
+            }
+        }
+    }
+
+    /// Get the name of the bookmark associated to `hotkey`
+    pub fn get_bookmark_by_hotkey(&self, hotkey: u8) -> Option<&str> {
+        self.hosts
+            .hotkeys
+            .get(&hotkey.to_string())
+            .map(|x| x.as_str())
+            .filter(|x| self.hosts.bookmarks.contains_key(*x))
+    }
     /// Iterate over recents keys
     pub fn iter_recents(&self) -> impl Iterator<Item = &String> + '_ {
         Box::new(self.hosts.recents.keys())
@@ -275,6 +319,77 @@ impl BookmarksClient {
         info!("Removed recent host {}", name);
     }
 
+    /// Get the last session with the host described by `params`
+    pub fn get_session(&self, params: &FileTransferParams) -> Option<&Session> {
+        self.hosts.sessions.get(&Self::session_key(params))
+    }
+
+    /// Save the last session with the host described by `params`.
+    /// If there are too many sessions, the least recent ones are removed
+    pub fn set_session(&mut self, params: &FileTransferParams, mut session: Session) {
+        let key = Self::session_key(params);
+        session.saved_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_secs())
+            .unwrap_or_default();
+        while !self.hosts.sessions.contains_key(&key) && self.hosts.sessions.len() >= SESSIONS_SIZE
+        {
+            let oldest = self
+                .hosts
+                .sessions
+                .iter()
+                .min_by_key(|(_, x)| x.saved_at)
+                .map(|(key, _)| key.clone())
+                .unwrap();
+            debug!("Removed session for {}", oldest);
+            self.hosts.sessions.remove(&oldest);
+        }
+        info!("Saved session for {}", key);
+        self.hosts.sessions.insert(key, session);
+    }
+
+    /// Get a copy of bookmarks, recents and sessions, with the secrets encrypted with `key`
+    /// instead of the key in the storage.
+    /// If `key` is `None`, secrets are removed
+    pub fn export_hosts(&self, key: Option<&str>) -> Result<UserHosts, SerializerError> {
+        let mut hosts: UserHosts = self.hosts.clone();
+        for bookmark in hosts.bookmarks.values_mut() {
+            match key {
+                Some(key) => Self::map_secrets(bookmark, |secret| {
+                    self.decrypt_str(secret)
+                        .map(|plain| crypto::aes128_b64_crypt(key, plain.as_str()))
+                })?,
+                None => Self::strip_secrets(bookmark),
+            }
+        }
+        Ok(hosts)
+    }
+
+    /// Replace bookmarks, recents and sessions with `hosts`, whose secrets are encrypted with `key`.
+    /// If `key` is `None`, secrets are removed.
+    /// Changes are not written to file
+    pub fn import_hosts(
+        &mut self,
+        mut hosts: UserHosts,
+        key: Option<&str>,
+    ) -> Result<(), SerializerError> {
+        for bookmark in hosts.bookmarks.values_mut() {
+            match key {
+                Some(key) => Self::map_secrets(bookmark, |secret| {
+                    crypto::aes128_b64_decrypt(key, secret)
+                        .map(|plain| self.encrypt_str(plain.as_str()))
+                        .map_err(|e| {
+                            SerializerError::new_ex(SerializerErrorKind::Syntax, e.to_string())
+                        })
+                })?,
+                None => Self::strip_secrets(bookmark),
+            }
+        }
+        info!("Imported {} bookmarks", hosts.bookmarks.len());
+        self.hosts = hosts;
+        Ok(())
+    }
+
     /// Write bookmarks to file
     pub fn write_bookmarks(&self) -> Result<(), SerializerError> {
         // Open file
@@ -330,6 +445,25 @@ impl BookmarksClient {
         random_alphanumeric_with_len(256)
     }
 
+    /// Make the key which identifies a host in sessions (e.g. `sftp://omar@192.168.1.31:22`)
+    fn session_key(params: &FileTransferParams) -> String {
+        let protocol = params.protocol.to_string().to_lowercase();
+        match &params.params {
+            ProtocolParams::Generic(params) => format!(
+                "{}://{}{}:{}",
+                protocol,
+                params
+                    .username
+                    .as_deref()
+                    .map(|x| format!("{}@", x))
+                    .unwrap_or_default(),
+                params.address,
+                params.port
+            ),
+            ProtocolParams::AwsS3(params) => format!("{}://{}", protocol, params.bucket_name),
+        }
+    }
+
     /// Make bookmark from credentials
     fn make_bookmark(&self, params: FileTransferParams) -> Bookmark {
         let mut bookmark: Bookmark = Bookmark::from(params);
@@ -349,6 +483,34 @@ impl BookmarksClient {
         bookmark
     }
 
+    /// Replace each secret of `bookmark` with the value returned by `f`
+    fn map_secrets<F>(bookmark: &mut Bookmark, f: F) -> Result<(), SerializerError>
+    where
+        F: Fn(&str) -> Result<String, SerializerError>,
+    {
+        if let Some(pwd) = bookmark.password.as_mut() {
+            *pwd = f(pwd.as_str())?;
+        }
+        if let Some(s3) = bookmark.s3.as_mut() {
+            if let Some(access_key) = s3.access_key.as_mut() {
+                *access_key = f(access_key.as_str())?;
+            }
+            if let Some(secret_access_key) = s3.secret_access_key.as_mut() {
+                *secret_access_key = f(secret_access_key.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove secrets from `bookmark`
+    fn strip_secrets(bookmark: &mut Bookmark) {
+        bookmark.password = None;
+        if let Some(s3) = bookmark.s3.as_mut() {
+            s3.access_key = None;
+            s3.secret_access_key = None;
+        }
+    }
+
     /// Encrypt provided string using AES-128. Encrypted buffer is then converted to BASE64
     fn encrypt_str(&self, txt: &str) -> String {
         crypto::aes128_b64_crypt(self.key.as_str(), txt)
@@ -375,6 +537,7 @@ mod tests {
     use crate::filetransfer::{FileTransferProtocol, ProtocolParams};
 
     use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
     use std::thread::sleep;
     use std::time::Duration;
     use tempfile::TempDir;
@@ -571,8 +734,21 @@ mod tests {
         assert_eq!(*bookmark.4.as_ref().unwrap(), String::from("mypassword"));
         // Write bookmarks
         assert!(client.write_bookmarks().is_ok());
+        // Hotkeys
+        client.set_hotkey("raspberry", Some(3));
+        assert_eq!(client.get_hotkey("raspberry"), Some(3));
+        assert_eq!(client.get_bookmark_by_hotkey(3), Some("raspberry"));
+        client.set_hotkey("raspberry2", Some(3));
+        assert_eq!(client.get_hotkey("raspberry"), None);
+        assert_eq!(client.get_bookmark_by_hotkey(3), Some("raspberry2"));
+        client.set_hotkey("raspberry", Some(1));
+        client.set_hotkey("raspberry", Some(10));
+        assert_eq!(client.get_hotkey("raspberry"), None);
+        client.set_hotkey("raspberry", Some(1));
+        assert!(client.get_bookmark_by_hotkey(2).is_none());
         // Delete bookmark
         client.del_bookmark(&String::from("raspberry"));
+        assert!(client.get_bookmark_by_hotkey(1).is_none());
         // Get unexisting bookmark
         assert!(client.get_bookmark(&String::from("raspberry")).is_none());
         // Write bookmarks
@@ -629,6 +805,100 @@ mod tests {
         assert_eq!(bookmark.4, None);
     }
 
+    #[test]
+    fn should_save_and_get_session() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16).unwrap();
+        let params = make_generic_ftparams(
+            FileTransferProtocol::Sftp,
+            "192.168.1.31",
+            22,
+            "pi",
+            Some("mypassword"),
+        );
+        assert!(client.get_session(&params).is_none());
+        let session = Session {
+            local_directory: PathBuf::from("/home/omar"),
+            remote_directory: PathBuf::from("/var/log"),
+            local_cursor: Some(String::from("Downloads")),
+            remote_cursor: None,
+            local_selection: vec![],
+            remote_selection: vec![String::from("syslog")],
+            local_commands: vec![],
+            remote_commands: vec![String::from("tail -n 100 syslog")],
+            local_marks: HashMap::new(),
+            remote_marks: HashMap::from([(String::from("1"), PathBuf::from("/var/log"))]),
+            saved_at: 0,
+        };
+        client.set_session(&params, session.clone());
+        let session = client.get_session(&params).unwrap().clone();
+        assert!(session.saved_at > 0);
+        assert_eq!(
+            BookmarksClient::session_key(&params).as_str(),
+            "sftp://pi@192.168.1.31:22"
+        );
+        // Other host
+        assert!(client
+            .get_session(&make_generic_ftparams(
+                FileTransferProtocol::Sftp,
+                "192.168.1.32",
+                22,
+                "pi",
+                None,
+            ))
+            .is_none());
+        assert_eq!(
+            BookmarksClient::session_key(&make_s3_ftparams()).as_str(),
+            "s3://omar"
+        );
+        // Write and read again
+        assert!(client.write_bookmarks().is_ok());
+        let client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16).unwrap();
+        assert_eq!(client.get_session(&params), Some(&session));
+    }
+
+    #[test]
+    fn should_evict_least_recent_sessions() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16).unwrap();
+        let params = |x: usize| {
+            make_generic_ftparams(
+                FileTransferProtocol::Sftp,
+                &format!("192.168.1.{}", x),
+                22,
+                "pi",
+                None,
+            )
+        };
+        for x in 0..SESSIONS_SIZE {
+            client.set_session(&params(x), Session::default());
+        }
+        // Make the first session the oldest one
+        client
+            .hosts
+            .sessions
+            .values_mut()
+            .for_each(|x| x.saved_at += 1);
+        client
+            .hosts
+            .sessions
+            .get_mut(&BookmarksClient::session_key(&params(0)))
+            .unwrap()
+            .saved_at = 0;
+        // Replacing a session doesn't evict anything
+        client.set_session(&params(1), Session::default());
+        assert_eq!(client.hosts.sessions.len(), SESSIONS_SIZE);
+        client.set_session(&params(SESSIONS_SIZE), Session::default());
+        assert_eq!(client.hosts.sessions.len(), SESSIONS_SIZE);
+        assert!(client.get_session(&params(0)).is_none());
+        assert!(client.get_session(&params(SESSIONS_SIZE)).is_some());
+    }
+
     #[test]
 
     fn test_system_bookmarks_manipulate_recents() {
@@ -797,6 +1067,51 @@ mod tests {
         assert!(client.decrypt_str("bidoof").is_err());
     }
 
+    #[test]
+    fn should_export_and_import_hosts() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16).unwrap();
+        client.add_bookmark(
+            "raspberry",
+            make_generic_ftparams(
+                FileTransferProtocol::Sftp,
+                "192.168.1.31",
+                22,
+                "pi",
+                Some("mypassword"),
+            ),
+            true,
+        );
+        client.add_bookmark("s3", make_s3_ftparams(), true);
+        // Export without secrets
+        let hosts = client.export_hosts(None).unwrap();
+        assert!(hosts.bookmarks.get("raspberry").unwrap().password.is_none());
+        let s3 = hosts.bookmarks.get("s3").unwrap().s3.as_ref().unwrap();
+        assert!(s3.access_key.is_none());
+        assert!(s3.secret_access_key.is_none());
+        // Export with key and import into another client
+        let hosts = client.export_hosts(Some("passphrase")).unwrap();
+        let other_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(other_dir.path());
+        let mut other: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16).unwrap();
+        assert!(other
+            .import_hosts(hosts.clone(), Some("wrong-passphrase"))
+            .is_err());
+        assert!(other.import_hosts(hosts, Some("passphrase")).is_ok());
+        let params = other.get_bookmark("raspberry").unwrap();
+        assert_eq!(
+            params.params.generic_params().unwrap().password.as_deref(),
+            Some("mypassword")
+        );
+        let params = other.get_bookmark("s3").unwrap();
+        let s3 = params.params.s3_params().unwrap();
+        assert_eq!(s3.access_key.as_deref(), Some("pippo"));
+        assert_eq!(s3.secret_access_key.as_deref(), Some("pluto"));
+    }
+
     /// Get paths for configuration and key for bookmarks
     fn get_paths(dir: &Path) -> (PathBuf, PathBuf) {
         let k: PathBuf = PathBuf::from(dir);
@@ -4,27 +4,35 @@
 
 // Locals
 use crate::config::{
-    params::{UserConfig, DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD},
+    overrides::{self, ConfigOverride},
+    params::{DirectoryView, LastView, UserConfig, DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD},
     serialization::{deserialize, serialize, SerializerError, SerializerErrorKind},
+    status_bar::StatusBarFmt,
 };
-use crate::explorer::GroupDirs;
-use crate::filetransfer::FileTransferProtocol;
+use crate::explorer::{ExplorerView, FileSorting, GroupDirs};
+use crate::filetransfer::{FileTransferProtocol, OwnershipMap, SymlinkPolicy, TransferFilter};
 // Ext
 use std::fs::{create_dir, remove_file, File, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::string::ToString;
+use toml::Value;
+
+/// Maximum amount of directories whose view options are remembered
+const DIRECTORY_VIEWS_MAX: usize = 64;
 
 // Types
 pub type SshHost = (String, String, PathBuf); // 0: host, 1: username, 2: RSA key path
 
 /// ConfigClient provides a high level API to communicate with the termscp configuration
 pub struct ConfigClient {
-    config: UserConfig,   // Configuration loaded
-    config_path: PathBuf, // Configuration TOML Path
-    ssh_key_dir: PathBuf, // SSH Key storage directory
-    degraded: bool,       // Indicates the `ConfigClient` is working in degraded mode
+    config: UserConfig,             // Configuration loaded
+    config_path: PathBuf,           // Configuration TOML Path
+    ssh_key_dir: PathBuf,           // SSH Key storage directory
+    degraded: bool,                 // Indicates the `ConfigClient` is working in degraded mode
+    overrides: Vec<ConfigOverride>, // Values overriding the configuration keys
+    original: Option<Value>,        // Configuration as it was before applying overrides
 }
 
 impl ConfigClient {
@@ -43,6 +51,8 @@ impl ConfigClient {
             config_path: PathBuf::from(config_path),
             ssh_key_dir: PathBuf::from(ssh_key_dir),
             degraded: false,
+            overrides: overrides::get_overrides(),
+            original: None,
         };
         // If ssh key directory doesn't exist, create it
         if !ssh_key_dir.exists() {
@@ -66,6 +76,7 @@ impl ConfigClient {
                 return Err(err);
             }
             debug!("Config file didn't exist; created file");
+            client.apply_overrides()?;
         } else {
             // otherwise Load configuration from file
             if let Err(err) = client.read_config() {
@@ -81,12 +92,18 @@ impl ConfigClient {
     /// When in degraded mode, the configuration in use will be the default configuration
     /// and the IO operation on configuration won't be available
     pub fn degraded() -> Self {
-        Self {
+        let mut client = Self {
             config: UserConfig::default(),
             config_path: PathBuf::default(),
             ssh_key_dir: PathBuf::default(),
             degraded: true,
+            overrides: overrides::get_overrides(),
+            original: None,
+        };
+        if let Err(err) = client.apply_overrides() {
+            error!("Could not apply configuration overrides: {}", err);
         }
+        client
     }
 
     // Text editor
@@ -149,6 +166,98 @@ impl ConfigClient {
         self.config.user_interface.prompt_on_file_replace = Some(value);
     }
 
+    /// Get how symlinks are handled during recursive transfers
+    pub fn get_symlink_policy(&self) -> SymlinkPolicy {
+        self.config
+            .user_interface
+            .symlink_policy
+            .as_deref()
+            .and_then(|x| SymlinkPolicy::from_str(x).ok())
+            .unwrap_or(SymlinkPolicy::Follow)
+    }
+
+    /// Set how symlinks are handled during recursive transfers
+    pub fn set_symlink_policy(&mut self, policy: SymlinkPolicy) {
+        self.config.user_interface.symlink_policy = Some(policy.to_string());
+    }
+
+    /// Get the mapping applied to the owner of transferred files.
+    /// Returns `None` if ownership must not be preserved
+    pub fn get_ownership_map(&self) -> Option<OwnershipMap> {
+        self.config
+            .user_interface
+            .ownership_map
+            .as_deref()
+            .and_then(|x| OwnershipMap::from_str(x).ok())
+    }
+
+    /// Set the mapping applied to the owner of transferred files
+    pub fn set_ownership_map(&mut self, map: Option<OwnershipMap>) {
+        self.config.user_interface.ownership_map = map.map(|x| x.to_string());
+    }
+
+    /// Get the format of the explorers status bar.
+    /// Returns `None` if the default layout must be used
+    pub fn get_status_bar_fmt(&self) -> Option<StatusBarFmt> {
+        self.config
+            .user_interface
+            .status_bar_fmt
+            .as_deref()
+            .and_then(|x| StatusBarFmt::from_str(x).ok())
+    }
+
+    /// Set the format of the explorers status bar
+    pub fn set_status_bar_fmt(&mut self, fmt: Option<StatusBarFmt>) {
+        self.config.user_interface.status_bar_fmt = fmt.map(|x| x.to_string());
+    }
+
+    /// Get the include/exclude patterns applied by default to recursive transfers
+    pub fn get_transfer_filter(&self) -> Option<TransferFilter> {
+        self.config
+            .user_interface
+            .transfer_filter
+            .as_deref()
+            .and_then(|x| TransferFilter::from_str(x).ok())
+            .filter(|x| !x.is_empty())
+    }
+
+    /// Set the include/exclude patterns applied by default to recursive transfers
+    pub fn set_transfer_filter(&mut self, filter: Option<TransferFilter>) {
+        self.config.user_interface.transfer_filter = filter.map(|x| x.to_string());
+    }
+
+    /// Get the size in bytes above which files are uploaded as chunks.
+    /// Returns `None` if files must never be split
+    pub fn get_split_size(&self) -> Option<u64> {
+        self.config.user_interface.split_size.filter(|x| *x > 0)
+    }
+
+    /// Set the size in bytes above which files are uploaded as chunks
+    pub fn set_split_size(&mut self, size: Option<u64>) {
+        self.config.user_interface.split_size = size;
+    }
+
+    /// Get the amount of files above which recursive deletes must be confirmed typing it.
+    /// Returns `None` if deletes don't require a typed confirmation
+    pub fn get_delete_confirmation_threshold(&self) -> Option<usize> {
+        self.config.user_interface.delete_confirmation_threshold
+    }
+
+    /// Set the amount of files above which recursive deletes must be confirmed typing it
+    pub fn set_delete_confirmation_threshold(&mut self, threshold: Option<usize>) {
+        self.config.user_interface.delete_confirmation_threshold = threshold;
+    }
+
+    /// Get value of `partial_uploads`
+    pub fn get_partial_uploads(&self) -> bool {
+        self.config.user_interface.partial_uploads.unwrap_or(false)
+    }
+
+    /// Set new value for `partial_uploads`
+    pub fn set_partial_uploads(&mut self, value: bool) {
+        self.config.user_interface.partial_uploads = Some(value);
+    }
+
     /// Get GroupDirs value from configuration (will be converted from string)
     pub fn get_group_dirs(&self) -> Option<GroupDirs> {
         // Convert string to `GroupDirs`
@@ -167,6 +276,118 @@ impl ConfigClient {
         self.config.user_interface.group_dirs = val.map(|val| val.to_string());
     }
 
+    /// Get default file sorting from configuration (will be converted from string)
+    pub fn get_file_sorting(&self) -> FileSorting {
+        self.config
+            .user_interface
+            .file_sorting
+            .as_deref()
+            .and_then(|x| FileSorting::from_str(x).ok())
+            .unwrap_or(FileSorting::Name)
+    }
+
+    /// Set default file sorting in configuration
+    pub fn set_file_sorting(&mut self, sorting: FileSorting) {
+        self.config.user_interface.file_sorting = Some(sorting.to_string());
+    }
+
+    /// Get value of `show_hidden_files` for remote host; fallbacks to local host value
+    pub fn get_remote_show_hidden_files(&self) -> bool {
+        self.config
+            .user_interface
+            .remote_show_hidden_files
+            .unwrap_or_else(|| self.get_show_hidden_files())
+    }
+
+    /// Get GroupDirs value for remote host; fallbacks to local host value
+    pub fn get_remote_group_dirs(&self) -> Option<GroupDirs> {
+        match &self.config.user_interface.remote_group_dirs {
+            None => self.get_group_dirs(),
+            Some(val) => GroupDirs::from_str(val.as_str()).ok(),
+        }
+    }
+
+    /// Get file sorting for remote host; fallbacks to local host value
+    pub fn get_remote_file_sorting(&self) -> FileSorting {
+        match self.config.user_interface.remote_file_sorting.as_deref() {
+            None => self.get_file_sorting(),
+            Some(val) => FileSorting::from_str(val).unwrap_or(FileSorting::Name),
+        }
+    }
+
+    /// Get view options remembered for directory at `path`
+    pub fn get_directory_view(&self, path: &Path, remote: bool) -> Option<ExplorerView> {
+        self.config
+            .directory_views
+            .iter()
+            .find(|x| x.path == path && x.remote == remote)
+            .map(|x| ExplorerView {
+                file_sorting: FileSorting::from_str(x.file_sorting.as_str())
+                    .unwrap_or(FileSorting::Name),
+                hidden_files: x.show_hidden_files,
+                reverse_sorting: x.reverse_sorting,
+            })
+    }
+
+    /// Remember view options for directory at `path`.
+    /// If too many directories are remembered, the least recently used is forgotten
+    pub fn set_directory_view(&mut self, path: &Path, remote: bool, view: ExplorerView) {
+        self.remove_directory_view(path, remote);
+        if self.config.directory_views.len() >= DIRECTORY_VIEWS_MAX {
+            self.config.directory_views.remove(0);
+        }
+        self.config.directory_views.push(DirectoryView {
+            path: path.to_path_buf(),
+            remote,
+            file_sorting: view.file_sorting.to_string(),
+            show_hidden_files: view.hidden_files,
+            reverse_sorting: view.reverse_sorting,
+        });
+    }
+
+    /// Forget view options for directory at `path`
+    pub fn remove_directory_view(&mut self, path: &Path, remote: bool) {
+        self.config
+            .directory_views
+            .retain(|x| x.path != path || x.remote != remote);
+    }
+
+    /// Get the view options of the local explorer, or of the remote one if `remote`, saved when
+    /// the explorers were last closed
+    pub fn get_last_view(&self, remote: bool) -> Option<ExplorerView> {
+        match remote {
+            false => self.config.last_local_view.as_ref(),
+            true => self.config.last_remote_view.as_ref(),
+        }
+        .map(|x| ExplorerView {
+            file_sorting: FileSorting::from_str(x.file_sorting.as_str())
+                .unwrap_or(FileSorting::Name),
+            hidden_files: x.show_hidden_files,
+            reverse_sorting: x.reverse_sorting,
+        })
+    }
+
+    /// Save the view options of the local explorer, or of the remote one if `remote`, to restore
+    /// them the next time the explorers are built
+    pub fn set_last_view(&mut self, remote: bool, view: ExplorerView) {
+        let view = Some(LastView {
+            file_sorting: view.file_sorting.to_string(),
+            show_hidden_files: view.hidden_files,
+            reverse_sorting: view.reverse_sorting,
+        });
+        match remote {
+            false => self.config.last_local_view = view,
+            true => self.config.last_remote_view = view,
+        }
+    }
+
+    /// Forget the view options saved when the explorers were last closed, so that the defaults are
+    /// used again
+    pub fn clear_last_views(&mut self) {
+        self.config.last_local_view = None;
+        self.config.last_remote_view = None;
+    }
+
     /// Get current file fmt for local host
     pub fn get_local_file_fmt(&self) -> Option<String> {
         self.config.user_interface.file_fmt.clone()
@@ -203,6 +424,16 @@ impl ConfigClient {
         self.config.user_interface.notifications = Some(value);
     }
 
+    /// Get value of `accessibility`
+    pub fn get_accessibility(&self) -> bool {
+        self.config.user_interface.accessibility.unwrap_or(false)
+    }
+
+    /// Set new value for `accessibility`
+    pub fn set_accessibility(&mut self, value: bool) {
+        self.config.user_interface.accessibility = Some(value);
+    }
+
     /// Get value of `notification_threshold`
     pub fn get_notification_threshold(&self) -> u64 {
         self.config
@@ -228,6 +459,26 @@ impl ConfigClient {
         self.config.remote.ssh_config = p;
     }
 
+    /// Get whether FTP data connections should use active mode
+    pub fn get_ftp_active_mode(&self) -> bool {
+        self.config.remote.ftp_active_mode.unwrap_or(false)
+    }
+
+    /// Set whether FTP data connections should use active mode
+    pub fn set_ftp_active_mode(&mut self, value: bool) {
+        self.config.remote.ftp_active_mode = Some(value);
+    }
+
+    /// Get whether the SSH transport should be compressed
+    pub fn get_ssh_compression(&self) -> bool {
+        self.config.remote.ssh_compression.unwrap_or(false)
+    }
+
+    /// Set whether the SSH transport should be compressed
+    pub fn set_ssh_compression(&mut self, value: bool) {
+        self.config.remote.ssh_compression = Some(value);
+    }
+
     // SSH Keys
 
     /// Save a SSH key into configuration.
@@ -336,6 +587,19 @@ impl ConfigClient {
                 String::from("Configuration won't be saved, since in degraded mode"),
             ));
         }
+        // Overridden keys are written with the value they had before being overridden
+        let config: Option<Value> = match self.original.as_ref() {
+            Some(original) => {
+                let mut config = Value::try_from(&self.config).map_err(|e| {
+                    SerializerError::new_ex(SerializerErrorKind::Serialization, e.to_string())
+                })?;
+                for o in self.overrides.iter() {
+                    o.restore(&mut config, original);
+                }
+                Some(config)
+            }
+            None => None,
+        };
         // Open file
         match OpenOptions::new()
             .create(true)
@@ -343,7 +607,10 @@ impl ConfigClient {
             .truncate(true)
             .open(self.config_path.as_path())
         {
-            Ok(writer) => serialize(&self.config, Box::new(writer)),
+            Ok(writer) => match config {
+                Some(config) => serialize(&config, Box::new(writer)),
+                None => serialize(&self.config, Box::new(writer)),
+            },
             Err(err) => {
                 error!("Failed to write configuration file: {}", err);
                 Err(SerializerError::new_ex(
@@ -372,7 +639,7 @@ impl ConfigClient {
                 match deserialize(Box::new(reader)) {
                     Ok(config) => {
                         self.config = config;
-                        Ok(())
+                        self.apply_overrides()
                     }
                     Err(err) => Err(err),
                 }
@@ -387,6 +654,38 @@ impl ConfigClient {
         }
     }
 
+    /// Apply overrides to the configuration loaded
+    fn apply_overrides(&mut self) -> Result<(), SerializerError> {
+        if self.overrides.is_empty() {
+            return Ok(());
+        }
+        let to_value = |config: &UserConfig| {
+            Value::try_from(config).map_err(|e| {
+                SerializerError::new_ex(SerializerErrorKind::Serialization, e.to_string())
+            })
+        };
+        let original: Value = to_value(&self.config)?;
+        let mut config: Value = original.clone();
+        for o in self.overrides.iter() {
+            debug!("Overriding configuration key {}", o.name());
+            o.apply(&mut config)
+                .map_err(|e| SerializerError::new_ex(SerializerErrorKind::Syntax, e))?;
+        }
+        self.config = config.try_into().map_err(|e: toml::de::Error| {
+            SerializerError::new_ex(SerializerErrorKind::Syntax, e.to_string())
+        })?;
+        // Keys which are unknown are discarded when deserializing
+        let config: Value = to_value(&self.config)?;
+        if let Some(o) = self.overrides.iter().find(|x| !x.is_set(&config)) {
+            return Err(SerializerError::new_ex(
+                SerializerErrorKind::Syntax,
+                format!("{}: no such configuration key", o.name()),
+            ));
+        }
+        self.original = Some(original);
+        Ok(())
+    }
+
     /// Hosts are saved as `username@host` into configuration.
     /// This method creates the key name, starting from host and username
     fn make_ssh_host_key(host: &str, username: &str) -> String {
@@ -567,6 +866,115 @@ mod tests {
         assert_eq!(client.get_prompt_on_file_replace(), false);
     }
 
+    #[test]
+    fn should_get_and_set_symlink_policy() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_symlink_policy(), SymlinkPolicy::Follow);
+        client.set_symlink_policy(SymlinkPolicy::Skip);
+        assert_eq!(client.get_symlink_policy(), SymlinkPolicy::Skip);
+        client.config.user_interface.symlink_policy = Some(String::from("foobar"));
+        assert_eq!(client.get_symlink_policy(), SymlinkPolicy::Follow);
+    }
+
+    #[test]
+    fn should_get_and_set_ownership_map() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_ownership_map().is_none());
+        let map = OwnershipMap::default().current_user().uid(1000, 1001);
+        client.set_ownership_map(Some(map.clone()));
+        assert_eq!(client.get_ownership_map().unwrap(), map);
+        client.config.user_interface.ownership_map = Some(String::from("foobar"));
+        assert!(client.get_ownership_map().is_none());
+        client.set_ownership_map(None);
+        assert!(client.get_ownership_map().is_none());
+    }
+
+    #[test]
+    fn should_get_and_set_status_bar_fmt() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_status_bar_fmt().is_none());
+        let fmt = StatusBarFmt::from_str("{PATH} | {RATE}").unwrap();
+        client.set_status_bar_fmt(Some(fmt.clone()));
+        assert_eq!(client.get_status_bar_fmt().unwrap(), fmt);
+        client.config.user_interface.status_bar_fmt = Some(String::from("{FOOBAR}"));
+        assert!(client.get_status_bar_fmt().is_none());
+        client.set_status_bar_fmt(None);
+        assert!(client.get_status_bar_fmt().is_none());
+    }
+
+    #[test]
+    fn should_get_and_set_transfer_filter() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_transfer_filter().is_none());
+        let filter = TransferFilter::default().exclude("target/").exclude("*.o");
+        client.set_transfer_filter(Some(filter.clone()));
+        assert_eq!(client.get_transfer_filter().unwrap(), filter);
+        client.config.user_interface.transfer_filter = Some(String::from(" , "));
+        assert!(client.get_transfer_filter().is_none());
+        client.set_transfer_filter(None);
+        assert!(client.get_transfer_filter().is_none());
+    }
+
+    #[test]
+    fn should_get_and_set_split_size() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_split_size().is_none());
+        client.set_split_size(Some(1073741824));
+        assert_eq!(client.get_split_size(), Some(1073741824));
+        client.set_split_size(Some(0));
+        assert!(client.get_split_size().is_none());
+        client.set_split_size(None);
+        assert!(client.get_split_size().is_none());
+    }
+
+    #[test]
+    fn should_get_and_set_delete_confirmation_threshold() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_delete_confirmation_threshold().is_none());
+        client.set_delete_confirmation_threshold(Some(100));
+        assert_eq!(client.get_delete_confirmation_threshold(), Some(100));
+        client.set_delete_confirmation_threshold(None);
+        assert!(client.get_delete_confirmation_threshold().is_none());
+    }
+
+    #[test]
+    fn should_get_and_set_partial_uploads() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_partial_uploads(), false);
+        client.set_partial_uploads(true);
+        assert_eq!(client.get_partial_uploads(), true);
+        client.set_partial_uploads(false);
+        assert_eq!(client.get_partial_uploads(), false);
+    }
+
     #[test]
     fn test_system_config_group_dirs() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -580,6 +988,126 @@ mod tests {
         assert_eq!(client.get_group_dirs(), None,);
     }
 
+    #[test]
+    fn test_system_config_file_sorting() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_file_sorting(), FileSorting::Name); // Null ?
+        client.set_file_sorting(FileSorting::NaturalName);
+        assert_eq!(client.get_file_sorting(), FileSorting::NaturalName);
+        client.config.user_interface.file_sorting = Some(String::from("omar"));
+        assert_eq!(client.get_file_sorting(), FileSorting::Name);
+    }
+
+    #[test]
+    fn test_system_config_remote_view() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        // Fallback to local values
+        client.set_show_hidden_files(true);
+        client.set_group_dirs(Some(GroupDirs::First));
+        client.set_file_sorting(FileSorting::Size);
+        assert_eq!(client.get_remote_show_hidden_files(), true);
+        assert_eq!(client.get_remote_group_dirs(), Some(GroupDirs::First));
+        assert_eq!(client.get_remote_file_sorting(), FileSorting::Size);
+        // Set remote values
+        client.config.user_interface.remote_show_hidden_files = Some(false);
+        client.config.user_interface.remote_group_dirs = Some(String::from("last"));
+        client.config.user_interface.remote_file_sorting = Some(String::from("by_mtime"));
+        assert_eq!(client.get_remote_show_hidden_files(), false);
+        assert_eq!(client.get_remote_group_dirs(), Some(GroupDirs::Last));
+        assert_eq!(client.get_remote_file_sorting(), FileSorting::ModifyTime);
+        assert_eq!(client.get_show_hidden_files(), true);
+        assert_eq!(client.get_group_dirs(), Some(GroupDirs::First));
+        assert_eq!(client.get_file_sorting(), FileSorting::Size);
+    }
+
+    #[test]
+    fn test_system_config_last_views() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        client.set_file_sorting(FileSorting::Size);
+        client.set_show_hidden_files(false);
+        assert!(client.get_last_view(false).is_none());
+        assert!(client.get_last_view(true).is_none());
+        let view = ExplorerView {
+            file_sorting: FileSorting::ModifyTime,
+            hidden_files: true,
+            reverse_sorting: true,
+        };
+        client.set_last_view(true, view);
+        assert!(client.get_last_view(false).is_none());
+        assert_eq!(client.get_last_view(true), Some(view));
+        // Defaults are not changed
+        assert_eq!(client.get_file_sorting(), FileSorting::Size);
+        assert_eq!(client.get_show_hidden_files(), false);
+        assert_eq!(client.get_remote_file_sorting(), FileSorting::Size);
+        assert_eq!(client.get_remote_show_hidden_files(), false);
+        client.set_last_view(false, view);
+        assert_eq!(client.get_last_view(false), Some(view));
+        client.clear_last_views();
+        assert!(client.get_last_view(false).is_none());
+        assert!(client.get_last_view(true).is_none());
+    }
+
+    #[test]
+    fn test_system_config_directory_views() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        let view = ExplorerView {
+            file_sorting: FileSorting::ModifyTime,
+            hidden_files: true,
+            reverse_sorting: false,
+        };
+        assert!(client
+            .get_directory_view(Path::new("/var/log"), true)
+            .is_none());
+        client.set_directory_view(Path::new("/var/log"), true, view);
+        assert_eq!(
+            client.get_directory_view(Path::new("/var/log"), true),
+            Some(view)
+        );
+        assert!(client
+            .get_directory_view(Path::new("/var/log"), false)
+            .is_none());
+        // Update
+        let view = ExplorerView {
+            file_sorting: FileSorting::Name,
+            hidden_files: false,
+            reverse_sorting: true,
+        };
+        client.set_directory_view(Path::new("/var/log"), true, view);
+        assert_eq!(client.config.directory_views.len(), 1);
+        assert_eq!(
+            client.get_directory_view(Path::new("/var/log"), true),
+            Some(view)
+        );
+        // Least recently used is removed
+        for i in 0..DIRECTORY_VIEWS_MAX {
+            client.set_directory_view(PathBuf::from(format!("/tmp/{}", i)).as_path(), false, view);
+        }
+        assert_eq!(client.config.directory_views.len(), DIRECTORY_VIEWS_MAX);
+        assert!(client
+            .get_directory_view(Path::new("/var/log"), true)
+            .is_none());
+        client.remove_directory_view(Path::new("/tmp/1"), false);
+        assert!(client
+            .get_directory_view(Path::new("/tmp/1"), false)
+            .is_none());
+    }
+
     #[test]
     fn test_system_config_local_file_fmt() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -627,6 +1155,20 @@ mod tests {
         assert_eq!(client.get_notifications(), false);
     }
 
+    #[test]
+    fn should_get_and_set_accessibility() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_accessibility(), false);
+        client.set_accessibility(true);
+        assert_eq!(client.get_accessibility(), true);
+        client.set_accessibility(false);
+        assert_eq!(client.get_accessibility(), false);
+    }
+
     #[test]
     fn test_system_config_remote_notification_threshold() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -658,6 +1200,66 @@ mod tests {
         assert_eq!(client.get_ssh_config(), None);
     }
 
+    #[test]
+    fn test_system_config_remote_ftp_active_mode() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_ftp_active_mode(), false); // Null ?
+        client.set_ftp_active_mode(true);
+        assert_eq!(client.get_ftp_active_mode(), true);
+        client.set_ftp_active_mode(false);
+        assert_eq!(client.get_ftp_active_mode(), false);
+    }
+
+    #[test]
+    fn test_system_config_remote_ssh_compression() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_ssh_compression(), false); // Null ?
+        client.set_ssh_compression(true);
+        assert_eq!(client.get_ssh_compression(), true);
+        client.set_ssh_compression(false);
+        assert_eq!(client.get_ssh_compression(), false);
+    }
+
+    #[test]
+    fn test_system_config_overrides() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        client.set_text_editor(PathBuf::from("nano"));
+        assert!(client.write_config().is_ok());
+        client.overrides = vec![
+            ConfigOverride::parse("user_interface.text_editor=vim").unwrap(),
+            ConfigOverride::parse("remote.ftp_active_mode=true").unwrap(),
+        ];
+        assert!(client.read_config().is_ok());
+        assert_eq!(client.get_text_editor(), PathBuf::from("vim"));
+        assert!(client.get_ftp_active_mode());
+        // Overridden keys are not written
+        client.set_show_hidden_files(true);
+        assert!(client.write_config().is_ok());
+        client.overrides = vec![];
+        assert!(client.read_config().is_ok());
+        assert_eq!(client.get_text_editor(), PathBuf::from("nano"));
+        assert!(!client.get_ftp_active_mode());
+        assert!(client.get_show_hidden_files());
+        // Bad overrides
+        client.overrides = vec![ConfigOverride::parse("remote.foobar=1").unwrap()];
+        assert!(client.read_config().is_err());
+        client.overrides =
+            vec![ConfigOverride::parse("user_interface.show_hidden_files=1").unwrap()];
+        assert!(client.read_config().is_err());
+    }
+
     #[test]
     fn test_system_config_ssh_keys() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -4,12 +4,131 @@
 
 // Ext
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Name of the file which enables portable mode, when placed next to the executable
+const PORTABLE_SENTINEL: &str = "termscp.portable";
+
+static PORTABLE: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref PROFILE: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// ### set_profile
+///
+/// Set the configuration profile in use. `None` selects the default profile
+pub fn set_profile(profile: Option<&str>) {
+    *PROFILE.lock().unwrap() = profile.map(|x| x.to_string());
+}
+
+/// ### get_profile
+///
+/// Get the name of the configuration profile in use, if any
+pub fn get_profile() -> Option<String> {
+    PROFILE.lock().unwrap().clone()
+}
+
+/// ### is_valid_profile_name
+///
+/// Returns whether `name` can be used as a profile name.
+/// Profile names may contain only alphanumeric characters, `-` and `_`
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '-' || x == '_')
+}
+
+/// ### list_profiles
+///
+/// List the names of the configuration profiles which have been created
+pub fn list_profiles() -> Vec<String> {
+    let profiles_dir = match init_base_config_dir() {
+        Ok(Some(dir)) => get_profiles_dir(dir.as_path()),
+        _ => return Vec::new(),
+    };
+    let mut profiles: Vec<String> = match std::fs::read_dir(profiles_dir.as_path()) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|x| x.path().is_dir())
+            .filter_map(|x| x.file_name().to_str().map(|x| x.to_string()))
+            .filter(|x| is_valid_profile_name(x))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    profiles.sort();
+    profiles
+}
+
+/// ### set_portable
+///
+/// Enable portable mode, regardless of the sentinel file
+pub fn set_portable(portable: bool) {
+    PORTABLE.store(portable, Ordering::Relaxed);
+}
+
+/// ### is_portable
+///
+/// Returns whether termscp is running in portable mode.
+/// Portable mode is enabled through `set_portable` or by the `termscp.portable` file placed next to the executable
+pub fn is_portable() -> bool {
+    get_portable_exe_dir().is_some()
+}
+
+/// ### get_portable_dir
+///
+/// Get the path of the directory where data is stored in portable mode,
+/// given the directory containing the executable
+pub fn get_portable_dir(exe_dir: &Path) -> PathBuf {
+    let mut portable_dir: PathBuf = PathBuf::from(exe_dir);
+    portable_dir.push("termscp-data/");
+    portable_dir
+}
+
+/// Returns the directory containing the executable, if running in portable mode
+fn get_portable_exe_dir() -> Option<PathBuf> {
+    let exe_dir: PathBuf = std::env::current_exe().ok()?.parent()?.to_path_buf();
+    match PORTABLE.load(Ordering::Relaxed) || exe_dir.join(PORTABLE_SENTINEL).exists() {
+        true => Some(exe_dir),
+        false => None,
+    }
+}
 
 /// ### get_config_dir
 ///
 /// Get termscp configuration directory path.
+/// If a profile is in use, the path of the profile directory is returned.
 /// Returns None, if it's not possible to get it
 pub fn init_config_dir() -> Result<Option<PathBuf>, String> {
+    match (init_base_config_dir()?, get_profile()) {
+        (Some(dir), Some(profile)) => {
+            let mut p: PathBuf = get_profiles_dir(dir.as_path());
+            p.push(format!("{}/", profile));
+            match p.exists() {
+                true => Ok(Some(p)),
+                false => match std::fs::create_dir_all(p.as_path()) {
+                    Ok(_) => Ok(Some(p)),
+                    Err(err) => Err(err.to_string()),
+                },
+            }
+        }
+        (dir, _) => Ok(dir),
+    }
+}
+
+/// ### get_profiles_dir
+///
+/// Get path of the directory containing the configuration profiles
+pub fn get_profiles_dir(config_dir: &Path) -> PathBuf {
+    let mut profiles_dir: PathBuf = PathBuf::from(config_dir);
+    profiles_dir.push("profiles/");
+    profiles_dir
+}
+
+/// Get termscp configuration directory path, ignoring profiles
+fn init_base_config_dir() -> Result<Option<PathBuf>, String> {
     // Get file
     #[cfg(not(test))]
     lazy_static! {
@@ -19,11 +138,17 @@ pub fn init_config_dir() -> Result<Option<PathBuf>, String> {
     lazy_static! {
         static ref CONF_DIR: Option<PathBuf> = Some(std::env::temp_dir());
     }
-    if CONF_DIR.is_some() {
-        // Get path of bookmarks
-        let mut p: PathBuf = CONF_DIR.as_ref().unwrap().clone();
-        // Append termscp dir
-        p.push("termscp/");
+    // In portable mode, use the directory next to the executable
+    let conf_dir: Option<PathBuf> = match get_portable_exe_dir() {
+        Some(exe_dir) => Some(get_portable_dir(exe_dir.as_path())),
+        None => CONF_DIR.as_ref().map(|dir| {
+            let mut p: PathBuf = dir.clone();
+            // Append termscp dir
+            p.push("termscp/");
+            p
+        }),
+    };
+    if let Some(p) = conf_dir {
         // If directory doesn't exist, create it
         match p.exists() {
             true => Ok(Some(p)),
@@ -122,6 +247,52 @@ mod tests {
         assert!(std::fs::remove_file(conf_dir.as_path()).is_ok());
     }
 
+    #[test]
+    #[serial]
+    fn test_system_environment_profiles() {
+        assert!(get_profile().is_none());
+        set_profile(Some("work"));
+        assert_eq!(get_profile().as_deref(), Some("work"));
+        let conf_dir: PathBuf = init_config_dir().ok().unwrap().unwrap();
+        assert!(conf_dir.ends_with("termscp/profiles/work"));
+        assert!(conf_dir.is_dir());
+        assert_eq!(list_profiles(), vec![String::from("work")]);
+        set_profile(None);
+        assert!(get_profile().is_none());
+        let conf_dir: PathBuf = init_config_dir().ok().unwrap().unwrap();
+        assert!(conf_dir.ends_with("termscp"));
+        // Remove dir
+        assert!(std::fs::remove_dir_all(conf_dir.as_path()).is_ok());
+    }
+
+    #[test]
+    fn test_system_environment_is_valid_profile_name() {
+        assert!(is_valid_profile_name("work"));
+        assert!(is_valid_profile_name("my-profile_2"));
+        assert!(!is_valid_profile_name(""));
+        assert!(!is_valid_profile_name("../work"));
+        assert!(!is_valid_profile_name("my profile"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_system_environment_get_portable_dir() {
+        assert!(!is_portable());
+        assert_eq!(
+            get_portable_dir(Path::new("/media/usb/termscp/")),
+            PathBuf::from("/media/usb/termscp/termscp-data/"),
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_system_environment_get_profiles_dir() {
+        assert_eq!(
+            get_profiles_dir(Path::new("/home/omar/.config/termscp/")),
+            PathBuf::from("/home/omar/.config/termscp/profiles/"),
+        );
+    }
+
     #[test]
     #[serial]
     fn test_system_environment_get_bookmarks_paths() {
@@ -5,6 +5,7 @@
 // modules
 pub mod auto_update;
 pub mod bookmarks_client;
+pub mod config_archive;
 pub mod config_client;
 pub mod environment;
 pub(self) mod keys;
@@ -14,9 +14,10 @@ use std::string::ToString;
 
 /// ThemeProvider provides a high level API to communicate with the termscp theme
 pub struct ThemeProvider {
-    theme: Theme,        // Theme loaded
-    theme_path: PathBuf, // Theme TOML Path
-    degraded: bool,      // Fallback mode; won't work with file system
+    theme: Theme,         // Theme loaded
+    high_contrast: Theme, // Theme used in accessibility mode
+    theme_path: PathBuf,  // Theme TOML Path
+    degraded: bool,       // Fallback mode; won't work with file system
 }
 
 impl ThemeProvider {
@@ -30,6 +31,7 @@ impl ThemeProvider {
         // Create provider
         let mut provider: ThemeProvider = ThemeProvider {
             theme: default_theme,
+            high_contrast: Theme::high_contrast(),
             theme_path: theme_path.to_path_buf(),
             degraded: false,
         };
@@ -56,6 +58,7 @@ impl ThemeProvider {
     pub fn degraded() -> Self {
         Self {
             theme: Theme::default(),
+            high_contrast: Theme::high_contrast(),
             theme_path: PathBuf::default(),
             degraded: true,
         }
@@ -68,6 +71,11 @@ impl ThemeProvider {
         &self.theme
     }
 
+    /// Returns the high contrast theme, used instead of the loaded one in accessibility mode
+    pub fn high_contrast_theme(&self) -> &Theme {
+        &self.high_contrast
+    }
+
     /// Returns a mutable reference to the theme
     pub fn theme_mut(&mut self) -> &mut Theme {
         &mut self.theme
@@ -191,6 +199,7 @@ mod test {
         assert!(provider.load().is_err());
         assert_eq!(provider.theme().auth_address, Color::Yellow);
         assert!(provider.save().is_err());
+        assert_eq!(provider.high_contrast_theme(), &Theme::high_contrast());
     }
 
     #[test]
@@ -22,6 +22,18 @@ impl AuthActivity {
         }
     }
 
+    /// Assign `hotkey` to the bookmark at index; if the bookmark already has it, the hotkey is removed
+    pub(super) fn toggle_bookmark_hotkey(&mut self, idx: usize, hotkey: u8) {
+        let name = self.bookmarks_list.get(idx).cloned();
+        if let (Some(bookmarks_cli), Some(name)) = (self.bookmarks_client_mut(), name) {
+            match bookmarks_cli.get_hotkey(name.as_str()) == Some(hotkey) {
+                true => bookmarks_cli.set_hotkey(name.as_str(), None),
+                false => bookmarks_cli.set_hotkey(name.as_str(), Some(hotkey)),
+            }
+            self.write_bookmarks();
+        }
+    }
+
     /// Load selected bookmark (at index) to input fields
     pub(super) fn load_bookmark(&mut self, idx: usize) {
         if let Some(bookmarks_cli) = self.bookmarks_client() {
@@ -154,6 +166,10 @@ impl AuthActivity {
                 .map(|x| x.to_string_lossy().to_string())
                 .unwrap_or_default(),
         );
+        self.mount_upload_limit(bookmark.upload_limit);
+        self.mount_download_limit(bookmark.download_limit);
+        self.mount_read_only(bookmark.read_only);
+        self.mount_production(bookmark.production);
         match bookmark.params {
             ProtocolParams::AwsS3(params) => self.load_bookmark_s3_into_gui(params),
             ProtocolParams::Generic(params) => self.load_bookmark_generic_into_gui(params),
@@ -165,6 +181,8 @@ impl AuthActivity {
         self.mount_port(params.port);
         self.mount_username(params.username.as_deref().unwrap_or(""));
         self.mount_password(params.password.as_deref().unwrap_or(""));
+        self.mount_ssh_compression(params.compression);
+        self.mount_filename_encoding(params.encoding.as_deref().unwrap_or(""));
     }
 
     fn load_bookmark_s3_into_gui(&mut self, params: AwsS3Params) {
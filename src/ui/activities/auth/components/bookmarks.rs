@@ -3,6 +3,7 @@
 //! auth activity bookmarks components
 
 use super::{FormMsg, Msg, UiMsg};
+use crate::ui::i18n::tr;
 
 use tui_realm_stdlib::{Input, List, Radio};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
@@ -26,7 +27,7 @@ impl BookmarksList {
                 .rewind(true)
                 .scroll(true)
                 .step(4)
-                .title("Bookmarks", Alignment::Left)
+                .title(tr("bookmarks"), Alignment::Left)
                 .rows(
                     bookmarks
                         .iter()
@@ -90,6 +91,15 @@ impl Component<Msg, NoUserEvent> for BookmarksList {
             Event::Keyboard(KeyEvent {
                 code: Key::Delete, ..
             }) => Some(Msg::Ui(UiMsg::ShowDeleteBookmarkPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                modifiers: KeyModifiers::NONE,
+            }) => match self.state() {
+                State::One(StateValue::Usize(choice)) => Some(Msg::Form(
+                    FormMsg::SetBookmarkHotkey(choice, ch as u8 - b'0'),
+                )),
+                _ => Some(Msg::None),
+            },
             _ => None,
         }
     }
@@ -111,7 +121,7 @@ impl RecentsList {
                 .rewind(true)
                 .scroll(true)
                 .step(4)
-                .title("Recent connections", Alignment::Left)
+                .title(tr("recent-connections"), Alignment::Left)
                 .rows(
                     bookmarks
                         .iter()
@@ -196,11 +206,11 @@ impl DeleteBookmarkPopup {
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .value(1)
                 .rewind(true)
                 .foreground(color)
-                .title("Delete selected bookmark?", Alignment::Center),
+                .title(tr("delete-bookmark"), Alignment::Center),
         }
     }
 }
@@ -264,11 +274,11 @@ impl DeleteRecentPopup {
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .value(1)
                 .rewind(true)
                 .foreground(color)
-                .title("Delete selected recent host?", Alignment::Center),
+                .title(tr("delete-recent"), Alignment::Center),
         }
     }
 }
@@ -335,11 +345,11 @@ impl BookmarkSavePassword {
                         .sides(BorderSides::BOTTOM | BorderSides::LEFT | BorderSides::RIGHT)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .value(0)
                 .rewind(true)
                 .foreground(color)
-                .title("Save secrets?", Alignment::Center),
+                .title(tr("save-secrets"), Alignment::Center),
         }
     }
 }
@@ -391,7 +401,7 @@ impl BookmarkName {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Bookmark name", Alignment::Left)
+                .title(tr("bookmark-name"), Alignment::Left)
                 .input_type(InputType::Text),
         }
     }
@@ -3,6 +3,9 @@
 //! auth activity components for file transfer params form
 
 use super::{FileTransferProtocol, FormMsg, Msg, UiMsg};
+use crate::filetransfer::encoding::encoding_for_label;
+use crate::ui::i18n::tr;
+use crate::utils::parser::{is_valid_host, parse_bytesize};
 
 use tui_realm_stdlib::{Input, Radio};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
@@ -29,7 +32,7 @@ impl ProtocolRadio {
                 .choices(&["SFTP", "SCP", "FTP", "FTPS", "S3"])
                 .foreground(color)
                 .rewind(true)
-                .title("Protocol", Alignment::Left)
+                .title(tr("protocol"), Alignment::Left)
                 .value(Self::protocol_enum_to_opt(default_protocol)),
         }
     }
@@ -107,7 +110,7 @@ impl InputRemoteDirectory {
                 )
                 .foreground(color)
                 .placeholder("/home/foo", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Default remote directory", Alignment::Left)
+                .title(tr("default-remote-directory"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(remote_dir),
         }
@@ -125,6 +128,126 @@ impl Component<Msg, NoUserEvent> for InputRemoteDirectory {
     }
 }
 
+// -- filename encoding
+
+#[derive(MockComponent)]
+pub struct InputFilenameEncoding {
+    component: Input,
+}
+
+impl InputFilenameEncoding {
+    pub fn new(encoding: &str, color: Color) -> Self {
+        // -- validators
+        fn validate(label: &str) -> bool {
+            label.is_empty() || encoding_for_label(label).is_some()
+        }
+        fn char_valid(_input: &str, _incoming: char) -> bool {
+            true
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder("UTF-8", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("filename-encoding"), Alignment::Left)
+                .value(encoding),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for InputFilenameEncoding {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Ui(UiMsg::FilenameEncodingBlurDown),
+            Msg::Ui(UiMsg::FilenameEncodingBlurUp),
+        )
+    }
+}
+
+// -- bandwidth limits
+
+#[derive(MockComponent)]
+pub struct InputUploadLimit {
+    component: Input,
+}
+
+impl InputUploadLimit {
+    pub fn new(limit: &str, color: Color) -> Self {
+        Self {
+            component: bandwidth_limit_input(limit, &tr("upload-limit"), color),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for InputUploadLimit {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Ui(UiMsg::UploadLimitBlurDown),
+            Msg::Ui(UiMsg::UploadLimitBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct InputDownloadLimit {
+    component: Input,
+}
+
+impl InputDownloadLimit {
+    pub fn new(limit: &str, color: Color) -> Self {
+        Self {
+            component: bandwidth_limit_input(limit, &tr("download-limit"), color),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for InputDownloadLimit {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Ui(UiMsg::DownloadLimitBlurDown),
+            Msg::Ui(UiMsg::DownloadLimitBlurUp),
+        )
+    }
+}
+
+/// Make input for a bandwidth limit; empty means unlimited
+fn bandwidth_limit_input(limit: &str, title: &str, color: Color) -> Input {
+    // -- validators
+    fn validate(bytes: &str) -> bool {
+        bytes.is_empty() || parse_bytesize(bytes).is_some()
+    }
+    fn char_valid(_input: &str, incoming: char) -> bool {
+        incoming.is_ascii_digit() || ['B', 'K', 'M', 'G', 'T', 'P'].contains(&incoming)
+    }
+    Input::default()
+        .borders(
+            Borders::default()
+                .color(color)
+                .modifiers(BorderType::Rounded),
+        )
+        .foreground(color)
+        .invalid_style(Style::default().fg(Color::Red))
+        .input_type(InputType::Custom(validate, char_valid))
+        .placeholder(
+            tr("unlimited"),
+            Style::default().fg(Color::Rgb(128, 128, 128)),
+        )
+        .title(title, Alignment::Left)
+        .value(limit)
+}
+
 // -- address
 
 #[derive(MockComponent)]
@@ -134,6 +257,13 @@ pub struct InputAddress {
 
 impl InputAddress {
     pub fn new(host: &str, color: Color) -> Self {
+        // -- validators
+        fn validate(host: &str) -> bool {
+            host.is_empty() || is_valid_host(host)
+        }
+        fn char_valid(_input: &str, incoming: char) -> bool {
+            !incoming.is_whitespace()
+        }
         Self {
             component: Input::default()
                 .borders(
@@ -142,9 +272,10 @@ impl InputAddress {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
+                .invalid_style(Style::default().fg(Color::Red))
                 .placeholder("127.0.0.1", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Remote host", Alignment::Left)
-                .input_type(InputType::Text)
+                .title(tr("remote-host"), Alignment::Left)
+                .input_type(InputType::Custom(validate, char_valid))
                 .value(host),
         }
     }
@@ -170,6 +301,13 @@ pub struct InputPort {
 
 impl InputPort {
     pub fn new(port: u16, color: Color) -> Self {
+        // -- validators
+        fn validate(port: &str) -> bool {
+            matches!(port.parse::<u16>(), Ok(port) if port > 0)
+        }
+        fn char_valid(_input: &str, incoming: char) -> bool {
+            incoming.is_ascii_digit()
+        }
         Self {
             component: Input::default()
                 .borders(
@@ -178,10 +316,11 @@ impl InputPort {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
+                .invalid_style(Style::default().fg(Color::Red))
                 .placeholder("22", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .input_type(InputType::UnsignedInteger)
+                .input_type(InputType::Custom(validate, char_valid))
                 .input_len(5)
-                .title("Port number", Alignment::Left)
+                .title(tr("port-number"), Alignment::Left)
                 .value(port.to_string()),
         }
     }
@@ -216,7 +355,7 @@ impl InputUsername {
                 )
                 .foreground(color)
                 .placeholder("root", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Username", Alignment::Left)
+                .title(tr("username"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(username),
         }
@@ -251,7 +390,7 @@ impl InputPassword {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Password", Alignment::Left)
+                .title(tr("password"), Alignment::Left)
                 .input_type(InputType::Password('*'))
                 .value(password),
         }
@@ -287,7 +426,7 @@ impl InputS3Bucket {
                 )
                 .foreground(color)
                 .placeholder("my-bucket", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Bucket name", Alignment::Left)
+                .title(tr("bucket-name"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(bucket),
         }
@@ -323,7 +462,7 @@ impl InputS3Region {
                 )
                 .foreground(color)
                 .placeholder("eu-west-1", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Region", Alignment::Left)
+                .title(tr("region"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(region),
         }
@@ -362,7 +501,7 @@ impl InputS3Endpoint {
                     "http://localhost:9000",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Endpoint", Alignment::Left)
+                .title(tr("endpoint"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(endpoint),
         }
@@ -396,10 +535,10 @@ impl RadioS3NewPathStyle {
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(color)
                 .rewind(true)
-                .title("New path style", Alignment::Left)
+                .title(tr("new-path-style"), Alignment::Left)
                 .value(if new_path_style { 0 } else { 1 }),
         }
     }
@@ -437,6 +576,179 @@ impl Component<Msg, NoUserEvent> for RadioS3NewPathStyle {
     }
 }
 
+// -- read only
+
+#[derive(MockComponent)]
+pub struct RadioReadOnly {
+    component: Radio,
+}
+
+impl RadioReadOnly {
+    pub fn new(read_only: bool, color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("yes"), tr("no")])
+                .foreground(color)
+                .rewind(true)
+                .title(tr("read-only"), Alignment::Left)
+                .value(if read_only { 0 } else { 1 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for RadioReadOnly {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Form(FormMsg::Connect)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Some(Msg::Ui(UiMsg::ReadOnlyBlurDown)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => Some(Msg::Ui(UiMsg::ReadOnlyBlurUp)),
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                Some(Msg::Ui(UiMsg::ParamsFormBlur))
+            }
+            _ => None,
+        }
+    }
+}
+
+// -- production
+
+#[derive(MockComponent)]
+pub struct RadioProduction {
+    component: Radio,
+}
+
+impl RadioProduction {
+    pub fn new(production: bool, color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("yes"), tr("no")])
+                .foreground(color)
+                .rewind(true)
+                .title(tr("production-host"), Alignment::Left)
+                .value(if production { 0 } else { 1 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for RadioProduction {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Form(FormMsg::Connect)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Some(Msg::Ui(UiMsg::ProductionBlurDown)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                Some(Msg::Ui(UiMsg::ProductionBlurUp))
+            }
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                Some(Msg::Ui(UiMsg::ParamsFormBlur))
+            }
+            _ => None,
+        }
+    }
+}
+
+// -- ssh compression
+
+#[derive(MockComponent)]
+pub struct RadioSshCompression {
+    component: Radio,
+}
+
+impl RadioSshCompression {
+    pub fn new(compression: Option<bool>, color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("default"), tr("yes"), tr("no")])
+                .foreground(color)
+                .rewind(true)
+                .title(tr("compression"), Alignment::Left)
+                .value(match compression {
+                    None => 0,
+                    Some(true) => 1,
+                    Some(false) => 2,
+                }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for RadioSshCompression {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Form(FormMsg::Connect)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Some(Msg::Ui(UiMsg::SshCompressionBlurDown)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                Some(Msg::Ui(UiMsg::SshCompressionBlurUp))
+            }
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                Some(Msg::Ui(UiMsg::ParamsFormBlur))
+            }
+            _ => None,
+        }
+    }
+}
+
 // -- s3 profile
 
 #[derive(MockComponent)]
@@ -455,7 +767,7 @@ impl InputS3Profile {
                 )
                 .foreground(color)
                 .placeholder("default", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Profile", Alignment::Left)
+                .title(tr("profile"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(profile),
         }
@@ -491,7 +803,7 @@ impl InputS3AccessKey {
                 )
                 .foreground(color)
                 .placeholder("AKIA...", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Access key", Alignment::Left)
+                .title(tr("access-key"), Alignment::Left)
                 .input_type(InputType::Text)
                 .value(access_key),
         }
@@ -524,7 +836,7 @@ impl InputS3SecretAccessKey {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Secret access key", Alignment::Left)
+                .title(tr("secret-access-key"), Alignment::Left)
                 .input_type(InputType::Password('*'))
                 .value(secret_access_key),
         }
@@ -557,7 +869,7 @@ impl InputS3SecurityToken {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Security token", Alignment::Left)
+                .title(tr("security-token"), Alignment::Left)
                 .input_type(InputType::Password('*'))
                 .value(security_token),
         }
@@ -590,7 +902,7 @@ impl InputS3SessionToken {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Session token", Alignment::Left)
+                .title(tr("session-token"), Alignment::Left)
                 .input_type(InputType::Password('*'))
                 .value(session_token),
         }
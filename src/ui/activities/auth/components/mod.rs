@@ -14,13 +14,15 @@ pub use bookmarks::{
     RecentsList,
 };
 pub use form::{
-    InputAddress, InputPassword, InputPort, InputRemoteDirectory, InputS3AccessKey, InputS3Bucket,
-    InputS3Endpoint, InputS3Profile, InputS3Region, InputS3SecretAccessKey, InputS3SecurityToken,
-    InputS3SessionToken, InputUsername, ProtocolRadio, RadioS3NewPathStyle,
+    InputAddress, InputDownloadLimit, InputFilenameEncoding, InputPassword, InputPort,
+    InputRemoteDirectory, InputS3AccessKey, InputS3Bucket, InputS3Endpoint, InputS3Profile,
+    InputS3Region, InputS3SecretAccessKey, InputS3SecurityToken, InputS3SessionToken,
+    InputUploadLimit, InputUsername, ProtocolRadio, RadioProduction, RadioReadOnly,
+    RadioS3NewPathStyle, RadioSshCompression,
 };
 pub use popup::{
-    ErrorPopup, InfoPopup, InstallUpdatePopup, Keybindings, QuitPopup, ReleaseNotes, WaitPopup,
-    WindowSizeError,
+    ConnectWarning, ConnectWarningPopup, ErrorPopup, InfoPopup, InstallUpdatePopup, Keybindings,
+    QuitPopup, ReleaseNotes, WaitPopup, WindowSizeError,
 };
 pub use text::{HelpFooter, NewVersionDisclaimer, Subtitle, Title};
 
@@ -3,6 +3,7 @@
 //! auth activity popups
 
 use super::{FormMsg, Msg, UiMsg};
+use crate::ui::i18n::{tr, tr_indented};
 
 use tui_realm_stdlib::{List, Paragraph, Radio, Textarea};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
@@ -46,6 +47,102 @@ impl Component<Msg, NoUserEvent> for ErrorPopup {
     }
 }
 
+// -- connect warning
+
+#[derive(MockComponent)]
+pub struct ConnectWarning {
+    component: Paragraph,
+}
+
+impl ConnectWarning {
+    pub fn new<S: AsRef<str>>(text: S, color: Color) -> Self {
+        Self {
+            component: Paragraph::default()
+                .alignment(Alignment::Center)
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .text(&[TextSpan::from(text.as_ref())])
+                .wrap(true),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ConnectWarning {
+    fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+        None
+    }
+}
+
+#[derive(MockComponent)]
+pub struct ConnectWarningPopup {
+    component: Radio,
+}
+
+impl ConnectWarningPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title(tr("connect-anyway"), Alignment::Center)
+                .rewind(true)
+                .choices(&[tr("yes"), tr("no")])
+                .value(1),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ConnectWarningPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseConnectWarningPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Form(FormMsg::ConnectAnyway)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::CloseConnectWarningPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::Form(FormMsg::ConnectAnyway))
+                } else {
+                    Some(Msg::Ui(UiMsg::CloseConnectWarningPopup))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
 // -- info popup
 
 #[derive(MockComponent)]
@@ -130,9 +227,7 @@ impl WindowSizeError {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .text(&[TextSpan::from(
-                    "termscp requires at least 24 lines of height to run",
-                )])
+                .text(&[TextSpan::from(tr("window-too-small"))])
                 .wrap(true),
         }
     }
@@ -161,9 +256,9 @@ impl QuitPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Quit termscp?", Alignment::Center)
+                .title(tr("auth-quit"), Alignment::Center)
                 .rewind(true)
-                .choices(&["Yes", "No"]),
+                .choices(&[tr("yes"), tr("no")]),
         }
     }
 }
@@ -228,9 +323,9 @@ impl InstallUpdatePopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Install update?", Alignment::Center)
+                .title(tr("install-update"), Alignment::Center)
                 .rewind(true)
-                .choices(&["Yes", "No"]),
+                .choices(&[tr("yes"), tr("no")]),
         }
     }
 }
@@ -295,7 +390,7 @@ impl ReleaseNotes {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .title("Release notes", Alignment::Center)
+                .title(tr("release-notes"), Alignment::Center)
                 .text_rows(
                     notes
                         .lines()
@@ -369,34 +464,37 @@ impl Keybindings {
                         .modifiers(BorderType::Rounded),
                 )
                 .highlighted_str("? ")
-                .title("Keybindings", Alignment::Center)
+                .title(tr("keybindings"), Alignment::Center)
                 .scroll(true)
                 .step(4)
                 .rows(
                     TableBuilder::default()
                         .add_col(TextSpan::new("<ESC>").bold().fg(color))
-                        .add_col(TextSpan::from("           Quit termscp"))
+                        .add_col(TextSpan::from(tr_indented(11, "help-quit")))
                         .add_row()
                         .add_col(TextSpan::new("<TAB>").bold().fg(color))
-                        .add_col(TextSpan::from("           Switch from form and bookmarks"))
+                        .add_col(TextSpan::from(tr_indented(11, "help-auth-switch-tab")))
                         .add_row()
                         .add_col(TextSpan::new("<RIGHT/LEFT>").bold().fg(color))
-                        .add_col(TextSpan::from("    Switch bookmark tab"))
+                        .add_col(TextSpan::from(tr_indented(4, "help-switch-bookmark-tab")))
                         .add_row()
                         .add_col(TextSpan::new("<UP/DOWN>").bold().fg(color))
-                        .add_col(TextSpan::from("       Move up/down in current tab"))
+                        .add_col(TextSpan::from(tr_indented(7, "help-move-in-tab")))
                         .add_row()
                         .add_col(TextSpan::new("<ENTER>").bold().fg(color))
-                        .add_col(TextSpan::from("         Connect/Load bookmark"))
+                        .add_col(TextSpan::from(tr_indented(9, "help-connect")))
                         .add_row()
                         .add_col(TextSpan::new("<DEL|E>").bold().fg(color))
-                        .add_col(TextSpan::from("         Delete selected bookmark"))
+                        .add_col(TextSpan::from(tr_indented(9, "help-delete-bookmark")))
+                        .add_row()
+                        .add_col(TextSpan::new("<1-9>").bold().fg(color))
+                        .add_col(TextSpan::from(tr_indented(11, "help-bookmark-hotkey")))
                         .add_row()
                         .add_col(TextSpan::new("<CTRL+C>").bold().fg(color))
-                        .add_col(TextSpan::from("        Enter setup"))
+                        .add_col(TextSpan::from(tr_indented(8, "help-enter-setup")))
                         .add_row()
                         .add_col(TextSpan::new("<CTRL+S>").bold().fg(color))
-                        .add_col(TextSpan::from("        Save bookmark"))
+                        .add_col(TextSpan::from(tr_indented(8, "help-save-bookmark")))
                         .build(),
                 ),
         }
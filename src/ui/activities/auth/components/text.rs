@@ -3,6 +3,7 @@
 //! auth activity texts
 
 use super::Msg;
+use crate::ui::i18n::tr;
 
 use tui_realm_stdlib::{Label, Span};
 use tuirealm::props::{Color, TextModifiers, TextSpan};
@@ -67,9 +68,7 @@ impl NewVersionDisclaimer {
             component: Span::default().foreground(color).spans(&[
                 TextSpan::from("termscp "),
                 TextSpan::new(new_version).underlined().bold(),
-                TextSpan::from(
-                    " is NOW available! Install update and view release notes with <CTRL+R>",
-                ),
+                TextSpan::from(format!(" {}", tr("new-version-available"))),
             ]),
         }
     }
@@ -89,22 +88,30 @@ pub struct HelpFooter {
 }
 
 impl HelpFooter {
-    pub fn new(key_color: Color) -> Self {
+    /// If `update_available`, the key to install the new version is shown too
+    pub fn new(key_color: Color, update_available: bool) -> Self {
+        let mut spans = vec![
+            TextSpan::from("<F1|CTRL+H>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-help"))),
+            TextSpan::from("<CTRL+C>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-enter-setup"))),
+            TextSpan::from("<UP/DOWN>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-change-field"))),
+            TextSpan::from("<TAB>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-switch-tab"))),
+            TextSpan::from("<ENTER>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-submit-form"))),
+            TextSpan::from("<F10|ESC>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-quit"))),
+        ];
+        if update_available {
+            spans.extend([
+                TextSpan::from("<CTRL+R>").bold().fg(key_color),
+                TextSpan::from(format!(" {} ", tr("footer-install-update"))),
+            ]);
+        }
         Self {
-            component: Span::default().spans(&[
-                TextSpan::from("<F1|CTRL+H>").bold().fg(key_color),
-                TextSpan::from(" Help "),
-                TextSpan::from("<CTRL+C>").bold().fg(key_color),
-                TextSpan::from(" Enter setup "),
-                TextSpan::from("<UP/DOWN>").bold().fg(key_color),
-                TextSpan::from(" Change field "),
-                TextSpan::from("<TAB>").bold().fg(key_color),
-                TextSpan::from(" Switch tab "),
-                TextSpan::from("<ENTER>").bold().fg(key_color),
-                TextSpan::from(" Submit form "),
-                TextSpan::from("<F10|ESC>").bold().fg(key_color),
-                TextSpan::from(" Quit "),
-            ]),
+            component: Span::default().spans(&spans),
         }
     }
 }
@@ -2,10 +2,13 @@
 //!
 //! `auth_activity` is the module which implements the authentication activity
 
-use super::{AuthActivity, FileTransferParams, FileTransferProtocol};
-use crate::filetransfer::params::ProtocolParams;
+use super::{AuthActivity, ExitReason, FileTransferParams, FileTransferProtocol};
+use crate::filetransfer::params::{AwsS3Params, GenericProtocolParams, ProtocolParams};
 use crate::system::auto_update::{Release, Update, UpdateStatus};
 use crate::system::notifications::Notification;
+use crate::system::sshkey_storage::SshKeyStorage;
+
+use remotefs_ssh::SshKeyStorage as SshKeyStorageTrait;
 
 impl AuthActivity {
     /// Get the default port for protocol
@@ -52,10 +55,20 @@ impl AuthActivity {
         if params.port == 0 {
             return Err("Invalid port");
         }
+        if self.is_ssh_protocol() {
+            self.get_input_filename_encoding()
+                .map_err(|_| "Invalid filename encoding")?;
+        }
+        let (upload_limit, download_limit) = self.collect_bandwidth_limits()?;
         Ok(FileTransferParams {
             protocol,
             params: ProtocolParams::Generic(params),
             entry_directory: self.get_input_remote_directory(),
+            local_directory: None,
+            upload_limit,
+            download_limit,
+            read_only: self.get_input_read_only(),
+            production: self.get_input_production(),
         })
     }
 
@@ -65,13 +78,80 @@ impl AuthActivity {
         if params.bucket_name.is_empty() {
             return Err("Invalid bucket");
         }
+        let (upload_limit, download_limit) = self.collect_bandwidth_limits()?;
         Ok(FileTransferParams {
             protocol: FileTransferProtocol::AwsS3,
             params: ProtocolParams::AwsS3(params),
             entry_directory: self.get_input_remote_directory(),
+            local_directory: None,
+            upload_limit,
+            download_limit,
+            read_only: self.get_input_read_only(),
+            production: self.get_input_production(),
         })
     }
 
+    /// Returns a warning about fields which don't work together in `params`, if any;
+    /// the user is asked whether to connect anyway
+    pub(super) fn connection_warning(&self, params: &FileTransferParams) -> Option<String> {
+        match &params.params {
+            ProtocolParams::Generic(params) if self.is_ssh_protocol() => {
+                self.ssh_connection_warning(params)
+            }
+            ProtocolParams::Generic(_) => None,
+            ProtocolParams::AwsS3(params) => Self::s3_connection_warning(params),
+        }
+    }
+
+    /// The password is used as passphrase of the SSH key associated to the host, if any
+    fn ssh_connection_warning(&self, params: &GenericProtocolParams) -> Option<String> {
+        let username = params.username.as_deref()?;
+        params.password.as_ref()?;
+        let key = SshKeyStorage::from(self.config()).resolve(&params.address, username)?;
+        Some(format!(
+            "An SSH key ({}) is configured for {}@{}: the password will be used as the key passphrase, not to log in",
+            key.display(),
+            username,
+            params.address
+        ))
+    }
+
+    /// Access keys take precedence over the profile and require both the access key and the secret
+    fn s3_connection_warning(params: &AwsS3Params) -> Option<String> {
+        match (
+            params.access_key.is_some(),
+            params.secret_access_key.is_some(),
+        ) {
+            (true, false) | (false, true) => Some(String::from(
+                "The access key and the secret access key must be set together",
+            )),
+            (true, true) if params.profile.is_some() => Some(String::from(
+                "The access key and the secret access key will be used instead of the profile",
+            )),
+            _ => None,
+        }
+    }
+
+    /// Connect to the remote host with `params`
+    pub(super) fn connect(&mut self, params: FileTransferParams) {
+        self.save_recent();
+        // Set file transfer params to context
+        self.context_mut().set_ftparams(params);
+        // Set exit reason
+        self.exit_reason = Some(ExitReason::Connect);
+    }
+
+    /// Get upload and download limits from fields or return an error if they're invalid
+    fn collect_bandwidth_limits(&self) -> Result<(Option<u64>, Option<u64>), &'static str> {
+        let upload_limit = self
+            .get_input_upload_limit()
+            .map_err(|_| "Invalid upload limit")?;
+        let download_limit = self
+            .get_input_download_limit()
+            .map_err(|_| "Invalid download limit")?;
+        Ok((upload_limit, download_limit))
+    }
+
     // -- update install
 
     /// If enabled in configuration, check for updates from Github
@@ -28,9 +28,13 @@ pub enum Id {
     BookmarkName,
     BookmarkSavePassword,
     BookmarksList,
+    ConnectWarning,
+    ConnectWarningPopup,
     DeleteBookmarkPopup,
     DeleteRecentPopup,
+    DownloadLimit,
     ErrorPopup,
+    FilenameEncoding,
     GlobalListener,
     HelpFooter,
     InfoPopup,
@@ -40,8 +44,10 @@ pub enum Id {
     NewVersionDisclaimer,
     Password,
     Port,
+    Production,
     Protocol,
     QuitPopup,
+    ReadOnly,
     RecentsList,
     RemoteDirectory,
     S3AccessKey,
@@ -53,8 +59,10 @@ pub enum Id {
     S3SecretAccessKey,
     S3SecurityToken,
     S3SessionToken,
+    SshCompression,
     Subtitle,
     Title,
+    UploadLimit,
     Username,
     WaitPopup,
     WindowSizeError,
@@ -70,6 +78,7 @@ pub enum Msg {
 #[derive(Debug, PartialEq, Eq)]
 pub enum FormMsg {
     Connect,
+    ConnectAnyway,
     DeleteBookmark,
     DeleteRecent,
     EnterSetup,
@@ -79,6 +88,7 @@ pub enum FormMsg {
     ProtocolChanged(FileTransferProtocol),
     Quit,
     SaveBookmark,
+    SetBookmarkHotkey(usize, u8),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -87,6 +97,7 @@ pub enum UiMsg {
     AddressBlurUp,
     BookmarksListBlur,
     BookmarksTabBlur,
+    CloseConnectWarningPopup,
     CloseDeleteBookmark,
     CloseDeleteRecent,
     CloseErrorPopup,
@@ -95,13 +106,21 @@ pub enum UiMsg {
     CloseKeybindingsPopup,
     CloseQuitPopup,
     CloseSaveBookmark,
+    DownloadLimitBlurDown,
+    DownloadLimitBlurUp,
+    FilenameEncodingBlurDown,
+    FilenameEncodingBlurUp,
     ParamsFormBlur,
     PasswordBlurDown,
     PasswordBlurUp,
     PortBlurDown,
     PortBlurUp,
+    ProductionBlurDown,
+    ProductionBlurUp,
     ProtocolBlurDown,
     ProtocolBlurUp,
+    ReadOnlyBlurDown,
+    ReadOnlyBlurUp,
     RececentsListBlur,
     RemoteDirectoryBlurDown,
     RemoteDirectoryBlurUp,
@@ -123,6 +142,8 @@ pub enum UiMsg {
     S3SecurityTokenBlurUp,
     S3SessionTokenBlurDown,
     S3SessionTokenBlurUp,
+    SshCompressionBlurDown,
+    SshCompressionBlurUp,
     BookmarkNameBlur,
     SaveBookmarkPasswordBlur,
     ShowDeleteBookmarkPopup,
@@ -131,6 +152,8 @@ pub enum UiMsg {
     ShowQuitPopup,
     ShowReleaseNotes,
     ShowSaveBookmarkPopup,
+    UploadLimitBlurDown,
+    UploadLimitBlurUp,
     UsernameBlurDown,
     UsernameBlurUp,
     WindowResized,
@@ -206,9 +229,12 @@ impl AuthActivity {
         self.context_mut().bookmarks_client_mut()
     }
 
-    /// Returns a reference to theme
+    /// Returns a reference to theme; the high contrast one in accessibility mode
     fn theme(&self) -> &Theme {
-        self.context().theme_provider().theme()
+        match self.config().get_accessibility() {
+            true => self.context().theme_provider().high_contrast_theme(),
+            false => self.context().theme_provider().theme(),
+        }
     }
 
     /// Get current input mask to show
@@ -220,6 +246,14 @@ impl AuthActivity {
             | FileTransferProtocol::Sftp => InputMask::Generic,
         }
     }
+
+    /// Returns whether current protocol is based on SSH
+    fn is_ssh_protocol(&self) -> bool {
+        matches!(
+            self.protocol,
+            FileTransferProtocol::Scp | FileTransferProtocol::Sftp
+        )
+    }
 }
 
 impl Activity for AuthActivity {
@@ -4,6 +4,7 @@
 
 use super::{AuthActivity, ExitReason, FormMsg, Id, InputMask, Msg, UiMsg, Update};
 
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue};
 use tuirealm::{State, StateValue};
 
 impl Update<Msg> for AuthActivity {
@@ -26,13 +27,17 @@ impl AuthActivity {
                         // mount error
                         self.mount_error(err);
                     }
-                    Ok(params) => {
-                        self.save_recent();
-                        // Set file transfer params to context
-                        self.context_mut().set_ftparams(params);
-                        // Set exit reason
-                        self.exit_reason = Some(super::ExitReason::Connect);
-                    }
+                    Ok(params) => match self.connection_warning(&params) {
+                        Some(warning) => self.mount_connect_warning(warning),
+                        None => self.connect(params),
+                    },
+                }
+            }
+            FormMsg::ConnectAnyway => {
+                self.umount_connect_warning();
+                match self.collect_host_params() {
+                    Err(err) => self.mount_error(err),
+                    Ok(params) => self.connect(params),
                 }
             }
             FormMsg::DeleteBookmark => {
@@ -94,6 +99,20 @@ impl AuthActivity {
             FormMsg::Quit => {
                 self.exit_reason = Some(ExitReason::Quit);
             }
+            FormMsg::SetBookmarkHotkey(i, hotkey) => {
+                self.toggle_bookmark_hotkey(i, hotkey);
+                // Update bookmarks, keeping the selected one
+                self.view_bookmarks();
+                assert!(self
+                    .app
+                    .attr(
+                        &Id::BookmarksList,
+                        Attribute::Value,
+                        AttrValue::Payload(PropPayload::One(PropValue::Usize(i))),
+                    )
+                    .is_ok());
+                assert!(self.app.active(&Id::BookmarksList).is_ok());
+            }
             FormMsg::SaveBookmark => {
                 // get bookmark name
                 let (name, save_password) = self.get_new_bookmark();
@@ -127,6 +146,9 @@ impl AuthActivity {
             UiMsg::BookmarksTabBlur => {
                 assert!(self.app.active(&Id::Protocol).is_ok());
             }
+            UiMsg::CloseConnectWarningPopup => {
+                self.umount_connect_warning();
+            }
             UiMsg::CloseDeleteBookmark => {
                 assert!(self.app.umount(&Id::DeleteBookmarkPopup).is_ok());
             }
@@ -151,11 +173,35 @@ impl AuthActivity {
                 assert!(self.app.umount(&Id::BookmarkName).is_ok());
                 assert!(self.app.umount(&Id::BookmarkSavePassword).is_ok());
             }
+            UiMsg::DownloadLimitBlurDown => {
+                assert!(self
+                    .app
+                    .active(match self.input_mask() {
+                        InputMask::Generic if self.is_ssh_protocol() => &Id::FilenameEncoding,
+                        InputMask::Generic | InputMask::AwsS3 => &Id::ReadOnly,
+                    })
+                    .is_ok());
+            }
+            UiMsg::DownloadLimitBlurUp => {
+                assert!(self.app.active(&Id::UploadLimit).is_ok());
+            }
+            UiMsg::FilenameEncodingBlurDown => {
+                assert!(self.app.active(&Id::ReadOnly).is_ok());
+            }
+            UiMsg::FilenameEncodingBlurUp => {
+                assert!(self.app.active(&Id::DownloadLimit).is_ok());
+            }
             UiMsg::ParamsFormBlur => {
                 assert!(self.app.active(&Id::BookmarksList).is_ok());
             }
             UiMsg::PasswordBlurDown => {
-                assert!(self.app.active(&Id::RemoteDirectory).is_ok());
+                assert!(self
+                    .app
+                    .active(match self.is_ssh_protocol() {
+                        true => &Id::SshCompression,
+                        false => &Id::RemoteDirectory,
+                    })
+                    .is_ok());
             }
             UiMsg::PasswordBlurUp => {
                 assert!(self.app.active(&Id::Username).is_ok());
@@ -175,19 +221,38 @@ impl AuthActivity {
                     })
                     .is_ok());
             }
+            UiMsg::ProductionBlurDown => {
+                assert!(self.app.active(&Id::Protocol).is_ok());
+            }
+            UiMsg::ProductionBlurUp => {
+                assert!(self.app.active(&Id::ReadOnly).is_ok());
+            }
             UiMsg::ProtocolBlurUp => {
-                assert!(self.app.active(&Id::RemoteDirectory).is_ok());
+                assert!(self.app.active(&Id::Production).is_ok());
+            }
+            UiMsg::ReadOnlyBlurDown => {
+                assert!(self.app.active(&Id::Production).is_ok());
+            }
+            UiMsg::ReadOnlyBlurUp => {
+                assert!(self
+                    .app
+                    .active(match self.input_mask() {
+                        InputMask::Generic if self.is_ssh_protocol() => &Id::FilenameEncoding,
+                        InputMask::Generic | InputMask::AwsS3 => &Id::DownloadLimit,
+                    })
+                    .is_ok());
             }
             UiMsg::RececentsListBlur => {
                 assert!(self.app.active(&Id::BookmarksList).is_ok());
             }
             UiMsg::RemoteDirectoryBlurDown => {
-                assert!(self.app.active(&Id::Protocol).is_ok());
+                assert!(self.app.active(&Id::UploadLimit).is_ok());
             }
             UiMsg::RemoteDirectoryBlurUp => {
                 assert!(self
                     .app
                     .active(match self.input_mask() {
+                        InputMask::Generic if self.is_ssh_protocol() => &Id::SshCompression,
                         InputMask::Generic => &Id::Password,
                         InputMask::AwsS3 => &Id::S3NewPathStyle,
                     })
@@ -247,6 +312,12 @@ impl AuthActivity {
             UiMsg::S3NewPathStyleBlurUp => {
                 assert!(self.app.active(&Id::S3SessionToken).is_ok());
             }
+            UiMsg::SshCompressionBlurDown => {
+                assert!(self.app.active(&Id::RemoteDirectory).is_ok());
+            }
+            UiMsg::SshCompressionBlurUp => {
+                assert!(self.app.active(&Id::Password).is_ok());
+            }
             UiMsg::SaveBookmarkPasswordBlur => {
                 assert!(self.app.active(&Id::BookmarkName).is_ok());
             }
@@ -268,6 +339,12 @@ impl AuthActivity {
             UiMsg::ShowSaveBookmarkPopup => {
                 self.mount_bookmark_save_dialog();
             }
+            UiMsg::UploadLimitBlurDown => {
+                assert!(self.app.active(&Id::DownloadLimit).is_ok());
+            }
+            UiMsg::UploadLimitBlurUp => {
+                assert!(self.app.active(&Id::RemoteDirectory).is_ok());
+            }
             UiMsg::UsernameBlurDown => {
                 assert!(self.app.active(&Id::Password).is_ok());
             }
@@ -6,6 +6,8 @@
 use super::{components, AuthActivity, Context, FileTransferProtocol, Id, InputMask};
 use crate::filetransfer::params::{AwsS3Params, GenericProtocolParams, ProtocolParams};
 use crate::filetransfer::FileTransferParams;
+use crate::utils::fmt::fmt_bytes;
+use crate::utils::parser::parse_bytesize;
 use crate::utils::ui::draw_area_in;
 
 use std::path::PathBuf;
@@ -33,11 +35,16 @@ impl AuthActivity {
             )
             .is_ok());
         // Footer
+        let update_available = self
+            .context()
+            .store()
+            .get_string(super::STORE_KEY_LATEST_VERSION)
+            .is_some();
         assert!(self
             .app
             .mount(
                 Id::HelpFooter,
-                Box::new(components::HelpFooter::new(key_color)),
+                Box::new(components::HelpFooter::new(key_color, update_available)),
                 vec![]
             )
             .is_ok());
@@ -46,10 +53,16 @@ impl AuthActivity {
         // Auth form
         self.mount_protocol(default_protocol);
         self.mount_remote_directory("");
+        self.mount_upload_limit(None);
+        self.mount_download_limit(None);
+        self.mount_read_only(false);
+        self.mount_production(false);
         self.mount_address("");
         self.mount_port(Self::get_default_port_for_protocol(default_protocol));
         self.mount_username("");
         self.mount_password("");
+        self.mount_ssh_compression(None);
+        self.mount_filename_encoding("");
         self.mount_s3_bucket("");
         self.mount_s3_profile("");
         self.mount_s3_region("");
@@ -231,6 +244,22 @@ impl AuthActivity {
                 let popup = draw_area_in(f.size(), 30, 10);
                 f.render_widget(Clear, popup);
                 self.app.view(&Id::DeleteRecentPopup, f, popup);
+            } else if self.app.mounted(&Id::ConnectWarningPopup) {
+                // make popup
+                let popup = draw_area_in(f.size(), 50, 30);
+                f.render_widget(Clear, popup);
+                let popup_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Min(3),    // Warning
+                            Constraint::Length(3), // Connect radio
+                        ]
+                        .as_ref(),
+                    )
+                    .split(popup);
+                self.app.view(&Id::ConnectWarning, f, popup_chunks[0]);
+                self.app.view(&Id::ConnectWarningPopup, f, popup_chunks[1]);
             } else if self.app.mounted(&Id::NewVersionChangelog) {
                 // make popup
                 let popup = draw_area_in(f.size(), 90, 85);
@@ -281,7 +310,8 @@ impl AuthActivity {
             .bookmarks_list
             .iter()
             .map(|x| {
-                Self::fmt_bookmark(x, self.bookmarks_client().unwrap().get_bookmark(x).unwrap())
+                let client = self.bookmarks_client().unwrap();
+                Self::fmt_bookmark(x, client.get_hotkey(x), client.get_bookmark(x).unwrap())
             })
             .collect();
         let bookmarks_color = self.theme().auth_bookmarks;
@@ -527,6 +557,34 @@ impl AuthActivity {
         }
     }
 
+    /// Mount warning about the connection parameters, asking whether to connect anyway
+    pub(super) fn mount_connect_warning<S: AsRef<str>>(&mut self, text: S) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::ConnectWarning,
+                Box::new(components::ConnectWarning::new(text, warn_color)),
+                vec![]
+            )
+            .is_ok());
+        assert!(self
+            .app
+            .remount(
+                Id::ConnectWarningPopup,
+                Box::new(components::ConnectWarningPopup::new(warn_color)),
+                vec![]
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::ConnectWarningPopup).is_ok());
+    }
+
+    /// Umount connection warning
+    pub(super) fn umount_connect_warning(&mut self) {
+        let _ = self.app.umount(&Id::ConnectWarning);
+        let _ = self.app.umount(&Id::ConnectWarningPopup);
+    }
+
     /// Umount release notes text area
     pub(super) fn umount_release_notes(&mut self) {
         let _ = self.app.umount(&Id::NewVersionChangelog);
@@ -560,6 +618,60 @@ impl AuthActivity {
             .is_ok());
     }
 
+    pub(super) fn mount_upload_limit(&mut self, limit: Option<u64>) {
+        let protocol_color = self.theme().auth_protocol;
+        assert!(self
+            .app
+            .remount(
+                Id::UploadLimit,
+                Box::new(components::InputUploadLimit::new(
+                    limit.map(fmt_bytes).unwrap_or_default().as_str(),
+                    protocol_color
+                )),
+                vec![]
+            )
+            .is_ok());
+    }
+
+    pub(super) fn mount_download_limit(&mut self, limit: Option<u64>) {
+        let protocol_color = self.theme().auth_protocol;
+        assert!(self
+            .app
+            .remount(
+                Id::DownloadLimit,
+                Box::new(components::InputDownloadLimit::new(
+                    limit.map(fmt_bytes).unwrap_or_default().as_str(),
+                    protocol_color
+                )),
+                vec![]
+            )
+            .is_ok());
+    }
+
+    pub(super) fn mount_read_only(&mut self, read_only: bool) {
+        let protocol_color = self.theme().auth_protocol;
+        assert!(self
+            .app
+            .remount(
+                Id::ReadOnly,
+                Box::new(components::RadioReadOnly::new(read_only, protocol_color)),
+                vec![]
+            )
+            .is_ok());
+    }
+
+    pub(super) fn mount_production(&mut self, production: bool) {
+        let protocol_color = self.theme().auth_protocol;
+        assert!(self
+            .app
+            .remount(
+                Id::Production,
+                Box::new(components::RadioProduction::new(production, protocol_color)),
+                vec![]
+            )
+            .is_ok());
+    }
+
     pub(super) fn mount_address(&mut self, address: &str) {
         let addr_color = self.theme().auth_address;
         assert!(self
@@ -716,6 +828,30 @@ impl AuthActivity {
             .is_ok());
     }
 
+    pub(crate) fn mount_ssh_compression(&mut self, compression: Option<bool>) {
+        let color = self.theme().auth_address;
+        assert!(self
+            .app
+            .remount(
+                Id::SshCompression,
+                Box::new(components::RadioSshCompression::new(compression, color)),
+                vec![]
+            )
+            .is_ok());
+    }
+
+    pub(crate) fn mount_filename_encoding(&mut self, encoding: &str) {
+        let color = self.theme().auth_address;
+        assert!(self
+            .app
+            .remount(
+                Id::FilenameEncoding,
+                Box::new(components::InputFilenameEncoding::new(encoding, color)),
+                vec![]
+            )
+            .is_ok());
+    }
+
     // -- query
 
     /// Collect input values from view
@@ -724,11 +860,20 @@ impl AuthActivity {
         let port: u16 = self.get_input_port();
         let username = self.get_input_username();
         let password = self.get_input_password();
+        let (compression, encoding) = match self.is_ssh_protocol() {
+            true => (
+                self.get_input_ssh_compression(),
+                self.get_input_filename_encoding().ok().flatten(),
+            ),
+            false => (None, None),
+        };
         GenericProtocolParams::default()
             .address(addr)
             .port(port)
             .username(username)
             .password(password)
+            .compression(compression)
+            .encoding(encoding)
     }
 
     /// Collect s3 input values from view
@@ -760,6 +905,39 @@ impl AuthActivity {
         }
     }
 
+    pub(super) fn get_input_upload_limit(&self) -> Result<Option<u64>, ()> {
+        Self::get_input_bandwidth_limit(self.app.state(&Id::UploadLimit).ok())
+    }
+
+    pub(super) fn get_input_download_limit(&self) -> Result<Option<u64>, ()> {
+        Self::get_input_bandwidth_limit(self.app.state(&Id::DownloadLimit).ok())
+    }
+
+    pub(super) fn get_input_read_only(&self) -> bool {
+        matches!(
+            self.app.state(&Id::ReadOnly),
+            Ok(State::One(StateValue::Usize(0)))
+        )
+    }
+
+    pub(super) fn get_input_production(&self) -> bool {
+        matches!(
+            self.app.state(&Id::Production),
+            Ok(State::One(StateValue::Usize(0)))
+        )
+    }
+
+    /// Get bandwidth limit from input state. Returns error if the input is invalid
+    fn get_input_bandwidth_limit(state: Option<State>) -> Result<Option<u64>, ()> {
+        match state {
+            Some(State::One(StateValue::String(x))) if x.is_empty() => Ok(None),
+            Some(State::One(StateValue::String(x))) => {
+                parse_bytesize(x).map(|x| Some(x.as_u64())).ok_or(())
+            }
+            _ => Err(()),
+        }
+    }
+
     pub(super) fn get_input_addr(&self) -> String {
         match self.app.state(&Id::Address) {
             Ok(State::One(StateValue::String(x))) => x,
@@ -854,6 +1032,23 @@ impl AuthActivity {
         )
     }
 
+    /// Get filename encoding from input. Returns error if the encoding is unknown
+    pub(super) fn get_input_filename_encoding(&self) -> Result<Option<String>, ()> {
+        match self.app.state(&Id::FilenameEncoding) {
+            Ok(State::One(StateValue::String(x))) if x.trim().is_empty() => Ok(None),
+            Ok(State::One(StateValue::String(x))) => Ok(Some(x.trim().to_string())),
+            _ => Err(()),
+        }
+    }
+
+    pub(super) fn get_input_ssh_compression(&self) -> Option<bool> {
+        match self.app.state(&Id::SshCompression) {
+            Ok(State::One(StateValue::Usize(1))) => Some(true),
+            Ok(State::One(StateValue::Usize(2))) => Some(false),
+            _ => None,
+        }
+    }
+
     /// Get new bookmark params
     pub(super) fn get_new_bookmark(&self) -> (String, bool) {
         let name = match self.app.state(&Id::BookmarkName) {
@@ -883,9 +1078,12 @@ impl AuthActivity {
     // -- fmt
 
     /// Format bookmark to display on ui
-    fn fmt_bookmark(name: &str, b: FileTransferParams) -> String {
+    fn fmt_bookmark(name: &str, hotkey: Option<u8>, b: FileTransferParams) -> String {
         let addr: String = Self::fmt_recent(b);
-        format!("{} ({})", name, addr)
+        match hotkey {
+            Some(hotkey) => format!("[{}] {} ({})", hotkey, name, addr),
+            None => format!("{} ({})", name, addr),
+        }
     }
 
     /// Format recent connection to display on ui
@@ -922,9 +1120,70 @@ impl AuthActivity {
     /// Get the visible element in the generic params form, based on current focus
     fn get_generic_params_view(&self) -> [Id; 4] {
         match self.app.focus() {
+            Some(&Id::SshCompression) => [Id::Port, Id::Username, Id::Password, Id::SshCompression],
+            Some(&Id::RemoteDirectory) if self.is_ssh_protocol() => [
+                Id::Username,
+                Id::Password,
+                Id::SshCompression,
+                Id::RemoteDirectory,
+            ],
             Some(&Id::RemoteDirectory) => {
                 [Id::Port, Id::Username, Id::Password, Id::RemoteDirectory]
             }
+            Some(&Id::UploadLimit) if self.is_ssh_protocol() => [
+                Id::Password,
+                Id::SshCompression,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+            ],
+            Some(&Id::UploadLimit) => [
+                Id::Username,
+                Id::Password,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+            ],
+            Some(&Id::DownloadLimit) if self.is_ssh_protocol() => [
+                Id::SshCompression,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+            ],
+            Some(&Id::DownloadLimit) => [
+                Id::Password,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+            ],
+            Some(&Id::FilenameEncoding) => [
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::FilenameEncoding,
+            ],
+            Some(&Id::ReadOnly) if self.is_ssh_protocol() => [
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::FilenameEncoding,
+                Id::ReadOnly,
+            ],
+            Some(&Id::ReadOnly) => [
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::ReadOnly,
+            ],
+            Some(&Id::Production) if self.is_ssh_protocol() => [
+                Id::DownloadLimit,
+                Id::FilenameEncoding,
+                Id::ReadOnly,
+                Id::Production,
+            ],
+            Some(&Id::Production) => [
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::ReadOnly,
+                Id::Production,
+            ],
             _ => [Id::Address, Id::Port, Id::Username, Id::Password],
         }
     }
@@ -965,6 +1224,30 @@ impl AuthActivity {
                 Id::S3NewPathStyle,
                 Id::RemoteDirectory,
             ],
+            Some(&Id::UploadLimit) => [
+                Id::S3SessionToken,
+                Id::S3NewPathStyle,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+            ],
+            Some(&Id::DownloadLimit) => [
+                Id::S3NewPathStyle,
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+            ],
+            Some(&Id::ReadOnly) => [
+                Id::RemoteDirectory,
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::ReadOnly,
+            ],
+            Some(&Id::Production) => [
+                Id::UploadLimit,
+                Id::DownloadLimit,
+                Id::ReadOnly,
+                Id::Production,
+            ],
             _ => [Id::S3Bucket, Id::S3Region, Id::S3Endpoint, Id::S3Profile],
         }
     }
@@ -1062,9 +1345,14 @@ impl AuthActivity {
                                     Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
                                         Id::BookmarkSavePassword,
                                     )))),
-                                    Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
-                                        Id::WaitPopup,
-                                    )))),
+                                    Box::new(SubClause::And(
+                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                            Id::ConnectWarningPopup,
+                                        )))),
+                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                            Id::WaitPopup,
+                                        )))),
+                                    )),
                                 )),
                             )),
                         )),
@@ -0,0 +1,45 @@
+//! # bucket actions
+//!
+//! actions associated to S3 buckets
+
+use super::{FileTransferActivity, LogLevel};
+use crate::filetransfer::{Builder, FileTransferProtocol, ProtocolParams};
+
+impl FileTransferActivity {
+    /// Mount the bucket popup, if connected to S3
+    pub(crate) fn action_show_bucket_popup(&mut self) {
+        match self.context().ft_params().map(|x| x.protocol) {
+            Some(FileTransferProtocol::AwsS3) => self.mount_bucket(),
+            _ => self.mount_error("Switching bucket is supported only for S3"),
+        }
+    }
+
+    /// Disconnect from the current bucket and connect to `bucket`
+    pub(crate) fn action_switch_bucket(&mut self, bucket: String) {
+        // Keep the current session, if the bucket name is invalid
+        if bucket.trim().is_empty() {
+            self.mount_error("Bucket name can't be empty");
+            return;
+        }
+        let mut ft_params = match self.context().ft_params() {
+            Some(params) => params.clone(),
+            None => return,
+        };
+        match &mut ft_params.params {
+            ProtocolParams::AwsS3(params) => params.bucket_name = bucket.clone(),
+            ProtocolParams::Generic(_) => return,
+        }
+        // Entry directory belongs to the previous bucket
+        ft_params.entry_directory = None;
+        if let Err(err) = self.client.disconnect() {
+            warn!("failed to disconnect from bucket: {}", err);
+        }
+        self.log(
+            LogLevel::Info,
+            format!("Switching to bucket \"{}\"", bucket),
+        );
+        self.client = Builder::build(ft_params.protocol, ft_params.params.clone(), self.config());
+        self.context_mut().set_ftparams(ft_params);
+        // Client is now disconnected; connection will be established on the next draw
+    }
+}
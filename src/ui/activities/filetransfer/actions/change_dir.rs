@@ -4,15 +4,17 @@
 
 // locals
 use super::{FileExplorerTab, FileTransferActivity, LogLevel, Msg, PendingActionMsg};
+use crate::utils::string::common_prefix;
 
 use remotefs::File;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Describes destination for sync browsing
 enum SyncBrowsingDestination {
     Path(String),
     ParentDir,
     PreviousDir,
+    NextDir,
 }
 
 impl FileTransferActivity {
@@ -54,7 +56,7 @@ impl FileTransferActivity {
 
     /// Go to previous directory from localhost
     pub(crate) fn action_go_to_previous_local_dir(&mut self) {
-        if let Some(d) = self.local_mut().popd() {
+        if let Some(d) = self.local_mut().back() {
             self.local_changedir(d.as_path(), false);
             // Check whether to sync
             if self.browser.sync_browsing && self.browser.found().is_none() {
@@ -65,7 +67,7 @@ impl FileTransferActivity {
 
     /// Go to previous directory from remote host
     pub(crate) fn action_go_to_previous_remote_dir(&mut self) {
-        if let Some(d) = self.remote_mut().popd() {
+        if let Some(d) = self.remote_mut().back() {
             self.remote_changedir(d.as_path(), false);
             // Check whether to sync
             if self.browser.sync_browsing && self.browser.found().is_none() {
@@ -74,6 +76,60 @@ impl FileTransferActivity {
         }
     }
 
+    /// Go to the directory left going back on localhost
+    pub(crate) fn action_go_to_next_local_dir(&mut self) {
+        if let Some(d) = self.local_mut().forward() {
+            self.local_changedir(d.as_path(), false);
+            // Check whether to sync
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::NextDir);
+            }
+        }
+    }
+
+    /// Go to the directory left going back on remote host
+    pub(crate) fn action_go_to_next_remote_dir(&mut self) {
+        if let Some(d) = self.remote_mut().forward() {
+            self.remote_changedir(d.as_path(), false);
+            // Check whether to sync
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::NextDir);
+            }
+        }
+    }
+
+    /// Go to the directory at `index` in the local directory history.
+    /// Histories differ between hosts, so browsing is not synchronized
+    pub(crate) fn action_go_to_local_history_dir(&mut self, index: usize) {
+        if let Some(d) = self.local_mut().jump(index) {
+            self.local_changedir(d.as_path(), false);
+        }
+    }
+
+    /// Go to the directory at `index` in the remote directory history.
+    /// Histories differ between hosts, so browsing is not synchronized
+    pub(crate) fn action_go_to_remote_history_dir(&mut self, index: usize) {
+        if let Some(d) = self.remote_mut().jump(index) {
+            self.remote_changedir(d.as_path(), false);
+        }
+    }
+
+    /// Show the directory history of the current explorer
+    pub(crate) fn action_show_dir_history(&mut self) {
+        let explorer = match self.browser.tab() {
+            FileExplorerTab::Local => self.local(),
+            FileExplorerTab::Remote => self.remote(),
+            _ => return,
+        };
+        let history: Vec<PathBuf> = explorer
+            .history()
+            .into_iter()
+            .map(|x| x.to_path_buf())
+            .collect();
+        let index = explorer.history_index();
+        self.mount_dir_history(history.as_slice(), index);
+    }
+
     /// Go to upper directory on local host
     pub(crate) fn action_go_to_local_upper_dir(&mut self) {
         // Get pwd
@@ -104,6 +160,88 @@ impl FileTransferActivity {
         }
     }
 
+    // -- goto
+
+    /// Complete the last component of the path typed in the goto popup with the directories
+    /// found in its parent. If more than one directory matches, the candidates are shown in the popup
+    pub(crate) fn action_complete_goto_path(&mut self, input: String) {
+        let (parent, name) = match input.rfind('/') {
+            Some(i) => input.split_at(i + 1),
+            None => ("", input.as_str()),
+        };
+        let mut candidates: Vec<String> = self
+            .goto_candidate_dirs(parent)
+            .into_iter()
+            .filter(|x| x.starts_with(name))
+            // Hidden directories are completed only if explicitly requested
+            .filter(|x| name.starts_with('.') || !x.starts_with('.'))
+            .collect();
+        candidates.sort();
+        match candidates.len() {
+            0 => self.mount_goto_completion(input.as_str(), &[]),
+            1 => self.mount_goto_completion(format!("{}{}/", parent, candidates[0]).as_str(), &[]),
+            _ => self.mount_goto_completion(
+                format!("{}{}", parent, common_prefix(candidates.as_slice())).as_str(),
+                candidates.as_slice(),
+            ),
+        }
+    }
+
+    /// Check whether `input` is a directory on the current explorer.
+    /// Returns the error to report otherwise
+    pub(crate) fn check_goto_dir(&mut self, input: &str) -> Result<(), String> {
+        match self.browser.tab() {
+            FileExplorerTab::Local => {
+                let path = self.local_to_abs_path(Path::new(input));
+                if path.is_dir() {
+                    Ok(())
+                } else if path.exists() {
+                    Err(format!("\"{}\" is not a directory", path.display()))
+                } else {
+                    Err(format!("No such directory: \"{}\"", path.display()))
+                }
+            }
+            FileExplorerTab::Remote => {
+                let path = self.remote_to_abs_path(Path::new(input));
+                match self.client.stat(path.as_path()) {
+                    Ok(file) if file.is_dir() || file.is_symlink() => Ok(()),
+                    Ok(_) => Err(format!("\"{}\" is not a directory", path.display())),
+                    Err(err) => Err(format!("Could not access \"{}\": {}", path.display(), err)),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// List the names of the directories in `parent` on the current explorer
+    fn goto_candidate_dirs(&mut self, parent: &str) -> Vec<String> {
+        match self.browser.tab() {
+            FileExplorerTab::Local => {
+                let path = self.local_to_abs_path(Path::new(parent));
+                match self.host.scan_dir(path.as_path()) {
+                    Ok(files) => files
+                        .into_iter()
+                        .filter(|x| x.is_dir() || (x.is_symlink() && x.path().is_dir()))
+                        .map(|x| x.name())
+                        .collect(),
+                    Err(err) => {
+                        debug!("Could not list {}: {}", path.display(), err);
+                        Vec::new()
+                    }
+                }
+            }
+            FileExplorerTab::Remote => {
+                let path = self.remote_to_abs_path(Path::new(parent));
+                self.remote_cached_listing(path.as_path())
+                    .into_iter()
+                    .filter(|x| x.is_dir() || x.is_symlink())
+                    .map(|x| x.name())
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
     // -- sync browsing
 
     /// Synchronize browsing on the target browser.
@@ -149,7 +287,10 @@ impl FileTransferActivity {
                 trace!("User wants to create the unexisting directory");
                 // Make directory
                 match self.browser.tab() {
-                    FileExplorerTab::Local => self.action_remote_mkdir(name.clone()),
+                    FileExplorerTab::Local => {
+                        self.remote_cache.clear();
+                        self.action_remote_mkdir(name.clone());
+                    }
                     FileExplorerTab::Remote => self.action_local_mkdir(name.clone()),
                     _ => {}
                 }
@@ -184,11 +325,13 @@ impl FileTransferActivity {
                 FileExplorerTab::Remote => self.local_changedir(path.as_path(), true),
                 _ => {}
             },
-            SyncBrowsingDestination::PreviousDir => match self.browser.tab() {
-                FileExplorerTab::Local => self.remote_changedir(path.as_path(), false),
-                FileExplorerTab::Remote => self.local_changedir(path.as_path(), false),
-                _ => {}
-            },
+            SyncBrowsingDestination::PreviousDir | SyncBrowsingDestination::NextDir => {
+                match self.browser.tab() {
+                    FileExplorerTab::Local => self.remote_changedir(path.as_path(), false),
+                    FileExplorerTab::Remote => self.local_changedir(path.as_path(), false),
+                    _ => {}
+                }
+            }
         }
     }
 
@@ -206,7 +349,7 @@ impl FileTransferActivity {
                 self.local().wrkdir.parent().map(|x| x.to_path_buf())
             }
             (SyncBrowsingDestination::PreviousDir, FileExplorerTab::Local) => {
-                if let Some(p) = self.remote_mut().popd() {
+                if let Some(p) = self.remote_mut().back() {
                     Some(p)
                 } else {
                     warn!("Cannot synchronize browsing: remote has no previous directory in stack");
@@ -214,13 +357,29 @@ impl FileTransferActivity {
                 }
             }
             (SyncBrowsingDestination::PreviousDir, FileExplorerTab::Remote) => {
-                if let Some(p) = self.local_mut().popd() {
+                if let Some(p) = self.local_mut().back() {
                     Some(p)
                 } else {
                     warn!("Cannot synchronize browsing: local has no previous directory in stack");
                     None
                 }
             }
+            (SyncBrowsingDestination::NextDir, FileExplorerTab::Local) => {
+                if let Some(p) = self.remote_mut().forward() {
+                    Some(p)
+                } else {
+                    warn!("Cannot synchronize browsing: remote has no next directory in history");
+                    None
+                }
+            }
+            (SyncBrowsingDestination::NextDir, FileExplorerTab::Remote) => {
+                if let Some(p) = self.local_mut().forward() {
+                    Some(p)
+                } else {
+                    warn!("Cannot synchronize browsing: local has no next directory in history");
+                    None
+                }
+            }
             (SyncBrowsingDestination::Path(p), _) => Some(PathBuf::from(p.as_str())),
             _ => {
                 warn!("Cannot synchronize browsing for current explorer");
@@ -0,0 +1,147 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::{File, FileExplorerTab, FileTransferActivity, LogLevel, SelectedFile};
+use crate::utils::checksum::ChecksumAlgorithm;
+use crate::utils::string::shell_quote;
+use crate::utils::tty;
+
+use remotefs::RemoteErrorType;
+use std::fs::File as StdFile;
+use std::path::Path;
+
+impl FileTransferActivity {
+    /// Show the checksum popup for the selected file
+    pub(crate) fn action_show_checksum_popup(&mut self) {
+        if let Some(file) = self.get_checksum_file() {
+            self.mount_checksum(file.name().as_str());
+        }
+    }
+
+    /// Compute the checksum of the selected file with `algorithm` and show it
+    pub(crate) fn action_checksum(&mut self, algorithm: ChecksumAlgorithm) {
+        let file = match self.get_checksum_file() {
+            Some(file) => file,
+            None => return,
+        };
+        let result = match self.browser.tab() {
+            FileExplorerTab::Local | FileExplorerTab::FindLocal => {
+                Self::local_checksum(file.path(), algorithm)
+            }
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                self.remote_checksum(file.path(), algorithm)
+            }
+        };
+        match result {
+            Ok(digest) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "{} of \"{}\": {}",
+                        algorithm.name(),
+                        file.path().display(),
+                        digest
+                    ),
+                );
+                self.mount_checksum_result(file.name().as_str(), algorithm, digest);
+            }
+            Err(err) => self.log_and_alert(
+                LogLevel::Error,
+                format!(
+                    "Could not compute checksum of \"{}\": {}",
+                    file.path().display(),
+                    err
+                ),
+            ),
+        }
+    }
+
+    /// Copy `text` to the clipboard
+    pub(crate) fn action_copy_to_clipboard(&mut self, text: String) {
+        match tty::copy_to_clipboard(text.as_str()) {
+            Ok(_) => self.log(LogLevel::Info, format!("Copied \"{}\" to clipboard", text)),
+            Err(err) => self.log_and_alert(
+                LogLevel::Error,
+                format!("Could not copy to clipboard: {}", err),
+            ),
+        }
+    }
+
+    // -- private
+
+    /// Get the file to compute the checksum of; reports an error if the selection is not a single file
+    fn get_checksum_file(&mut self) -> Option<File> {
+        let selected = match self.browser.tab() {
+            FileExplorerTab::Local => self.get_local_selected_entries(),
+            FileExplorerTab::Remote => self.get_remote_selected_entries(),
+            FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => {
+                self.get_found_selected_entries()
+            }
+        };
+        match selected {
+            SelectedFile::One(file) if file.is_file() => Some(file),
+            SelectedFile::One(_) => {
+                self.mount_error("Checksum can be computed only for files");
+                None
+            }
+            SelectedFile::Many(_) => {
+                self.mount_error("Checksum cannot be computed if more than one file is selected");
+                None
+            }
+            SelectedFile::None => None,
+        }
+    }
+
+    pub(super) fn local_checksum(
+        path: &Path,
+        algorithm: ChecksumAlgorithm,
+    ) -> Result<String, String> {
+        StdFile::open(path)
+            .and_then(|reader| algorithm.digest(reader))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Compute the checksum of a remote file.
+    /// The digest is computed on the remote host if possible, otherwise the file is read and hashed locally
+    pub(super) fn remote_checksum(
+        &mut self,
+        path: &Path,
+        algorithm: ChecksumAlgorithm,
+    ) -> Result<String, String> {
+        let cmd = format!(
+            "{} {}",
+            algorithm.command(),
+            shell_quote(path.to_string_lossy().as_ref())
+        );
+        match self.client.exec(cmd.as_str()) {
+            Ok((0, output)) => {
+                if let Some(digest) = algorithm.parse_command_output(output.as_str()) {
+                    return Ok(digest);
+                }
+                debug!("unexpected output from \"{}\": {}", cmd, output);
+            }
+            Ok((rc, output)) => debug!("\"{}\" exited with {}: {}", cmd, rc, output),
+            Err(err) => debug!("could not execute \"{}\": {}", cmd, err),
+        }
+        debug!("computing checksum of {} locally", path.display());
+        match self.client.open(path) {
+            Ok(mut reader) => {
+                let digest = algorithm.digest(&mut reader).map_err(|e| e.to_string())?;
+                self.client.on_read(reader).map_err(|e| e.to_string())?;
+                Ok(digest)
+            }
+            Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => {
+                // Streams not supported; download the file and hash it locally
+                let tmpfile = tempfile::NamedTempFile::new().map_err(|e| e.to_string())?;
+                let writer = tmpfile.reopen().map_err(|e| e.to_string())?;
+                self.client
+                    .open_file(path, Box::new(writer))
+                    .map_err(|e| e.to_string())?;
+                Self::local_checksum(tmpfile.path(), algorithm)
+            }
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
@@ -76,6 +76,11 @@ impl FileTransferActivity {
     }
 
     fn remote_copy_file(&mut self, entry: File, dest: &Path) {
+        if !self.capabilities.copy {
+            // Files can't be copied on the remote host; download and upload them again
+            let _ = self.tricky_copy(entry, dest);
+            return;
+        }
         match self.client.as_mut().copy(entry.path(), dest) {
             Ok(_) => {
                 self.log(
@@ -3,11 +3,53 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 // locals
+use super::super::browser::FileExplorerTab;
 use super::{FileTransferActivity, LogLevel, SelectedFile};
+use crate::ui::i18n::tr;
 
 use remotefs::File;
 
 impl FileTransferActivity {
+    /// Ask to confirm the delete of the selected files.
+    /// The amount of files must be typed to confirm deletes on production hosts and
+    /// recursive deletes of more files than the configured threshold
+    pub(crate) fn action_show_delete_popup(&mut self) {
+        let (selected, remote) = match self.browser.tab() {
+            FileExplorerTab::Local => (self.get_local_selected_entries(), false),
+            FileExplorerTab::Remote => (self.get_remote_selected_entries(), true),
+            FileExplorerTab::FindLocal => (self.get_found_selected_entries(), false),
+            FileExplorerTab::FindRemote => (self.get_found_selected_entries(), true),
+        };
+        let entries = match selected {
+            SelectedFile::One(entry) => vec![entry],
+            SelectedFile::Many(entries) => entries,
+            SelectedFile::None => return,
+        };
+        let production = remote && self.production();
+        let threshold = self
+            .config()
+            .get_delete_confirmation_threshold()
+            .filter(|_| entries.iter().any(|x| x.is_dir()));
+        if !production && threshold.is_none() {
+            self.mount_radio_delete();
+            return;
+        }
+        self.mount_blocking_wait(tr("wait-counting-files").as_str());
+        let count: usize = entries
+            .iter()
+            .map(|x| match remote {
+                true => self.count_remote_files(x),
+                false => self.count_local_files(x),
+            })
+            .sum();
+        self.umount_wait();
+        if production || threshold.is_some_and(|x| count > x) {
+            self.mount_delete_confirm(count);
+        } else {
+            self.mount_radio_delete();
+        }
+    }
+
     pub(crate) fn action_local_delete(&mut self) {
         match self.get_local_selected_entries() {
             SelectedFile::One(entry) => {
@@ -42,6 +84,58 @@ impl FileTransferActivity {
         }
     }
 
+    /// Count the files which would be removed deleting `entry`, including `entry` itself
+    fn count_local_files(&mut self, entry: &File) -> usize {
+        if !entry.is_dir() {
+            return 1;
+        }
+        match self.host.scan_dir(entry.path()) {
+            Ok(files) => {
+                1 + files
+                    .iter()
+                    .map(|x| self.count_local_files(x))
+                    .sum::<usize>()
+            }
+            Err(err) => {
+                self.log(
+                    LogLevel::Warn,
+                    format!(
+                        "Could not list directory {}: {}",
+                        entry.path().display(),
+                        err
+                    ),
+                );
+                1
+            }
+        }
+    }
+
+    /// Count the files which would be removed deleting `entry`, including `entry` itself
+    fn count_remote_files(&mut self, entry: &File) -> usize {
+        if !entry.is_dir() {
+            return 1;
+        }
+        match self.client.list_dir(entry.path()) {
+            Ok(files) => {
+                1 + files
+                    .iter()
+                    .map(|x| self.count_remote_files(x))
+                    .sum::<usize>()
+            }
+            Err(err) => {
+                self.log(
+                    LogLevel::Warn,
+                    format!(
+                        "Could not list directory {}: {}",
+                        entry.path().display(),
+                        err
+                    ),
+                );
+                1
+            }
+        }
+    }
+
     pub(crate) fn local_remove_file(&mut self, entry: &File) {
         match self.host.remove(entry) {
             Ok(_) => {
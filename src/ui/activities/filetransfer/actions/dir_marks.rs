@@ -0,0 +1,54 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::{FileExplorerTab, FileTransferActivity, LogLevel};
+
+use std::path::PathBuf;
+
+impl FileTransferActivity {
+    /// Mark the working directory of the current explorer at `slot`
+    pub(crate) fn action_set_dir_mark(&mut self, slot: usize) {
+        let (wrkdir, marks) = match self.browser.tab() {
+            FileExplorerTab::Local => (self.local().wrkdir.clone(), &mut self.local_marks),
+            FileExplorerTab::Remote => (self.remote().wrkdir.clone(), &mut self.remote_marks),
+            _ => return,
+        };
+        marks.set(slot, wrkdir.as_path());
+        self.log(
+            LogLevel::Info,
+            format!("Marked \"{}\" at slot {}", wrkdir.display(), slot),
+        );
+    }
+
+    /// Go to the directory marked at `slot` in the current explorer.
+    /// Marks differ between hosts, so browsing is not synchronized
+    pub(crate) fn action_go_to_dir_mark(&mut self, slot: usize) {
+        let (path, local): (Option<PathBuf>, bool) = match self.browser.tab() {
+            FileExplorerTab::Local => (self.local_marks.get(slot).map(|x| x.to_path_buf()), true),
+            FileExplorerTab::Remote => {
+                (self.remote_marks.get(slot).map(|x| x.to_path_buf()), false)
+            }
+            _ => return,
+        };
+        match path {
+            Some(path) if local => self.local_changedir(path.as_path(), true),
+            Some(path) => self.remote_changedir(path.as_path(), true),
+            None => self.log_and_alert(
+                LogLevel::Warn,
+                format!("No directory marked at slot {}", slot),
+            ),
+        }
+    }
+
+    /// Show the directories marked in the current explorer
+    pub(crate) fn action_show_dir_marks(&mut self) {
+        let marks = match self.browser.tab() {
+            FileExplorerTab::Local => self.local_marks.clone(),
+            FileExplorerTab::Remote => self.remote_marks.clone(),
+            _ => return,
+        };
+        self.mount_dir_marks(&marks);
+    }
+}
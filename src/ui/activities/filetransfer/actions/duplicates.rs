@@ -0,0 +1,121 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::super::browser::{FileExplorerTab, FoundExplorerTab};
+use super::super::lib::duplicates::{self, Duplicate};
+use super::{File, FileTransferActivity, LogLevel};
+use crate::ui::i18n::tr;
+use crate::utils::checksum::ChecksumAlgorithm;
+
+/// Algorithm used to tell whether files with the same size have the same content
+const DUPLICATES_ALGORITHM: ChecksumAlgorithm = ChecksumAlgorithm::Sha256;
+
+impl FileTransferActivity {
+    /// Find the files in the current explorer with the same content of a file in the other explorer
+    /// and show them as find results, so that they can be deleted or left where they are.
+    /// If `recursive` is true, the files in the subdirectories are compared too
+    pub(crate) fn action_find_duplicates(&mut self, recursive: bool) {
+        let local = matches!(self.browser.tab(), FileExplorerTab::Local);
+        self.mount_blocking_wait(tr("wait-duplicates").as_str());
+        let result = self.find_duplicates(recursive);
+        self.umount_wait();
+        let duplicates = match result {
+            Ok(duplicates) => duplicates,
+            Err(err) => {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not find duplicates: {}", err),
+                );
+                return;
+            }
+        };
+        if duplicates.is_empty() {
+            self.mount_info("No duplicates found");
+            return;
+        }
+        for duplicate in duplicates.iter() {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "\"{}\" has the same content of \"{}\"",
+                    duplicate.local.path().display(),
+                    duplicate.remote.path().display()
+                ),
+            );
+        }
+        let (tab, files, wrkdir, title) = match local {
+            true => (
+                FoundExplorerTab::Local,
+                duplicates.into_iter().map(|x| x.local).collect(),
+                self.local().wrkdir.clone(),
+                "Duplicates of remote files",
+            ),
+            false => (
+                FoundExplorerTab::Remote,
+                duplicates.into_iter().map(|x| x.remote).collect(),
+                self.remote().wrkdir.clone(),
+                "Duplicates of local files",
+            ),
+        };
+        self.browser.set_found(tab, files, wrkdir.as_path());
+        self.mount_find(title.to_string());
+        self.update_find_list();
+        self.browser.change_tab(match local {
+            true => FileExplorerTab::FindLocal,
+            false => FileExplorerTab::FindRemote,
+        });
+    }
+
+    // -- private
+
+    /// Match the files in the local and in the remote working directories by size, then by checksum
+    fn find_duplicates(&mut self, recursive: bool) -> Result<Vec<Duplicate>, String> {
+        let (local, remote): (Vec<File>, Vec<File>) = match recursive {
+            true => (
+                self.host.find("*").map_err(|e| e.to_string())?,
+                self.client.find("*").map_err(|e| e.to_string())?,
+            ),
+            false => (
+                self.local().iter_files_all().cloned().collect(),
+                self.remote().iter_files_all().cloned().collect(),
+            ),
+        };
+        let (local, remote) = duplicates::same_size(
+            local.into_iter().filter(|x| x.is_file()).collect(),
+            remote.into_iter().filter(|x| x.is_file()).collect(),
+        );
+        debug!(
+            "hashing {} local and {} remote files with the same size",
+            local.len(),
+            remote.len()
+        );
+        let mut local_digests = Vec::with_capacity(local.len());
+        for file in local.into_iter() {
+            match Self::local_checksum(file.path(), DUPLICATES_ALGORITHM) {
+                Ok(digest) => local_digests.push((file, digest)),
+                Err(err) => self.log_checksum_skipped(&file, err),
+            }
+        }
+        let mut remote_digests = Vec::with_capacity(remote.len());
+        for file in remote.into_iter() {
+            match self.remote_checksum(file.path(), DUPLICATES_ALGORITHM) {
+                Ok(digest) => remote_digests.push((file, digest)),
+                Err(err) => self.log_checksum_skipped(&file, err),
+            }
+        }
+        Ok(duplicates::match_digests(local_digests, remote_digests))
+    }
+
+    fn log_checksum_skipped(&mut self, file: &File, err: String) {
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "Skipped \"{}\": could not compute checksum: {}",
+                file.path().display(),
+                err
+            ),
+        );
+    }
+}
@@ -7,10 +7,29 @@ use super::{FileTransferActivity, LogLevel};
 
 impl FileTransferActivity {
     pub(crate) fn action_local_exec(&mut self, input: String) {
+        self.local_commands.push(input.as_str());
         match self.host.exec(input.as_str()) {
             Ok(output) => {
                 // Reload files
-                self.log(LogLevel::Info, format!("\"{}\": {}", input, output));
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "\"{}\" (exitcode: {}): {}{}",
+                        input,
+                        output
+                            .exit_code
+                            .map(|x| x.to_string())
+                            .unwrap_or_else(|| String::from("none")),
+                        output.stdout,
+                        output.stderr
+                    ),
+                );
+                self.mount_exec_result(
+                    input.as_str(),
+                    output.exit_code,
+                    output.stdout.as_str(),
+                    output.stderr.as_str(),
+                );
             }
             Err(err) => {
                 // Report err
@@ -23,6 +42,7 @@ impl FileTransferActivity {
     }
 
     pub(crate) fn action_remote_exec(&mut self, input: String) {
+        self.remote_commands.push(input.as_str());
         match self.client.as_mut().exec(input.as_str()) {
             Ok((rc, output)) => {
                 // Reload files
@@ -30,6 +50,8 @@ impl FileTransferActivity {
                     LogLevel::Info,
                     format!("\"{}\" (exitcode: {}): {}", input, rc, output),
                 );
+                // The remote host doesn't tell stdout and stderr apart
+                self.mount_exec_result(input.as_str(), Some(rc as i32), output.as_str(), "");
             }
             Err(err) => {
                 // Report err
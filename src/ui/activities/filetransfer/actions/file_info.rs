@@ -0,0 +1,76 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::super::lib::file_metadata::{self, RichMetadata, SAMPLE_SIZE};
+use super::{File, FileExplorerTab, FileTransferActivity};
+
+use std::fs::File as StdFile;
+use std::io::{self, Seek, SeekFrom};
+use std::path::Path;
+
+impl FileTransferActivity {
+    /// Show the file info popup for `file`.
+    /// For regular files, the metadata is read from their content the first time they're shown
+    pub(crate) fn action_show_file_info(&mut self, file: &File) {
+        let remote = matches!(
+            self.browser.tab(),
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote
+        );
+        if file.is_file() && self.file_metadata.get(file, remote).is_none() {
+            let metadata = match remote {
+                true => self.read_remote_metadata(file.path(), file.metadata().size),
+                false => Self::read_local_metadata(file.path(), file.metadata().size),
+            };
+            match metadata {
+                Ok(metadata) => self.file_metadata.insert(file, remote, metadata),
+                Err(err) => debug!(
+                    "could not read metadata of {}: {}",
+                    file.path().display(),
+                    err
+                ),
+            }
+        }
+        let metadata = self
+            .file_metadata
+            .get(file, remote)
+            .filter(|x| !x.is_empty())
+            .cloned();
+        self.mount_file_info(file, metadata.as_ref());
+    }
+
+    // -- private
+
+    /// Read the metadata of a local file, from its first and last bytes
+    fn read_local_metadata(path: &Path, size: u64) -> Result<RichMetadata, String> {
+        Self::read_local_sample(path, size).map_err(|e| e.to_string())
+    }
+
+    fn read_local_sample(path: &Path, size: u64) -> io::Result<RichMetadata> {
+        let mut reader = StdFile::open(path)?;
+        let head = file_metadata::read_sample(&mut reader)?;
+        if size <= SAMPLE_SIZE as u64 {
+            return Ok(RichMetadata::parse(&head, Some(&head)));
+        }
+        reader.seek(SeekFrom::End(-(SAMPLE_SIZE as i64)))?;
+        let tail = file_metadata::read_sample(&mut reader)?;
+        Ok(RichMetadata::parse(&head, Some(&tail)))
+    }
+
+    /// Read the metadata of a remote file, from its first bytes only,
+    /// since streams can't seek; the whole file is never downloaded
+    fn read_remote_metadata(&mut self, path: &Path, size: u64) -> Result<RichMetadata, String> {
+        let mut reader = self.client.open(path).map_err(|e| e.to_string())?;
+        let head = file_metadata::read_sample(&mut reader);
+        if let Err(err) = self.client.on_read(reader) {
+            debug!("could not finalize read of {}: {}", path.display(), err);
+        }
+        let head = head.map_err(|e| e.to_string())?;
+        let tail = match size <= SAMPLE_SIZE as u64 {
+            true => Some(head.as_slice()),
+            false => None,
+        };
+        Ok(RichMetadata::parse(&head, tail))
+    }
+}
@@ -4,9 +4,13 @@
 
 // locals
 use super::super::browser::FileExplorerTab;
+use super::super::lib::search_results::{self, ExportFormat};
 use super::{File, FileTransferActivity, LogLevel, SelectedFile, TransferOpts, TransferPayload};
 
-use std::path::PathBuf;
+use remotefs::fs::UnixPex;
+use remotefs::RemoteErrorType;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 impl FileTransferActivity {
     pub(crate) fn action_local_find(&mut self, input: String) -> Result<Vec<File>, String> {
@@ -47,7 +51,34 @@ impl FileTransferActivity {
         }
     }
 
+    /// Change directory to the one containing the selected entry, whether it is a file or a directory
+    pub(crate) fn action_find_goto_parent_dir(&mut self) {
+        if let SelectedFile::One(entry) = self.get_found_selected_entries() {
+            let path = match entry.path().parent() {
+                None => PathBuf::from("."),
+                Some(p) => p.to_path_buf(),
+            };
+            match self.browser.tab() {
+                FileExplorerTab::FindLocal | FileExplorerTab::Local => {
+                    self.local_changedir(path.as_path(), true)
+                }
+                FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
+                    self.remote_changedir(path.as_path(), true)
+                }
+            }
+        }
+    }
+
     pub(crate) fn action_find_transfer(&mut self, opts: TransferOpts) {
+        // Symlink policy and filter apply to this action only
+        self.transfer.symlink_policy = opts.symlink_policy;
+        self.transfer.filter = opts.filter.clone();
+        self.find_transfer(opts);
+        self.transfer.symlink_policy = None;
+        self.transfer.filter = None;
+    }
+
+    fn find_transfer(&mut self, opts: TransferOpts) {
         let wrkdir: PathBuf = match self.browser.tab() {
             FileExplorerTab::FindLocal | FileExplorerTab::Local => self.remote().wrkdir.clone(),
             FileExplorerTab::FindRemote | FileExplorerTab::Remote => self.local().wrkdir.clone(),
@@ -108,6 +139,9 @@ impl FileTransferActivity {
                 // Iter files
                 match self.browser.tab() {
                     FileExplorerTab::FindLocal | FileExplorerTab::Local => {
+                        if !self.should_start_transfer_of(entries.as_slice(), true) {
+                            return;
+                        }
                         if self.config().get_prompt_on_file_replace() {
                             // Check which file would be replaced
                             let existing_files: Vec<&File> = entries
@@ -139,6 +173,9 @@ impl FileTransferActivity {
                         }
                     }
                     FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
+                        if !self.should_start_transfer_of(entries.as_slice(), false) {
+                            return;
+                        }
                         if self.config().get_prompt_on_file_replace() {
                             // Check which file would be replaced
                             let existing_files: Vec<&File> = entries
@@ -173,6 +210,131 @@ impl FileTransferActivity {
         }
     }
 
+    /// Export all the search results to the local file at `dest`.
+    /// The results are written as CSV if the file has the `.csv` extension, as text otherwise
+    pub(crate) fn action_find_export(&mut self, dest: &Path) {
+        let (count, export) = match self.found() {
+            Some(found) => (
+                found.iter_files_all().count(),
+                search_results::export(found.iter_files_all(), ExportFormat::from_path(dest)),
+            ),
+            None => return,
+        };
+        match fs::write(dest, export) {
+            Ok(()) => self.log(
+                LogLevel::Info,
+                format!(
+                    "Exported {} search results to \"{}\"",
+                    count,
+                    dest.display()
+                ),
+            ),
+            Err(err) => self.log_and_alert(
+                LogLevel::Error,
+                format!(
+                    "Could not export search results to \"{}\": {}",
+                    dest.display(),
+                    err
+                ),
+            ),
+        }
+    }
+
+    /// Transfer all the search results to the working directory of the other explorer,
+    /// preserving their directory structure relative to the directory the search started from
+    pub(crate) fn action_find_transfer_all(&mut self) {
+        let (root, files): (PathBuf, Vec<File>) = match self.found() {
+            Some(found) => (
+                found.wrkdir.clone(),
+                found.iter_files_all().cloned().collect(),
+            ),
+            None => return,
+        };
+        let upload = matches!(
+            self.browser.tab(),
+            FileExplorerTab::FindLocal | FileExplorerTab::Local
+        );
+        let wrkdir: PathBuf = match upload {
+            true => self.remote().wrkdir.clone(),
+            false => self.local().wrkdir.clone(),
+        };
+        // Ask once for all the results, before checking which files would be replaced
+        if !self.should_start_transfer_of(files.as_slice(), upload) {
+            return;
+        }
+        let layout = search_results::transfer_layout(files.as_slice(), root.as_path());
+        if self.config().get_prompt_on_file_replace() {
+            // Check which file would be replaced
+            let existing_files: Vec<&File> = layout
+                .iter()
+                .flat_map(|(dir, files)| files.iter().map(move |x| (dir, x)))
+                .filter(|(dir, x)| {
+                    let path = Self::file_to_check_many(x, wrkdir.join(dir).as_path());
+                    match upload {
+                        true => self.remote_file_exists(path.as_path()),
+                        false => self.local_file_exists(path.as_path()),
+                    }
+                })
+                .map(|(_, x)| x)
+                .collect();
+            // Check whether to replace files
+            if !existing_files.is_empty() && !self.should_replace_files(existing_files) {
+                return;
+            }
+        }
+        for (dir, files) in layout.into_iter() {
+            if let Err(err) = self.find_mkdir_all(wrkdir.as_path(), dir.as_path(), upload) {
+                self.log_and_alert(LogLevel::Error, err);
+                return;
+            }
+            let dest_path = wrkdir.join(dir);
+            let result = match upload {
+                true => {
+                    self.filetransfer_send(TransferPayload::Many(files), dest_path.as_path(), None)
+                }
+                false => {
+                    self.filetransfer_recv(TransferPayload::Many(files), dest_path.as_path(), None)
+                }
+            };
+            if let Err(err) = result {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not transfer search results: {}", err),
+                );
+                return;
+            }
+        }
+    }
+
+    /// Create the directory `dir`, relative to `wrkdir`, with all its parents on the remote host
+    /// if `remote` is true or on the local host otherwise
+    fn find_mkdir_all(&mut self, wrkdir: &Path, dir: &Path, remote: bool) -> Result<(), String> {
+        let mut path = wrkdir.to_path_buf();
+        for component in dir.components() {
+            path.push(component);
+            let result = match remote {
+                true => match self.client.create_dir(path.as_path(), UnixPex::from(0o755)) {
+                    Err(err) if err.kind != RemoteErrorType::DirectoryAlreadyExists => {
+                        Err(err.to_string())
+                    }
+                    _ => Ok(()),
+                },
+                false => self
+                    .host
+                    .mkdir_ex(path.as_path(), true)
+                    .map_err(|err| err.to_string()),
+            };
+            if let Err(err) = result {
+                return Err(format!(
+                    "Could not create directory \"{}\": {}",
+                    path.display(),
+                    err
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn action_find_delete(&mut self) {
         match self.get_found_selected_entries() {
             SelectedFile::One(entry) => {
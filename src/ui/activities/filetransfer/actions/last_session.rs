@@ -0,0 +1,134 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::{FileTransferActivity, Id, SelectedFile};
+use crate::config::bookmarks::Session;
+use crate::explorer::FileExplorer;
+
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue};
+
+impl FileTransferActivity {
+    /// Get the last session with the current host, if any
+    pub(crate) fn get_last_session(&self) -> Option<Session> {
+        let ft_params = self.context().ft_params()?;
+        self.context()
+            .bookmarks_client()
+            .and_then(|x| x.get_session(ft_params))
+            .cloned()
+    }
+
+    /// Save directories, cursors and selections of the explorers,
+    /// so they can be restored on the next connection to the current host
+    pub(crate) fn save_last_session(&mut self) {
+        let session = Session {
+            local_directory: self.local().wrkdir.clone(),
+            remote_directory: self.remote().wrkdir.clone(),
+            local_cursor: self.get_cursor_name(&Id::ExplorerLocal, self.local()),
+            remote_cursor: self.get_cursor_name(&Id::ExplorerRemote, self.remote()),
+            local_selection: Self::selection_names(self.get_local_selected_entries()),
+            remote_selection: Self::selection_names(self.get_remote_selected_entries()),
+            local_commands: self.local_commands.commands().to_vec(),
+            remote_commands: self.remote_commands.commands().to_vec(),
+            local_marks: self.local_marks.to_map(),
+            remote_marks: self.remote_marks.to_map(),
+            saved_at: 0,
+        };
+        let ft_params = match self.context().ft_params() {
+            Some(params) => params.clone(),
+            None => return,
+        };
+        if let Some(client) = self.context_mut().bookmarks_client_mut() {
+            client.set_session(&ft_params, session);
+            if let Err(err) = client.write_bookmarks() {
+                error!("Could not save session: {}", err);
+            }
+        }
+    }
+
+    /// Restore cursors and selections of the explorers from `session`, for the explorers whose
+    /// directory has been restored. Files which don't exist anymore are ignored
+    pub(crate) fn restore_last_session_entries(
+        &mut self,
+        session: &Session,
+        local: bool,
+        remote: bool,
+    ) {
+        if local {
+            let (cursor, selection) = Self::session_entries(
+                self.local(),
+                session.local_cursor.as_deref(),
+                &session.local_selection,
+            );
+            self.restore_explorer_entries(Id::ExplorerLocal, cursor, selection);
+        }
+        if remote {
+            let (cursor, selection) = Self::session_entries(
+                self.remote(),
+                session.remote_cursor.as_deref(),
+                &session.remote_selection,
+            );
+            self.restore_explorer_entries(Id::ExplorerRemote, cursor, selection);
+        }
+    }
+
+    // -- private
+
+    fn get_cursor_name(&self, id: &Id, explorer: &FileExplorer) -> Option<String> {
+        match self.app.query(id, Attribute::Value) {
+            Ok(Some(AttrValue::Payload(PropPayload::One(PropValue::Usize(idx))))) => {
+                explorer.get(idx).map(|x| x.name())
+            }
+            _ => None,
+        }
+    }
+
+    fn selection_names(selection: SelectedFile) -> Vec<String> {
+        match selection {
+            SelectedFile::Many(files) => files.iter().map(|x| x.name()).collect(),
+            SelectedFile::One(_) | SelectedFile::None => Vec::new(),
+        }
+    }
+
+    /// Get the index of the cursor and of the selected files in `explorer`
+    fn session_entries(
+        explorer: &FileExplorer,
+        cursor: Option<&str>,
+        selection: &[String],
+    ) -> (Option<usize>, Vec<usize>) {
+        let cursor = cursor.and_then(|name| explorer.iter_files().position(|x| x.name() == name));
+        let selection = explorer
+            .iter_files()
+            .enumerate()
+            .filter(|(_, x)| selection.contains(&x.name()))
+            .map(|(i, _)| i)
+            .collect();
+        (cursor, selection)
+    }
+
+    fn restore_explorer_entries(&mut self, id: Id, cursor: Option<usize>, selection: Vec<usize>) {
+        if let Some(idx) = cursor {
+            assert!(self
+                .app
+                .attr(
+                    &id,
+                    Attribute::Value,
+                    AttrValue::Payload(PropPayload::One(PropValue::Usize(idx)))
+                )
+                .is_ok());
+        }
+        if !selection.is_empty() {
+            assert!(self
+                .app
+                .attr(
+                    &id,
+                    Attribute::Value,
+                    AttrValue::Payload(PropPayload::Vec(
+                        selection.into_iter().map(PropValue::Usize).collect()
+                    ))
+                )
+                .is_ok());
+        }
+    }
+}
@@ -10,12 +10,18 @@ pub(self) use remotefs::File;
 use tuirealm::{State, StateValue};
 
 // actions
+pub(crate) mod bucket;
 pub(crate) mod change_dir;
+pub(crate) mod checksum;
 pub(crate) mod copy;
 pub(crate) mod delete;
+pub(crate) mod dir_marks;
+pub(crate) mod duplicates;
 pub(crate) mod edit;
 pub(crate) mod exec;
+pub(crate) mod file_info;
 pub(crate) mod find;
+pub(crate) mod last_session;
 pub(crate) mod mkdir;
 pub(crate) mod newfile;
 pub(crate) mod open;
@@ -24,6 +30,8 @@ pub(crate) mod rename;
 pub(crate) mod save;
 pub(crate) mod submit;
 pub(crate) mod symlink;
+pub(crate) mod tail;
+pub(crate) mod type_ahead;
 pub(crate) mod watcher;
 
 #[derive(Debug)]
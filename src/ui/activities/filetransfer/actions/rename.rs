@@ -74,6 +74,11 @@ impl FileTransferActivity {
     }
 
     pub(crate) fn remote_rename_file(&mut self, entry: &File, dest: &Path) {
+        if !self.capabilities.rename {
+            // Files can't be renamed on the remote host; copy them, then remove them
+            self.tricky_move(entry, dest);
+            return;
+        }
         match self.client.as_mut().mov(entry.path(), dest) {
             Ok(_) => {
                 self.log(
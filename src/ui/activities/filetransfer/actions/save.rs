@@ -3,10 +3,13 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 // locals
+use super::super::lib::transfer::TransferSummary;
 use super::{
     File, FileTransferActivity, LogLevel, Msg, PendingActionMsg, SelectedFile, TransferOpts,
     TransferPayload,
 };
+use crate::filetransfer::{SymlinkPolicy, TransferFilter};
+
 use std::path::{Path, PathBuf};
 
 impl FileTransferActivity {
@@ -26,7 +29,41 @@ impl FileTransferActivity {
         self.remote_recv_file(TransferOpts::default());
     }
 
+    pub(crate) fn action_local_send_with_symlink_policy(&mut self, policy: SymlinkPolicy) {
+        self.local_send_file(TransferOpts::default().symlink_policy(policy));
+    }
+
+    pub(crate) fn action_remote_recv_with_symlink_policy(&mut self, policy: SymlinkPolicy) {
+        self.remote_recv_file(TransferOpts::default().symlink_policy(policy));
+    }
+
+    pub(crate) fn action_local_send_with_filter(&mut self, filter: TransferFilter) {
+        self.local_send_file(TransferOpts::default().filter(filter));
+    }
+
+    pub(crate) fn action_remote_recv_with_filter(&mut self, filter: TransferFilter) {
+        self.remote_recv_file(TransferOpts::default().filter(filter));
+    }
+
     fn local_send_file(&mut self, opts: TransferOpts) {
+        // Symlink policy and filter apply to this action only
+        self.transfer.symlink_policy = opts.symlink_policy;
+        self.transfer.filter = opts.filter.clone();
+        self.local_send_selection(opts);
+        self.transfer.symlink_policy = None;
+        self.transfer.filter = None;
+    }
+
+    fn remote_recv_file(&mut self, opts: TransferOpts) {
+        // Symlink policy and filter apply to this action only
+        self.transfer.symlink_policy = opts.symlink_policy;
+        self.transfer.filter = opts.filter.clone();
+        self.remote_recv_selection(opts);
+        self.transfer.symlink_policy = None;
+        self.transfer.filter = None;
+    }
+
+    fn local_send_selection(&mut self, opts: TransferOpts) {
         let wrkdir: PathBuf = self.remote().wrkdir.clone();
         match self.get_local_selected_entries() {
             SelectedFile::One(entry) => {
@@ -58,6 +95,10 @@ impl FileTransferActivity {
                 if let Some(save_as) = opts.save_as {
                     dest_path.push(save_as);
                 }
+                // Ask whether to start the transfer, before checking which files would be replaced
+                if !self.should_start_transfer_of(entries.as_slice(), true) {
+                    return;
+                }
                 // Iter files
                 if self.config().get_prompt_on_file_replace() {
                     // Check which file would be replaced
@@ -91,7 +132,7 @@ impl FileTransferActivity {
         }
     }
 
-    fn remote_recv_file(&mut self, opts: TransferOpts) {
+    fn remote_recv_selection(&mut self, opts: TransferOpts) {
         let wrkdir: PathBuf = self.local().wrkdir.clone();
         match self.get_remote_selected_entries() {
             SelectedFile::One(entry) => {
@@ -122,6 +163,10 @@ impl FileTransferActivity {
                 if let Some(save_as) = opts.save_as {
                     dest_path.push(save_as);
                 }
+                // Ask whether to start the transfer, before checking which files would be replaced
+                if !self.should_start_transfer_of(entries.as_slice(), false) {
+                    return;
+                }
                 // Iter files
                 if self.config().get_prompt_on_file_replace() {
                     // Check which file would be replaced
@@ -175,6 +220,33 @@ impl FileTransferActivity {
         }
     }
 
+    /// Ask the user whether to transfer files which require `required` bytes,
+    /// while only `available` bytes are available on the destination
+    pub(crate) fn should_transfer_without_space(&mut self, required: u64, available: u64) -> bool {
+        self.mount_free_space(required, available);
+        // Wait for answer
+        trace!("Asking user whether he wants to transfer files anyway");
+        let transfer = self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::CloseFreeSpacePopup),
+            Msg::PendingAction(PendingActionMsg::TransferPendingFile),
+        ]) == Msg::PendingAction(PendingActionMsg::TransferPendingFile);
+        self.umount_free_space();
+        transfer
+    }
+
+    /// Show the summary of a transfer of many entries and ask the user whether to start it
+    pub(crate) fn should_start_transfer(&mut self, summary: &TransferSummary) -> bool {
+        self.mount_start_transfer(summary);
+        // Wait for answer
+        trace!("Asking user whether he wants to start the transfer");
+        let transfer = self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::CloseStartTransferPopup),
+            Msg::PendingAction(PendingActionMsg::TransferPendingFile),
+        ]) == Msg::PendingAction(PendingActionMsg::TransferPendingFile);
+        self.umount_start_transfer();
+        transfer
+    }
+
     /// Set pending transfer for many files into storage and mount radio
     pub(crate) fn should_replace_files(&mut self, files: Vec<&File>) -> bool {
         let file_names: Vec<String> = files.iter().map(|x| x.name()).collect();
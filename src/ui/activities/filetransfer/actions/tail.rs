@@ -0,0 +1,174 @@
+//! # tail actions
+//!
+//! actions associated to the remote file follow mode (`tail -f`)
+
+use super::super::lib::tail::{parse_tail_output, tail_command, TailMethod, TailStates};
+use super::{FileTransferActivity, Id, LogLevel, SelectedFile};
+
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::Duration;
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue, TextSpan};
+
+/// Interval between two reads of the followed file
+const TAIL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+impl FileTransferActivity {
+    /// Start following the selected remote file
+    pub(crate) fn action_remote_tail(&mut self) {
+        let file = match self.get_remote_selected_entries() {
+            SelectedFile::One(file) if file.is_file() => file,
+            SelectedFile::One(_) => {
+                self.mount_error("Only files can be followed");
+                return;
+            }
+            SelectedFile::Many(_) => {
+                self.mount_error("Follow mode cannot be used if more than one file is selected");
+                return;
+            }
+            SelectedFile::None => return,
+        };
+        let protocol = self.context().ft_params().unwrap().protocol;
+        let method = match TailMethod::new(protocol, self.capabilities.tail) {
+            Some(method) => method,
+            None => {
+                self.mount_error(
+                    "Follow mode requires SFTP or a remote host providing tail, head and od",
+                );
+                return;
+            }
+        };
+        debug!(
+            "following remote file {} ({:?})",
+            file.path().display(),
+            method
+        );
+        self.tail = Some(TailStates::new(
+            file.path(),
+            file.metadata().size,
+            TAIL_POLL_INTERVAL,
+            method,
+        ));
+        self.mount_tail(file.path());
+        self.poll_tail();
+    }
+
+    /// Stop following the remote file
+    pub(crate) fn action_stop_tail(&mut self) {
+        if let Some(tail) = self.tail.take() {
+            debug!("stopped following {}", tail.path().display());
+        }
+        self.umount_tail();
+    }
+
+    /// Read data appended to the followed file, if the poll interval has elapsed
+    pub(crate) fn poll_tail(&mut self) {
+        let (path, offset, method) = match self.tail.as_ref() {
+            Some(tail) if tail.should_poll() => {
+                (tail.path().to_path_buf(), tail.offset(), tail.method())
+            }
+            _ => return,
+        };
+        let size = match self.client.stat(path.as_path()) {
+            Ok(file) => file.metadata().size,
+            Err(err) => {
+                self.tail_failed(
+                    path.as_path(),
+                    format!("Could not stat followed file: {}", err),
+                );
+                return;
+            }
+        };
+        let offset = if size < offset {
+            trace!("{} has been truncated", path.display());
+            self.tail.as_mut().unwrap().truncated();
+            0
+        } else {
+            offset
+        };
+        if size > offset {
+            match self.tail_read(path.as_path(), offset, size - offset, method) {
+                Ok(data) => self.tail.as_mut().unwrap().push(&data),
+                Err(err) => {
+                    self.tail_failed(
+                        path.as_path(),
+                        format!("Could not read followed file: {}", err),
+                    );
+                    return;
+                }
+            }
+        }
+        self.tail.as_mut().unwrap().polled();
+        self.update_tail();
+    }
+
+    /// Report the failed poll of the followed file at `path`.
+    /// The file is polled less frequently after each consecutive failure, and it's not followed
+    /// anymore after too many of them
+    fn tail_failed(&mut self, path: &Path, err: String) {
+        self.log(LogLevel::Warn, format!("{} (\"{}\")", err, path.display()));
+        if self.tail.as_mut().unwrap().failed() {
+            self.action_stop_tail();
+            self.log_and_alert(
+                LogLevel::Error,
+                format!("Stopped following \"{}\": {}", path.display(), err),
+            );
+        }
+    }
+
+    /// Read `len` bytes from remote file at `path`, starting from `offset`, with `method`
+    fn tail_read(
+        &mut self,
+        path: &Path,
+        offset: u64,
+        len: u64,
+        method: TailMethod,
+    ) -> Result<Vec<u8>, String> {
+        match method {
+            TailMethod::Seek => {
+                let mut data: Vec<u8> = Vec::with_capacity(len as usize);
+                let mut reader = self.client.open(path).map_err(|e| e.to_string())?;
+                if !reader.seekable() {
+                    return Err(String::from("the file can't be read from an offset"));
+                }
+                reader
+                    .seek(SeekFrom::Start(offset))
+                    .map_err(|e| e.to_string())?;
+                (&mut reader)
+                    .take(len)
+                    .read_to_end(&mut data)
+                    .map_err(|e| e.to_string())?;
+                self.client.on_read(reader).map_err(|e| e.to_string())?;
+                Ok(data)
+            }
+            TailMethod::Exec => match self.client.exec(tail_command(path, offset, len).as_str()) {
+                Ok((0, output)) => parse_tail_output(output.as_str())
+                    .ok_or_else(|| String::from("unexpected output from tail")),
+                Ok((rc, output)) => Err(format!("tail exited with code {}: {}", rc, output.trim())),
+                Err(err) => Err(err.to_string()),
+            },
+        }
+    }
+
+    /// Update tail popup content and scroll to the last line
+    fn update_tail(&mut self) {
+        let lines: Vec<Vec<TextSpan>> = match self.tail.as_ref() {
+            Some(tail) => tail.lines().map(|x| vec![TextSpan::from(x)]).collect(),
+            None => return,
+        };
+        let last = lines.len().saturating_sub(1);
+        assert!(self
+            .app
+            .attr(&Id::TailPopup, Attribute::Content, AttrValue::Table(lines))
+            .is_ok());
+        assert!(self
+            .app
+            .attr(
+                &Id::TailPopup,
+                Attribute::Value,
+                AttrValue::Payload(PropPayload::One(PropValue::Usize(last)))
+            )
+            .is_ok());
+        self.redraw = true;
+    }
+}
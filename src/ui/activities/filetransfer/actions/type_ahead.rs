@@ -0,0 +1,36 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::{FileExplorerTab, FileTransferActivity, Id};
+
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue};
+
+impl FileTransferActivity {
+    /// Move the cursor of the current explorer to the first entry whose name starts with `query` (case insensitive)
+    pub(crate) fn action_type_ahead(&mut self, query: String) {
+        let (explorer, id) = match self.browser.tab() {
+            FileExplorerTab::Local => (self.local(), Id::ExplorerLocal),
+            FileExplorerTab::Remote => (self.remote(), Id::ExplorerRemote),
+            FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => match self.found() {
+                Some(found) => (found, Id::ExplorerFind),
+                None => return,
+            },
+        };
+        let query = query.to_lowercase();
+        let position = explorer
+            .iter_files()
+            .position(|x| x.name().to_lowercase().starts_with(query.as_str()));
+        if let Some(idx) = position {
+            assert!(self
+                .app
+                .attr(
+                    &id,
+                    Attribute::Value,
+                    AttrValue::Payload(PropPayload::One(PropValue::Usize(idx)))
+                )
+                .is_ok());
+        }
+    }
+}
@@ -90,6 +90,7 @@ impl FileTransferActivity {
                         remote.display()
                     ),
                 );
+                self.refresh_local_status_bar();
             }
             Some(Err(err)) => {
                 self.log_and_alert(
@@ -109,6 +110,7 @@ impl FileTransferActivity {
                     LogLevel::Info,
                     format!("{} is no longer watched", path.display()),
                 );
+                self.refresh_local_status_bar();
             }
             Some(Err(err)) => {
                 self.log_and_alert(LogLevel::Error, format!("could not unwatch path: {}", err));
@@ -3,6 +3,7 @@
 //! log tab component
 
 use super::{Msg, UiMsg};
+use crate::ui::i18n::tr;
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent};
@@ -59,7 +60,7 @@ impl MockComponent for Log {
         let w = TuiList::new(list_items)
             .block(tui_realm_stdlib::utils::get_block(
                 borders,
-                Some(("Log".to_string(), Alignment::Left)),
+                Some((tr("log"), Alignment::Left)),
                 focus,
                 None,
             ))
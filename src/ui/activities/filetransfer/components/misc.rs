@@ -3,6 +3,7 @@
 //! file transfer activity components
 
 use super::Msg;
+use crate::ui::i18n::tr;
 
 use tui_realm_stdlib::Span;
 use tuirealm::props::{Color, TextSpan};
@@ -14,34 +15,48 @@ pub struct FooterBar {
 }
 
 impl FooterBar {
-    pub fn new(key_color: Color) -> Self {
-        Self {
-            component: Span::default().spans(&[
-                TextSpan::from("<F1|H>").bold().fg(key_color),
-                TextSpan::from(" Help "),
-                TextSpan::from("<TAB>").bold().fg(key_color),
-                TextSpan::from(" Change tab "),
-                TextSpan::from("<SPACE>").bold().fg(key_color),
-                TextSpan::from(" Transfer "),
-                TextSpan::from("<ENTER>").bold().fg(key_color),
-                TextSpan::from(" Enter dir "),
-                TextSpan::from("<F2|S>").bold().fg(key_color),
-                TextSpan::from(" Save as "),
-                TextSpan::from("<F3|V>").bold().fg(key_color),
-                TextSpan::from(" View "),
+    pub fn new(key_color: Color, read_only: bool) -> Self {
+        let mut spans = vec![
+            TextSpan::from("<F1|H>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-help"))),
+            TextSpan::from("<TAB>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-change-tab"))),
+            TextSpan::from("<SPACE>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-transfer"))),
+            TextSpan::from("<ENTER>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-enter-dir"))),
+            TextSpan::from("<F2|S>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-save-as"))),
+            TextSpan::from("<F3|V>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-view"))),
+        ];
+        // Destructive operations are hidden in read-only mode
+        if !read_only {
+            spans.extend([
                 TextSpan::from("<F4|O>").bold().fg(key_color),
-                TextSpan::from(" Edit "),
+                TextSpan::from(format!(" {} ", tr("footer-edit"))),
                 TextSpan::from("<F5|C>").bold().fg(key_color),
-                TextSpan::from(" Copy "),
+                TextSpan::from(format!(" {} ", tr("footer-copy"))),
                 TextSpan::from("<F6|R>").bold().fg(key_color),
-                TextSpan::from(" Rename "),
-                TextSpan::from("<F7|D>").bold().fg(key_color),
-                TextSpan::from(" Make dir "),
+                TextSpan::from(format!(" {} ", tr("footer-rename"))),
+            ]);
+        }
+        spans.extend([
+            TextSpan::from("<F7|D>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-make-dir"))),
+        ]);
+        if !read_only {
+            spans.extend([
                 TextSpan::from("<F8|DEL>").bold().fg(key_color),
-                TextSpan::from(" Delete "),
-                TextSpan::from("<F10|Q>").bold().fg(key_color),
-                TextSpan::from(" Quit "),
-            ]),
+                TextSpan::from(format!(" {} ", tr("footer-delete"))),
+            ]);
+        }
+        spans.extend([
+            TextSpan::from("<F10|Q>").bold().fg(key_color),
+            TextSpan::from(format!(" {} ", tr("footer-quit"))),
+        ]);
+        Self {
+            component: Span::default().spans(&spans),
         }
     }
 }
@@ -19,13 +19,17 @@ mod transfer;
 pub use self::log::Log;
 pub use misc::FooterBar;
 pub use popups::{
-    CopyPopup, DeletePopup, DisconnectPopup, ErrorPopup, ExecPopup, FatalPopup, FileInfoPopup,
-    FindPopup, GoToPopup, KeybindingsPopup, MkdirPopup, NewfilePopup, OpenWithPopup,
-    ProgressBarFull, ProgressBarPartial, QuitPopup, RenamePopup, ReplacePopup,
-    ReplacingFilesListPopup, SaveAsPopup, SortingPopup, StatusBarLocal, StatusBarRemote,
-    SymlinkPopup, SyncBrowsingMkdirPopup, WaitPopup, WatchedPathsList, WatcherPopup,
+    BucketPopup, ChecksumPopup, ChecksumResultPopup, CopyPopup, DeleteConfirmPopup, DeletePopup,
+    DirHistoryPopup, DirMarksPopup, DisconnectPopup, DuplicatesPopup, ErrorPopup, ExecPopup,
+    ExecResultPopup, ExportFindPopup, FatalPopup, FileInfoPopup, FindPopup, FreeSpacePopup,
+    GoToPopup, KeybindingsPopup, MkdirPopup, NewfilePopup, OpenWithPopup, ProgressBarFull,
+    ProgressBarPartial, QuitPopup, RenamePopup, ReplacePopup, ReplacingFilesListPopup, SaveAsPopup,
+    SessionSummaryPopup, SortingPopup, StartTransferPopup, StatusBarInfo, StatusBarLocal,
+    StatusBarRemote, SymlinkPolicyPopup, SymlinkPopup, SyncBrowsingMkdirPopup, TailPopup,
+    TransferFilterPopup, TransferSummaryPopup, TypeAheadPopup, WaitPopup, WatchedPathsList,
+    WatcherLogPopup, WatcherPopup,
 };
-pub use transfer::{ExplorerFind, ExplorerLocal, ExplorerRemote};
+pub use transfer::{ExplorerFind, ExplorerLocal, ExplorerRemote, FILE_LIST_TEXT_MARKERS};
 
 #[derive(Default, MockComponent)]
 pub struct GlobalListener {
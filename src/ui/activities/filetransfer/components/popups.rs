@@ -2,12 +2,23 @@
 //!
 //! popups components
 
+use super::super::lib::capabilities::RemoteCapabilities;
+use super::super::lib::dir_marks::DirMarks;
+use super::super::lib::file_metadata::RichMetadata;
+use super::super::lib::free_space::DiskSpace;
+use super::super::lib::transfer::TransferSummary;
+use super::super::lib::watcher_log::WatcherEvent;
 use super::super::Browser;
 use super::{Msg, PendingActionMsg, TransferMsg, UiMsg};
-use crate::explorer::FileSorting;
-use crate::utils::fmt::fmt_time;
+use crate::config::status_bar::{StatusBarFmt, StatusBarSegment, StatusBarToken};
+use crate::explorer::{FileExplorer, FileSorting};
+use crate::filetransfer::SymlinkPolicy;
+use crate::ui::i18n::{tr, tr_args, tr_indented};
+use crate::utils::checksum::ChecksumAlgorithm;
+use crate::utils::fmt::{fmt_duration, fmt_time};
 
 use bytesize::ByteSize;
+use chrono::{DateTime, Local};
 use remotefs::File;
 use std::time::UNIX_EPOCH;
 
@@ -15,18 +26,22 @@ use tui_realm_stdlib::{Input, List, Paragraph, ProgressBar, Radio, Span};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{
-    Alignment, BorderSides, BorderType, Borders, Color, InputType, Style, TableBuilder, TextSpan,
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, Color, InputType, Style,
+    TableBuilder, TextSpan,
 };
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 #[cfg(target_family = "unix")]
 use users::{get_group_by_gid, get_user_by_uid};
 
+/// Maximum amount of completion candidates shown in the goto popup title
+const GOTO_CANDIDATES_SHOWN: usize = 8;
+
 #[derive(MockComponent)]
-pub struct CopyPopup {
+pub struct BucketPopup {
     component: Input,
 }
 
-impl CopyPopup {
+impl BucketPopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -37,16 +52,13 @@ impl CopyPopup {
                 )
                 .foreground(color)
                 .input_type(InputType::Text)
-                .placeholder(
-                    "destination",
-                    Style::default().fg(Color::Rgb(128, 128, 128)),
-                )
-                .title("Copy file(s) to…", Alignment::Center),
+                .placeholder("my-bucket", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("switch-bucket"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for CopyPopup {
+impl Component<Msg, NoUserEvent> for BucketPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -94,13 +106,14 @@ impl Component<Msg, NoUserEvent> for CopyPopup {
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
             }) => match self.state() {
-                State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::CopyFileTo(i)))
-                }
+                // Bucket name can't be empty
+                State::One(StateValue::String(i)) if !i.trim().is_empty() => Some(Msg::Transfer(
+                    TransferMsg::SwitchBucket(i.trim().to_string()),
+                )),
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseCopyPopup))
+                Some(Msg::Ui(UiMsg::CloseBucketPopup))
             }
             _ => None,
         }
@@ -108,12 +121,12 @@ impl Component<Msg, NoUserEvent> for CopyPopup {
 }
 
 #[derive(MockComponent)]
-pub struct DeletePopup {
+pub struct ChecksumPopup {
     component: Radio,
 }
 
-impl DeletePopup {
-    pub fn new(color: Color) -> Self {
+impl ChecksumPopup {
+    pub fn new<S: AsRef<str>>(file: S, color: Color) -> Self {
         Self {
             component: Radio::default()
                 .borders(
@@ -122,14 +135,17 @@ impl DeletePopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(&["Yes", "No"])
-                .value(1)
-                .title("Delete file(s)?", Alignment::Center),
+                .choices(&["MD5", "SHA-1", "SHA-256"])
+                .value(2)
+                .title(
+                    tr_args("compute-checksum-of", &[("file", &file.as_ref())]),
+                    Alignment::Center,
+                ),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for DeletePopup {
+impl Component<Msg, NoUserEvent> for ChecksumPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -145,27 +161,17 @@ impl Component<Msg, NoUserEvent> for DeletePopup {
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseDeletePopup))
+                Some(Msg::Ui(UiMsg::CloseChecksumPopup))
             }
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('y'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Transfer(TransferMsg::DeleteFile)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('n'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::CloseDeletePopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
             }) => {
-                if matches!(
-                    self.perform(Cmd::Submit),
-                    CmdResult::Submit(State::One(StateValue::Usize(0)))
-                ) {
-                    Some(Msg::Transfer(TransferMsg::DeleteFile))
-                } else {
-                    Some(Msg::Ui(UiMsg::CloseDeletePopup))
-                }
+                let algorithm = match self.state() {
+                    State::One(StateValue::Usize(0)) => ChecksumAlgorithm::Md5,
+                    State::One(StateValue::Usize(1)) => ChecksumAlgorithm::Sha1,
+                    _ => ChecksumAlgorithm::Sha256,
+                };
+                Some(Msg::Transfer(TransferMsg::ComputeChecksum(algorithm)))
             }
             _ => None,
         }
@@ -173,12 +179,12 @@ impl Component<Msg, NoUserEvent> for DeletePopup {
 }
 
 #[derive(MockComponent)]
-pub struct DisconnectPopup {
+pub struct SymlinkPolicyPopup {
     component: Radio,
 }
 
-impl DisconnectPopup {
-    pub fn new(color: Color) -> Self {
+impl SymlinkPolicyPopup {
+    pub fn new(policy: SymlinkPolicy, color: Color) -> Self {
         Self {
             component: Radio::default()
                 .borders(
@@ -187,13 +193,22 @@ impl DisconnectPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(&["Yes", "No"])
-                .title("Are you sure you want to disconnect?", Alignment::Center),
+                .choices(&[
+                    tr("symlink-follow"),
+                    tr("symlink-copy-as-link"),
+                    tr("symlink-skip"),
+                ])
+                .value(match policy {
+                    SymlinkPolicy::Follow => 0,
+                    SymlinkPolicy::CopyAsLink => 1,
+                    SymlinkPolicy::Skip => 2,
+                })
+                .title(tr("transfer-symlinks"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for DisconnectPopup {
+impl Component<Msg, NoUserEvent> for SymlinkPolicyPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -209,27 +224,19 @@ impl Component<Msg, NoUserEvent> for DisconnectPopup {
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseDisconnectPopup))
+                Some(Msg::Ui(UiMsg::CloseSymlinkPolicyPopup))
             }
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('y'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::Disconnect)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('n'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::CloseDisconnectPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
             }) => {
-                if matches!(
-                    self.perform(Cmd::Submit),
-                    CmdResult::Submit(State::One(StateValue::Usize(0)))
-                ) {
-                    Some(Msg::Ui(UiMsg::Disconnect))
-                } else {
-                    Some(Msg::Ui(UiMsg::CloseDisconnectPopup))
-                }
+                let policy = match self.state() {
+                    State::One(StateValue::Usize(1)) => SymlinkPolicy::CopyAsLink,
+                    State::One(StateValue::Usize(2)) => SymlinkPolicy::Skip,
+                    _ => SymlinkPolicy::Follow,
+                };
+                Some(Msg::Transfer(TransferMsg::TransferFileWithSymlinkPolicy(
+                    policy,
+                )))
             }
             _ => None,
         }
@@ -237,12 +244,18 @@ impl Component<Msg, NoUserEvent> for DisconnectPopup {
 }
 
 #[derive(MockComponent)]
-pub struct ErrorPopup {
+pub struct ChecksumResultPopup {
     component: Paragraph,
+    digest: String,
 }
 
-impl ErrorPopup {
-    pub fn new<S: AsRef<str>>(text: S, color: Color) -> Self {
+impl ChecksumResultPopup {
+    pub fn new<S: AsRef<str>>(
+        file: S,
+        algorithm: ChecksumAlgorithm,
+        digest: String,
+        color: Color,
+    ) -> Self {
         Self {
             component: Paragraph::default()
                 .alignment(Alignment::Center)
@@ -252,30 +265,42 @@ impl ErrorPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .text(&[TextSpan::from(text.as_ref())])
+                .text(&[TextSpan::from(digest.as_str())])
+                .title(
+                    tr_args(
+                        "checksum-of",
+                        &[("algorithm", &algorithm.name()), ("file", &file.as_ref())],
+                    ),
+                    Alignment::Center,
+                )
                 .wrap(true),
+            digest,
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ErrorPopup {
+impl Component<Msg, NoUserEvent> for ChecksumResultPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
                 code: Key::Esc | Key::Enter,
                 ..
-            }) => Some(Msg::Ui(UiMsg::CloseErrorPopup)),
+            }) => Some(Msg::Ui(UiMsg::CloseChecksumResultPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('c'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::CopyToClipboard(self.digest.clone()))),
             _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct ExecPopup {
+pub struct CopyPopup {
     component: Input,
 }
 
-impl ExecPopup {
+impl CopyPopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -286,13 +311,16 @@ impl ExecPopup {
                 )
                 .foreground(color)
                 .input_type(InputType::Text)
-                .placeholder("ps a", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Execute command", Alignment::Center),
+                .placeholder(
+                    "destination",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title(tr("copy-files-to"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ExecPopup {
+impl Component<Msg, NoUserEvent> for CopyPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -341,12 +369,12 @@ impl Component<Msg, NoUserEvent> for ExecPopup {
                 code: Key::Enter, ..
             }) => match self.state() {
                 State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::ExecuteCmd(i)))
+                    Some(Msg::Transfer(TransferMsg::CopyFileTo(i)))
                 }
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseExecPopup))
+                Some(Msg::Ui(UiMsg::CloseCopyPopup))
             }
             _ => None,
         }
@@ -354,151 +382,79 @@ impl Component<Msg, NoUserEvent> for ExecPopup {
 }
 
 #[derive(MockComponent)]
-pub struct FatalPopup {
-    component: Paragraph,
+pub struct DeletePopup {
+    component: Radio,
 }
 
-impl FatalPopup {
-    pub fn new<S: AsRef<str>>(text: S, color: Color) -> Self {
+impl DeletePopup {
+    pub fn new(color: Color) -> Self {
         Self {
-            component: Paragraph::default()
-                .alignment(Alignment::Center)
+            component: Radio::default()
                 .borders(
                     Borders::default()
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .text(&[TextSpan::from(text.as_ref())])
-                .wrap(true),
+                .choices(&[tr("yes"), tr("no")])
+                .value(1)
+                .title(tr("delete-files"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for FatalPopup {
+impl Component<Msg, NoUserEvent> for DeletePopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
-                code: Key::Esc | Key::Enter,
-                ..
-            }) => Some(Msg::Ui(UiMsg::CloseFatalPopup)),
-            _ => None,
-        }
-    }
-}
-
-#[derive(MockComponent)]
-pub struct FileInfoPopup {
-    component: List,
-}
-
-impl FileInfoPopup {
-    pub fn new(file: &File) -> Self {
-        let mut texts: TableBuilder = TableBuilder::default();
-        // Abs path
-        let real_path = file.metadata().symlink.as_deref();
-        let path: String = match real_path {
-            Some(symlink) => format!("{} -> {}", file.path().display(), symlink.display()),
-            None => format!("{}", file.path().display()),
-        };
-        // Make texts
-        texts
-            .add_col(TextSpan::from("Path: "))
-            .add_col(TextSpan::new(path.as_str()).fg(Color::Yellow));
-        if let Some(filetype) = file.extension() {
-            texts
-                .add_row()
-                .add_col(TextSpan::from("File type: "))
-                .add_col(TextSpan::new(filetype).fg(Color::LightGreen));
-        }
-        let (bsize, size): (ByteSize, u64) = (ByteSize(file.metadata().size), file.metadata().size);
-        texts
-            .add_row()
-            .add_col(TextSpan::from("Size: "))
-            .add_col(TextSpan::new(format!("{} ({})", bsize, size).as_str()).fg(Color::Cyan));
-        let atime: String = fmt_time(
-            file.metadata().accessed.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
-        );
-        let ctime: String = fmt_time(
-            file.metadata().created.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
-        );
-        let mtime: String = fmt_time(
-            file.metadata().modified.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
-        );
-        texts
-            .add_row()
-            .add_col(TextSpan::from("Creation time: "))
-            .add_col(TextSpan::new(ctime.as_str()).fg(Color::LightGreen));
-        texts
-            .add_row()
-            .add_col(TextSpan::from("Last modified time: "))
-            .add_col(TextSpan::new(mtime.as_str()).fg(Color::LightBlue));
-        texts
-            .add_row()
-            .add_col(TextSpan::from("Last access time: "))
-            .add_col(TextSpan::new(atime.as_str()).fg(Color::LightRed));
-        // User
-        #[cfg(target_family = "unix")]
-        let username: String = match file.metadata().uid {
-            Some(uid) => match get_user_by_uid(uid) {
-                Some(user) => user.name().to_string_lossy().to_string(),
-                None => uid.to_string(),
-            },
-            None => String::from("0"),
-        };
-        #[cfg(target_os = "windows")]
-        let username: String = format!("{}", file.metadata().uid.unwrap_or(0));
-        // Group
-        #[cfg(target_family = "unix")]
-        let group: String = match file.metadata().gid {
-            Some(gid) => match get_group_by_gid(gid) {
-                Some(group) => group.name().to_string_lossy().to_string(),
-                None => gid.to_string(),
-            },
-            None => String::from("0"),
-        };
-        #[cfg(target_os = "windows")]
-        let group: String = format!("{}", file.metadata().gid.unwrap_or(0));
-        texts
-            .add_row()
-            .add_col(TextSpan::from("User: "))
-            .add_col(TextSpan::new(username.as_str()).fg(Color::LightYellow));
-        texts
-            .add_row()
-            .add_col(TextSpan::from("Group: "))
-            .add_col(TextSpan::new(group.as_str()).fg(Color::Blue));
-        Self {
-            component: List::default()
-                .borders(Borders::default().modifiers(BorderType::Rounded))
-                .scroll(false)
-                .title(file.name(), Alignment::Left)
-                .rows(texts.build()),
-        }
-    }
-}
-
-impl Component<Msg, NoUserEvent> for FileInfoPopup {
-    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        match ev {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
             Event::Keyboard(KeyEvent {
-                code: Key::Esc | Key::Enter,
-                ..
-            }) => Some(Msg::Ui(UiMsg::CloseFileInfoPopup)),
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDeletePopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::DeleteFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::CloseDeletePopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::Transfer(TransferMsg::DeleteFile))
+                } else {
+                    Some(Msg::Ui(UiMsg::CloseDeletePopup))
+                }
+            }
             _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct FindPopup {
+pub struct DeleteConfirmPopup {
     component: Input,
+    /// Amount of files to delete, which must be typed to confirm
+    count: usize,
 }
 
-impl FindPopup {
-    pub fn new(color: Color) -> Self {
+impl DeleteConfirmPopup {
+    pub fn new(count: usize, color: Color) -> Self {
         Self {
             component: Input::default()
                 .borders(
@@ -507,17 +463,17 @@ impl FindPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .input_type(InputType::Text)
-                .placeholder(
-                    "Search files by name",
-                    Style::default().fg(Color::Rgb(128, 128, 128)),
-                )
-                .title("*.txt", Alignment::Center),
+                .input_type(InputType::UnsignedInteger)
+                .title(
+                    tr_args("delete-files-confirm", &[("count", &count)]),
+                    Alignment::Center,
+                ),
+            count,
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for FindPopup {
+impl Component<Msg, NoUserEvent> for DeleteConfirmPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -533,17 +489,909 @@ impl Component<Msg, NoUserEvent> for FindPopup {
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
+                code: Key::Backspace,
+                ..
             }) => {
-                self.perform(Cmd::GoTo(Position::Begin));
-                Some(Msg::None)
-            }
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End));
+                self.perform(Cmd::Delete);
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Delete, ..
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                // Files are deleted only if the amount typed is the right one
+                State::One(StateValue::String(i)) if i.trim() == self.count.to_string() => {
+                    Some(Msg::Transfer(TransferMsg::DeleteFile))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDeleteConfirmPopup))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct DirHistoryPopup {
+    component: List,
+    len: usize,
+}
+
+impl DirHistoryPopup {
+    /// Instantiates the popup listing `history` from the newest to the oldest directory.
+    /// `current` is the position of the working directory in `history`
+    pub fn new(history: &[std::path::PathBuf], current: usize, color: Color) -> Self {
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(tr("directory-history"), Alignment::Center)
+                .rows(
+                    history
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .map(|(i, x)| {
+                            let path = x.to_string_lossy().to_string();
+                            match i == current {
+                                true => {
+                                    vec![TextSpan::from(format!("{} ({})", path, tr("current")))
+                                        .bold()]
+                                }
+                                false => vec![TextSpan::from(path)],
+                            }
+                        })
+                        .collect(),
+                )
+                .selected_line(history.len().saturating_sub(current + 1)),
+            len: history.len(),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DirHistoryPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDirHistoryPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                // Rows are listed from the newest to the oldest
+                if let State::One(StateValue::Usize(row)) = self.component.state() {
+                    Some(Msg::Transfer(TransferMsg::GoToHistoryDirectory(
+                        self.len - row - 1,
+                    )))
+                } else {
+                    Some(Msg::None)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct DirMarksPopup {
+    component: List,
+}
+
+impl DirMarksPopup {
+    pub fn new(marks: &DirMarks, color: Color) -> Self {
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(tr("directory-marks"), Alignment::Center)
+                .rows(
+                    marks
+                        .slots()
+                        .map(|(slot, path)| match path {
+                            Some(path) => vec![
+                                TextSpan::from(format!("{}  ", slot)).bold(),
+                                TextSpan::from(path.to_string_lossy()),
+                            ],
+                            None => vec![
+                                TextSpan::from(format!("{}  ", slot)).bold(),
+                                TextSpan::from("-").italic(),
+                            ],
+                        })
+                        .collect(),
+                )
+                .selected_line(0),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DirMarksPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDirMarksPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                ..
+            }) => Some(Msg::Transfer(TransferMsg::GoToMark(
+                ch.to_digit(10).unwrap_or_default() as usize,
+            ))),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if let State::One(StateValue::Usize(row)) = self.component.state() {
+                    Some(Msg::Transfer(TransferMsg::GoToMark(row + 1)))
+                } else {
+                    Some(Msg::None)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct DisconnectPopup {
+    component: Radio,
+}
+
+impl DisconnectPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .choices(&[tr("yes"), tr("no")])
+                .title(tr("are-you-sure-to-disconnect"), Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DisconnectPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDisconnectPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::Disconnect)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::CloseDisconnectPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::Ui(UiMsg::Disconnect))
+                } else {
+                    Some(Msg::Ui(UiMsg::CloseDisconnectPopup))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct DuplicatesPopup {
+    component: Radio,
+}
+
+impl DuplicatesPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .choices(&[tr("yes"), tr("no")])
+                .value(1)
+                .title(tr("find-duplicates-recursive"), Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DuplicatesPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDuplicatesPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::FindDuplicates(true))),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::FindDuplicates(false))),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                let recursive = matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                );
+                Some(Msg::Transfer(TransferMsg::FindDuplicates(recursive)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct ErrorPopup {
+    component: Paragraph,
+}
+
+impl ErrorPopup {
+    pub fn new<S: AsRef<str>>(text: S, color: Color) -> Self {
+        Self {
+            component: Paragraph::default()
+                .alignment(Alignment::Center)
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .text(&[TextSpan::from(text.as_ref())])
+                .wrap(true),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ErrorPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseErrorPopup)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct ExecPopup {
+    component: Input,
+    /// Commands executed before, from the oldest to the most recent
+    history: Vec<String>,
+    /// Index of the command recalled from the history
+    index: Option<usize>,
+    /// Command being typed before recalling the history
+    draft: String,
+}
+
+impl ExecPopup {
+    pub fn new(color: Color, history: &[String]) -> Self {
+        Self {
+            history: history.to_vec(),
+            index: None,
+            draft: String::new(),
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder("ps a", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("execute-command"), Alignment::Center),
+        }
+    }
+
+    /// Put the command at `index` of the history in the input field;
+    /// if `index` is `None`, the command which was being typed is restored
+    fn recall(&mut self, index: Option<usize>) {
+        self.index = index;
+        let value = match index {
+            Some(index) => self.history[index].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.attr(Attribute::Value, AttrValue::String(value));
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ExecPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                match self.index {
+                    None if self.history.is_empty() => {}
+                    None => {
+                        if let State::One(StateValue::String(draft)) = self.state() {
+                            self.draft = draft;
+                        }
+                        self.recall(Some(self.history.len() - 1));
+                    }
+                    Some(index) => self.recall(Some(index.saturating_sub(1))),
+                }
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                match self.index {
+                    None => {}
+                    Some(index) if index + 1 < self.history.len() => self.recall(Some(index + 1)),
+                    Some(_) => self.recall(None),
+                }
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => {
+                    Some(Msg::Transfer(TransferMsg::ExecuteCmd(i)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseExecPopup))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct FatalPopup {
+    component: Paragraph,
+}
+
+impl FatalPopup {
+    pub fn new<S: AsRef<str>>(text: S, color: Color) -> Self {
+        Self {
+            component: Paragraph::default()
+                .alignment(Alignment::Center)
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .text(&[TextSpan::from(text.as_ref())])
+                .wrap(true),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FatalPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseFatalPopup)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct FileInfoPopup {
+    component: List,
+}
+
+impl FileInfoPopup {
+    /// Instantiates a new `FileInfoPopup` for `file`, showing also the `metadata` read from its content
+    pub fn new(file: &File, metadata: Option<&RichMetadata>) -> Self {
+        let mut texts: TableBuilder = TableBuilder::default();
+        // Abs path
+        let real_path = file.metadata().symlink.as_deref();
+        let path: String = match real_path {
+            Some(symlink) => format!("{} -> {}", file.path().display(), symlink.display()),
+            None => format!("{}", file.path().display()),
+        };
+        // Make texts
+        texts
+            .add_col(TextSpan::from(format!("{}: ", tr("file-path"))))
+            .add_col(TextSpan::new(path.as_str()).fg(Color::Yellow));
+        if let Some(filetype) = file.extension() {
+            texts
+                .add_row()
+                .add_col(TextSpan::from(format!("{}: ", tr("file-type"))))
+                .add_col(TextSpan::new(filetype).fg(Color::LightGreen));
+        }
+        let (bsize, size): (ByteSize, u64) = (ByteSize(file.metadata().size), file.metadata().size);
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-size"))))
+            .add_col(TextSpan::new(format!("{} ({})", bsize, size).as_str()).fg(Color::Cyan));
+        let atime: String = fmt_time(
+            file.metadata().accessed.unwrap_or(UNIX_EPOCH),
+            "%b %d %Y %H:%M:%S",
+        );
+        let ctime: String = fmt_time(
+            file.metadata().created.unwrap_or(UNIX_EPOCH),
+            "%b %d %Y %H:%M:%S",
+        );
+        let mtime: String = fmt_time(
+            file.metadata().modified.unwrap_or(UNIX_EPOCH),
+            "%b %d %Y %H:%M:%S",
+        );
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-creation-time"))))
+            .add_col(TextSpan::new(ctime.as_str()).fg(Color::LightGreen));
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!(
+                "{}: ",
+                tr("file-last-modified-time")
+            )))
+            .add_col(TextSpan::new(mtime.as_str()).fg(Color::LightBlue));
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-last-access-time"))))
+            .add_col(TextSpan::new(atime.as_str()).fg(Color::LightRed));
+        // User
+        #[cfg(target_family = "unix")]
+        let username: String = match file.metadata().uid {
+            Some(uid) => match get_user_by_uid(uid) {
+                Some(user) => user.name().to_string_lossy().to_string(),
+                None => uid.to_string(),
+            },
+            None => String::from("0"),
+        };
+        #[cfg(target_os = "windows")]
+        let username: String = format!("{}", file.metadata().uid.unwrap_or(0));
+        // Group
+        #[cfg(target_family = "unix")]
+        let group: String = match file.metadata().gid {
+            Some(gid) => match get_group_by_gid(gid) {
+                Some(group) => group.name().to_string_lossy().to_string(),
+                None => gid.to_string(),
+            },
+            None => String::from("0"),
+        };
+        #[cfg(target_os = "windows")]
+        let group: String = format!("{}", file.metadata().gid.unwrap_or(0));
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-user"))))
+            .add_col(TextSpan::new(username.as_str()).fg(Color::LightYellow));
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-group"))))
+            .add_col(TextSpan::new(group.as_str()).fg(Color::Blue));
+        if let Some(metadata) = metadata {
+            Self::add_metadata_rows(&mut texts, metadata);
+        }
+        Self {
+            component: List::default()
+                .borders(Borders::default().modifiers(BorderType::Rounded))
+                .scroll(false)
+                .title(file.name(), Alignment::Left)
+                .rows(texts.build()),
+        }
+    }
+
+    fn add_metadata_rows(texts: &mut TableBuilder, metadata: &RichMetadata) {
+        let mut rows: Vec<(String, String)> = Vec::new();
+        if let Some((width, height)) = metadata.dimensions {
+            rows.push((tr("file-dimensions"), format!("{}x{}", width, height)));
+        }
+        if let Some(camera) = metadata.camera.as_ref() {
+            rows.push((tr("file-camera"), camera.clone()));
+        }
+        if let Some(date) = metadata.date_taken.as_ref() {
+            rows.push((tr("file-date-taken"), date.clone()));
+        }
+        if let Some(duration) = metadata.duration {
+            rows.push((tr("file-duration"), fmt_duration(duration)));
+        }
+        if let Some(entries) = metadata.entries {
+            rows.push((tr("file-entries"), entries.to_string()));
+        }
+        if let Some(encoding) = metadata.encoding {
+            rows.push((tr("file-encoding"), encoding.label()));
+        }
+        for (key, value) in rows {
+            texts
+                .add_row()
+                .add_col(TextSpan::from(format!("{}: ", key)))
+                .add_col(TextSpan::new(value.as_str()).fg(Color::LightMagenta));
+        }
+    }
+
+    /// Instantiates a new `FileInfoPopup` which summarizes many selected files
+    pub fn many(files: &[File]) -> Self {
+        let mut texts: TableBuilder = TableBuilder::default();
+        let dirs = files.iter().filter(|x| x.is_dir()).count();
+        let size: u64 = files
+            .iter()
+            .filter(|x| !x.is_dir())
+            .map(|x| x.metadata().size)
+            .sum();
+        texts
+            .add_col(TextSpan::from(format!("{}: ", tr("file-selected"))))
+            .add_col(
+                TextSpan::new(
+                    tr_args(
+                        "file-selected-count",
+                        &[
+                            ("count", &files.len()),
+                            ("files", &(files.len() - dirs)),
+                            ("dirs", &dirs),
+                        ],
+                    )
+                    .as_str(),
+                )
+                .fg(Color::Yellow),
+            );
+        texts
+            .add_row()
+            .add_col(TextSpan::from(format!("{}: ", tr("file-size"))))
+            .add_col(
+                TextSpan::new(format!("{} ({})", ByteSize(size), size).as_str()).fg(Color::Cyan),
+            );
+        for file in files.iter() {
+            texts.add_row().add_col(TextSpan::from("  ")).add_col(
+                TextSpan::new(file.path().to_string_lossy().as_ref()).fg(match file.is_dir() {
+                    true => Color::LightBlue,
+                    false => Color::LightGreen,
+                }),
+            );
+        }
+        Self {
+            component: List::default()
+                .borders(Borders::default().modifiers(BorderType::Rounded))
+                .scroll(true)
+                .step(8)
+                .title(
+                    tr_args("selected-files", &[("count", &files.len())]),
+                    Alignment::Left,
+                )
+                .rows(texts.build()),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FileInfoPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseFileInfoPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct FindPopup {
+    component: Input,
+}
+
+impl FindPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder(
+                    tr("search-files-by-name").as_str(),
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title("*.txt", Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FindPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => {
+                    Some(Msg::Transfer(TransferMsg::SearchFile(i)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseFindPopup))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct GoToPopup {
+    component: Input,
+}
+
+impl GoToPopup {
+    /// Instantiates the popup with `value` in the input field.
+    /// `candidates` are the completions of the last path component, shown in the title
+    pub fn new(color: Color, value: &str, candidates: &[String]) -> Self {
+        let title = match candidates.is_empty() {
+            true => tr("go-to"),
+            false if candidates.len() > GOTO_CANDIDATES_SHOWN => format!(
+                "{} ({} …)",
+                tr("go-to"),
+                candidates[..GOTO_CANDIDATES_SHOWN].join("  ")
+            ),
+            false => format!("{} ({})", tr("go-to"), candidates.join("  ")),
+        };
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder(
+                    "/foo/bar/buzz",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .value(value)
+                .title(title, Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for GoToPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
             }) => {
                 self.perform(Cmd::Cancel);
                 Some(Msg::None)
@@ -562,28 +1410,281 @@ impl Component<Msg, NoUserEvent> for FindPopup {
                 self.perform(Cmd::Type(ch));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent {
-                code: Key::Enter, ..
-            }) => match self.state() {
-                State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::SearchFile(i)))
-                }
-                _ => Some(Msg::None),
-            },
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseFindPopup))
-            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::GoTo(i))),
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => match self.state() {
+                State::One(StateValue::String(i)) => Some(Msg::Ui(UiMsg::CompleteGotoPath(i))),
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseGotoPopup))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct KeybindingsPopup {
+    component: List,
+}
+
+impl KeybindingsPopup {
+    /// Keys of the destructive operations, which are hidden in read-only mode
+    const DESTRUCTIVE_KEYS: [&'static str; 7] = [
+        "<C|F5>",
+        "<K>",
+        "<O|F4>",
+        "<R|F6>",
+        "<T>",
+        "<X>",
+        "<DEL|F8|E>",
+    ];
+
+    /// Instantiates the keybindings popup; keys of the operations which are not in `capabilities`
+    /// are greyed out
+    pub fn new(key_color: Color, read_only: bool, capabilities: RemoteCapabilities) -> Self {
+        let unsupported_keys: Vec<&str> =
+            [(capabilities.exec, "<X>"), (capabilities.symlink, "<K>")]
+                .iter()
+                .filter(|(supported, _)| !supported)
+                .map(|(_, key)| *key)
+                .collect();
+        Self {
+            component: List::default()
+                .borders(Borders::default().modifiers(BorderType::Rounded))
+                .scroll(true)
+                .step(8)
+                .highlighted_str("? ")
+                .title(tr("keybindings"), Alignment::Center)
+                .rewind(true)
+                .rows(
+                    TableBuilder::default()
+                        .add_col(TextSpan::new("<ESC>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(13, "help-disconnect")))
+                        .add_row()
+                        .add_col(TextSpan::new("<BACKSPACE>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(7, "help-go-to-previous-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<ALT+LEFT|RIGHT>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(2, "help-history-back-forward")))
+                        .add_row()
+                        .add_col(TextSpan::new("<TAB|RIGHT|LEFT>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(2, "help-change-explorer-tab")))
+                        .add_row()
+                        .add_col(TextSpan::new("<UP/DOWN>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(9, "help-move-in-list")))
+                        .add_row()
+                        .add_col(TextSpan::new("<ENTER>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(11, "help-enter-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<SPACE>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(11, "help-transfer-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<BACKTAB>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(9, "help-switch-log")))
+                        .add_row()
+                        .add_col(TextSpan::new("<A>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-toggle-hidden-files")))
+                        .add_row()
+                        .add_col(TextSpan::new("<B>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-sort-files")))
+                        .add_row()
+                        .add_col(TextSpan::new("<C|F5>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-copy")))
+                        .add_row()
+                        .add_col(TextSpan::new("<D|F7>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-make-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<F>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-search-files")))
+                        .add_row()
+                        .add_col(TextSpan::new("<G>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-go-to-path")))
+                        .add_row()
+                        .add_col(TextSpan::new("<H|F1>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-show-help")))
+                        .add_row()
+                        .add_col(TextSpan::new("<I>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-file-info")))
+                        .add_row()
+                        .add_col(TextSpan::new("<J>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-dir-history")))
+                        .add_row()
+                        .add_col(TextSpan::new("<K>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-create-symlink")))
+                        .add_row()
+                        .add_col(TextSpan::new("<L>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-reload-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<M>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-select-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<N>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-new-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<O|F4>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-edit-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<Q|F10>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(11, "help-quit")))
+                        .add_row()
+                        .add_col(TextSpan::new("<R|F6>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-rename-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<S|F2>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-save-as")))
+                        .add_row()
+                        .add_col(TextSpan::new("<T>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-watch")))
+                        .add_row()
+                        .add_col(TextSpan::new("<U>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-go-to-parent-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<V|F3>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-open-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<W>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-open-file-with")))
+                        .add_row()
+                        .add_col(TextSpan::new("<X>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-exec")))
+                        .add_row()
+                        .add_col(TextSpan::new("<Y>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-sync-browsing")))
+                        .add_row()
+                        .add_col(TextSpan::new("<Z>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-checksum")))
+                        .add_row()
+                        .add_col(TextSpan::new("</>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-jump-to")))
+                        .add_row()
+                        .add_col(TextSpan::new("<'>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(15, "help-show-marks")))
+                        .add_row()
+                        .add_col(TextSpan::new("<1..9>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(12, "help-go-to-mark")))
+                        .add_row()
+                        .add_col(TextSpan::new("<ALT+1..9>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(8, "help-mark-dir")))
+                        .add_row()
+                        .add_col(TextSpan::new("<DEL|F8|E>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(8, "help-delete-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+A>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-select-all")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+B>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-switch-bucket")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+C>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-interrupt-transfer")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+D>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-find-duplicates")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+F>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-follow-file")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+L>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-transfer-symlinks")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+T>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-show-watched")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+W>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-watcher-log")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+X>").bold().fg(key_color))
+                        .add_col(TextSpan::from(tr_indented(10, "help-transfer-filter")))
+                        .build()
+                        .into_iter()
+                        .filter(|row| {
+                            !read_only
+                                || !row.first().is_some_and(|key| {
+                                    Self::DESTRUCTIVE_KEYS.contains(&key.content.as_str())
+                                })
+                        })
+                        .map(|row| {
+                            match row
+                                .first()
+                                .is_some_and(|key| unsupported_keys.contains(&key.content.as_str()))
+                            {
+                                true => Self::grey_out(row),
+                                false => row,
+                            }
+                        })
+                        .collect(),
+                ),
+        }
+    }
+
+    /// Grey out the row of an operation not supported by the remote host
+    fn grey_out(row: Vec<TextSpan>) -> Vec<TextSpan> {
+        let mut row: Vec<TextSpan> = row
+            .into_iter()
+            .map(|x| TextSpan::from(x.content).fg(Color::DarkGray))
+            .collect();
+        row.push(TextSpan::from(tr_indented(1, "not-supported-by-remote")).fg(Color::DarkGray));
+        row
+    }
+}
+
+impl Component<Msg, NoUserEvent> for KeybindingsPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseKeybindingsPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
             _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct GoToPopup {
+pub struct MkdirPopup {
     component: Input,
 }
 
-impl GoToPopup {
+impl MkdirPopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -595,15 +1696,15 @@ impl GoToPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "/foo/bar/buzz",
+                    tr("new-directory-name").as_str(),
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Go to…", Alignment::Center),
+                .title("directory-name", Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for GoToPopup {
+impl Component<Msg, NoUserEvent> for MkdirPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -651,11 +1752,11 @@ impl Component<Msg, NoUserEvent> for GoToPopup {
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
             }) => match self.state() {
-                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::GoTo(i))),
+                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::Mkdir(i))),
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseGotoPopup))
+                Some(Msg::Ui(UiMsg::CloseMkdirPopup))
             }
             _ => None,
         }
@@ -663,191 +1764,95 @@ impl Component<Msg, NoUserEvent> for GoToPopup {
 }
 
 #[derive(MockComponent)]
-pub struct KeybindingsPopup {
-    component: List,
+pub struct NewfilePopup {
+    component: Input,
 }
 
-impl KeybindingsPopup {
-    pub fn new(key_color: Color) -> Self {
+impl NewfilePopup {
+    pub fn new(color: Color) -> Self {
         Self {
-            component: List::default()
-                .borders(Borders::default().modifiers(BorderType::Rounded))
-                .scroll(true)
-                .step(8)
-                .highlighted_str("? ")
-                .title("Keybindings", Alignment::Center)
-                .rewind(true)
-                .rows(
-                    TableBuilder::default()
-                        .add_col(TextSpan::new("<ESC>").bold().fg(key_color))
-                        .add_col(TextSpan::from("             Disconnect"))
-                        .add_row()
-                        .add_col(TextSpan::new("<BACKSPACE>").bold().fg(key_color))
-                        .add_col(TextSpan::from("       Go to previous directory"))
-                        .add_row()
-                        .add_col(TextSpan::new("<TAB|RIGHT|LEFT>").bold().fg(key_color))
-                        .add_col(TextSpan::from("  Change explorer tab"))
-                        .add_row()
-                        .add_col(TextSpan::new("<UP/DOWN>").bold().fg(key_color))
-                        .add_col(TextSpan::from("         Move up/down in list"))
-                        .add_row()
-                        .add_col(TextSpan::new("<ENTER>").bold().fg(key_color))
-                        .add_col(TextSpan::from("           Enter directory"))
-                        .add_row()
-                        .add_col(TextSpan::new("<SPACE>").bold().fg(key_color))
-                        .add_col(TextSpan::from("           Upload/Download file"))
-                        .add_row()
-                        .add_col(TextSpan::new("<BACKTAB>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "         Switch between explorer and log window",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<A>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Toggle hidden files"))
-                        .add_row()
-                        .add_col(TextSpan::new("<B>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Change file sorting mode"))
-                        .add_row()
-                        .add_col(TextSpan::new("<C|F5>").bold().fg(key_color))
-                        .add_col(TextSpan::from("            Copy"))
-                        .add_row()
-                        .add_col(TextSpan::new("<D|F7>").bold().fg(key_color))
-                        .add_col(TextSpan::from("            Make directory"))
-                        .add_row()
-                        .add_col(TextSpan::new("<F>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Search files"))
-                        .add_row()
-                        .add_col(TextSpan::new("<G>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Go to path"))
-                        .add_row()
-                        .add_col(TextSpan::new("<H|F1>").bold().fg(key_color))
-                        .add_col(TextSpan::from("            Show help"))
-                        .add_row()
-                        .add_col(TextSpan::new("<I>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "               Show info about selected file",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<K>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "               Create symlink pointing to the current selected entry",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<L>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Reload directory content"))
-                        .add_row()
-                        .add_col(TextSpan::new("<M>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Select file"))
-                        .add_row()
-                        .add_col(TextSpan::new("<N>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Create new file"))
-                        .add_row()
-                        .add_col(TextSpan::new("<O|F4>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "            Open text file with preferred editor",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<Q|F10>").bold().fg(key_color))
-                        .add_col(TextSpan::from("           Quit termscp"))
-                        .add_row()
-                        .add_col(TextSpan::new("<R|F6>").bold().fg(key_color))
-                        .add_col(TextSpan::from("            Rename file"))
-                        .add_row()
-                        .add_col(TextSpan::new("<S|F2>").bold().fg(key_color))
-                        .add_col(TextSpan::from("            Save file as"))
-                        .add_row()
-                        .add_col(TextSpan::new("<T>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Watch/unwatch file changes"))
-                        .add_row()
-                        .add_col(TextSpan::new("<U>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Go to parent directory"))
-                        .add_row()
-                        .add_col(TextSpan::new("<V|F3>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "            Open file with default application for file type",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<W>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "               Open file with specified application",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<X>").bold().fg(key_color))
-                        .add_col(TextSpan::from("               Execute shell command"))
-                        .add_row()
-                        .add_col(TextSpan::new("<Y>").bold().fg(key_color))
-                        .add_col(TextSpan::from(
-                            "               Toggle synchronized browsing",
-                        ))
-                        .add_row()
-                        .add_col(TextSpan::new("<DEL|F8|E>").bold().fg(key_color))
-                        .add_col(TextSpan::from("        Delete selected file"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+A>").bold().fg(key_color))
-                        .add_col(TextSpan::from("          Select all files"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+C>").bold().fg(key_color))
-                        .add_col(TextSpan::from("          Interrupt file transfer"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+T>").bold().fg(key_color))
-                        .add_col(TextSpan::from("          Show watched paths"))
-                        .build(),
-                ),
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder(
+                    tr("new-file-name").as_str(),
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title("file.txt", Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for KeybindingsPopup {
+impl Component<Msg, NoUserEvent> for NewfilePopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
-                code: Key::Esc | Key::Enter,
-                ..
-            }) => Some(Msg::Ui(UiMsg::CloseKeybindingsPopup)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Down, ..
+                code: Key::Left, ..
             }) => {
-                self.perform(Cmd::Move(Direction::Down));
+                self.perform(Cmd::Move(Direction::Left));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
-                self.perform(Cmd::Move(Direction::Up));
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::PageDown,
-                ..
+                code: Key::Home, ..
             }) => {
-                self.perform(Cmd::Scroll(Direction::Down));
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::PageUp, ..
+                code: Key::Delete, ..
             }) => {
-                self.perform(Cmd::Scroll(Direction::Up));
+                self.perform(Cmd::Cancel);
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
+                code: Key::Backspace,
+                ..
             }) => {
-                self.perform(Cmd::GoTo(Position::Begin));
+                self.perform(Cmd::Delete);
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End));
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
                 Some(Msg::None)
             }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::NewFile(i))),
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseNewFilePopup))
+            }
             _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct MkdirPopup {
+pub struct OpenWithPopup {
     component: Input,
 }
 
-impl MkdirPopup {
+impl OpenWithPopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -859,15 +1864,15 @@ impl MkdirPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "New directory name",
+                    tr("open-file-with").as_str(),
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("directory-name", Alignment::Center),
+                .title(tr("type-program-to-open-with"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for MkdirPopup {
+impl Component<Msg, NoUserEvent> for OpenWithPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -915,11 +1920,13 @@ impl Component<Msg, NoUserEvent> for MkdirPopup {
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
             }) => match self.state() {
-                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::Mkdir(i))),
+                State::One(StateValue::String(i)) => {
+                    Some(Msg::Transfer(TransferMsg::OpenFileWith(i)))
+                }
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseMkdirPopup))
+                Some(Msg::Ui(UiMsg::CloseOpenWithPopup))
             }
             _ => None,
         }
@@ -927,31 +1934,103 @@ impl Component<Msg, NoUserEvent> for MkdirPopup {
 }
 
 #[derive(MockComponent)]
-pub struct NewfilePopup {
-    component: Input,
+pub struct ProgressBarFull {
+    component: ProgressBar,
+}
+
+impl ProgressBarFull {
+    pub fn new<S: AsRef<str>>(prog: f64, label: S, title: S, color: Color) -> Self {
+        Self {
+            component: ProgressBar::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .sides(BorderSides::TOP | BorderSides::LEFT | BorderSides::RIGHT),
+                )
+                .foreground(color)
+                .label(label)
+                .progress(prog)
+                .title(title, Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ProgressBarFull {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        if matches!(
+            ev,
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('c'),
+                modifiers: KeyModifiers::CONTROL
+            })
+        ) {
+            Some(Msg::Transfer(TransferMsg::AbortTransfer))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct ProgressBarPartial {
+    component: ProgressBar,
+}
+
+impl ProgressBarPartial {
+    pub fn new<S: AsRef<str>>(prog: f64, label: S, title: S, color: Color) -> Self {
+        Self {
+            component: ProgressBar::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .sides(BorderSides::BOTTOM | BorderSides::LEFT | BorderSides::RIGHT),
+                )
+                .foreground(color)
+                .label(label)
+                .progress(prog)
+                .title(title, Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ProgressBarPartial {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        if matches!(
+            ev,
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('c'),
+                modifiers: KeyModifiers::CONTROL
+            })
+        ) {
+            Some(Msg::Transfer(TransferMsg::AbortTransfer))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct QuitPopup {
+    component: Radio,
 }
 
-impl NewfilePopup {
+impl QuitPopup {
     pub fn new(color: Color) -> Self {
         Self {
-            component: Input::default()
+            component: Radio::default()
                 .borders(
                     Borders::default()
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .input_type(InputType::Text)
-                .placeholder(
-                    "New file name",
-                    Style::default().fg(Color::Rgb(128, 128, 128)),
-                )
-                .title("file.txt", Alignment::Center),
+                .choices(&[tr("yes"), tr("no")])
+                .title(tr("are-you-sure-to-quit"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for NewfilePopup {
+impl Component<Msg, NoUserEvent> for QuitPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -966,44 +2045,28 @@ impl Component<Msg, NoUserEvent> for NewfilePopup {
                 self.perform(Cmd::Move(Direction::Right));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
-            }) => {
-                self.perform(Cmd::GoTo(Position::Begin));
-                Some(Msg::None)
-            }
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End));
-                Some(Msg::None)
-            }
-            Event::Keyboard(KeyEvent {
-                code: Key::Delete, ..
-            }) => {
-                self.perform(Cmd::Cancel);
-                Some(Msg::None)
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseQuitPopup))
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Backspace,
-                ..
-            }) => {
-                self.perform(Cmd::Delete);
-                Some(Msg::None)
-            }
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::Quit)),
             Event::Keyboard(KeyEvent {
-                code: Key::Char(ch),
-                ..
-            }) => {
-                self.perform(Cmd::Type(ch));
-                Some(Msg::None)
-            }
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::CloseQuitPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
-            }) => match self.state() {
-                State::One(StateValue::String(i)) => Some(Msg::Transfer(TransferMsg::NewFile(i))),
-                _ => Some(Msg::None),
-            },
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseNewFilePopup))
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::Ui(UiMsg::Quit))
+                } else {
+                    Some(Msg::Ui(UiMsg::CloseQuitPopup))
+                }
             }
             _ => None,
         }
@@ -1011,11 +2074,11 @@ impl Component<Msg, NoUserEvent> for NewfilePopup {
 }
 
 #[derive(MockComponent)]
-pub struct OpenWithPopup {
+pub struct RenamePopup {
     component: Input,
 }
 
-impl OpenWithPopup {
+impl RenamePopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -1027,15 +2090,15 @@ impl OpenWithPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "Open file with…",
+                    "/foo/bar/buzz.txt",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Type the program to open the file with", Alignment::Center),
+                .title(tr("move-files-to"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for OpenWithPopup {
+impl Component<Msg, NoUserEvent> for RenamePopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -1084,12 +2147,12 @@ impl Component<Msg, NoUserEvent> for OpenWithPopup {
                 code: Key::Enter, ..
             }) => match self.state() {
                 State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::OpenFileWith(i)))
+                    Some(Msg::Transfer(TransferMsg::RenameFile(i)))
                 }
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseOpenWithPopup))
+                Some(Msg::Ui(UiMsg::CloseRenamePopup))
             }
             _ => None,
         }
@@ -1097,139 +2160,215 @@ impl Component<Msg, NoUserEvent> for OpenWithPopup {
 }
 
 #[derive(MockComponent)]
-pub struct ProgressBarFull {
-    component: ProgressBar,
+pub struct FreeSpacePopup {
+    component: Radio,
 }
 
-impl ProgressBarFull {
-    pub fn new<S: AsRef<str>>(prog: f64, label: S, title: S, color: Color) -> Self {
+impl FreeSpacePopup {
+    pub fn new(required: u64, available: u64, color: Color) -> Self {
         Self {
-            component: ProgressBar::default()
+            component: Radio::default()
                 .borders(
                     Borders::default()
-                        .modifiers(BorderType::Rounded)
-                        .sides(BorderSides::TOP | BorderSides::LEFT | BorderSides::RIGHT),
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .label(label)
-                .progress(prog)
-                .title(title, Alignment::Center),
+                .choices(&[tr("yes"), tr("no")])
+                .value(1)
+                .title(
+                    tr_args(
+                        "not-enough-space",
+                        &[
+                            ("required", &ByteSize(required)),
+                            ("available", &ByteSize(available)),
+                        ],
+                    ),
+                    Alignment::Center,
+                ),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ProgressBarFull {
+impl Component<Msg, NoUserEvent> for FreeSpacePopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        if matches!(
-            ev,
+        match ev {
             Event::Keyboard(KeyEvent {
-                code: Key::Char('c'),
-                modifiers: KeyModifiers::CONTROL
-            })
-        ) {
-            Some(Msg::Transfer(TransferMsg::AbortTransfer))
-        } else {
-            None
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::PendingAction(PendingActionMsg::CloseFreeSpacePopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::CloseFreeSpacePopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile))
+                } else {
+                    Some(Msg::PendingAction(PendingActionMsg::CloseFreeSpacePopup))
+                }
+            }
+            _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct ProgressBarPartial {
-    component: ProgressBar,
+pub struct ReplacePopup {
+    component: Radio,
 }
 
-impl ProgressBarPartial {
-    pub fn new<S: AsRef<str>>(prog: f64, label: S, title: S, color: Color) -> Self {
+impl ReplacePopup {
+    pub fn new(filename: Option<&str>, color: Color) -> Self {
+        let text = match filename {
+            Some(f) => tr_args("overwrite-file", &[("file", &f)]),
+            None => tr("overwrite-files"),
+        };
         Self {
-            component: ProgressBar::default()
+            component: Radio::default()
                 .borders(
                     Borders::default()
-                        .modifiers(BorderType::Rounded)
-                        .sides(BorderSides::BOTTOM | BorderSides::LEFT | BorderSides::RIGHT),
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .label(label)
-                .progress(prog)
-                .title(title, Alignment::Center),
+                .choices(&[tr("yes"), tr("no")])
+                .title(text, Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ProgressBarPartial {
+impl Component<Msg, NoUserEvent> for ReplacePopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        if matches!(
-            ev,
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                Some(Msg::Ui(UiMsg::ReplacePopupTabbed))
+            }
             Event::Keyboard(KeyEvent {
-                code: Key::Char('c'),
-                modifiers: KeyModifiers::CONTROL
-            })
-        ) {
-            Some(Msg::Transfer(TransferMsg::AbortTransfer))
-        } else {
-            None
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile))
+                } else {
+                    Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
+                }
+            }
+            _ => None,
         }
     }
 }
 
 #[derive(MockComponent)]
-pub struct QuitPopup {
-    component: Radio,
+pub struct ReplacingFilesListPopup {
+    component: List,
 }
 
-impl QuitPopup {
-    pub fn new(color: Color) -> Self {
+impl ReplacingFilesListPopup {
+    pub fn new(files: &[String], color: Color) -> Self {
         Self {
-            component: Radio::default()
+            component: List::default()
                 .borders(
                     Borders::default()
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
-                .foreground(color)
-                .choices(&["Yes", "No"])
-                .title("Are you sure you want to quit termscp?", Alignment::Center),
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(tr("files-to-replace"), Alignment::Center)
+                .rows(files.iter().map(|x| vec![TextSpan::from(x)]).collect()),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for QuitPopup {
+impl Component<Msg, NoUserEvent> for ReplacingFilesListPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
+            }
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                Some(Msg::Ui(UiMsg::ReplacePopupTabbed))
+            }
             Event::Keyboard(KeyEvent {
-                code: Key::Left, ..
+                code: Key::Down, ..
             }) => {
-                self.perform(Cmd::Move(Direction::Left));
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Right, ..
+                code: Key::PageDown,
+                ..
             }) => {
-                self.perform(Cmd::Move(Direction::Right));
+                self.perform(Cmd::Scroll(Direction::Down));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseQuitPopup))
-            }
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('y'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::Quit)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('n'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::CloseQuitPopup)),
             Event::Keyboard(KeyEvent {
-                code: Key::Enter, ..
+                code: Key::PageUp, ..
             }) => {
-                if matches!(
-                    self.perform(Cmd::Submit),
-                    CmdResult::Submit(State::One(StateValue::Usize(0)))
-                ) {
-                    Some(Msg::Ui(UiMsg::Quit))
-                } else {
-                    Some(Msg::Ui(UiMsg::CloseQuitPopup))
-                }
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
             }
             _ => None,
         }
@@ -1237,11 +2376,11 @@ impl Component<Msg, NoUserEvent> for QuitPopup {
 }
 
 #[derive(MockComponent)]
-pub struct RenamePopup {
+pub struct SaveAsPopup {
     component: Input,
 }
 
-impl RenamePopup {
+impl SaveAsPopup {
     pub fn new(color: Color) -> Self {
         Self {
             component: Input::default()
@@ -1256,12 +2395,12 @@ impl RenamePopup {
                     "/foo/bar/buzz.txt",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Move file(s) to…", Alignment::Center),
+                .title(tr("save-as"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for RenamePopup {
+impl Component<Msg, NoUserEvent> for SaveAsPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -1310,12 +2449,12 @@ impl Component<Msg, NoUserEvent> for RenamePopup {
                 code: Key::Enter, ..
             }) => match self.state() {
                 State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::RenameFile(i)))
+                    Some(Msg::Transfer(TransferMsg::SaveFileAs(i)))
                 }
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseRenamePopup))
+                Some(Msg::Ui(UiMsg::CloseSaveAsPopup))
             }
             _ => None,
         }
@@ -1323,70 +2462,96 @@ impl Component<Msg, NoUserEvent> for RenamePopup {
 }
 
 #[derive(MockComponent)]
-pub struct ReplacePopup {
-    component: Radio,
+pub struct ExecResultPopup {
+    component: List,
 }
 
-impl ReplacePopup {
-    pub fn new(filename: Option<&str>, color: Color) -> Self {
-        let text = match filename {
-            Some(f) => format!(r#"File "{}" already exists. Overwrite file?"#, f),
-            None => "Overwrite files?".to_string(),
+impl ExecResultPopup {
+    pub fn new(
+        cmd: &str,
+        exit_code: Option<i32>,
+        stdout: &str,
+        stderr: &str,
+        color: Color,
+        error_color: Color,
+    ) -> Self {
+        let mut rows: Vec<Vec<TextSpan>> =
+            stdout.lines().map(|x| vec![TextSpan::from(x)]).collect();
+        if !stderr.is_empty() {
+            if !rows.is_empty() {
+                rows.push(vec![TextSpan::from("")]);
+            }
+            rows.extend(
+                stderr
+                    .lines()
+                    .map(|x| vec![TextSpan::from(x).fg(error_color)]),
+            );
+        }
+        if rows.is_empty() {
+            rows.push(vec![TextSpan::from(tr("no-output")).italic()]);
+        }
+        let title = match exit_code {
+            Some(code) => tr_args("command-exit-code", &[("command", &cmd), ("code", &code)]),
+            None => tr_args("command-terminated-by-signal", &[("command", &cmd)]),
         };
         Self {
-            component: Radio::default()
+            component: List::default()
                 .borders(
                     Borders::default()
-                        .color(color)
+                        .color(match exit_code {
+                            Some(0) => color,
+                            _ => error_color,
+                        })
                         .modifiers(BorderType::Rounded),
                 )
-                .foreground(color)
-                .choices(&["Yes", "No"])
-                .title(text, Alignment::Center),
+                .scroll(true)
+                .step(8)
+                .highlighted_str("> ")
+                .title(title, Alignment::Center)
+                .rows(rows),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ReplacePopup {
+impl Component<Msg, NoUserEvent> for ExecResultPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
-            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
-                Some(Msg::Ui(UiMsg::ReplacePopupTabbed))
-            }
             Event::Keyboard(KeyEvent {
-                code: Key::Left, ..
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseExecResultPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
             }) => {
-                self.perform(Cmd::Move(Direction::Left));
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Right, ..
+                code: Key::PageDown,
+                ..
             }) => {
-                self.perform(Cmd::Move(Direction::Right));
+                self.perform(Cmd::Scroll(Direction::Down));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
-            }
             Event::Keyboard(KeyEvent {
-                code: Key::Char('y'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('n'),
-                modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups)),
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
             Event::Keyboard(KeyEvent {
-                code: Key::Enter, ..
+                code: Key::Home, ..
             }) => {
-                if matches!(
-                    self.perform(Cmd::Submit),
-                    CmdResult::Submit(State::One(StateValue::Usize(0)))
-                ) {
-                    Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile))
-                } else {
-                    Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
-                }
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
             }
             _ => None,
         }
@@ -1394,73 +2559,85 @@ impl Component<Msg, NoUserEvent> for ReplacePopup {
 }
 
 #[derive(MockComponent)]
-pub struct ReplacingFilesListPopup {
-    component: List,
+pub struct ExportFindPopup {
+    component: Input,
 }
 
-impl ReplacingFilesListPopup {
-    pub fn new(files: &[String], color: Color) -> Self {
+impl ExportFindPopup {
+    pub fn new(color: Color) -> Self {
         Self {
-            component: List::default()
+            component: Input::default()
                 .borders(
                     Borders::default()
                         .color(color)
                         .modifiers(BorderType::Rounded),
                 )
-                .scroll(true)
-                .step(4)
-                .highlighted_color(color)
-                .highlighted_str("➤ ")
-                .title(
-                    "The following files are going to be replaced",
-                    Alignment::Center,
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder(
+                    "results.csv",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .rows(files.iter().map(|x| vec![TextSpan::from(x)]).collect()),
+                .title(tr("export-search-results"), Alignment::Center),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for ReplacingFilesListPopup {
+impl Component<Msg, NoUserEvent> for ExportFindPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::PendingAction(PendingActionMsg::CloseReplacePopups))
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
-                Some(Msg::Ui(UiMsg::ReplacePopupTabbed))
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Down, ..
+                code: Key::Home, ..
             }) => {
-                self.perform(Cmd::Move(Direction::Down));
+                self.perform(Cmd::GoTo(Position::Begin));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
-                self.perform(Cmd::Move(Direction::Up));
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::PageDown,
-                ..
+                code: Key::Delete, ..
             }) => {
-                self.perform(Cmd::Scroll(Direction::Down));
+                self.perform(Cmd::Cancel);
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::PageUp, ..
+                code: Key::Backspace,
+                ..
             }) => {
-                self.perform(Cmd::Scroll(Direction::Up));
+                self.perform(Cmd::Delete);
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
+                code: Key::Char(ch),
+                ..
             }) => {
-                self.perform(Cmd::GoTo(Position::Begin));
+                self.perform(Cmd::Type(ch));
                 Some(Msg::None)
             }
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End));
-                Some(Msg::None)
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => {
+                    Some(Msg::Transfer(TransferMsg::ExportFindResults(i)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseExportFindPopup))
             }
             _ => None,
         }
@@ -1468,12 +2645,12 @@ impl Component<Msg, NoUserEvent> for ReplacingFilesListPopup {
 }
 
 #[derive(MockComponent)]
-pub struct SaveAsPopup {
+pub struct TransferFilterPopup {
     component: Input,
 }
 
-impl SaveAsPopup {
-    pub fn new(color: Color) -> Self {
+impl TransferFilterPopup {
+    pub fn new(value: &str, color: Color) -> Self {
         Self {
             component: Input::default()
                 .borders(
@@ -1484,15 +2661,16 @@ impl SaveAsPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "/foo/bar/buzz.txt",
+                    "target/, .git/, *.o",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Save as…", Alignment::Center),
+                .title(tr("transfer-filter"), Alignment::Center)
+                .value(value),
         }
     }
 }
 
-impl Component<Msg, NoUserEvent> for SaveAsPopup {
+impl Component<Msg, NoUserEvent> for TransferFilterPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
@@ -1541,25 +2719,59 @@ impl Component<Msg, NoUserEvent> for SaveAsPopup {
                 code: Key::Enter, ..
             }) => match self.state() {
                 State::One(StateValue::String(i)) => {
-                    Some(Msg::Transfer(TransferMsg::SaveFileAs(i)))
+                    Some(Msg::Transfer(TransferMsg::TransferFileWithFilter(i)))
                 }
                 _ => Some(Msg::None),
             },
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseSaveAsPopup))
+                Some(Msg::Ui(UiMsg::CloseTransferFilterPopup))
             }
             _ => None,
         }
     }
 }
 
+#[derive(MockComponent)]
+pub struct SessionSummaryPopup {
+    component: List,
+}
+
+impl SessionSummaryPopup {
+    pub fn new(lines: &[String], color: Color) -> Self {
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title(tr("session-summary"), Alignment::Center)
+                .rows(lines.iter().map(|x| vec![TextSpan::from(x)]).collect()),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SessionSummaryPopup {
+    fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+        None
+    }
+}
+
 #[derive(MockComponent)]
 pub struct SortingPopup {
     component: Radio,
 }
 
 impl SortingPopup {
-    pub fn new(value: FileSorting, color: Color) -> Self {
+    pub fn new(value: FileSorting, reversed: bool, remembered: bool, color: Color) -> Self {
+        let title = tr_args(
+            "sort-files-by",
+            &[
+                ("reversed", &on_off_label(reversed)),
+                ("remembered", &on_off_label(remembered)),
+            ],
+        );
         Self {
             component: Radio::default()
                 .borders(
@@ -1568,13 +2780,26 @@ impl SortingPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(&["Name", "Modify time", "Creation time", "Size"])
-                .title("Sort files by…", Alignment::Center)
+                .choices(&[
+                    tr("sorting-name"),
+                    tr("sorting-natural"),
+                    tr("sorting-modify-time"),
+                    tr("sorting-creation-time"),
+                    tr("sorting-size"),
+                    tr("sorting-extension"),
+                    tr("sorting-owner"),
+                    tr("sorting-permissions"),
+                ])
+                .title(title, Alignment::Center)
                 .value(match value {
-                    FileSorting::CreationTime => 2,
-                    FileSorting::ModifyTime => 1,
+                    FileSorting::CreationTime => 3,
+                    FileSorting::Extension => 5,
+                    FileSorting::ModifyTime => 2,
                     FileSorting::Name => 0,
-                    FileSorting::Size => 3,
+                    FileSorting::NaturalName => 1,
+                    FileSorting::Owner => 6,
+                    FileSorting::Permissions => 7,
+                    FileSorting::Size => 4,
                 }),
         }
     }
@@ -1584,27 +2809,108 @@ impl Component<Msg, NoUserEvent> for SortingPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         let result = match ev {
             Event::Keyboard(KeyEvent {
-                code: Key::Left, ..
-            }) => self.perform(Cmd::Move(Direction::Left)),
+                code: Key::Left, ..
+            }) => self.perform(Cmd::Move(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => self.perform(Cmd::Move(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => return Some(Msg::Ui(UiMsg::CloseFileSortingPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('r'),
+                modifiers: KeyModifiers::NONE,
+            }) => return Some(Msg::Ui(UiMsg::ToggleReverseSorting)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('d'),
+                modifiers: KeyModifiers::NONE,
+            }) => return Some(Msg::Ui(UiMsg::ToggleDirectoryView)),
+            _ => return None,
+        };
+        if let CmdResult::Changed(State::One(StateValue::Usize(i))) = result {
+            Some(Msg::Ui(UiMsg::ChangeFileSorting(match i {
+                0 => FileSorting::Name,
+                1 => FileSorting::NaturalName,
+                2 => FileSorting::ModifyTime,
+                3 => FileSorting::CreationTime,
+                4 => FileSorting::Size,
+                5 => FileSorting::Extension,
+                6 => FileSorting::Owner,
+                7 => FileSorting::Permissions,
+                _ => FileSorting::Name,
+            })))
+        } else {
+            Some(Msg::None)
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct StartTransferPopup {
+    component: Radio,
+}
+
+impl StartTransferPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .choices(&[tr("yes"), tr("no")])
+                .value(0)
+                .title(tr("start-transfer"), Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for StartTransferPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::PendingAction(
+                PendingActionMsg::CloseStartTransferPopup,
+            )),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile)),
             Event::Keyboard(KeyEvent {
-                code: Key::Right, ..
-            }) => self.perform(Cmd::Move(Direction::Right)),
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(
+                PendingActionMsg::CloseStartTransferPopup,
+            )),
             Event::Keyboard(KeyEvent {
-                code: Key::Esc | Key::Enter,
-                ..
-            }) => return Some(Msg::Ui(UiMsg::CloseFileSortingPopup)),
-            _ => return None,
-        };
-        if let CmdResult::Changed(State::One(StateValue::Usize(i))) = result {
-            Some(Msg::Ui(UiMsg::ChangeFileSorting(match i {
-                0 => FileSorting::Name,
-                1 => FileSorting::ModifyTime,
-                2 => FileSorting::CreationTime,
-                3 => FileSorting::Size,
-                _ => FileSorting::Name,
-            })))
-        } else {
-            Some(Msg::None)
+                code: Key::Enter, ..
+            }) => {
+                if matches!(
+                    self.perform(Cmd::Submit),
+                    CmdResult::Submit(State::One(StateValue::Usize(0)))
+                ) {
+                    Some(Msg::PendingAction(PendingActionMsg::TransferPendingFile))
+                } else {
+                    Some(Msg::PendingAction(
+                        PendingActionMsg::CloseStartTransferPopup,
+                    ))
+                }
+            }
+            _ => None,
         }
     }
 }
@@ -1615,16 +2921,49 @@ pub struct StatusBarLocal {
 }
 
 impl StatusBarLocal {
-    pub fn new(browser: &Browser, sorting_color: Color, hidden_color: Color) -> Self {
-        let file_sorting = file_sorting_label(browser.local().file_sorting);
+    pub fn new(
+        browser: &Browser,
+        info: &StatusBarInfo,
+        fmt: Option<&StatusBarFmt>,
+        sorting_color: Color,
+        hidden_color: Color,
+        disk_space_color: Color,
+    ) -> Self {
+        if let Some(fmt) = fmt {
+            let spans =
+                status_bar_spans(fmt, browser.local(), browser.sync_browsing, info, |token| {
+                    match token {
+                        StatusBarToken::Path | StatusBarToken::Sorting => sorting_color,
+                        StatusBarToken::Hidden | StatusBarToken::Host => hidden_color,
+                        _ => disk_space_color,
+                    }
+                });
+            return Self {
+                component: Span::default().spans(&spans),
+            };
+        }
+        let file_sorting = file_sorting_label(
+            browser.local().file_sorting,
+            browser.local().reverse_sorting(),
+        );
         let hidden_files = hidden_files_label(browser.local().hidden_files_visible());
+        let mut spans = vec![
+            TextSpan::new(format!("{}: ", tr("status-file-sorting"))).fg(sorting_color),
+            TextSpan::new(file_sorting).fg(sorting_color).reversed(),
+            TextSpan::new(format!(" {}: ", tr("status-hidden-files"))).fg(hidden_color),
+            TextSpan::new(hidden_files).fg(hidden_color).reversed(),
+        ];
+        spans.extend(disk_space_spans(info.disk_space, disk_space_color));
+        if info.watched_paths > 0 {
+            spans.extend(vec![
+                TextSpan::new(format!(" {}: ", tr("status-watcher"))).fg(hidden_color),
+                TextSpan::new(watcher_label(info.watched_paths, info.last_sync))
+                    .fg(hidden_color)
+                    .reversed(),
+            ]);
+        }
         Self {
-            component: Span::default().spans(&[
-                TextSpan::new("File sorting: ").fg(sorting_color),
-                TextSpan::new(file_sorting).fg(sorting_color).reversed(),
-                TextSpan::new(" Hidden files: ").fg(hidden_color),
-                TextSpan::new(hidden_files).fg(hidden_color).reversed(),
-            ]),
+            component: Span::default().spans(&spans),
         }
     }
 }
@@ -1643,25 +2982,49 @@ pub struct StatusBarRemote {
 impl StatusBarRemote {
     pub fn new(
         browser: &Browser,
+        info: &StatusBarInfo,
+        fmt: Option<&StatusBarFmt>,
         sorting_color: Color,
         hidden_color: Color,
         sync_color: Color,
+        disk_space_color: Color,
     ) -> Self {
-        let file_sorting = file_sorting_label(browser.remote().file_sorting);
+        if let Some(fmt) = fmt {
+            let spans = status_bar_spans(
+                fmt,
+                browser.remote(),
+                browser.sync_browsing,
+                info,
+                |token| match token {
+                    StatusBarToken::Path | StatusBarToken::Sorting => sorting_color,
+                    StatusBarToken::Hidden | StatusBarToken::Host => hidden_color,
+                    StatusBarToken::Rate | StatusBarToken::Sync | StatusBarToken::Watch => {
+                        sync_color
+                    }
+                    StatusBarToken::Disk => disk_space_color,
+                },
+            );
+            return Self {
+                component: Span::default().spans(&spans),
+            };
+        }
+        let file_sorting = file_sorting_label(
+            browser.remote().file_sorting,
+            browser.remote().reverse_sorting(),
+        );
         let hidden_files = hidden_files_label(browser.remote().hidden_files_visible());
-        let sync_browsing = match browser.sync_browsing {
-            true => "ON ",
-            false => "OFF",
-        };
+        let sync_browsing = format!("{:<3}", on_off_label(browser.sync_browsing));
+        let mut spans = vec![
+            TextSpan::new(format!("{}: ", tr("status-file-sorting"))).fg(sorting_color),
+            TextSpan::new(file_sorting).fg(sorting_color).reversed(),
+            TextSpan::new(format!(" {}: ", tr("status-hidden-files"))).fg(hidden_color),
+            TextSpan::new(hidden_files).fg(hidden_color).reversed(),
+            TextSpan::new(format!(" {}: ", tr("status-sync-browsing"))).fg(sync_color),
+            TextSpan::new(sync_browsing).fg(sync_color).reversed(),
+        ];
+        spans.extend(disk_space_spans(info.disk_space, disk_space_color));
         Self {
-            component: Span::default().spans(&[
-                TextSpan::new("File sorting: ").fg(sorting_color),
-                TextSpan::new(file_sorting).fg(sorting_color).reversed(),
-                TextSpan::new(" Hidden files: ").fg(hidden_color),
-                TextSpan::new(hidden_files).fg(hidden_color).reversed(),
-                TextSpan::new(" Sync browsing: ").fg(sync_color),
-                TextSpan::new(sync_browsing).fg(sync_color).reversed(),
-            ]),
+            component: Span::default().spans(&spans),
         }
     }
 }
@@ -1672,19 +3035,127 @@ impl Component<Msg, NoUserEvent> for StatusBarRemote {
     }
 }
 
-fn file_sorting_label(sorting: FileSorting) -> &'static str {
-    match sorting {
-        FileSorting::CreationTime => "By creation time",
-        FileSorting::ModifyTime => "By modify time",
-        FileSorting::Name => "By name",
-        FileSorting::Size => "By size",
+/// Values shown in the status bar which don't belong to the file explorer
+pub struct StatusBarInfo {
+    pub disk_space: Option<DiskSpace>,
+    pub host: String,
+    /// Speed of the last transfer in bytes per second
+    pub rate: Option<u64>,
+    /// Amount of paths watched by the file watcher
+    pub watched_paths: usize,
+    /// Time of the last change synchronized by the file watcher
+    pub last_sync: Option<DateTime<Local>>,
+}
+
+/// Build the status bar spans from `fmt`.
+/// Text takes the color of the value following it, while values are reversed
+fn status_bar_spans<F>(
+    fmt: &StatusBarFmt,
+    explorer: &FileExplorer,
+    sync_browsing: bool,
+    info: &StatusBarInfo,
+    color: F,
+) -> Vec<TextSpan>
+where
+    F: Fn(StatusBarToken) -> Color,
+{
+    let segments = fmt.segments();
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| match segment {
+            StatusBarSegment::Text(text) => {
+                let token = first_token(segments[i + 1..].iter())
+                    .or_else(|| first_token(segments[..i].iter().rev()))
+                    .unwrap_or(StatusBarToken::Sorting);
+                TextSpan::new(text).fg(color(token))
+            }
+            StatusBarSegment::Token(token) => {
+                let value = match token {
+                    StatusBarToken::Disk => info
+                        .disk_space
+                        .map(|x| x.label())
+                        .unwrap_or_else(|| tr("status-not-available")),
+                    StatusBarToken::Hidden => hidden_files_label(explorer.hidden_files_visible()),
+                    StatusBarToken::Host => info.host.clone(),
+                    StatusBarToken::Path => explorer.wrkdir.display().to_string(),
+                    StatusBarToken::Rate => info
+                        .rate
+                        .map(|x| format!("{}/s", ByteSize(x)))
+                        .unwrap_or_else(|| tr("status-not-available")),
+                    StatusBarToken::Sorting => {
+                        file_sorting_label(explorer.file_sorting, explorer.reverse_sorting())
+                    }
+                    StatusBarToken::Sync => on_off_label(sync_browsing),
+                    StatusBarToken::Watch => watcher_label(info.watched_paths, info.last_sync),
+                };
+                TextSpan::new(value).fg(color(*token)).reversed()
+            }
+        })
+        .collect()
+}
+
+fn first_token<'a>(
+    mut segments: impl Iterator<Item = &'a StatusBarSegment>,
+) -> Option<StatusBarToken> {
+    segments.find_map(|x| match x {
+        StatusBarSegment::Token(token) => Some(*token),
+        StatusBarSegment::Text(_) => None,
+    })
+}
+
+fn file_sorting_label(sorting: FileSorting, reversed: bool) -> String {
+    let label = match sorting {
+        FileSorting::CreationTime => tr("sorted-by-creation-time"),
+        FileSorting::Extension => tr("sorted-by-extension"),
+        FileSorting::ModifyTime => tr("sorted-by-modify-time"),
+        FileSorting::Name => tr("sorted-by-name"),
+        FileSorting::NaturalName => tr("sorted-by-natural-name"),
+        FileSorting::Owner => tr("sorted-by-owner"),
+        FileSorting::Permissions => tr("sorted-by-permissions"),
+        FileSorting::Size => tr("sorted-by-size"),
+    };
+    match reversed {
+        true => tr_args("sorted-reversed", &[("sorting", &label)]),
+        false => label,
     }
 }
 
-fn hidden_files_label(visible: bool) -> &'static str {
+fn on_off_label(value: bool) -> String {
+    match value {
+        true => tr("on"),
+        false => tr("off"),
+    }
+}
+
+fn disk_space_spans(disk_space: Option<DiskSpace>, color: Color) -> Vec<TextSpan> {
+    match disk_space {
+        Some(disk_space) => vec![
+            TextSpan::new(format!(" {}: ", tr("status-disk"))).fg(color),
+            TextSpan::new(disk_space.label()).fg(color).reversed(),
+        ],
+        None => vec![],
+    }
+}
+
+fn watcher_label(watched_paths: usize, last_sync: Option<DateTime<Local>>) -> String {
+    let paths = watched_paths.to_string();
+    match last_sync {
+        Some(time) => tr_args(
+            "status-watcher-last-sync",
+            &[
+                ("paths", &paths),
+                ("time", &time.format("%H:%M:%S").to_string()),
+            ],
+        ),
+        None => tr_args("status-watcher-paths", &[("paths", &paths)]),
+    }
+}
+
+fn hidden_files_label(visible: bool) -> String {
     match visible {
-        true => "Show",
-        false => "Hide",
+        true => tr("status-hidden-files-show"),
+        false => tr("status-hidden-files-hide"),
     }
 }
 
@@ -1705,13 +3176,10 @@ impl SymlinkPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "Symlink name",
+                    tr("symlink-name").as_str(),
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title(
-                    "Create a symlink pointing to the selected entry",
-                    Alignment::Center,
-                ),
+                .title(tr("create-symlink"), Alignment::Center),
         }
     }
 }
@@ -1792,12 +3260,9 @@ impl SyncBrowsingMkdirPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .title(
-                    format!(
-                        r#"Sync browsing: directory "{}" doesn't exist. Do you want to create it?"#,
-                        dir_name
-                    ),
+                    tr_args("sync-browsing-create-dir", &[("dir", &dir_name)]),
                     Alignment::Center,
                 ),
         }
@@ -1851,6 +3316,175 @@ impl Component<Msg, NoUserEvent> for SyncBrowsingMkdirPopup {
     }
 }
 
+#[derive(MockComponent)]
+pub struct TailPopup {
+    component: List,
+}
+
+impl TailPopup {
+    pub fn new(path: &std::path::Path, color: Color) -> Self {
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .scroll(true)
+                .step(8)
+                .highlighted_str("> ")
+                .title(format!("tail -f {}", path.display()), Alignment::Center)
+                .rows(vec![]),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for TailPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseTailPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct TransferSummaryPopup {
+    component: List,
+}
+
+impl TransferSummaryPopup {
+    pub fn new(summary: &TransferSummary, color: Color) -> Self {
+        let total = summary.total();
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title(
+                    tr_args(
+                        "transfer-summary",
+                        &[
+                            ("files", &total.files),
+                            ("size", &ByteSize(total.bytes as u64)),
+                        ],
+                    ),
+                    Alignment::Center,
+                )
+                .rows(
+                    summary
+                        .largest()
+                        .into_iter()
+                        .map(|(name, size)| {
+                            vec![
+                                TextSpan::from(name),
+                                TextSpan::from(format!(" {}", ByteSize(size.bytes as u64))).bold(),
+                            ]
+                        })
+                        .collect(),
+                ),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for TransferSummaryPopup {
+    fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+        None
+    }
+}
+
+#[derive(MockComponent)]
+pub struct TypeAheadPopup {
+    component: Input,
+}
+
+impl TypeAheadPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .title(tr("jump-to"), Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for TypeAheadPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                match self.state() {
+                    State::One(StateValue::String(query)) => Some(Msg::Ui(UiMsg::TypeAhead(query))),
+                    _ => Some(Msg::None),
+                }
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                match self.state() {
+                    State::One(StateValue::String(query)) => Some(Msg::Ui(UiMsg::TypeAhead(query))),
+                    _ => Some(Msg::None),
+                }
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter | Key::Esc,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseTypeAheadPopup)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct WaitPopup {
     component: Paragraph,
@@ -1898,10 +3532,7 @@ impl WatchedPathsList {
                 .step(4)
                 .highlighted_color(color)
                 .highlighted_str("➤ ")
-                .title(
-                    "These files are currently synched with the remote host",
-                    Alignment::Center,
-                )
+                .title(tr("synchronized-files"), Alignment::Center)
                 .rows(
                     paths
                         .iter()
@@ -1966,6 +3597,97 @@ impl Component<Msg, NoUserEvent> for WatchedPathsList {
     }
 }
 
+#[derive(MockComponent)]
+pub struct WatcherLogPopup {
+    component: List,
+}
+
+impl WatcherLogPopup {
+    pub fn new(events: &[WatcherEvent], color: Color, error_color: Color) -> Self {
+        let mut rows: Vec<Vec<TextSpan>> = events
+            .iter()
+            .map(|x| {
+                let mut row = vec![
+                    TextSpan::from(x.time.format("%H:%M:%S ").to_string()),
+                    match x.error {
+                        None => TextSpan::from(format!("{:<6}", tr("watcher-ok"))).fg(color),
+                        Some(_) => {
+                            TextSpan::from(format!("{:<6}", tr("watcher-error"))).fg(error_color)
+                        }
+                    },
+                    TextSpan::from(format!("{:<7}", x.action.label())).bold(),
+                    TextSpan::from(x.path.to_string_lossy().to_string()),
+                ];
+                if let Some(err) = x.error.as_ref() {
+                    row.push(TextSpan::from(format!(": {}", err)).fg(error_color));
+                }
+                row
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(vec![TextSpan::from(tr("watcher-log-empty")).italic()]);
+        }
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .scroll(true)
+                .step(8)
+                .highlighted_str("> ")
+                .title(tr("watcher-log"), Alignment::Center)
+                .rows(rows),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for WatcherLogPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseWatcherLogPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct WatcherPopup {
     component: Radio,
@@ -1974,8 +3696,8 @@ pub struct WatcherPopup {
 impl WatcherPopup {
     pub fn new(watched: bool, local: &str, remote: &str, color: Color) -> Self {
         let text = match watched {
-            false => format!(r#"Synchronize changes from "{}" to "{}"?"#, local, remote),
-            true => format!(r#"Stop synchronizing changes at "{}"?"#, local),
+            false => tr_args("watch-start", &[("local", &local), ("remote", &remote)]),
+            true => tr_args("watch-stop", &[("local", &local)]),
         };
         Self {
             component: Radio::default()
@@ -1985,7 +3707,7 @@ impl WatcherPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .title(text, Alignment::Center),
         }
     }
@@ -4,7 +4,8 @@
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, Style, Table, TextModifiers,
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Style, Table,
+    TextModifiers,
 };
 use tuirealm::tui::layout::Corner;
 use tuirealm::tui::text::{Span, Spans};
@@ -12,6 +13,8 @@ use tuirealm::tui::widgets::{List as TuiList, ListItem, ListState};
 use tuirealm::{MockComponent, Props, State, StateValue};
 
 pub const FILE_LIST_CMD_SELECT_ALL: &str = "A";
+/// Flag attribute which marks the selected entries and the cursor with text, other than with styles
+pub const FILE_LIST_TEXT_MARKERS: &str = "text-markers";
 
 /// OwnStates contains states for this component
 #[derive(Clone, Default)]
@@ -85,6 +88,12 @@ impl OwnStates {
         self.selected.clone()
     }
 
+    /// Move list index to `index`, if possible, otherwise to the last element
+    pub fn list_index_at(&mut self, index: usize) {
+        self.list_index = index;
+        self.fix_list_index();
+    }
+
     /// Keep index if possible, otherwise set to lenght - 1
     fn fix_list_index(&mut self) {
         if self.list_index >= self.list_len() && self.list_len() > 0 {
@@ -111,6 +120,14 @@ impl OwnStates {
         }
     }
 
+    /// Select files with provided entry indexes, ignoring the ones out of the list
+    pub fn select_entries(&mut self, entries: &[usize]) {
+        let len = self.list_len();
+        for entry in entries.iter().filter(|x| **x < len) {
+            self.select(*entry);
+        }
+    }
+
     /// Select provided index if not selected yet
     fn select(&mut self, entry: usize) {
         if !self.is_selected(entry) {
@@ -184,6 +201,13 @@ impl MockComponent for FileList {
             .props
             .get_or(Attribute::Focus, AttrValue::Flag(false))
             .unwrap_flag();
+        let text_markers = self
+            .props
+            .get_or(
+                Attribute::Custom(FILE_LIST_TEXT_MARKERS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
         let div = tui_realm_stdlib::utils::get_block(borders, Some(title), focus, None);
         // Make list entries
         let list_items: Vec<ListItem> = match self
@@ -195,9 +219,14 @@ impl MockComponent for FileList {
                 .iter()
                 .enumerate()
                 .map(|(num, row)| {
-                    let columns: Vec<Span> = row
-                        .iter()
-                        .map(|col| {
+                    let marker = match (text_markers, self.states.is_selected(num)) {
+                        (false, _) => None,
+                        (true, true) => Some(Span::raw("* ")),
+                        (true, false) => Some(Span::raw("  ")),
+                    };
+                    let columns: Vec<Span> = marker
+                        .into_iter()
+                        .chain(row.iter().map(|col| {
                             let (fg, bg, mut modifiers) =
                                 tui_realm_stdlib::utils::use_or_default_styles(&self.props, col);
                             if self.states.is_selected(num) {
@@ -209,7 +238,7 @@ impl MockComponent for FileList {
                                 col.content.clone(),
                                 Style::default().add_modifier(modifiers).fg(fg).bg(bg),
                             )
-                        })
+                        }))
                         .collect();
                     ListItem::new(Spans::from(columns))
                 })
@@ -228,6 +257,9 @@ impl MockComponent for FileList {
         let mut list = TuiList::new(list_items)
             .block(div)
             .start_corner(Corner::TopLeft);
+        if text_markers {
+            list = list.highlight_symbol("> ");
+        }
         if let Some(highlighted_color) = highlighted_color {
             list = list.highlight_style(
                 Style::default()
@@ -241,6 +273,25 @@ impl MockComponent for FileList {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // Value is used to move the cursor
+        if let (Attribute::Value, AttrValue::Payload(PropPayload::One(PropValue::Usize(index)))) =
+            (attr, &value)
+        {
+            self.states.list_index_at(*index);
+            return;
+        }
+        // Vec of values is used to select files
+        if let (Attribute::Value, AttrValue::Payload(PropPayload::Vec(entries))) = (attr, &value) {
+            let entries: Vec<usize> = entries
+                .iter()
+                .filter_map(|x| match x {
+                    PropValue::Usize(x) => Some(*x),
+                    _ => None,
+                })
+                .collect();
+            self.states.select_entries(entries.as_slice());
+            return;
+        }
         self.props.set(attr, value);
         if matches!(attr, Attribute::Content) {
             self.states.init_list_states(
@@ -254,6 +305,12 @@ impl MockComponent for FileList {
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        // Value returns the cursor position
+        if matches!(attr, Attribute::Value) {
+            return Some(AttrValue::Payload(PropPayload::One(PropValue::Usize(
+                self.states.list_index(),
+            ))));
+        }
         self.props.get(attr)
     }
 
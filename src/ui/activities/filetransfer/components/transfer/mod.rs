@@ -6,6 +6,7 @@ use super::{Msg, TransferMsg, UiMsg};
 
 mod file_list;
 use file_list::FileList;
+pub use file_list::FILE_LIST_TEXT_MARKERS;
 
 use tuirealm::command::{Cmd, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
@@ -96,6 +97,18 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
                 code: Key::Char(' '),
                 ..
             }) => Some(Msg::Transfer(TransferMsg::TransferFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('l'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowSymlinkPolicyPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('x'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowTransferFilterPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('/'),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowTypeAheadPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Backspace,
                 ..
@@ -120,14 +133,30 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
                 code: Key::Char('s') | Key::Function(2),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowSaveAsPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('u'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::GoToParentDirectory)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('v') | Key::Function(3),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Transfer(TransferMsg::OpenFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('t'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::TransferAllFound)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('w'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowOpenWithPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('x'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowExportFindPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowChecksumPopup)),
             _ => None,
         }
     }
@@ -206,6 +235,14 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
                 Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
             }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left,
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right,
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::GoToNextDirectory)),
             Event::Keyboard(KeyEvent {
                 code: Key::Right | Key::Tab | Key::BackTab,
                 ..
@@ -221,6 +258,34 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 code: Key::Char(' '),
                 ..
             }) => Some(Msg::Transfer(TransferMsg::TransferFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('l'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowSymlinkPolicyPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('x'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowTransferFilterPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('/'),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowTypeAheadPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('\''),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowDirMarksPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::SetMark(
+                ch.to_digit(10).unwrap_or_default() as usize,
+            ))),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::GoToMark(
+                ch.to_digit(10).unwrap_or_default() as usize,
+            ))),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('a'),
                 modifiers: KeyModifiers::NONE,
@@ -233,6 +298,10 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 code: Key::Char('c') | Key::Function(5),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowCopyPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('d'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowDuplicatesPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('d') | Key::Function(7),
                 modifiers: KeyModifiers::NONE,
@@ -253,6 +322,10 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 code: Key::Char('i'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowFileInfoPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowDirHistoryPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('k'),
                 modifiers: KeyModifiers::NONE,
@@ -289,6 +362,10 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 code: Key::Char('t'),
                 modifiers: KeyModifiers::CONTROL,
             }) => Some(Msg::Ui(UiMsg::ShowWatchedPathsList)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('w'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowWatcherLogPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('u'),
                 modifiers: KeyModifiers::NONE,
@@ -309,6 +386,10 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 code: Key::Char('w'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowOpenWithPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowChecksumPopup)),
             _ => None,
         }
     }
@@ -387,6 +468,14 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
                 Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
             }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left,
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right,
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::GoToNextDirectory)),
             Event::Keyboard(KeyEvent {
                 code: Key::Left | Key::Tab | Key::BackTab,
                 ..
@@ -402,6 +491,34 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char(' '),
                 ..
             }) => Some(Msg::Transfer(TransferMsg::TransferFile)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('l'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowSymlinkPolicyPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('x'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowTransferFilterPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('/'),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowTypeAheadPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('\''),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowDirMarksPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                modifiers: KeyModifiers::ALT,
+            }) => Some(Msg::Transfer(TransferMsg::SetMark(
+                ch.to_digit(10).unwrap_or_default() as usize,
+            ))),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch @ '1'..='9'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Transfer(TransferMsg::GoToMark(
+                ch.to_digit(10).unwrap_or_default() as usize,
+            ))),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('a'),
                 modifiers: KeyModifiers::NONE,
@@ -410,10 +527,18 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char('b'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowFileSortingPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('b'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowBucketPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('c') | Key::Function(5),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowCopyPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('d'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowDuplicatesPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('d') | Key::Function(7),
                 modifiers: KeyModifiers::NONE,
@@ -426,6 +551,10 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char('f'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowFindPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('f'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowTailPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('g'),
                 modifiers: KeyModifiers::NONE,
@@ -434,6 +563,10 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char('i'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowFileInfoPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowDirHistoryPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('k'),
                 modifiers: KeyModifiers::NONE,
@@ -470,6 +603,10 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char('t'),
                 modifiers: KeyModifiers::CONTROL,
             }) => Some(Msg::Ui(UiMsg::ShowWatchedPathsList)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('w'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Ui(UiMsg::ShowWatcherLogPopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('u'),
                 modifiers: KeyModifiers::NONE,
@@ -490,6 +627,10 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 code: Key::Char('w'),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Ui(UiMsg::ShowOpenWithPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::Ui(UiMsg::ShowChecksumPopup)),
             _ => None,
         }
     }
@@ -1,3 +1,4 @@
+use super::lib::watcher_log::WatcherAction;
 use super::{FileTransferActivity, LogLevel, TransferPayload};
 use crate::system::watcher::FsChange;
 
@@ -18,6 +19,7 @@ impl FileTransferActivity {
                     mov.source().display(),
                     mov.destination().display()
                 );
+                self.remote_cache.clear();
                 self.move_watched_file(mov.source(), mov.destination());
             }
             Ok(Some(FsChange::Remove(remove))) => {
@@ -25,6 +27,7 @@ impl FileTransferActivity {
                     "fs watcher reported a `Remove` of {}",
                     remove.path().display()
                 );
+                self.remote_cache.clear();
                 self.remove_watched_file(remove.path());
             }
             Ok(Some(FsChange::Update(update))) => {
@@ -33,6 +36,7 @@ impl FileTransferActivity {
                     update.local().display(),
                     update.remote().display()
                 );
+                self.remote_cache.clear();
                 self.upload_watched_file(update.local(), update.remote());
             }
             Err(err) => {
@@ -44,6 +48,25 @@ impl FileTransferActivity {
         }
     }
 
+    /// Returns the amount of paths watched by the file watcher
+    pub(super) fn watched_paths_count(&self) -> usize {
+        self.fswatcher
+            .as_ref()
+            .map(|w| w.watched_paths().len())
+            .unwrap_or(0)
+    }
+
+    /// Record the outcome of a change synchronized with the remote host in the watcher log
+    fn log_watcher_event(
+        &mut self,
+        action: WatcherAction,
+        path: &Path,
+        result: Result<(), String>,
+    ) {
+        self.watcher_log.push(action, path, result);
+        self.refresh_local_status_bar();
+    }
+
     fn move_watched_file(&mut self, source: &Path, destination: &Path) {
         // stat remote file
         trace!(
@@ -63,11 +86,19 @@ impl FileTransferActivity {
                         err
                     ),
                 );
+                self.log_watcher_event(WatcherAction::Move, destination, Err(err.to_string()));
                 return;
             }
         };
         // rename using action
-        self.remote_rename_file(&origin, destination)
+        self.remote_rename_file(&origin, destination);
+        // the file has been moved if it now exists at destination
+        let result = self
+            .client
+            .stat(destination)
+            .map(|_| ())
+            .map_err(|err| err.to_string());
+        self.log_watcher_event(WatcherAction::Move, destination, result);
     }
 
     fn remove_watched_file(&mut self, file: &Path) {
@@ -77,16 +108,40 @@ impl FileTransferActivity {
                     LogLevel::Info,
                     format!("removed watched file at {}", file.display()),
                 );
+                self.log_watcher_event(WatcherAction::Remove, file, Ok(()));
             }
             Err(err) => {
                 self.log(
                     LogLevel::Error,
                     format!("failed to remove watched file {}: {}", file.display(), err),
                 );
+                self.log_watcher_event(WatcherAction::Remove, file, Err(err.to_string()));
             }
         }
     }
 
+    /// Returns whether the watched file at `local` is excluded by the configured transfer filter.
+    /// The filter is applied to the path relative to the watched directory
+    fn is_watched_file_filtered_out(&self, local: &Path, is_dir: bool) -> bool {
+        let filter = match self.config().get_transfer_filter() {
+            Some(filter) => filter,
+            None => return false,
+        };
+        let root = self.fswatcher.as_ref().and_then(|watcher| {
+            watcher
+                .watched_paths()
+                .into_iter()
+                .filter(|x| local.starts_with(x))
+                .max_by_key(|x| x.components().count())
+                .map(|x| x.to_path_buf())
+        });
+        let relative = match root.as_deref() {
+            Some(root) => local.strip_prefix(root).unwrap_or(local),
+            None => local,
+        };
+        filter.excludes_path(relative, is_dir)
+    }
+
     fn upload_watched_file(&mut self, local: &Path, remote: &Path) {
         // stat local file
         let entry = match self.host.stat(local) {
@@ -100,9 +155,17 @@ impl FileTransferActivity {
                         err
                     ),
                 );
+                self.log_watcher_event(WatcherAction::Upload, remote, Err(err.to_string()));
                 return;
             }
         };
+        if self.is_watched_file_filtered_out(local, entry.is_dir()) {
+            debug!(
+                "not syncing {}: excluded by transfer filter",
+                local.display()
+            );
+            return;
+        }
         // send
         trace!(
             "syncing local file {} with remote {}",
@@ -120,12 +183,14 @@ impl FileTransferActivity {
                         remote.display()
                     ),
                 );
+                self.log_watcher_event(WatcherAction::Upload, remote, Ok(()));
             }
             Err(err) => {
                 self.log(
                     LogLevel::Error,
                     format!("failed to sync watched file {}: {}", remote.display(), err),
                 );
+                self.log_watcher_event(WatcherAction::Upload, remote, Err(err));
             }
         }
     }
@@ -2,7 +2,9 @@
 //!
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
-use crate::explorer::{builder::FileExplorerBuilder, FileExplorer, FileSorting, GroupDirs};
+use crate::explorer::{
+    builder::FileExplorerBuilder, ExplorerView, FileExplorer, FileSorting, GroupDirs,
+};
 use crate::system::config_client::ConfigClient;
 
 use remotefs::File;
@@ -30,17 +32,26 @@ pub struct Browser {
     remote: FileExplorer,                            // Remote File explorer state
     found: Option<(FoundExplorerTab, FileExplorer)>, // File explorer for find result
     tab: FileExplorerTab,                            // Current selected tab
+    local_view: Option<ExplorerView>, // Local view to restore when leaving a directory with its own view
+    remote_view: Option<ExplorerView>, // Remote view to restore when leaving a directory with its own view
+    initial_views: (ExplorerView, ExplorerView), // Local and remote views when the explorers were built
     pub sync_browsing: bool,
 }
 
 impl Browser {
     /// Build a new `Browser` struct
     pub fn new(cli: &ConfigClient) -> Self {
+        let local = Self::build_local_explorer(cli);
+        let remote = Self::build_remote_explorer(cli);
+        let initial_views = (local.view(), remote.view());
         Self {
-            local: Self::build_local_explorer(cli),
-            remote: Self::build_remote_explorer(cli),
+            local,
+            remote,
             found: None,
             tab: FileExplorerTab::Local,
+            local_view: None,
+            remote_view: None,
+            initial_views,
             sync_browsing: false,
         }
     }
@@ -71,6 +82,8 @@ impl Browser {
 
     pub fn set_found(&mut self, tab: FoundExplorerTab, files: Vec<File>, wrkdir: &Path) {
         let mut explorer = Self::build_found_explorer(wrkdir);
+        // Keep the directory the search started from, since results are relative to it
+        explorer.wrkdir = wrkdir.to_path_buf();
         explorer.set_files(files);
         self.found = Some((tab, explorer));
     }
@@ -93,34 +106,99 @@ impl Browser {
         self.tab = tab;
     }
 
+    /// Apply `view` remembered for the current directory of the local explorer.
+    /// If `None`, the view used before entering a directory with its own view is restored
+    pub fn apply_local_directory_view(&mut self, view: Option<ExplorerView>) {
+        Self::apply_directory_view(&mut self.local, &mut self.local_view, view);
+    }
+
+    /// Apply `view` remembered for the current directory of the remote explorer.
+    /// If `None`, the view used before entering a directory with its own view is restored
+    pub fn apply_remote_directory_view(&mut self, view: Option<ExplorerView>) {
+        Self::apply_directory_view(&mut self.remote, &mut self.remote_view, view);
+    }
+
+    /// Returns the view of the local explorer, regardless of the directory views
+    pub fn local_view(&self) -> ExplorerView {
+        self.local_view.unwrap_or_else(|| self.local.view())
+    }
+
+    /// Returns the view of the remote explorer, regardless of the directory views
+    pub fn remote_view(&self) -> ExplorerView {
+        self.remote_view.unwrap_or_else(|| self.remote.view())
+    }
+
+    /// Returns whether the view of the local explorer has been changed since it was built
+    pub fn local_view_changed(&self) -> bool {
+        self.local_view() != self.initial_views.0
+    }
+
+    /// Returns whether the view of the remote explorer has been changed since it was built
+    pub fn remote_view_changed(&self) -> bool {
+        self.remote_view() != self.initial_views.1
+    }
+
+    fn apply_directory_view(
+        explorer: &mut FileExplorer,
+        prev_view: &mut Option<ExplorerView>,
+        view: Option<ExplorerView>,
+    ) {
+        match view {
+            Some(view) => {
+                if prev_view.is_none() {
+                    *prev_view = Some(explorer.view());
+                }
+                explorer.set_view(view);
+            }
+            None => {
+                if let Some(view) = prev_view.take() {
+                    explorer.set_view(view);
+                }
+            }
+        }
+    }
+
     /// Invert the current state for the sync browsing
     pub fn toggle_sync_browsing(&mut self) {
         self.sync_browsing = !self.sync_browsing;
     }
 
-    /// Build a file explorer with local host setup
+    /// Build a file explorer with local host setup.
+    /// The view saved when the explorer was last closed is restored, if any
     pub fn build_local_explorer(cli: &ConfigClient) -> FileExplorer {
-        let mut builder = Self::build_explorer(cli);
-        builder.with_formatter(cli.get_local_file_fmt().as_deref());
-        builder.build()
+        let mut builder = Self::build_explorer();
+        builder
+            .with_file_sorting(cli.get_file_sorting())
+            .with_group_dirs(cli.get_group_dirs())
+            .with_hidden_files(cli.get_show_hidden_files())
+            .with_formatter(cli.get_local_file_fmt().as_deref());
+        let mut explorer = builder.build();
+        if let Some(view) = cli.get_last_view(false) {
+            explorer.set_view(view);
+        }
+        explorer
     }
 
-    /// Build a file explorer with remote host setup
+    /// Build a file explorer with remote host setup.
+    /// The view saved when the explorer was last closed is restored, if any
     pub fn build_remote_explorer(cli: &ConfigClient) -> FileExplorer {
-        let mut builder = Self::build_explorer(cli);
-        builder.with_formatter(cli.get_remote_file_fmt().as_deref());
-        builder.build()
+        let mut builder = Self::build_explorer();
+        builder
+            .with_file_sorting(cli.get_remote_file_sorting())
+            .with_group_dirs(cli.get_remote_group_dirs())
+            .with_hidden_files(cli.get_remote_show_hidden_files())
+            .with_formatter(cli.get_remote_file_fmt().as_deref());
+        let mut explorer = builder.build();
+        if let Some(view) = cli.get_last_view(true) {
+            explorer.set_view(view);
+        }
+        explorer
     }
 
-    /// Build explorer reading configuration from `ConfigClient`
-    fn build_explorer(cli: &ConfigClient) -> FileExplorerBuilder {
+    /// Build explorer with the keys common to local and remote explorers
+    fn build_explorer() -> FileExplorerBuilder {
         let mut builder: FileExplorerBuilder = FileExplorerBuilder::new();
-        // Set common keys
-        builder
-            .with_file_sorting(FileSorting::Name)
-            .with_stack_size(16)
-            .with_group_dirs(cli.get_group_dirs())
-            .with_hidden_files(cli.get_show_hidden_files());
+        builder.with_stack_size(16);
         builder
     }
 
@@ -0,0 +1,167 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+use crate::filetransfer::FileTransferProtocol;
+
+/// Command which prints the name of each command used by termscp which is available on the
+/// remote host, one per line
+pub const PROBE_COMMAND: &str =
+    "for x in cp mv ln find tail head od; do command -v $x > /dev/null && echo $x; done; true";
+
+/// ### RemoteCapabilities
+///
+/// Describes which optional operations are supported by the remote host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteCapabilities {
+    /// Files can be copied on the remote host; otherwise they're downloaded and uploaded again
+    pub copy: bool,
+    pub exec: bool,
+    /// Hard links can be looked for (`find`) and created (`ln`) on the remote host
+    pub hard_links: bool,
+    /// Files can be renamed on the remote host; otherwise they're copied, then removed
+    pub rename: bool,
+    pub symlink: bool,
+    /// Appended data can be read with `tail`, `head` and `od` on the remote host
+    pub tail: bool,
+}
+
+impl Default for RemoteCapabilities {
+    fn default() -> Self {
+        Self {
+            copy: true,
+            exec: true,
+            hard_links: true,
+            rename: true,
+            symlink: true,
+            tail: true,
+        }
+    }
+}
+
+impl RemoteCapabilities {
+    /// Get capabilities of a remote host connected with `protocol`.
+    /// `probe` is the output of `PROBE_COMMAND` on the remote host, or `None` if commands can't
+    /// be executed on it.
+    ///
+    /// The capabilities of FTP and S3 only depend on the protocol, since the features advertised
+    /// by the server (e.g. FTP `FEAT`) are not exposed by the client
+    pub fn new(protocol: FileTransferProtocol, probe: Option<&str>) -> Self {
+        let exec = probe.is_some();
+        let has = |commands: &[&str]| {
+            probe
+                .map(|x| {
+                    commands
+                        .iter()
+                        .all(|cmd| x.lines().any(|l| l.trim() == *cmd))
+                })
+                .unwrap_or(false)
+        };
+        match protocol {
+            FileTransferProtocol::Sftp => Self {
+                copy: has(&["cp"]),
+                exec,
+                hard_links: has(&["find", "ln"]),
+                rename: true,
+                symlink: true,
+                tail: has(&["tail", "head", "od"]),
+            },
+            // Everything is performed through shell commands
+            FileTransferProtocol::Scp => Self {
+                copy: has(&["cp"]),
+                exec,
+                hard_links: has(&["find", "ln"]),
+                rename: has(&["mv"]),
+                symlink: has(&["ln"]),
+                tail: has(&["tail", "head", "od"]),
+            },
+            FileTransferProtocol::Ftp(_) => Self {
+                copy: false,
+                exec: false,
+                hard_links: false,
+                rename: true,
+                symlink: false,
+                tail: false,
+            },
+            FileTransferProtocol::AwsS3 => Self {
+                copy: false,
+                exec: false,
+                hard_links: false,
+                rename: false,
+                symlink: false,
+                tail: false,
+            },
+        }
+    }
+
+    /// Returns the names of the operations which are not supported
+    pub fn unsupported(&self) -> Vec<&'static str> {
+        Self::names_of_missing(&[(self.exec, "exec"), (self.symlink, "symlink")])
+    }
+
+    /// Returns the names of the operations which can't be performed on the remote host,
+    /// and are performed downloading and uploading files again instead
+    pub fn emulated(&self) -> Vec<&'static str> {
+        Self::names_of_missing(&[(self.copy, "copy"), (self.rename, "rename")])
+    }
+
+    fn names_of_missing(operations: &[(bool, &'static str)]) -> Vec<&'static str> {
+        operations
+            .iter()
+            .filter(|(supported, _)| !supported)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_capabilities() {
+        assert!(RemoteCapabilities::default().unsupported().is_empty());
+        assert!(RemoteCapabilities::default().emulated().is_empty());
+        let all = "cp\nmv\nln\nfind\ntail\nhead\nod\n";
+        let sftp = RemoteCapabilities::new(FileTransferProtocol::Sftp, Some(all));
+        assert_eq!(sftp, RemoteCapabilities::default());
+        let sftp = RemoteCapabilities::new(FileTransferProtocol::Sftp, None);
+        assert_eq!(sftp.unsupported(), vec!["exec"]);
+        assert_eq!(sftp.emulated(), vec!["copy"]);
+        assert!(!sftp.hard_links);
+        assert!(!sftp.tail);
+        let scp = RemoteCapabilities::new(FileTransferProtocol::Scp, Some(all));
+        assert_eq!(scp, RemoteCapabilities::default());
+        let scp = RemoteCapabilities::new(FileTransferProtocol::Scp, None);
+        assert_eq!(scp.unsupported(), vec!["exec", "symlink"]);
+        assert_eq!(scp.emulated(), vec!["copy", "rename"]);
+        let ftp = RemoteCapabilities::new(FileTransferProtocol::Ftp(true), None);
+        assert_eq!(ftp.unsupported(), vec!["exec", "symlink"]);
+        assert_eq!(ftp.emulated(), vec!["copy"]);
+        let s3 = RemoteCapabilities::new(FileTransferProtocol::AwsS3, None);
+        assert_eq!(s3.unsupported(), vec!["exec", "symlink"]);
+        assert_eq!(s3.emulated(), vec!["copy", "rename"]);
+    }
+
+    #[test]
+    fn should_get_capabilities_from_available_commands() {
+        // Busybox without find, od and cp
+        let scp = RemoteCapabilities::new(FileTransferProtocol::Scp, Some("mv\nln\ntail\nhead\n"));
+        assert!(scp.exec);
+        assert!(scp.rename);
+        assert!(scp.symlink);
+        assert!(!scp.hard_links);
+        assert!(!scp.tail);
+        assert_eq!(scp.emulated(), vec!["copy"]);
+        // Commands can be executed, but none is available
+        let sftp = RemoteCapabilities::new(FileTransferProtocol::Sftp, Some(""));
+        assert!(sftp.exec);
+        assert!(sftp.unsupported().is_empty());
+        assert_eq!(sftp.emulated(), vec!["copy"]);
+        assert!(!sftp.hard_links);
+        assert!(!sftp.tail);
+    }
+}
@@ -0,0 +1,77 @@
+//! ## CommandHistory
+//!
+//! `command_history` provides the history of the commands executed on a host
+
+/// Maximum amount of commands kept in the history
+const HISTORY_SIZE: usize = 64;
+
+/// Commands executed on a host, from the oldest to the most recent
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CommandHistory {
+    commands: Vec<String>,
+}
+
+impl CommandHistory {
+    /// Instantiates the history with `commands`, from the oldest to the most recent.
+    /// Only the most recent commands are kept, if they exceed the history size
+    pub fn new(commands: Vec<String>) -> Self {
+        let mut history = Self::default();
+        commands.into_iter().for_each(|x| history.push(x));
+        history
+    }
+
+    /// Push `command` as the most recent command.
+    /// If it was already in the history, it is moved to the end
+    pub fn push<S: AsRef<str>>(&mut self, command: S) {
+        let command = command.as_ref().trim();
+        if command.is_empty() {
+            return;
+        }
+        self.commands.retain(|x| x != command);
+        self.commands.push(command.to_string());
+        if self.commands.len() > HISTORY_SIZE {
+            self.commands.remove(0);
+        }
+    }
+
+    /// Returns the commands, from the oldest to the most recent
+    pub fn commands(&self) -> &[String] {
+        self.commands.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_push_commands() {
+        let mut history = CommandHistory::default();
+        history.push("ls -l");
+        history.push("  ");
+        history.push("df -h ");
+        history.push("ls -l");
+        assert_eq!(
+            history.commands(),
+            &[String::from("df -h"), String::from("ls -l")]
+        );
+    }
+
+    #[test]
+    fn should_keep_most_recent_commands() {
+        let history = CommandHistory::new(
+            (0..HISTORY_SIZE + 2)
+                .map(|x| format!("echo {}", x))
+                .collect(),
+        );
+        assert_eq!(history.commands().len(), HISTORY_SIZE);
+        assert_eq!(history.commands()[0].as_str(), "echo 2");
+        assert_eq!(
+            history.commands().last().unwrap(),
+            &format!("echo {}", HISTORY_SIZE + 1)
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! ## DirMarks
+//!
+//! `dir_marks` provides the directories marked by the user on a host, to jump back to them
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Amount of slots available; slots are numbered from 1
+pub const MARKS_SLOTS: usize = 9;
+
+/// Directories marked on a host, by slot
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirMarks {
+    slots: [Option<PathBuf>; MARKS_SLOTS],
+}
+
+impl DirMarks {
+    /// Instantiates the marks from `marks`, the paths by slot number as saved in the session.
+    /// Invalid slots are ignored
+    pub fn new(marks: &HashMap<String, PathBuf>) -> Self {
+        let mut dir_marks = Self::default();
+        for (slot, path) in marks.iter() {
+            if let Ok(slot) = slot.parse::<usize>() {
+                dir_marks.set(slot, path.as_path());
+            }
+        }
+        dir_marks
+    }
+
+    /// Mark `path` at `slot`, replacing the directory marked there before
+    pub fn set(&mut self, slot: usize, path: &Path) {
+        if let Some(mark) = Self::index(slot).map(|x| &mut self.slots[x]) {
+            *mark = Some(path.to_path_buf());
+        }
+    }
+
+    /// Get the directory marked at `slot`
+    pub fn get(&self, slot: usize) -> Option<&Path> {
+        Self::index(slot).and_then(|x| self.slots[x].as_deref())
+    }
+
+    /// Returns the marked directory of each slot, from the first to the last slot
+    pub fn slots(&self) -> impl Iterator<Item = (usize, Option<&Path>)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i + 1, x.as_deref()))
+    }
+
+    /// Returns the marked directories by slot number, to be saved in the session
+    pub fn to_map(&self) -> HashMap<String, PathBuf> {
+        self.slots()
+            .filter_map(|(slot, path)| path.map(|x| (slot.to_string(), x.to_path_buf())))
+            .collect()
+    }
+
+    fn index(slot: usize) -> Option<usize> {
+        (1..=MARKS_SLOTS).contains(&slot).then(|| slot - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_set_and_get_marks() {
+        let mut marks = DirMarks::default();
+        assert!(marks.get(1).is_none());
+        marks.set(1, Path::new("/home/omar"));
+        marks.set(9, Path::new("/var/log"));
+        marks.set(1, Path::new("/tmp"));
+        // out of range
+        marks.set(0, Path::new("/etc"));
+        marks.set(10, Path::new("/etc"));
+        assert_eq!(marks.get(1), Some(Path::new("/tmp")));
+        assert_eq!(marks.get(9), Some(Path::new("/var/log")));
+        assert!(marks.get(0).is_none());
+        assert!(marks.get(10).is_none());
+        assert_eq!(marks.slots().count(), MARKS_SLOTS);
+        assert_eq!(marks.slots().filter(|(_, x)| x.is_some()).count(), 2);
+    }
+
+    #[test]
+    fn should_convert_marks_from_and_to_map() {
+        let mut map = HashMap::new();
+        map.insert(String::from("2"), PathBuf::from("/srv/www"));
+        map.insert(String::from("5"), PathBuf::from("/opt"));
+        map.insert(String::from("foo"), PathBuf::from("/etc"));
+        map.insert(String::from("12"), PathBuf::from("/etc"));
+        let marks = DirMarks::new(&map);
+        assert_eq!(marks.get(2), Some(Path::new("/srv/www")));
+        assert_eq!(marks.get(5), Some(Path::new("/opt")));
+        map.remove("foo");
+        map.remove("12");
+        assert_eq!(marks.to_map(), map);
+    }
+}
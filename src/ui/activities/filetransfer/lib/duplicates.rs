@@ -0,0 +1,126 @@
+//! ## Duplicates
+//!
+//! `duplicates` provides the matching of the files with the same content on the local and on the remote host.
+//!
+//! Files are first matched by size; only the files with a size matching a file on the other host are
+//! hashed, and files are duplicates if their digests match too.
+
+use remotefs::File;
+use std::collections::{HashMap, HashSet};
+
+/// A file on the local host with the same content of a file on the remote host
+#[derive(Debug, Clone)]
+pub struct Duplicate {
+    pub local: File,
+    pub remote: File,
+}
+
+/// Keep the files whose size matches the size of a file on the other host.
+/// Empty files are never considered duplicates.
+/// Returns the local and the remote candidates
+pub fn same_size(local: Vec<File>, remote: Vec<File>) -> (Vec<File>, Vec<File>) {
+    let sizes = |files: &[File]| -> HashSet<u64> {
+        files
+            .iter()
+            .map(|x| x.metadata().size)
+            .filter(|x| *x > 0)
+            .collect()
+    };
+    let local_sizes = sizes(&local);
+    let remote_sizes = sizes(&remote);
+    (
+        local
+            .into_iter()
+            .filter(|x| remote_sizes.contains(&x.metadata().size))
+            .collect(),
+        remote
+            .into_iter()
+            .filter(|x| local_sizes.contains(&x.metadata().size))
+            .collect(),
+    )
+}
+
+/// Match the local files with the remote files with the same size and digest.
+/// Each local file is matched with the first remote file with the same content
+pub fn match_digests(local: Vec<(File, String)>, remote: Vec<(File, String)>) -> Vec<Duplicate> {
+    let mut remote_files: HashMap<(u64, String), File> = HashMap::new();
+    for (file, digest) in remote.into_iter() {
+        remote_files
+            .entry((file.metadata().size, digest))
+            .or_insert(file);
+    }
+    local
+        .into_iter()
+        .filter_map(|(file, digest)| {
+            remote_files
+                .get(&(file.metadata().size, digest))
+                .map(|remote| Duplicate {
+                    local: file,
+                    remote: remote.clone(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::Metadata;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_keep_files_with_same_size() {
+        let (local, remote) = same_size(
+            vec![
+                make_file("/home/omar/a.png", 1024),
+                make_file("/home/omar/b.png", 2048),
+                make_file("/home/omar/empty", 0),
+            ],
+            vec![
+                make_file("/assets/a.png", 1024),
+                make_file("/assets/c.png", 4096),
+                make_file("/assets/empty", 0),
+            ],
+        );
+        assert_eq!(names(&local), vec!["a.png"]);
+        assert_eq!(names(&remote), vec!["a.png"]);
+    }
+
+    #[test]
+    fn should_match_digests() {
+        let duplicates = match_digests(
+            vec![
+                (make_file("/home/omar/a.png", 1024), String::from("aaaa")),
+                (make_file("/home/omar/b.png", 1024), String::from("bbbb")),
+                (make_file("/home/omar/copy.png", 1024), String::from("aaaa")),
+            ],
+            vec![
+                (make_file("/assets/a.png", 1024), String::from("aaaa")),
+                (make_file("/assets/again.png", 1024), String::from("aaaa")),
+                (make_file("/assets/c.png", 1024), String::from("cccc")),
+            ],
+        );
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].local.name().as_str(), "a.png");
+        assert_eq!(duplicates[0].remote.name().as_str(), "a.png");
+        assert_eq!(duplicates[1].local.name().as_str(), "copy.png");
+        assert_eq!(duplicates[1].remote.name().as_str(), "a.png");
+    }
+
+    fn make_file(path: &str, size: u64) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata {
+                size,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn names(files: &[File]) -> Vec<String> {
+        files.iter().map(|x| x.name()).collect()
+    }
+}
@@ -0,0 +1,480 @@
+//! ## FileMetadata
+//!
+//! `file_metadata` extracts metadata from the content of files (image dimensions, media duration,
+//! archive entries, text encoding), reading only their first and last bytes
+
+use crate::ui::i18n::tr;
+
+use remotefs::File;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Amount of bytes read from the beginning (and from the end, when possible) of a file
+pub const SAMPLE_SIZE: usize = 64 * 1024;
+
+/// Read up to `SAMPLE_SIZE` bytes from `reader`
+pub fn read_sample<R: Read>(reader: R) -> io::Result<Vec<u8>> {
+    let mut sample = Vec::with_capacity(SAMPLE_SIZE);
+    reader.take(SAMPLE_SIZE as u64).read_to_end(&mut sample)?;
+    Ok(sample)
+}
+
+/// Encoding of a text file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Ascii,
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    /// 8-bit encoding other than UTF-8 (e.g. ISO-8859-1)
+    Extended,
+}
+
+impl TextEncoding {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Ascii => String::from("ASCII"),
+            Self::Utf8 => String::from("UTF-8"),
+            Self::Utf8Bom => tr("file-encoding-utf8-bom"),
+            Self::Utf16Le => String::from("UTF-16LE"),
+            Self::Utf16Be => String::from("UTF-16BE"),
+            Self::Extended => tr("file-encoding-extended"),
+        }
+    }
+}
+
+/// Metadata extracted from the content of a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichMetadata {
+    /// Width and height of the image in pixels
+    pub dimensions: Option<(u32, u32)>,
+    /// Camera make and model, from EXIF
+    pub camera: Option<String>,
+    /// Date the picture was taken, from EXIF
+    pub date_taken: Option<String>,
+    /// Duration of audio files
+    pub duration: Option<Duration>,
+    /// Amount of entries in the archive
+    pub entries: Option<u64>,
+    pub encoding: Option<TextEncoding>,
+}
+
+impl RichMetadata {
+    /// Extract the metadata from the first bytes of a file (`head`)
+    /// and, if available, from its last bytes (`tail`)
+    pub fn parse(head: &[u8], tail: Option<&[u8]>) -> Self {
+        let mut metadata = Self::default();
+        if head.starts_with(b"\x89PNG\r\n\x1a\n") {
+            metadata.dimensions = png_dimensions(head);
+        } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+            metadata.dimensions = gif_dimensions(head);
+        } else if head.starts_with(b"BM") {
+            metadata.dimensions = bmp_dimensions(head);
+        } else if head.starts_with(&[0xff, 0xd8]) {
+            metadata.parse_jpeg(head);
+        } else if head.starts_with(b"RIFF") && head.get(8..12) == Some(b"WAVE") {
+            metadata.duration = wav_duration(head);
+        } else if head.starts_with(b"fLaC") {
+            metadata.duration = flac_duration(head);
+        } else if head.starts_with(b"PK\x03\x04") || head.starts_with(b"PK\x05\x06") {
+            metadata.entries = tail.and_then(zip_entries);
+        } else {
+            metadata.encoding = text_encoding(head);
+        }
+        metadata
+    }
+
+    /// Returns whether no metadata could be extracted
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    fn parse_jpeg(&mut self, data: &[u8]) {
+        let mut i = 2;
+        while i + 4 <= data.len() {
+            if data[i] != 0xff {
+                return;
+            }
+            let marker = data[i + 1];
+            // markers without length
+            if marker == 0xff {
+                i += 1;
+                continue;
+            }
+            if marker == 0x01 || (0xd0..=0xd9).contains(&marker) {
+                i += 2;
+                continue;
+            }
+            // the length includes its own two bytes
+            let len = match u16_be(data, i + 2) {
+                Some(len) if len >= 2 => len as usize,
+                _ => return,
+            };
+            let segment = match data.get(i + 4..data.len().min(i + 2 + len)) {
+                Some(segment) => segment,
+                None => return,
+            };
+            match marker {
+                // APP1
+                0xe1 if segment.starts_with(b"Exif\0\0") => self.parse_exif(&segment[6..]),
+                // SOF, except DHT, JPG and DAC
+                0xc0..=0xcf if ![0xc4, 0xc8, 0xcc].contains(&marker) => {
+                    if let (Some(height), Some(width)) = (u16_be(segment, 1), u16_be(segment, 3)) {
+                        self.dimensions = Some((width as u32, height as u32));
+                    }
+                    return;
+                }
+                _ => {}
+            }
+            i += 2 + len;
+        }
+    }
+
+    /// Parse the camera and the date from IFD0 of the EXIF TIFF structure
+    fn parse_exif(&mut self, tiff: &[u8]) {
+        let le = match tiff.get(0..2) {
+            Some(b"II") => true,
+            Some(b"MM") => false,
+            _ => return,
+        };
+        let u16_at = |offset| match le {
+            true => u16_le(tiff, offset),
+            false => u16_be(tiff, offset),
+        };
+        let u32_at = |offset| match le {
+            true => u32_le(tiff, offset),
+            false => u32_be(tiff, offset),
+        };
+        let ifd = match u32_at(4) {
+            Some(offset) => offset as usize,
+            None => return,
+        };
+        let count = u16_at(ifd).unwrap_or(0) as usize;
+        let (mut make, mut model) = (None, None);
+        for entry in (0..count).map(|x| ifd + 2 + x * 12) {
+            let (tag, kind, len) = match (u16_at(entry), u16_at(entry + 2), u32_at(entry + 4)) {
+                (Some(tag), Some(kind), Some(len)) => (tag, kind, len as usize),
+                _ => break,
+            };
+            // only ASCII values are read
+            if kind != 2 {
+                continue;
+            }
+            let offset = match len <= 4 {
+                true => entry + 8,
+                false => u32_at(entry + 8).unwrap_or(0) as usize,
+            };
+            let value = tiff
+                .get(offset..offset + len)
+                .map(|x| {
+                    String::from_utf8_lossy(x)
+                        .trim_matches(char::from(0))
+                        .trim()
+                        .to_string()
+                })
+                .filter(|x| !x.is_empty());
+            match tag {
+                0x010f => make = value,
+                0x0110 => model = value,
+                0x0132 => self.date_taken = value,
+                _ => {}
+            }
+        }
+        self.camera = match (make, model) {
+            // the model often includes the make already
+            (Some(make), Some(model)) if model.starts_with(&make) => Some(model),
+            (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+            (make, model) => make.or(model),
+        };
+    }
+}
+
+/// Metadata extracted from files, valid as long as their size and modification time don't change
+#[derive(Debug, Default)]
+pub struct RichMetadataCache {
+    entries: HashMap<(bool, PathBuf), (u64, Option<SystemTime>, RichMetadata)>,
+}
+
+impl RichMetadataCache {
+    /// Get the metadata of `file` on the remote host if `remote`, otherwise on the local host
+    pub fn get(&self, file: &File, remote: bool) -> Option<&RichMetadata> {
+        self.entries
+            .get(&(remote, file.path().to_path_buf()))
+            .filter(|(size, modified, _)| {
+                *size == file.metadata().size && *modified == file.metadata().modified
+            })
+            .map(|(_, _, metadata)| metadata)
+    }
+
+    pub fn insert(&mut self, file: &File, remote: bool, metadata: RichMetadata) {
+        self.entries.insert(
+            (remote, file.path().to_path_buf()),
+            (file.metadata().size, file.metadata().modified, metadata),
+        );
+    }
+}
+
+// -- formats
+
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if data.get(12..16) != Some(b"IHDR") {
+        return None;
+    }
+    Some((u32_be(data, 16)?, u32_be(data, 20)?))
+}
+
+fn gif_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    Some((u16_le(data, 6)? as u32, u16_le(data, 8)? as u32))
+}
+
+fn bmp_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let width = u32_le(data, 18)? as i32;
+    // negative height means top-down bitmap
+    let height = u32_le(data, 22)? as i32;
+    Some((width.unsigned_abs(), height.unsigned_abs()))
+}
+
+fn wav_duration(data: &[u8]) -> Option<Duration> {
+    let mut byte_rate = None;
+    let mut i = 12;
+    while i + 8 <= data.len() {
+        let size = u32_le(data, i + 4)? as usize;
+        match data.get(i..i + 4)? {
+            b"fmt " => byte_rate = u32_le(data, i + 16).filter(|x| *x > 0),
+            b"data" => {
+                return byte_rate.map(|rate| Duration::from_secs_f64(size as f64 / rate as f64))
+            }
+            _ => {}
+        }
+        // chunks are word aligned
+        i += 8 + size + size % 2;
+    }
+    None
+}
+
+fn flac_duration(data: &[u8]) -> Option<Duration> {
+    // STREAMINFO is always the first metadata block
+    if data.get(4)? & 0x7f != 0 {
+        return None;
+    }
+    let info = data.get(8..26)?;
+    let sample_rate = (info[10] as u64) << 12 | (info[11] as u64) << 4 | (info[12] as u64) >> 4;
+    let samples = (info[13] as u64 & 0x0f) << 32 | u32_be(info, 14)? as u64;
+    if sample_rate == 0 || samples == 0 {
+        return None;
+    }
+    Some(Duration::from_secs_f64(samples as f64 / sample_rate as f64))
+}
+
+/// Read the amount of entries from the end of central directory record
+fn zip_entries(tail: &[u8]) -> Option<u64> {
+    let eocd = tail.windows(4).rposition(|x| x == b"PK\x05\x06")?;
+    u16_le(tail, eocd + 10).map(|x| x as u64)
+}
+
+fn text_encoding(data: &[u8]) -> Option<TextEncoding> {
+    if data.starts_with(&[0xef, 0xbb, 0xbf]) {
+        return Some(TextEncoding::Utf8Bom);
+    }
+    if data.starts_with(&[0xff, 0xfe]) {
+        return Some(TextEncoding::Utf16Le);
+    }
+    if data.starts_with(&[0xfe, 0xff]) {
+        return Some(TextEncoding::Utf16Be);
+    }
+    // control characters other than tabs, line breaks, form feeds and escapes mean binary data
+    if data.is_empty()
+        || data
+            .iter()
+            .any(|x| *x < 0x20 && ![b'\t', b'\n', b'\r', 0x0c, 0x1b].contains(x))
+    {
+        return None;
+    }
+    match std::str::from_utf8(data) {
+        Ok(_) if data.is_ascii() => Some(TextEncoding::Ascii),
+        Ok(_) => Some(TextEncoding::Utf8),
+        // the sample may end in the middle of a character
+        Err(err) if err.error_len().is_none() => Some(TextEncoding::Utf8),
+        Err(_) => Some(TextEncoding::Extended),
+    }
+}
+
+// -- bytes
+
+fn u16_le(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|x| u16::from_le_bytes([x[0], x[1]]))
+}
+
+fn u16_be(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|x| u16::from_be_bytes([x[0], x[1]]))
+}
+
+fn u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+}
+
+fn u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|x| u32::from_be_bytes([x[0], x[1], x[2], x[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
+    use std::path::Path;
+
+    #[test]
+    fn should_read_image_dimensions() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend(640u32.to_be_bytes());
+        png.extend(480u32.to_be_bytes());
+        assert_eq!(RichMetadata::parse(&png, None).dimensions, Some((640, 480)));
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend(32u16.to_le_bytes());
+        gif.extend(16u16.to_le_bytes());
+        assert_eq!(RichMetadata::parse(&gif, None).dimensions, Some((32, 16)));
+        let mut bmp = b"BM".to_vec();
+        bmp.extend([0; 16]);
+        bmp.extend(100i32.to_le_bytes());
+        bmp.extend((-50i32).to_le_bytes());
+        assert_eq!(RichMetadata::parse(&bmp, None).dimensions, Some((100, 50)));
+    }
+
+    #[test]
+    fn should_read_jpeg_dimensions_and_exif() {
+        // TIFF (big endian) with make, model and date
+        let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+        tiff.extend(3u16.to_be_bytes());
+        let values: [(u16, &[u8]); 3] = [
+            (0x010f, b"Canon\0"),
+            (0x0110, b"Canon EOS 80D\0"),
+            (0x0132, b"2021:07:14 18:30:00\0"),
+        ];
+        let mut data: Vec<u8> = Vec::new();
+        let data_offset = 8 + 2 + 3 * 12 + 4;
+        for (tag, value) in values.iter() {
+            tiff.extend(tag.to_be_bytes());
+            tiff.extend(2u16.to_be_bytes());
+            tiff.extend((value.len() as u32).to_be_bytes());
+            tiff.extend(((data_offset + data.len()) as u32).to_be_bytes());
+            data.extend(*value);
+        }
+        tiff.extend([0; 4]);
+        tiff.extend(data);
+        let mut jpeg = vec![0xff, 0xd8, 0xff, 0xe1];
+        jpeg.extend(((tiff.len() + 8) as u16).to_be_bytes());
+        jpeg.extend(b"Exif\0\0");
+        jpeg.extend(tiff);
+        // SOF0: precision, height, width
+        jpeg.extend([0xff, 0xc0, 0x00, 0x11, 0x08]);
+        jpeg.extend(1080u16.to_be_bytes());
+        jpeg.extend(1920u16.to_be_bytes());
+        let metadata = RichMetadata::parse(&jpeg, None);
+        assert_eq!(metadata.dimensions, Some((1920, 1080)));
+        assert_eq!(metadata.camera.as_deref(), Some("Canon EOS 80D"));
+        assert_eq!(metadata.date_taken.as_deref(), Some("2021:07:14 18:30:00"));
+    }
+
+    #[test]
+    fn should_not_panic_on_corrupted_jpeg() {
+        // zero and one byte long segments
+        assert!(RichMetadata::parse(&[0xff, 0xd8, 0xff, 0xe0, 0x00, 0x00], None).is_empty());
+        assert!(RichMetadata::parse(&[0xff, 0xd8, 0xff, 0xe0, 0x00, 0x01, 0x00], None).is_empty());
+        // segment longer than the data
+        assert!(RichMetadata::parse(&[0xff, 0xd8, 0xff, 0xc0, 0xff, 0xff, 0x08], None).is_empty());
+    }
+
+    #[test]
+    fn should_read_audio_duration() {
+        let mut wav = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        wav.extend(16u32.to_le_bytes());
+        wav.extend([1, 0, 2, 0]);
+        wav.extend(44100u32.to_le_bytes());
+        wav.extend(176400u32.to_le_bytes());
+        wav.extend([4, 0, 16, 0]);
+        wav.extend(b"data");
+        wav.extend((176400u32 * 90).to_le_bytes());
+        assert_eq!(
+            RichMetadata::parse(&wav, None).duration,
+            Some(Duration::from_secs(90))
+        );
+        let mut flac = b"fLaC\x00\x00\x00\x22".to_vec();
+        flac.extend([0; 10]);
+        // 44100 Hz, stereo, 16 bits, 441000 samples
+        flac.extend([0x0a, 0xc4, 0x42, 0xf0]);
+        flac.extend(441000u32.to_be_bytes());
+        assert_eq!(
+            RichMetadata::parse(&flac, None).duration,
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn should_read_zip_entries() {
+        let mut zip = b"PK\x03\x04".to_vec();
+        zip.extend([0; 64]);
+        zip.extend(b"PK\x05\x06\0\0\0\0");
+        zip.extend(12u16.to_le_bytes());
+        zip.extend(12u16.to_le_bytes());
+        zip.extend([0; 10]);
+        assert_eq!(RichMetadata::parse(&zip, Some(&zip)).entries, Some(12));
+        // without the end of the file, entries are unknown
+        assert!(RichMetadata::parse(&zip, None).is_empty());
+    }
+
+    #[test]
+    fn should_detect_text_encoding() {
+        let encoding = |data: &[u8]| RichMetadata::parse(data, None).encoding;
+        assert_eq!(encoding(b"hello world\n"), Some(TextEncoding::Ascii));
+        assert_eq!(
+            encoding("perché no?\n".as_bytes()),
+            Some(TextEncoding::Utf8)
+        );
+        // truncated in the middle of a character
+        assert_eq!(encoding(&"città".as_bytes()[..5]), Some(TextEncoding::Utf8));
+        assert_eq!(encoding(b"\xef\xbb\xbfhello"), Some(TextEncoding::Utf8Bom));
+        assert_eq!(encoding(b"\xff\xfeh\0i\0"), Some(TextEncoding::Utf16Le));
+        assert_eq!(encoding(b"perch\xe8 no?"), Some(TextEncoding::Extended));
+        assert_eq!(encoding(b"\x7fELF\x02\x01\x01\0"), None);
+        assert_eq!(encoding(b""), None);
+    }
+
+    #[test]
+    fn should_cache_metadata() {
+        let mut cache = RichMetadataCache::default();
+        let mut file = File {
+            path: Path::new("/tmp/a.txt").to_path_buf(),
+            metadata: Metadata::default()
+                .file_type(FileType::File)
+                .size(8)
+                .modified(SystemTime::UNIX_EPOCH),
+        };
+        let metadata = RichMetadata {
+            encoding: Some(TextEncoding::Ascii),
+            ..Default::default()
+        };
+        cache.insert(&file, false, metadata.clone());
+        assert_eq!(cache.get(&file, false), Some(&metadata));
+        assert!(cache.get(&file, true).is_none());
+        // file changed
+        file.metadata.size = 16;
+        assert!(cache.get(&file, false).is_none());
+    }
+
+    #[test]
+    fn should_read_sample() {
+        let data = vec![0u8; SAMPLE_SIZE + 16];
+        assert_eq!(read_sample(data.as_slice()).unwrap().len(), SAMPLE_SIZE);
+        assert_eq!(read_sample(&data[..16]).unwrap().len(), 16);
+    }
+}
@@ -0,0 +1,258 @@
+//! ## FreeSpace
+//!
+//! `free_space` provides the utilities to get the space available on the destination of a transfer,
+//! taking into account the disk quota of the user on the remote host
+
+use crate::ui::i18n::tr_args;
+use crate::utils::string::shell_quote;
+
+use bytesize::ByteSize;
+use std::path::Path;
+
+/// Command which reports the disk quota of the user on the remote host, without wrapping lines
+pub const QUOTA_COMMAND: &str = "quota -w -p";
+
+/// Size and space available in bytes of a filesystem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskSpace {
+    pub available: u64,
+    pub total: u64,
+    /// Whether the space is limited by the disk quota of the user rather than by the filesystem
+    pub quota: bool,
+}
+
+impl DiskSpace {
+    /// Returns the disk space as `<available> free of <total>`
+    pub fn label(&self) -> String {
+        let id = match self.quota {
+            true => "disk-space-free-quota",
+            false => "disk-space-free",
+        };
+        tr_args(
+            id,
+            &[
+                ("available", &ByteSize(self.available)),
+                ("total", &ByteSize(self.total)),
+            ],
+        )
+    }
+
+    /// Limit the disk space to `quota`, if it leaves less space available than the filesystem
+    pub fn with_quota(self, quota: Quota) -> Self {
+        let available = quota.limit.saturating_sub(quota.used);
+        match available < self.available {
+            true => Self {
+                available,
+                total: quota.limit,
+                quota: true,
+            },
+            false => self,
+        }
+    }
+}
+
+/// Disk quota of the user on a filesystem, in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    pub used: u64,
+    pub limit: u64,
+}
+
+/// Returns the disk space of the local filesystem containing `path`
+pub fn local_disk_space(path: &Path) -> Option<DiskSpace> {
+    match fs2::available_space(path).and_then(|available| {
+        fs2::total_space(path).map(|total| DiskSpace {
+            available,
+            total,
+            quota: false,
+        })
+    }) {
+        Ok(space) => Some(space),
+        Err(err) => {
+            debug!("could not get disk space for {}: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+/// Returns the command which reports the space available for `path` on the remote host
+pub fn df_command(path: &Path) -> String {
+    format!("df -Pk {}", shell_quote(path.to_string_lossy().as_ref()))
+}
+
+/// Get the disk space from the output of `df_command()`.
+/// The output in POSIX format is made up of an header and a line with
+/// `<filesystem> <blocks> <used> <available> <capacity> <mountpoint>`, with sizes in KB
+pub fn parse_df_output(output: &str) -> Option<DiskSpace> {
+    let columns: Vec<u64> = output
+        .lines()
+        .nth(1)?
+        .split_whitespace()
+        .skip(1)
+        .take(3)
+        .map(|x| x.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    match columns.as_slice() {
+        [total, _, available] => Some(DiskSpace {
+            available: available * 1024,
+            total: total * 1024,
+            quota: false,
+        }),
+        _ => None,
+    }
+}
+
+/// Get the filesystem from the output of `df_command()`
+pub fn parse_df_filesystem(output: &str) -> Option<&str> {
+    output.lines().nth(1)?.split_whitespace().next()
+}
+
+/// Get the quota of the user on `filesystem` from the output of `QUOTA_COMMAND`.
+/// Each filesystem with a quota is reported on a line with
+/// `<filesystem> <used> <soft limit> <hard limit> <grace> ...`, with sizes in KB and the used
+/// blocks marked with `*` when the soft limit is exceeded.
+/// The hard limit is used if set, otherwise the soft one; filesystems without limits have no quota
+pub fn parse_quota_output(output: &str, filesystem: &str) -> Option<Quota> {
+    let columns: Vec<&str> = output
+        .lines()
+        .map(|x| x.split_whitespace().collect::<Vec<&str>>())
+        .find(|x| x.first() == Some(&filesystem))?;
+    let blocks = columns
+        .iter()
+        .skip(1)
+        .take(3)
+        .map(|x| x.trim_end_matches('*').parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    match blocks.as_slice() {
+        [used, soft, hard] if *hard > 0 || *soft > 0 => Some(Quota {
+            used: used * 1024,
+            limit: if *hard > 0 { *hard } else { *soft } * 1024,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_parse_df_output() {
+        assert_eq!(
+            parse_df_output(
+                "Filesystem     1024-blocks      Used Available Capacity Mounted on\n/dev/sda1        479596204 250467488 204697404      56% /\n"
+            ),
+            Some(DiskSpace {
+                available: 204697404 * 1024,
+                total: 479596204 * 1024,
+                quota: false,
+            })
+        );
+        assert_eq!(
+            parse_df_filesystem(
+                "Filesystem     1024-blocks      Used Available Capacity Mounted on\n/dev/sda1        479596204 250467488 204697404      56% /\n"
+            ),
+            Some("/dev/sda1")
+        );
+        assert!(parse_df_filesystem("").is_none());
+        assert!(parse_df_output("df: /foo: No such file or directory").is_none());
+        assert!(parse_df_output("").is_none());
+        assert_eq!(
+            df_command(Path::new("/home/omar/my docs")).as_str(),
+            "df -Pk '/home/omar/my docs'"
+        );
+    }
+
+    #[test]
+    fn should_get_local_disk_space() {
+        let tmpdir = tempfile::TempDir::new().unwrap();
+        let space = local_disk_space(tmpdir.path()).unwrap();
+        assert!(space.available <= space.total);
+        assert!(local_disk_space(Path::new("/this/path/does/not/exist")).is_none());
+    }
+
+    #[test]
+    fn should_format_disk_space_label() {
+        let space = DiskSpace {
+            available: 1_500_000,
+            total: 10_000_000,
+            quota: false,
+        };
+        assert_eq!(space.label().as_str(), "1.5 MB free of 10.0 MB");
+        let space = DiskSpace {
+            quota: true,
+            ..space
+        };
+        assert_eq!(space.label().as_str(), "1.5 MB free of 10.0 MB (quota)");
+    }
+
+    #[test]
+    fn should_parse_quota_output() {
+        let output = "Disk quotas for user omar (uid 1000): \n     Filesystem  blocks   quota   limit   grace   files   quota   limit   grace\n      /dev/sda1  102400  204800  256000       0      10       0       0       0\n      /dev/sdb1  300000* 204800       0  604800      10       0       0       0\n";
+        assert_eq!(
+            parse_quota_output(output, "/dev/sda1"),
+            Some(Quota {
+                used: 102400 * 1024,
+                limit: 256000 * 1024
+            })
+        );
+        assert_eq!(
+            parse_quota_output(output, "/dev/sdb1"),
+            Some(Quota {
+                used: 300000 * 1024,
+                limit: 204800 * 1024
+            })
+        );
+        assert!(parse_quota_output(output, "/dev/sdc1").is_none());
+        assert!(
+            parse_quota_output("Disk quotas for user omar (uid 1000): none", "/dev/sda1").is_none()
+        );
+        assert!(parse_quota_output(
+            "      /dev/sda1  102400       0       0       0      10       0       0       0",
+            "/dev/sda1"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn should_limit_disk_space_to_quota() {
+        let space = DiskSpace {
+            available: 100_000_000,
+            total: 500_000_000,
+            quota: false,
+        };
+        assert_eq!(
+            space.with_quota(Quota {
+                used: 15_000_000,
+                limit: 20_000_000
+            }),
+            DiskSpace {
+                available: 5_000_000,
+                total: 20_000_000,
+                quota: true
+            }
+        );
+        assert_eq!(
+            space.with_quota(Quota {
+                used: 25_000_000,
+                limit: 20_000_000
+            }),
+            DiskSpace {
+                available: 0,
+                total: 20_000_000,
+                quota: true
+            }
+        );
+        // Quota larger than the space available on the filesystem
+        assert_eq!(
+            space.with_quota(Quota {
+                used: 0,
+                limit: 1_000_000_000
+            }),
+            space
+        );
+    }
+}
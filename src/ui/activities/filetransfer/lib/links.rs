@@ -0,0 +1,204 @@
+//! ## Links
+//!
+//! `links` provides the utilities to preserve hard links and to detect special files
+//! (FIFOs, sockets and devices) during recursive transfers
+
+use crate::utils::string::shell_quote;
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Identifies a file on a filesystem (device, inode).
+/// Remote files are identified by the scan which found them instead of the device, since each
+/// scan doesn't cross filesystems
+pub type FileId = (u64, u64);
+
+/// Hard links and special files met during a transfer
+#[derive(Default)]
+pub struct LinkStates {
+    /// Destination path of the first file transferred for each file id
+    transferred: HashMap<FileId, PathBuf>,
+    /// Ids of the remote files with more than one link
+    remote_ids: HashMap<PathBuf, FileId>,
+    /// Amount of remote scans stored
+    remote_scans: u64,
+    /// Remote special files
+    remote_special: HashSet<PathBuf>,
+}
+
+impl LinkStates {
+    /// Clear all states
+    pub fn clear(&mut self) {
+        self.transferred.clear();
+        self.remote_ids.clear();
+        self.remote_scans = 0;
+        self.remote_special.clear();
+    }
+
+    /// Register the file `id` as transferred to `dest`.
+    /// If a file with the same id has already been transferred, its destination is returned instead,
+    /// so that a hard link to it can be created
+    pub fn register(&mut self, id: FileId, dest: &Path) -> Option<PathBuf> {
+        match self.transferred.get(&id) {
+            Some(first) => Some(first.clone()),
+            None => {
+                self.transferred.insert(id, dest.to_path_buf());
+                None
+            }
+        }
+    }
+
+    /// Store the remote files with more than one link and the special files read from the output of
+    /// `remote_scan_command()`.
+    /// Inodes are unique only within a filesystem, so files found by different scans are never
+    /// considered links of each other
+    pub fn store_remote_scan(&mut self, output: &str) {
+        self.remote_scans += 1;
+        for line in output.lines() {
+            if let Some(path) = line.strip_prefix("s ") {
+                self.remote_special.insert(PathBuf::from(path));
+            } else if let Some((inode, path)) = line
+                .strip_prefix("h ")
+                .and_then(|x| x.trim_start().split_once(' '))
+            {
+                if let Ok(inode) = inode.parse::<u64>() {
+                    self.remote_ids
+                        .insert(PathBuf::from(path), (self.remote_scans, inode));
+                }
+            }
+        }
+    }
+
+    /// Returns the id of the remote file at `path`, if it has more than one link
+    pub fn remote_file_id(&self, path: &Path) -> Option<FileId> {
+        self.remote_ids.get(path).copied()
+    }
+
+    /// Returns whether the remote file at `path` is a special file
+    pub fn is_remote_special_file(&self, path: &Path) -> bool {
+        self.remote_special.contains(path)
+    }
+}
+
+/// Returns the command which lists the files under `root` on the remote host having more than one link
+/// (`h <inode> <path>`) and the special files (`s <path>`). Only POSIX options are used.
+/// Files with more than one link are looked for only on the filesystem of `root`, so that their inodes are unique
+pub fn remote_scan_command(root: &Path) -> String {
+    let root = shell_quote(root.to_string_lossy().as_ref());
+    format!(
+        "find {root} -xdev -type f -links +1 -exec ls -di {{}} + | sed 's/^/h /'; find {root} \\( -type p -o -type s -o -type b -o -type c \\) | sed 's/^/s /'",
+        root = root
+    )
+}
+
+/// Returns the id of the local file at `path`, if it has more than one link
+#[cfg(target_family = "unix")]
+pub fn local_file_id(path: &Path) -> Option<FileId> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::symlink_metadata(path)
+        .ok()
+        .filter(|x| x.is_file() && x.nlink() > 1)
+        .map(|x| (x.dev(), x.ino()))
+}
+
+/// Returns the id of the local file at `path`, if it has more than one link
+#[cfg(target_os = "windows")]
+pub fn local_file_id(_path: &Path) -> Option<FileId> {
+    None
+}
+
+/// Returns whether the local file at `path` is a FIFO, a socket or a device
+#[cfg(target_family = "unix")]
+pub fn is_local_special_file(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+
+    std::fs::metadata(path)
+        .map(|x| {
+            let file_type = x.file_type();
+            file_type.is_fifo()
+                || file_type.is_socket()
+                || file_type.is_block_device()
+                || file_type.is_char_device()
+        })
+        .unwrap_or(false)
+}
+
+/// Returns whether the local file at `path` is a FIFO, a socket or a device
+#[cfg(target_os = "windows")]
+pub fn is_local_special_file(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn should_register_transferred_files() {
+        let mut states = LinkStates::default();
+        assert!(states.register((1, 10), Path::new("/tmp/a.txt")).is_none());
+        assert!(states.register((1, 11), Path::new("/tmp/b.txt")).is_none());
+        assert_eq!(
+            states.register((1, 10), Path::new("/tmp/c.txt")).unwrap(),
+            PathBuf::from("/tmp/a.txt")
+        );
+        states.clear();
+        assert!(states.register((1, 10), Path::new("/tmp/c.txt")).is_none());
+    }
+
+    #[test]
+    fn should_store_remote_scan() {
+        let mut states = LinkStates::default();
+        states.store_remote_scan(
+            "h  1234 /home/omar/a.txt\nh 1234 /home/omar/my docs/b.txt\ns /home/omar/fifo\nh foo /home/omar/c.txt\n",
+        );
+        assert_eq!(
+            states.remote_file_id(Path::new("/home/omar/a.txt")),
+            Some((1, 1234))
+        );
+        assert_eq!(
+            states.remote_file_id(Path::new("/home/omar/my docs/b.txt")),
+            Some((1, 1234))
+        );
+        assert!(states
+            .remote_file_id(Path::new("/home/omar/c.txt"))
+            .is_none());
+        assert!(states.is_remote_special_file(Path::new("/home/omar/fifo")));
+        assert!(!states.is_remote_special_file(Path::new("/home/omar/a.txt")));
+    }
+
+    #[test]
+    fn should_not_link_remote_files_on_different_filesystems() {
+        let mut states = LinkStates::default();
+        // Same inode on two filesystems, found by two scans
+        states.store_remote_scan("h 1234 /home/omar/a.txt\n");
+        states.store_remote_scan("h 1234 /mnt/usb/b.txt\n");
+        let a = states
+            .remote_file_id(Path::new("/home/omar/a.txt"))
+            .unwrap();
+        let b = states.remote_file_id(Path::new("/mnt/usb/b.txt")).unwrap();
+        assert_ne!(a, b);
+        assert!(states.register(a, Path::new("/tmp/a.txt")).is_none());
+        assert!(states.register(b, Path::new("/tmp/b.txt")).is_none());
+        assert!(remote_scan_command(Path::new("/home/omar")).contains("-xdev -type f -links +1"));
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn should_detect_local_hard_links_and_special_files() {
+        let tmpdir = tempfile::TempDir::new().unwrap();
+        let file = tmpdir.path().join("a.txt");
+        let link = tmpdir.path().join("b.txt");
+        std::fs::write(file.as_path(), b"termscp").unwrap();
+        assert!(local_file_id(file.as_path()).is_none());
+        std::fs::hard_link(file.as_path(), link.as_path()).unwrap();
+        assert!(local_file_id(file.as_path()).is_some());
+        assert_eq!(local_file_id(file.as_path()), local_file_id(link.as_path()));
+        assert!(!is_local_special_file(file.as_path()));
+        assert!(is_local_special_file(Path::new("/dev/null")));
+    }
+}
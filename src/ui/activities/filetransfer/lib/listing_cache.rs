@@ -0,0 +1,167 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+use remotefs::fs::File;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Maximum amount of directories queued for prefetch
+const PREFETCH_MAX: usize = 16;
+
+/// ### ListingCache
+///
+/// ListingCache keeps the directory listings read during the session, so they don't have to be
+/// read again from the remote host until they expire.
+/// It also holds the queue of directories whose listing should be read in advance.
+/// Since listings are read on the ui thread, a directory is prefetched only once the ui has been
+/// idle for the prefetch interval.
+pub struct ListingCache {
+    ttl: Duration,
+    listings: HashMap<PathBuf, (Instant, Vec<File>)>,
+    prefetch: VecDeque<PathBuf>,
+    prefetch_interval: Duration,
+    idle_since: Instant,
+}
+
+impl ListingCache {
+    /// Instantiates a new `ListingCache`, whose listings expire after `ttl`.
+    /// Directories are prefetched one at a time, after `prefetch_interval` of idle time
+    pub fn new(ttl: Duration, prefetch_interval: Duration) -> Self {
+        Self {
+            ttl,
+            listings: HashMap::new(),
+            prefetch: VecDeque::with_capacity(PREFETCH_MAX),
+            prefetch_interval,
+            idle_since: Instant::now(),
+        }
+    }
+
+    /// Get listing for directory at `path`, if cached and not expired
+    pub fn get(&self, path: &Path) -> Option<&[File]> {
+        self.listings
+            .get(path)
+            .filter(|(time, _)| time.elapsed() < self.ttl)
+            .map(|(_, files)| files.as_slice())
+    }
+
+    /// Cache listing for directory at `path`
+    pub fn insert(&mut self, path: &Path, files: Vec<File>) {
+        self.listings
+            .insert(path.to_path_buf(), (Instant::now(), files));
+    }
+
+    /// Remove the listing of the directory at `path`, since it has changed
+    pub fn invalidate(&mut self, path: &Path) {
+        self.listings.remove(path);
+    }
+
+    /// Remove all listings and the prefetch queue
+    pub fn clear(&mut self) {
+        self.listings.clear();
+        self.prefetch.clear();
+    }
+
+    /// Replace the prefetch queue with `dirs`. Only the first directories are kept.
+    pub fn prefetch(&mut self, dirs: impl Iterator<Item = PathBuf>) {
+        self.prefetch.clear();
+        self.prefetch.extend(dirs.take(PREFETCH_MAX));
+    }
+
+    /// Report user activity; prefetch is postponed until the ui is idle again
+    pub fn postpone_prefetch(&mut self) {
+        self.idle_since = Instant::now();
+    }
+
+    /// Pop the next directory to prefetch, skipping the ones which are already cached.
+    /// Returns `None` until the ui has been idle for the prefetch interval since the last activity
+    /// or the last prefetch
+    pub fn next_prefetch(&mut self) -> Option<PathBuf> {
+        if self.idle_since.elapsed() < self.prefetch_interval {
+            return None;
+        }
+        while let Some(path) = self.prefetch.pop_front() {
+            if self.get(path.as_path()).is_none() {
+                self.idle_since = Instant::now();
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::Metadata;
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_listing_cache() {
+        let mut cache = ListingCache::new(Duration::from_secs(60), Duration::ZERO);
+        assert!(cache.get(Path::new("/home")).is_none());
+        cache.insert(
+            Path::new("/home"),
+            vec![File {
+                path: PathBuf::from("/home/omar"),
+                metadata: Metadata::default(),
+            }],
+        );
+        assert_eq!(cache.get(Path::new("/home")).unwrap().len(), 1);
+        assert!(cache.get(Path::new("/tmp")).is_none());
+        cache.insert(Path::new("/tmp"), vec![]);
+        cache.invalidate(Path::new("/tmp"));
+        assert!(cache.get(Path::new("/tmp")).is_none());
+        assert!(cache.get(Path::new("/home")).is_some());
+        cache.clear();
+        assert!(cache.get(Path::new("/home")).is_none());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_listing_cache_prefetch() {
+        let mut cache = ListingCache::new(Duration::from_secs(60), Duration::ZERO);
+        assert!(cache.next_prefetch().is_none());
+        cache.insert(Path::new("/home/b"), vec![]);
+        cache.prefetch((0..32).map(|x| PathBuf::from(format!("/home/{}", x))));
+        assert_eq!(cache.prefetch.len(), PREFETCH_MAX);
+        cache.prefetch(
+            vec![
+                PathBuf::from("/home/a"),
+                PathBuf::from("/home/b"),
+                PathBuf::from("/home/c"),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(cache.next_prefetch().unwrap(), PathBuf::from("/home/a"));
+        assert_eq!(cache.next_prefetch().unwrap(), PathBuf::from("/home/c"));
+        assert!(cache.next_prefetch().is_none());
+        cache.prefetch(vec![PathBuf::from("/home/a")].into_iter());
+        cache.clear();
+        assert!(cache.next_prefetch().is_none());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_listing_cache_expired() {
+        let mut cache = ListingCache::new(Duration::ZERO, Duration::ZERO);
+        cache.insert(Path::new("/home"), vec![]);
+        assert!(cache.get(Path::new("/home")).is_none());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_listing_cache_prefetch_when_idle() {
+        let mut cache = ListingCache::new(Duration::from_secs(60), Duration::from_secs(60));
+        cache.prefetch(vec![PathBuf::from("/home/a")].into_iter());
+        assert!(cache.next_prefetch().is_none());
+        cache.idle_since = Instant::now() - Duration::from_secs(61);
+        assert_eq!(cache.next_prefetch().unwrap(), PathBuf::from("/home/a"));
+        // Prefetch and activity restart the interval
+        cache.prefetch(vec![PathBuf::from("/home/b")].into_iter());
+        assert!(cache.next_prefetch().is_none());
+        cache.idle_since = Instant::now() - Duration::from_secs(61);
+        cache.postpone_prefetch();
+        assert!(cache.next_prefetch().is_none());
+    }
+}
@@ -3,4 +3,19 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 pub(crate) mod browser;
+pub(crate) mod capabilities;
+pub(crate) mod command_history;
+pub(crate) mod dir_marks;
+pub(crate) mod duplicates;
+pub(crate) mod file_metadata;
+pub(crate) mod free_space;
+pub(crate) mod links;
+pub(crate) mod listing_cache;
+pub(crate) mod search_results;
+pub(crate) mod sparse;
+pub(crate) mod split;
+pub(crate) mod summary;
+pub(crate) mod tail;
+pub(crate) mod throttle;
 pub(crate) mod transfer;
+pub(crate) mod watcher_log;
@@ -0,0 +1,192 @@
+//! ## SearchResults
+//!
+//! `search_results` provides the export of the find results and the layout used to transfer all of them
+
+use crate::utils::fmt::fmt_time;
+
+use remotefs::File;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Format used to export the search results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values, with a header
+    Csv,
+    /// Tab separated values
+    Text,
+}
+
+impl ExportFormat {
+    /// Get the format from the extension of the file at `path`: `.csv` files are exported as CSV,
+    /// any other file as text
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(ext) if ext.to_string_lossy().eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// Export `files` in `format`, writing one line with path, size and last modification time for each file
+pub fn export<'a>(files: impl Iterator<Item = &'a File>, format: ExportFormat) -> String {
+    let rows = files.map(|x| {
+        [
+            x.path().display().to_string(),
+            x.metadata().size.to_string(),
+            x.metadata()
+                .modified
+                .map(|x| fmt_time(x, "%Y-%m-%d %H:%M:%S"))
+                .unwrap_or_default(),
+        ]
+    });
+    let mut lines: Vec<String> = match format {
+        ExportFormat::Csv => std::iter::once(String::from("path,size,mtime"))
+            .chain(rows.map(|row| {
+                row.iter()
+                    .map(|x| csv_field(x))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            }))
+            .collect(),
+        ExportFormat::Text => rows.map(|row| row.join("\t")).collect(),
+    };
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Quote a CSV field if it contains separators, quotes or line breaks
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Group `files` by the directory, relative to the search `root`, they must be transferred to in order
+/// to preserve the directory structure.
+/// Entries placed inside another directory of the results are left out, since they're transferred
+/// along with it
+pub fn transfer_layout(files: &[File], root: &Path) -> BTreeMap<PathBuf, Vec<File>> {
+    let dirs: Vec<&Path> = files
+        .iter()
+        .filter(|x| x.is_dir())
+        .map(|x| x.path())
+        .collect();
+    let mut layout: BTreeMap<PathBuf, Vec<File>> = BTreeMap::new();
+    for file in files.iter().filter(|x| {
+        !dirs
+            .iter()
+            .any(|dir| x.path() != *dir && x.path().starts_with(dir))
+    }) {
+        let parent = file
+            .path()
+            .parent()
+            .and_then(|x| x.strip_prefix(root).ok())
+            .map(|x| x.to_path_buf())
+            .unwrap_or_default();
+        layout.entry(parent).or_default().push(file.clone());
+    }
+    layout
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
+    use std::time::SystemTime;
+
+    #[test]
+    fn should_get_export_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("/tmp/results.csv")),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("results.CSV")),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("results.txt")),
+            ExportFormat::Text
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("results")),
+            ExportFormat::Text
+        );
+    }
+
+    #[test]
+    fn should_export_results() {
+        let t = SystemTime::now();
+        let files = [
+            make_file("/home/omar/a.txt", 1024, Some(t), false),
+            make_file("/home/omar/b,c.txt", 8, None, false),
+        ];
+        let mtime = fmt_time(t, "%Y-%m-%d %H:%M:%S");
+        assert_eq!(
+            export(files.iter(), ExportFormat::Csv),
+            format!(
+                "path,size,mtime\n/home/omar/a.txt,1024,{}\n\"/home/omar/b,c.txt\",8,\n",
+                mtime
+            )
+        );
+        assert_eq!(
+            export(files.iter(), ExportFormat::Text),
+            format!(
+                "/home/omar/a.txt\t1024\t{}\n/home/omar/b,c.txt\t8\t\n",
+                mtime
+            )
+        );
+        assert_eq!(export([].iter(), ExportFormat::Csv), "path,size,mtime\n");
+    }
+
+    #[test]
+    fn should_quote_csv_fields() {
+        assert_eq!(csv_field("omar").as_str(), "omar");
+        assert_eq!(csv_field("a,b").as_str(), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\"").as_str(), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn should_get_transfer_layout() {
+        let files = vec![
+            make_file("/home/omar/src/main.rs", 64, None, false),
+            make_file("/home/omar/src/lib/mod.rs", 64, None, false),
+            make_file("/home/omar/docs", 4096, None, true),
+            make_file("/home/omar/docs/mod.rs", 64, None, false),
+            make_file("/home/omar/mod.rs", 64, None, false),
+        ];
+        let layout = transfer_layout(&files, Path::new("/home/omar"));
+        assert_eq!(layout.len(), 3);
+        let names = |dir: &str| -> Vec<String> {
+            layout
+                .get(Path::new(dir))
+                .unwrap()
+                .iter()
+                .map(|x| x.name())
+                .collect()
+        };
+        assert_eq!(names(""), vec!["docs", "mod.rs"]);
+        assert_eq!(names("src"), vec!["main.rs"]);
+        assert_eq!(names("src/lib"), vec!["mod.rs"]);
+    }
+
+    fn make_file(path: &str, size: u64, modified: Option<SystemTime>, is_dir: bool) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata {
+                modified,
+                size,
+                file_type: match is_dir {
+                    true => FileType::Directory,
+                    false => FileType::File,
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! ## Sparse
+//!
+//! `sparse` provides the utilities to preserve holes of sparse files when transferring them
+
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Size of the blocks checked for zeros
+const BLOCK_SIZE: usize = 4096;
+
+/// Writer which skips blocks made of zeros seeking over them instead of writing them,
+/// so that the filesystem can leave holes in the destination file
+pub struct SparseWriter<W: Write + Seek> {
+    writer: W,
+    enabled: bool,
+    /// Amount of zeros skipped and not written yet
+    pending: u64,
+}
+
+impl<W: Write + Seek> SparseWriter<W> {
+    /// Instantiates a new `SparseWriter`. If `enabled` is false, data is written as is
+    pub fn new(writer: W, enabled: bool) -> Self {
+        Self {
+            writer,
+            enabled,
+            pending: 0,
+        }
+    }
+
+    /// Write the skipped zeros at the end of the file, so that it gets its full size,
+    /// then return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.pending > 0 {
+            self.writer
+                .seek(SeekFrom::Current(self.pending as i64 - 1))?;
+            self.writer.write_all(&[0])?;
+        }
+        Ok(self.writer)
+    }
+
+    fn write_data(&mut self, data: &[u8]) -> io::Result<()> {
+        if self.pending > 0 {
+            self.writer.seek(SeekFrom::Current(self.pending as i64))?;
+            self.pending = 0;
+        }
+        self.writer.write_all(data)
+    }
+}
+
+impl<W: Write + Seek> Write for SparseWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.enabled {
+            return self.writer.write(buf);
+        }
+        for block in buf.chunks(BLOCK_SIZE) {
+            if block.len() == BLOCK_SIZE && block.iter().all(|x| *x == 0) {
+                self.pending += BLOCK_SIZE as u64;
+            } else {
+                self.write_data(block)?;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Returns whether the local file at `path` is sparse (it occupies less blocks than its size)
+#[cfg(target_family = "unix")]
+pub fn is_sparse(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match std::fs::metadata(path) {
+        Ok(metadata) => metadata.blocks() * 512 < metadata.len(),
+        Err(_) => false,
+    }
+}
+
+/// Returns whether the local file at `path` is sparse (it occupies less blocks than its size)
+#[cfg(target_os = "windows")]
+pub fn is_sparse(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use std::io::Cursor;
+
+    fn write_sparse(data: &[u8], enabled: bool) -> Vec<u8> {
+        let mut writer = SparseWriter::new(Cursor::new(Vec::new()), enabled);
+        for chunk in data.chunks(65535) {
+            assert_eq!(writer.write(chunk).unwrap(), chunk.len());
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn should_write_sparse_data() {
+        let mut data: Vec<u8> = vec![0; 256 * 1024];
+        data[1000] = 1;
+        data[100_000..100_010].copy_from_slice(b"termscp!!!");
+        assert_eq!(write_sparse(&data, true), data);
+        assert_eq!(write_sparse(&data, false), data);
+        // Ending with data
+        data.extend_from_slice(b"end");
+        assert_eq!(write_sparse(&data, true), data);
+        // Only zeros
+        let data: Vec<u8> = vec![0; 16 * 1024];
+        assert_eq!(write_sparse(&data, true), data);
+        assert!(write_sparse(&[], true).is_empty());
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn should_write_sparse_file() {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+        let mut writer = SparseWriter::new(tmpfile.reopen().unwrap(), true);
+        writer.write_all(&vec![0; 1024 * 1024]).unwrap();
+        writer.write_all(b"termscp").unwrap();
+        writer.finish().unwrap();
+        assert_eq!(
+            std::fs::metadata(tmpfile.path()).unwrap().len(),
+            1024 * 1024 + 7
+        );
+        // NOTE: whether holes are actually created depends on the filesystem
+        std::fs::write(tmpfile.path(), b"termscp").unwrap();
+        assert!(!is_sparse(tmpfile.path()));
+    }
+}
@@ -0,0 +1,248 @@
+//! ## Split
+//!
+//! `split` provides the utilities to upload large files as chunks and to join them back on download.
+//!
+//! A file split into chunks is stored on the remote host as `<name>.chunk000`, `<name>.chunk001`, …
+//! along with a sidecar manifest, `<name>.termscp-split`, which describes how to join them.
+
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// Extension of the manifests describing a file split into chunks
+pub const MANIFEST_EXTENSION: &str = "termscp-split";
+
+/// Describes a file split into chunks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitManifest {
+    /// Name of the joined file
+    pub name: String,
+    /// Size of the joined file in bytes
+    pub size: u64,
+    /// Size of each chunk in bytes; the last one may be shorter
+    pub chunk_size: u64,
+    /// Names of the chunks, in order
+    pub chunks: Vec<String>,
+}
+
+impl SplitManifest {
+    /// Describe the file `name` of `size` bytes split into chunks of `chunk_size` bytes
+    pub fn new<S: AsRef<str>>(name: S, size: u64, chunk_size: u64) -> Self {
+        let name = name.as_ref().to_string();
+        let chunk_size = chunk_size.max(1);
+        let count = match size {
+            0 => 1,
+            _ => size.div_ceil(chunk_size),
+        };
+        Self {
+            chunks: (0..count)
+                .map(|x| format!("{}.chunk{:03}", name, x))
+                .collect(),
+            name,
+            size,
+            chunk_size,
+        }
+    }
+
+    /// Returns the name of the manifest file
+    pub fn manifest_name(&self) -> String {
+        format!("{}.{}", self.name, MANIFEST_EXTENSION)
+    }
+
+    /// Returns the offset and the length of each chunk, according to the size of the joined file
+    pub fn chunk_ranges(&self) -> Vec<(u64, u64)> {
+        (0..self.chunks.len() as u64)
+            .map(|x| {
+                let offset = (x * self.chunk_size).min(self.size);
+                (offset, self.chunk_size.min(self.size - offset))
+            })
+            .collect()
+    }
+
+    /// Returns whether `name` is the name of a manifest
+    pub fn is_manifest(name: &str) -> bool {
+        name.strip_suffix(MANIFEST_EXTENSION)
+            .map(|x| x.ends_with('.') && x.len() > 1)
+            .unwrap_or(false)
+    }
+
+    /// Returns the name of the joined file the chunk `name` belongs to, if `name` is the name of a chunk
+    pub fn chunk_of(name: &str) -> Option<&str> {
+        let (base, index) = name.rsplit_once(".chunk")?;
+        match !base.is_empty() && index.len() >= 3 && index.chars().all(|x| x.is_ascii_digit()) {
+            true => Some(base),
+            false => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SplitManifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            toml::ser::to_string(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl std::str::FromStr for SplitManifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let manifest: Self =
+            toml::de::from_str(s).map_err(|err| format!("invalid manifest: {}", err))?;
+        if manifest.chunks.is_empty()
+            || manifest.chunk_size == 0
+            || manifest.name.is_empty()
+            || manifest.name.contains(['/', '\\'])
+            || manifest.chunks.iter().any(|x| x.contains(['/', '\\']))
+        {
+            return Err(String::from("invalid manifest: bad file names"));
+        }
+        Ok(manifest)
+    }
+}
+
+/// Reads a window of `len` bytes starting at `offset` of the inner reader, as if it was a file on its own
+pub struct ChunkReader<R: Read + Seek> {
+    inner: R,
+    offset: u64,
+    len: u64,
+    pos: u64,
+}
+
+impl<R: Read + Seek> ChunkReader<R> {
+    pub fn new(mut inner: R, offset: u64, len: u64) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(offset))?;
+        Ok(Self {
+            inner,
+            offset,
+            len,
+            pos: 0,
+        })
+    }
+}
+
+impl<R: Read + Seek> Read for ChunkReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.len.saturating_sub(self.pos) as usize;
+        if remaining == 0 {
+            return Ok(0);
+        }
+        let max = buf.len().min(remaining);
+        let bytes = self.inner.read(&mut buf[..max])?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+}
+
+impl<R: Read + Seek> Seek for ChunkReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(x) => x as i64,
+            SeekFrom::End(x) => self.len as i64 + x,
+            SeekFrom::Current(x) => self.pos as i64 + x,
+        };
+        if pos < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek before the start of the chunk",
+            ));
+        }
+        self.pos = (pos as u64).min(self.len);
+        self.inner.seek(SeekFrom::Start(self.offset + self.pos))?;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_make_manifest() {
+        let manifest = SplitManifest::new("omar.iso", 2500, 1000);
+        assert_eq!(manifest.name.as_str(), "omar.iso");
+        assert_eq!(manifest.size, 2500);
+        assert_eq!(
+            manifest.chunks,
+            vec![
+                String::from("omar.iso.chunk000"),
+                String::from("omar.iso.chunk001"),
+                String::from("omar.iso.chunk002"),
+            ]
+        );
+        assert_eq!(manifest.manifest_name().as_str(), "omar.iso.termscp-split");
+        assert_eq!(
+            manifest.chunk_ranges(),
+            vec![(0, 1000), (1000, 1000), (2000, 500)]
+        );
+        // Empty files have one empty chunk
+        let manifest = SplitManifest::new("empty", 0, 1000);
+        assert_eq!(manifest.chunks.len(), 1);
+        assert_eq!(manifest.chunk_ranges(), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn should_serialize_manifest() {
+        let manifest = SplitManifest::new("omar.iso", 2500, 1000);
+        assert_eq!(
+            SplitManifest::from_str(manifest.to_string().as_str()).unwrap(),
+            manifest
+        );
+        assert!(SplitManifest::from_str("name = \"omar\"").is_err());
+        assert!(SplitManifest::from_str(
+            "name = \"../omar\"\nsize = 1\nchunk_size = 1\nchunks = [\"a\"]"
+        )
+        .is_err());
+        assert!(SplitManifest::from_str(
+            "name = \"omar\"\nsize = 1\nchunk_size = 1\nchunks = [\"/etc/a\"]"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_tell_manifests_and_chunks() {
+        assert!(SplitManifest::is_manifest("omar.iso.termscp-split"));
+        assert!(!SplitManifest::is_manifest(".termscp-split"));
+        assert!(!SplitManifest::is_manifest("omar.iso"));
+        assert_eq!(
+            SplitManifest::chunk_of("omar.iso.chunk001"),
+            Some("omar.iso")
+        );
+        assert_eq!(
+            SplitManifest::chunk_of("omar.iso.chunk1000"),
+            Some("omar.iso")
+        );
+        assert_eq!(SplitManifest::chunk_of("omar.iso.chunk01"), None);
+        assert_eq!(SplitManifest::chunk_of("omar.iso.chunkabc"), None);
+        assert_eq!(SplitManifest::chunk_of(".chunk000"), None);
+        assert_eq!(SplitManifest::chunk_of("omar.iso"), None);
+    }
+
+    #[test]
+    fn should_read_chunks() {
+        let data: Vec<u8> = (0..10).collect();
+        let mut reader = ChunkReader::new(Cursor::new(data.clone()), 3, 4).unwrap();
+        assert_eq!(reader.seek(SeekFrom::End(0)).unwrap(), 4);
+        assert_eq!(reader.seek(SeekFrom::Start(0)).unwrap(), 0);
+        let mut buffer = Vec::new();
+        assert_eq!(reader.read_to_end(&mut buffer).unwrap(), 4);
+        assert_eq!(buffer, vec![3, 4, 5, 6]);
+        assert_eq!(reader.seek(SeekFrom::Current(-2)).unwrap(), 2);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![5, 6]);
+        assert!(reader.seek(SeekFrom::Current(-10)).is_err());
+        // Last chunk
+        let mut reader = ChunkReader::new(Cursor::new(data), 8, 4).unwrap();
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![8, 9]);
+    }
+}
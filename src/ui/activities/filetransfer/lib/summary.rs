@@ -0,0 +1,135 @@
+//! ## Summary
+//!
+//! `summary` provides the statistics of the transfers made during a session
+
+use bytesize::ByteSize;
+use std::time::{Duration, Instant};
+
+/// Statistics of the transfers made during a session
+pub struct SessionSummary {
+    started: Instant,
+    uploaded_files: usize,
+    uploaded_bytes: u64,
+    downloaded_files: usize,
+    downloaded_bytes: u64,
+    failures: usize,
+    last_rate: Option<u64>,
+}
+
+impl Default for SessionSummary {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            uploaded_files: 0,
+            uploaded_bytes: 0,
+            downloaded_files: 0,
+            downloaded_bytes: 0,
+            failures: 0,
+            last_rate: None,
+        }
+    }
+}
+
+impl SessionSummary {
+    /// Track a file of `size` bytes which has been uploaded
+    pub fn uploaded(&mut self, size: u64) {
+        self.uploaded_files += 1;
+        self.uploaded_bytes += size;
+    }
+
+    /// Track a file of `size` bytes which has been downloaded
+    pub fn downloaded(&mut self, size: u64) {
+        self.downloaded_files += 1;
+        self.downloaded_bytes += size;
+    }
+
+    /// Track the speed in bytes per second of the last file transfer
+    pub fn rate(&mut self, bytes_per_second: u64) {
+        self.last_rate = Some(bytes_per_second);
+    }
+
+    /// Returns the speed in bytes per second of the last file transfer
+    pub fn last_rate(&self) -> Option<u64> {
+        self.last_rate
+    }
+
+    /// Track a file transfer which has failed
+    pub fn failed(&mut self) {
+        self.failures += 1;
+    }
+
+    /// Returns the time elapsed since the session started
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the summary as text lines
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!(
+                "Uploaded: {} file(s) ({})",
+                self.uploaded_files,
+                ByteSize(self.uploaded_bytes)
+            ),
+            format!(
+                "Downloaded: {} file(s) ({})",
+                self.downloaded_files,
+                ByteSize(self.downloaded_bytes)
+            ),
+            format!(
+                "Total: {}",
+                ByteSize(self.uploaded_bytes + self.downloaded_bytes)
+            ),
+            format!("Failures: {}", self.failures),
+            format!("Elapsed time: {}", Self::fmt_elapsed(self.elapsed())),
+        ]
+    }
+
+    fn fmt_elapsed(elapsed: Duration) -> String {
+        let secs = elapsed.as_secs();
+        format!(
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            (secs / 60) % 60,
+            secs % 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_track_session_summary() {
+        let mut summary = SessionSummary::default();
+        summary.uploaded(1000);
+        summary.uploaded(1000);
+        summary.downloaded(500);
+        summary.failed();
+        assert!(summary.last_rate().is_none());
+        summary.rate(2048);
+        assert_eq!(summary.last_rate(), Some(2048));
+        let lines = summary.lines();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0].as_str(), "Uploaded: 2 file(s) (2.0 KB)");
+        assert_eq!(lines[1].as_str(), "Downloaded: 1 file(s) (500 B)");
+        assert_eq!(lines[2].as_str(), "Total: 2.5 KB");
+        assert_eq!(lines[3].as_str(), "Failures: 1");
+    }
+
+    #[test]
+    fn should_format_elapsed_time() {
+        assert_eq!(
+            SessionSummary::fmt_elapsed(Duration::from_secs(3725)).as_str(),
+            "01:02:05"
+        );
+        assert_eq!(
+            SessionSummary::fmt_elapsed(Duration::from_secs(59)).as_str(),
+            "00:00:59"
+        );
+    }
+}
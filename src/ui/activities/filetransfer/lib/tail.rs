@@ -0,0 +1,316 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+use crate::filetransfer::FileTransferProtocol;
+use crate::utils::string::shell_quote;
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Amount of bytes read from the end of the file when follow mode starts
+const TAIL_INITIAL_BYTES: u64 = 8192;
+/// Maximum amount of lines kept in the tail buffer
+const TAIL_CAPACITY: usize = 1024;
+/// Amount of consecutive failed polls after which the file is not followed anymore
+const TAIL_MAX_ERRORS: u32 = 5;
+
+/// ### TailMethod
+///
+/// Describes how the data appended to the followed file is read, without reading the file again
+/// from the beginning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailMethod {
+    /// Open the file and seek to the last offset read
+    Seek,
+    /// Execute `tail` on the remote host, starting from the last offset read
+    Exec,
+}
+
+impl TailMethod {
+    /// Get the method to follow files on a host connected with `protocol`.
+    /// `commands` tells whether `tail`, `head` and `od` can be executed on the remote host.
+    /// Returns `None` if files can't be read from an offset, since they would be downloaded again at
+    /// each poll
+    pub fn new(protocol: FileTransferProtocol, commands: bool) -> Option<Self> {
+        match protocol {
+            // Sftp streams are seekable
+            FileTransferProtocol::Sftp => Some(Self::Seek),
+            _ if commands => Some(Self::Exec),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the command which prints, as hex bytes, `len` bytes of the file at `path` starting
+/// from `offset`. The bytes are printed as hex, since command outputs must be valid UTF-8
+pub fn tail_command(path: &Path, offset: u64, len: u64) -> String {
+    format!(
+        "tail -c +{} {} | head -c {} | od -An -v -tx1",
+        offset + 1,
+        shell_quote(path.to_string_lossy().as_ref()),
+        len
+    )
+}
+
+/// Parse the output of `tail_command()` into the bytes read.
+/// Returns `None` if the output is not a sequence of hex bytes
+pub fn parse_tail_output(output: &str) -> Option<Vec<u8>> {
+    output
+        .split_whitespace()
+        .map(|x| u8::from_str_radix(x, 16).ok())
+        .collect()
+}
+
+/// ### TailStates
+///
+/// TailStates contains the states of a remote file being followed (`tail -f`)
+pub struct TailStates {
+    path: PathBuf,
+    offset: u64,
+    lines: VecDeque<String>,
+    partial: String,
+    skip_line: bool,
+    interval: Duration,
+    last_poll: Option<Instant>,
+    method: TailMethod,
+    errors: u32,
+}
+
+impl TailStates {
+    /// Instantiates a new `TailStates` for file at `path` with size `size`.
+    /// Only the last bytes of the file are going to be read at the first poll.
+    /// The appended data is read with `method`.
+    pub fn new(path: &Path, size: u64, interval: Duration, method: TailMethod) -> Self {
+        let offset = size.saturating_sub(TAIL_INITIAL_BYTES);
+        Self {
+            path: path.to_path_buf(),
+            offset,
+            lines: VecDeque::with_capacity(TAIL_CAPACITY),
+            partial: String::new(),
+            skip_line: offset > 0,
+            interval,
+            last_poll: None,
+            method,
+            errors: 0,
+        }
+    }
+
+    /// Returns the path of the followed file
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// Returns the offset to read from at the next poll
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the method used to read the appended data
+    pub fn method(&self) -> TailMethod {
+        self.method
+    }
+
+    /// Returns whether the poll interval has elapsed since the last poll.
+    /// The interval is doubled at each consecutive failed poll
+    pub fn should_poll(&self) -> bool {
+        self.last_poll
+            .map(|t| t.elapsed() >= self.interval * 2u32.pow(self.errors))
+            .unwrap_or(true)
+    }
+
+    /// Mark the file as polled now
+    pub fn polled(&mut self) {
+        self.last_poll = Some(Instant::now());
+        self.errors = 0;
+    }
+
+    /// Mark the file as polled now, but without success.
+    /// Returns whether too many consecutive polls failed, so the file should not be followed anymore
+    pub fn failed(&mut self) -> bool {
+        self.last_poll = Some(Instant::now());
+        self.errors += 1;
+        self.errors >= TAIL_MAX_ERRORS
+    }
+
+    /// Reset the offset after the file has been truncated or rotated
+    pub fn truncated(&mut self) {
+        self.offset = 0;
+        self.skip_line = false;
+        self.partial.clear();
+        self.push_line(String::from("--- file truncated ---"));
+    }
+
+    /// Push data appended to the file into the buffer
+    pub fn push(&mut self, data: &[u8]) {
+        self.offset += data.len() as u64;
+        self.partial
+            .push_str(String::from_utf8_lossy(data).as_ref());
+        while let Some(pos) = self.partial.find('\n') {
+            let line: String = self.partial.drain(..=pos).collect();
+            // The first line may have been cut, if we didn't start from the beginning
+            if self.skip_line {
+                self.skip_line = false;
+                continue;
+            }
+            self.push_line(line.trim_end_matches(&['\r', '\n'][..]).to_string());
+        }
+    }
+
+    /// Iterate over buffered lines, including the pending (not terminated) one
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .map(|x| x.as_str())
+            .chain(Some(self.partial.as_str()).filter(|x| !self.skip_line && !x.is_empty()))
+    }
+
+    fn push_line(&mut self, line: String) {
+        if self.lines.len() >= TAIL_CAPACITY {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_states() {
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            128,
+            Duration::ZERO,
+            TailMethod::Seek,
+        );
+        assert_eq!(states.path(), Path::new("/var/log/syslog"));
+        assert_eq!(states.offset(), 0);
+        assert!(states.should_poll());
+        states.push(b"hello\nworld\r\npart");
+        assert_eq!(states.offset(), 17);
+        assert_eq!(
+            states.lines().collect::<Vec<&str>>(),
+            vec!["hello", "world", "part"]
+        );
+        states.push(b"ial\n");
+        assert_eq!(
+            states.lines().collect::<Vec<&str>>(),
+            vec!["hello", "world", "partial"]
+        );
+        states.polled();
+        assert!(states.should_poll());
+        states.truncated();
+        assert_eq!(states.offset(), 0);
+        assert_eq!(states.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_states_skip_first_line() {
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            10000,
+            Duration::ZERO,
+            TailMethod::Seek,
+        );
+        assert_eq!(states.offset(), 10000 - TAIL_INITIAL_BYTES);
+        states.push(b"ut line");
+        assert_eq!(states.lines().count(), 0);
+        states.push(b"\nfull line\n");
+        assert_eq!(states.lines().collect::<Vec<&str>>(), vec!["full line"]);
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_states_capacity() {
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            0,
+            Duration::ZERO,
+            TailMethod::Seek,
+        );
+        for i in 0..TAIL_CAPACITY + 10 {
+            states.push(format!("{}\n", i).as_bytes());
+        }
+        assert_eq!(states.lines().count(), TAIL_CAPACITY);
+        assert_eq!(states.lines().next().unwrap(), "10");
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_states_poll_interval() {
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            0,
+            Duration::from_secs(60),
+            TailMethod::Seek,
+        );
+        assert!(states.should_poll());
+        states.polled();
+        assert!(!states.should_poll());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_states_errors() {
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            0,
+            Duration::ZERO,
+            TailMethod::Exec,
+        );
+        assert_eq!(states.method(), TailMethod::Exec);
+        for _ in 1..TAIL_MAX_ERRORS {
+            assert_eq!(states.failed(), false);
+        }
+        states.polled();
+        for _ in 1..TAIL_MAX_ERRORS {
+            assert_eq!(states.failed(), false);
+        }
+        assert_eq!(states.failed(), true);
+        // Back off
+        let mut states = TailStates::new(
+            Path::new("/var/log/syslog"),
+            0,
+            Duration::from_millis(100),
+            TailMethod::Seek,
+        );
+        states.polled();
+        states.failed();
+        std::thread::sleep(Duration::from_millis(120));
+        assert!(!states.should_poll());
+        std::thread::sleep(Duration::from_millis(120));
+        assert!(states.should_poll());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_method() {
+        assert_eq!(
+            TailMethod::new(FileTransferProtocol::Sftp, false),
+            Some(TailMethod::Seek)
+        );
+        assert_eq!(
+            TailMethod::new(FileTransferProtocol::Scp, true),
+            Some(TailMethod::Exec)
+        );
+        assert!(TailMethod::new(FileTransferProtocol::Scp, false).is_none());
+        assert!(TailMethod::new(FileTransferProtocol::Ftp(false), false).is_none());
+        assert!(TailMethod::new(FileTransferProtocol::AwsS3, false).is_none());
+    }
+
+    #[test]
+    fn test_ui_activities_filetransfer_lib_tail_command() {
+        assert_eq!(
+            tail_command(Path::new("/var/log/my log"), 1024, 512).as_str(),
+            "tail -c +1025 '/var/log/my log' | head -c 512 | od -An -v -tx1"
+        );
+        assert_eq!(
+            parse_tail_output(" 68 65 6c 6c 6f 0a\n ff\n").unwrap(),
+            b"hello\n\xff".to_vec()
+        );
+        assert!(parse_tail_output("").unwrap().is_empty());
+        assert!(parse_tail_output("tail: cannot open").is_none());
+    }
+}
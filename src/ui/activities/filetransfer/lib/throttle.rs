@@ -0,0 +1,66 @@
+//! ## Throttle
+//!
+//! `throttle` provides the limiter which keeps the transfer speed under a certain rate
+
+use std::time::{Duration, Instant};
+
+/// Keeps track of the bytes transferred to limit the transfer rate
+pub struct Throttle {
+    /// Maximum rate in bytes per second; if `None`, rate is unlimited
+    limit: Option<u64>,
+    started: Instant,
+    bytes: u64,
+}
+
+impl Throttle {
+    /// Instantiates a new `Throttle` with the provided limit in bytes per second
+    pub fn new(limit: Option<u64>) -> Self {
+        Self {
+            limit: limit.filter(|x| *x > 0),
+            started: Instant::now(),
+            bytes: 0,
+        }
+    }
+
+    /// Track `bytes` transferred and returns how long to wait in order to stay under the limit
+    pub fn consume(&mut self, bytes: usize) -> Duration {
+        self.bytes += bytes as u64;
+        match self.limit {
+            None => Duration::ZERO,
+            Some(limit) => {
+                let expected = Duration::from_secs_f64(self.bytes as f64 / limit as f64);
+                expected.saturating_sub(self.started.elapsed())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_not_throttle_without_limit() {
+        let mut throttle = Throttle::new(None);
+        assert_eq!(throttle.consume(1024 * 1024 * 1024), Duration::ZERO);
+        let mut throttle = Throttle::new(Some(0));
+        assert_eq!(throttle.consume(1024 * 1024 * 1024), Duration::ZERO);
+    }
+
+    #[test]
+    fn should_throttle_transfer() {
+        let mut throttle = Throttle::new(Some(1000));
+        // 2000 bytes at 1000 B/s must take 2 seconds
+        let wait = throttle.consume(2000);
+        assert!(wait > Duration::from_millis(1900) && wait <= Duration::from_secs(2));
+        // Time has passed
+        throttle.started = throttle
+            .started
+            .checked_sub(Duration::from_secs(3))
+            .unwrap();
+        assert_eq!(throttle.consume(500), Duration::ZERO);
+    }
+}
@@ -2,8 +2,18 @@
 //!
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
+use super::links::LinkStates;
+use crate::filetransfer::{SymlinkPolicy, TransferFilter};
+use crate::utils::path::normalize;
+
 use bytesize::ByteSize;
+use remotefs::File;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 // -- States and progress
@@ -15,6 +25,18 @@ pub struct TransferStates {
     aborted: bool,               // Describes whether the transfer process has been aborted
     pub full: ProgressStates,    // full transfer states
     pub partial: ProgressStates, // Partial transfer states
+    /// Directory listings read while computing the transfer size, reused while transferring
+    listings: HashMap<PathBuf, Vec<File>>,
+    /// Sizes of the entries to transfer, computed before the transfer to show its summary
+    planned: HashMap<PathBuf, TransferSize>,
+    /// Policy overriding the configured one for symlinks met during the transfer
+    pub symlink_policy: Option<SymlinkPolicy>,
+    /// Filter overriding the configured one for the entries met while recursing
+    pub filter: Option<TransferFilter>,
+    /// Hard links and special files met during the transfer
+    pub links: LinkStates,
+    /// Directories entered while walking the transferred entries, by their resolved path
+    visited: HashSet<PathBuf>,
 }
 
 /// ### ProgressStates
@@ -39,12 +61,62 @@ impl TransferStates {
             aborted: false,
             full: ProgressStates::default(),
             partial: ProgressStates::default(),
+            listings: HashMap::new(),
+            planned: HashMap::new(),
+            symlink_policy: None,
+            filter: None,
+            links: LinkStates::default(),
+            visited: HashSet::new(),
         }
     }
 
     /// Re-intiialize transfer states
     pub fn reset(&mut self) {
         self.aborted = false;
+        self.listings.clear();
+        self.planned.clear();
+        self.links.clear();
+        self.visited.clear();
+    }
+
+    /// Mark the local directory at `path` as entered, by its canonical path.
+    /// Returns `false` if it had already been entered during the walk, which happens when following
+    /// symlinks pointing to each other or to one of their parents
+    pub fn visit_local_dir(&mut self, path: &Path) -> bool {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| normalize(path));
+        self.visited.insert(path)
+    }
+
+    /// Mark the remote directory at `path` as entered, by its normalized path.
+    /// `path` must be the resolved target for symlinks.
+    /// Returns `false` if it had already been entered during the walk
+    pub fn visit_remote_dir(&mut self, path: &Path) -> bool {
+        self.visited.insert(normalize(path))
+    }
+
+    /// Forget the directories entered, to walk the entries again
+    pub fn clear_visited(&mut self) {
+        self.visited.clear();
+    }
+
+    /// Store the listing of the directory at `path`, so that it doesn't need to be read again
+    pub fn store_listing(&mut self, path: &Path, files: Vec<File>) {
+        self.listings.insert(path.to_path_buf(), files);
+    }
+
+    /// Take the stored listing of the directory at `path`, if any
+    pub fn take_listing(&mut self, path: &Path) -> Option<Vec<File>> {
+        self.listings.remove(path)
+    }
+
+    /// Store the size of the entry at `path`, computed before starting the transfer
+    pub fn plan(&mut self, path: &Path, size: TransferSize) {
+        self.planned.insert(path.to_path_buf(), size);
+    }
+
+    /// Take the size of the entry at `path` computed before starting the transfer, if any
+    pub fn take_planned(&mut self, path: &Path) -> Option<TransferSize> {
+        self.planned.remove(path)
     }
 
     /// Set aborted to true
@@ -157,6 +229,84 @@ impl ProgressStates {
     }
 }
 
+// -- Partial uploads
+
+/// Extension appended to the files being uploaded, when partial uploads are enabled
+pub const PARTIAL_EXTENSION: &str = "part";
+
+/// Returns the path where the file at `path` is written while it is being uploaded
+pub fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(PARTIAL_EXTENSION);
+    path.with_file_name(name)
+}
+
+// -- Summary
+
+/// Amount of entries listed as the largest ones in the transfer summary
+const SUMMARY_LARGEST_ENTRIES: usize = 5;
+
+/// Size in bytes and amount of files of an entry to transfer
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransferSize {
+    pub bytes: usize,
+    pub files: usize,
+}
+
+impl TransferSize {
+    /// Size of a single file of `bytes`
+    pub fn file(bytes: usize) -> Self {
+        Self { bytes, files: 1 }
+    }
+}
+
+impl Add for TransferSize {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            files: self.files + other.files,
+        }
+    }
+}
+
+impl Sum for TransferSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Summary of the transfer of many entries, shown before it starts
+pub struct TransferSummary {
+    /// Name and size of the entries to transfer
+    entries: Vec<(String, TransferSize)>,
+}
+
+impl TransferSummary {
+    pub fn new(entries: Vec<(String, TransferSize)>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the total size and amount of files of the transfer
+    pub fn total(&self) -> TransferSize {
+        self.entries.iter().map(|(_, size)| *size).sum()
+    }
+
+    /// Returns the largest entries, from the largest one
+    pub fn largest(&self) -> Vec<(&str, TransferSize)> {
+        let mut entries: Vec<(&str, TransferSize)> = self
+            .entries
+            .iter()
+            .map(|(name, size)| (name.as_str(), *size))
+            .collect();
+        entries.sort_by_key(|(_, size)| Reverse(size.bytes));
+        entries.truncate(SUMMARY_LARGEST_ENTRIES);
+        entries
+    }
+}
+
 // -- Options
 
 /// Defines the transfer options for transfer actions
@@ -164,6 +314,10 @@ impl ProgressStates {
 pub struct TransferOpts {
     /// Save file as
     pub save_as: Option<String>,
+    /// Policy for symlinks; if unset, the configured one is used
+    pub symlink_policy: Option<SymlinkPolicy>,
+    /// Include/exclude patterns for recursive transfers; if unset, the configured ones are used
+    pub filter: Option<TransferFilter>,
 }
 
 impl TransferOpts {
@@ -172,6 +326,18 @@ impl TransferOpts {
         self.save_as = n.map(|x| x.as_ref().to_string());
         self
     }
+
+    /// Define how symlinks are handled during the transfer
+    pub fn symlink_policy(mut self, policy: SymlinkPolicy) -> Self {
+        self.symlink_policy = Some(policy);
+        self
+    }
+
+    /// Define which entries are transferred while recursing into directories
+    pub fn filter(mut self, filter: TransferFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -247,11 +413,127 @@ mod test {
         assert_eq!(states.full_size(), 1024);
     }
 
+    #[test]
+    fn should_store_and_take_listings() {
+        let mut states: TransferStates = TransferStates::default();
+        let files = vec![File {
+            path: PathBuf::from("/tmp/a.txt"),
+            metadata: Default::default(),
+        }];
+        states.store_listing(Path::new("/tmp"), files);
+        assert!(states.take_listing(Path::new("/home")).is_none());
+        assert_eq!(states.take_listing(Path::new("/tmp")).unwrap().len(), 1);
+        assert!(states.take_listing(Path::new("/tmp")).is_none());
+    }
+
+    #[test]
+    fn should_plan_transfer_sizes() {
+        let mut states: TransferStates = TransferStates::default();
+        states.plan(Path::new("/tmp/a.txt"), TransferSize::file(1024));
+        assert_eq!(
+            states.take_planned(Path::new("/tmp/a.txt")),
+            Some(TransferSize::file(1024))
+        );
+        assert!(states.take_planned(Path::new("/tmp/a.txt")).is_none());
+        states.plan(Path::new("/tmp/a.txt"), TransferSize::file(1024));
+        states.reset();
+        assert!(states.take_planned(Path::new("/tmp/a.txt")).is_none());
+        // Listings are taken only once
+        assert!(states.take_listing(Path::new("/tmp")).is_none());
+        // Listings are cleared on reset
+        states.store_listing(Path::new("/tmp"), vec![]);
+        states.reset();
+        assert!(states.take_listing(Path::new("/tmp")).is_none());
+    }
+
     #[test]
     fn transfer_opts() {
         let opts = TransferOpts::default();
         assert!(opts.save_as.is_none());
         let opts = TransferOpts::default().save_as(Some("omar.txt"));
         assert_eq!(opts.save_as.as_deref().unwrap(), "omar.txt");
+        assert!(opts.symlink_policy.is_none());
+        let opts = TransferOpts::default().symlink_policy(SymlinkPolicy::Skip);
+        assert_eq!(opts.symlink_policy, Some(SymlinkPolicy::Skip));
+        assert!(opts.filter.is_none());
+        let opts = TransferOpts::default().filter(TransferFilter::default().exclude("*.o"));
+        assert_eq!(opts.filter, Some(TransferFilter::default().exclude("*.o")));
+    }
+
+    #[test]
+    fn should_get_partial_path() {
+        assert_eq!(
+            partial_path(Path::new("/var/www/index.html")),
+            PathBuf::from("/var/www/index.html.part")
+        );
+        assert_eq!(
+            partial_path(Path::new("README")),
+            PathBuf::from("README.part")
+        );
+    }
+
+    #[test]
+    fn should_sum_transfer_sizes() {
+        let size: TransferSize = vec![
+            TransferSize::file(1024),
+            TransferSize {
+                bytes: 4096,
+                files: 3,
+            },
+        ]
+        .into_iter()
+        .sum();
+        assert_eq!(
+            size,
+            TransferSize {
+                bytes: 5120,
+                files: 4
+            }
+        );
+        assert_eq!(
+            Vec::<TransferSize>::new().into_iter().sum::<TransferSize>(),
+            TransferSize::default()
+        );
+    }
+
+    #[test]
+    fn should_summarize_transfer() {
+        let summary = TransferSummary::new(
+            (1..=7)
+                .map(|x| (format!("file{}", x), TransferSize::file(x * 1024)))
+                .collect(),
+        );
+        assert_eq!(
+            summary.total(),
+            TransferSize {
+                bytes: 28 * 1024,
+                files: 7
+            }
+        );
+        let largest = summary.largest();
+        assert_eq!(largest.len(), SUMMARY_LARGEST_ENTRIES);
+        assert_eq!(largest[0], ("file7", TransferSize::file(7 * 1024)));
+        assert_eq!(largest[4], ("file3", TransferSize::file(3 * 1024)));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn should_not_visit_self_referencing_link_twice() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let link = tmp_dir.path().join("a");
+        std::os::unix::fs::symlink(".", link.as_path()).unwrap();
+        let mut states = TransferStates::default();
+        assert!(states.visit_local_dir(tmp_dir.path()));
+        assert!(!states.visit_local_dir(link.as_path()));
+        assert!(!states.visit_local_dir(link.join("a").as_path()));
+        states.clear_visited();
+        assert!(states.visit_local_dir(link.as_path()));
+        // Remote paths: `a -> .` and `a -> ..`
+        assert!(states.visit_remote_dir(Path::new("/home/omar")));
+        assert!(!states.visit_remote_dir(Path::new("/home/omar/.")));
+        assert!(states.visit_remote_dir(Path::new("/home")));
+        assert!(!states.visit_remote_dir(Path::new("/home/omar/..")));
+        states.reset();
+        assert!(states.visit_remote_dir(Path::new("/home")));
     }
 }
@@ -0,0 +1,117 @@
+//! ## WatcherLog
+//!
+//! `watcher_log` keeps the changes synchronized with the remote host by the file watcher
+
+use crate::ui::i18n::tr;
+
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Maximum amount of events kept in the log
+const LOG_SIZE: usize = 64;
+
+/// Change reported by the file watcher and applied to the remote host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherAction {
+    Move,
+    Remove,
+    Upload,
+}
+
+impl WatcherAction {
+    pub fn label(&self) -> String {
+        tr(match self {
+            Self::Move => "watcher-move",
+            Self::Remove => "watcher-remove",
+            Self::Upload => "watcher-upload",
+        })
+    }
+}
+
+/// Change synchronized by the file watcher and its outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatcherEvent {
+    pub time: DateTime<Local>,
+    pub action: WatcherAction,
+    /// Remote path of the file
+    pub path: PathBuf,
+    /// Error returned synchronizing the change
+    pub error: Option<String>,
+}
+
+/// Changes synchronized by the file watcher, from the most recent to the oldest
+#[derive(Debug, Default)]
+pub struct WatcherLog {
+    events: VecDeque<WatcherEvent>,
+}
+
+impl WatcherLog {
+    /// Record the outcome of `action` on `path`.
+    /// Only the most recent events are kept, if they exceed the log size
+    pub fn push(&mut self, action: WatcherAction, path: &Path, result: Result<(), String>) {
+        self.events.push_front(WatcherEvent {
+            time: Local::now(),
+            action,
+            path: path.to_path_buf(),
+            error: result.err(),
+        });
+        self.events.truncate(LOG_SIZE);
+    }
+
+    /// Returns the events, from the most recent to the oldest
+    pub fn events(&self) -> impl Iterator<Item = &WatcherEvent> {
+        self.events.iter()
+    }
+
+    /// Returns the time of the most recent event
+    pub fn last_sync(&self) -> Option<DateTime<Local>> {
+        self.events.front().map(|x| x.time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_push_events() {
+        let mut log = WatcherLog::default();
+        assert!(log.last_sync().is_none());
+        log.push(WatcherAction::Upload, Path::new("/tmp/a.txt"), Ok(()));
+        log.push(
+            WatcherAction::Remove,
+            Path::new("/tmp/b.txt"),
+            Err(String::from("permission denied")),
+        );
+        let events: Vec<&WatcherEvent> = log.events().collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].action, WatcherAction::Remove);
+        assert_eq!(events[0].path.as_path(), Path::new("/tmp/b.txt"));
+        assert_eq!(events[0].error.as_deref(), Some("permission denied"));
+        assert_eq!(events[1].action, WatcherAction::Upload);
+        assert!(events[1].error.is_none());
+        assert_eq!(log.last_sync(), Some(events[0].time));
+    }
+
+    #[test]
+    fn should_keep_most_recent_events() {
+        let mut log = WatcherLog::default();
+        for i in 0..LOG_SIZE + 2 {
+            log.push(
+                WatcherAction::Upload,
+                PathBuf::from(format!("/tmp/{}", i)).as_path(),
+                Ok(()),
+            );
+        }
+        assert_eq!(log.events().count(), LOG_SIZE);
+        assert_eq!(
+            log.events().next().unwrap().path,
+            PathBuf::from(format!("/tmp/{}", LOG_SIZE + 1))
+        );
+        assert_eq!(log.events().last().unwrap().path, PathBuf::from("/tmp/2"));
+    }
+}
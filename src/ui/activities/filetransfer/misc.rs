@@ -6,10 +6,12 @@ use super::{
 use crate::filetransfer::ProtocolParams;
 use crate::system::environment;
 use crate::system::notifications::Notification;
+use crate::ui::i18n::tr_args;
 use crate::utils::fmt::{fmt_millis, fmt_path_elide_ex};
 use crate::utils::path;
 // Ext
 use bytesize::ByteSize;
+use remotefs::File;
 use std::env;
 use std::path::{Path, PathBuf};
 use tuirealm::props::{
@@ -20,6 +22,47 @@ use tuirealm::{PollStrategy, Update};
 const LOG_CAPACITY: usize = 256;
 
 impl FileTransferActivity {
+    /// Returns whether an operation can be performed on the current tab.
+    /// Unsupported operations are greyed out in the keybindings, so they're just ignored here
+    pub(super) fn is_operation_supported(&mut self, supported: bool, operation: &str) -> bool {
+        match self.browser.tab() {
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote if !supported => {
+                self.log(
+                    LogLevel::Warn,
+                    format!("{} is not supported by the remote host", operation),
+                );
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns whether the session has been opened in read-only mode
+    pub(super) fn read_only(&self) -> bool {
+        self.context()
+            .ft_params()
+            .map(|x| x.read_only)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the remote host is a production host
+    pub(super) fn production(&self) -> bool {
+        self.context()
+            .ft_params()
+            .map(|x| x.production)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether a destructive operation can be performed.
+    /// If the session is read-only, an error is displayed.
+    pub(super) fn is_operation_allowed(&mut self, operation: &str) -> bool {
+        if self.read_only() {
+            self.mount_error(format!("{} is disabled in read-only mode", operation));
+            return false;
+        }
+        true
+    }
+
     /// Call `Application::tick()` and process messages in `Update`
     pub(super) fn tick(&mut self) {
         match self.app.tick(PollStrategy::UpTo(3)) {
@@ -90,6 +133,114 @@ impl FileTransferActivity {
         }
     }
 
+    /// Save the view options (sorting and hidden files) of the explorers changed by the user, to
+    /// restore them the next time. The defaults from the configuration are left untouched
+    pub(super) fn save_view_settings(&mut self) {
+        let local = self
+            .browser
+            .local_view_changed()
+            .then(|| self.browser.local_view());
+        let remote = self
+            .browser
+            .remote_view_changed()
+            .then(|| self.browser.remote_view());
+        if local.is_none() && remote.is_none() {
+            return;
+        }
+        let config = self.context_mut().config_mut();
+        if let Some(view) = local {
+            config.set_last_view(false, view);
+        }
+        if let Some(view) = remote {
+            config.set_last_view(true, view);
+        }
+        if let Err(err) = config.write_config() {
+            warn!("Could not save view settings: {}", err);
+        }
+    }
+
+    /// Apply the view options remembered for the current local directory, if any
+    pub(super) fn apply_local_directory_view(&mut self) {
+        let wrkdir = self.local().wrkdir.clone();
+        let view = self.config().get_directory_view(wrkdir.as_path(), false);
+        if let Some(view) = view {
+            // Mark as recently used
+            self.context_mut()
+                .config_mut()
+                .set_directory_view(wrkdir.as_path(), false, view);
+        }
+        self.browser.apply_local_directory_view(view);
+    }
+
+    /// Apply the view options remembered for the current remote directory, if any
+    pub(super) fn apply_remote_directory_view(&mut self) {
+        let wrkdir = self.remote().wrkdir.clone();
+        let view = self.config().get_directory_view(wrkdir.as_path(), true);
+        if let Some(view) = view {
+            // Mark as recently used
+            self.context_mut()
+                .config_mut()
+                .set_directory_view(wrkdir.as_path(), true, view);
+        }
+        self.browser.apply_remote_directory_view(view);
+    }
+
+    /// Returns the working directory of the explorer for the current tab and whether it's remote
+    fn current_directory(&self) -> (PathBuf, bool) {
+        match self.browser.tab() {
+            FileExplorerTab::Local | FileExplorerTab::FindLocal => {
+                (self.local().wrkdir.clone(), false)
+            }
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                (self.remote().wrkdir.clone(), true)
+            }
+        }
+    }
+
+    /// Returns whether the current directory has its own view options
+    pub(super) fn has_directory_view(&self) -> bool {
+        let (wrkdir, remote) = self.current_directory();
+        self.config()
+            .get_directory_view(wrkdir.as_path(), remote)
+            .is_some()
+    }
+
+    /// Update the view options remembered for the current directory, if it has its own view
+    pub(super) fn update_directory_view(&mut self) {
+        let (wrkdir, remote) = self.current_directory();
+        if self.has_directory_view() {
+            let view = match remote {
+                false => self.local().view(),
+                true => self.remote().view(),
+            };
+            self.context_mut()
+                .config_mut()
+                .set_directory_view(wrkdir.as_path(), remote, view);
+        }
+    }
+
+    /// Remember the current view options for the current directory, or forget them if already remembered
+    pub(super) fn toggle_directory_view(&mut self) {
+        let (wrkdir, remote) = self.current_directory();
+        if self.has_directory_view() {
+            self.context_mut()
+                .config_mut()
+                .remove_directory_view(wrkdir.as_path(), remote);
+        } else {
+            let view = match remote {
+                false => self.local().view(),
+                true => self.remote().view(),
+            };
+            self.context_mut()
+                .config_mut()
+                .set_directory_view(wrkdir.as_path(), remote, view);
+        }
+        match remote {
+            false => self.apply_local_directory_view(),
+            true => self.apply_remote_directory_view(),
+        }
+    }
+
     /// Set text editor to use
     pub(super) fn setup_text_editor(&self) {
         env::set_var("EDITOR", self.config().get_text_editor());
@@ -105,6 +256,119 @@ impl FileTransferActivity {
         path::absolutize(self.remote().wrkdir.as_path(), path)
     }
 
+    /// Expand a path typed by the user in an input field.
+    /// If `other_side` is true, the path refers to the host of the other explorer (e.g. save as destination)
+    pub(super) fn expand_path_input(
+        &mut self,
+        input: &str,
+        other_side: bool,
+    ) -> Result<String, String> {
+        let remote = matches!(
+            self.browser.tab(),
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote
+        );
+        match remote != other_side {
+            true => self.expand_remote_path(input),
+            false => self.expand_local_path(input),
+        }
+    }
+
+    /// Expand `~`, the environment variables and the wildcards in a path typed by the user for the local host.
+    /// Returns an error if the wildcards match more than one file
+    pub(super) fn expand_local_path(&mut self, input: &str) -> Result<String, String> {
+        let home = dirs::home_dir();
+        let expanded = path::expand_vars(input, home.as_deref(), |name| env::var(name).ok());
+        if !path::has_wildcards(expanded.as_str()) {
+            return Ok(expanded);
+        }
+        let pattern = self.local_to_abs_path(Path::new(expanded.as_str()));
+        let matches = path::glob_matches(pattern.as_path(), |dir| {
+            self.host
+                .scan_dir(dir)
+                .map(|files| files.into_iter().map(|x| x.name()).collect())
+                .unwrap_or_default()
+        });
+        Self::resolve_glob_matches(expanded, matches)
+    }
+
+    /// Expand `~`, the environment variables and the wildcards in a path typed by the user for the remote host.
+    /// `~` and the variables can be expanded only if commands can be executed on the remote host.
+    /// Returns an error if the wildcards match more than one file
+    pub(super) fn expand_remote_path(&mut self, input: &str) -> Result<String, String> {
+        let home = match input.starts_with('~') {
+            true => self.remote_env_var("HOME").map(PathBuf::from),
+            false => None,
+        };
+        let expanded = path::expand_vars(input, home.as_deref(), |name| self.remote_env_var(name));
+        if !path::has_wildcards(expanded.as_str()) {
+            return Ok(expanded);
+        }
+        let pattern = self.remote_to_abs_path(Path::new(expanded.as_str()));
+        let matches = path::glob_matches(pattern.as_path(), |dir| {
+            self.remote_cached_listing(dir)
+                .into_iter()
+                .map(|x| x.name())
+                .collect()
+        });
+        Self::resolve_glob_matches(expanded, matches)
+    }
+
+    /// Returns the only match of a glob pattern; if nothing matches, the pattern is kept as it is
+    fn resolve_glob_matches(pattern: String, mut matches: Vec<PathBuf>) -> Result<String, String> {
+        match matches.len() {
+            0 => Ok(pattern),
+            1 => Ok(matches.remove(0).to_string_lossy().to_string()),
+            n => Err(format!("\"{}\" matches {} files", pattern, n)),
+        }
+    }
+
+    /// Read the environment variable `name` on the remote host
+    fn remote_env_var(&mut self, name: &str) -> Option<String> {
+        if !self.capabilities.exec
+            || name.is_empty()
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return None;
+        }
+        match self.client.exec(format!("printenv {}", name).as_str()) {
+            Ok((0, output)) => Some(output.trim_end_matches('\n').to_string()),
+            Ok(_) => None,
+            Err(err) => {
+                debug!("Could not read {} on remote: {}", name, err);
+                None
+            }
+        }
+    }
+
+    /// Returns the content of the remote directory `path`, using the cached listing if available
+    pub(super) fn remote_cached_listing(&mut self, path: &Path) -> Vec<File> {
+        if let Some(files) = self.remote_cache.get(path) {
+            return files.to_vec();
+        }
+        match self.client.list_dir(path) {
+            Ok(files) => {
+                self.remote_cache.insert(path, files.clone());
+                files
+            }
+            Err(err) => {
+                debug!("Could not list {}: {}", path.display(), err);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Get local hostname
+    pub(super) fn get_local_hostname() -> String {
+        match hostname::get() {
+            Ok(h) => {
+                let hostname: String = h.as_os_str().to_string_lossy().to_string();
+                let tokens: Vec<&str> = hostname.split('.').collect();
+                String::from(*tokens.first().unwrap_or(&"localhost"))
+            }
+            Err(_) => String::from("localhost"),
+        }
+    }
+
     /// Get remote hostname
     pub(super) fn get_remote_hostname(&self) -> String {
         let ft_params = self.context().ft_params().unwrap();
@@ -122,7 +386,10 @@ impl FileTransferActivity {
                     "Client is not connected to remote; connecting to {}:{}",
                     params.address, params.port
                 );
-                format!("Connecting to {}:{}…", params.address, params.port)
+                tr_args(
+                    "wait-connecting",
+                    &[("host", &format!("{}:{}", params.address, params.port))],
+                )
             }
             ProtocolParams::AwsS3(params) => {
                 info!(
@@ -131,7 +398,7 @@ impl FileTransferActivity {
                     params.bucket_name,
                     params.region.as_deref().unwrap_or("custom")
                 );
-                format!("Connecting to {}…", params.bucket_name)
+                tr_args("wait-connecting", &[("host", &params.bucket_name)])
             }
         }
     }
@@ -204,14 +471,7 @@ impl FileTransferActivity {
             .size()
             .map(|x| (x.width / 2) - 2)
             .unwrap_or(0) as usize;
-        let hostname: String = match hostname::get() {
-            Ok(h) => {
-                let hostname: String = h.as_os_str().to_string_lossy().to_string();
-                let tokens: Vec<&str> = hostname.split('.').collect();
-                String::from(*tokens.first().unwrap_or(&"localhost"))
-            }
-            Err(_) => String::from("localhost"),
-        };
+        let hostname: String = Self::get_local_hostname();
         let hostname: String = format!(
             "{}:{} ",
             hostname,
@@ -239,6 +499,8 @@ impl FileTransferActivity {
                 AttrValue::Title((hostname, Alignment::Left))
             )
             .is_ok());
+        // Update disk space
+        self.refresh_local_status_bar();
     }
 
     /// Update remote file list
@@ -283,6 +545,8 @@ impl FileTransferActivity {
                 AttrValue::Title((hostname, Alignment::Left))
             )
             .is_ok());
+        // Update disk space
+        self.refresh_remote_status_bar();
     }
 
     /// Update log box
@@ -16,13 +16,23 @@ mod view;
 use super::{Activity, Context, ExitReason};
 use crate::config::themes::Theme;
 use crate::explorer::{FileExplorer, FileSorting};
-use crate::filetransfer::{Builder, FileTransferParams};
+use crate::filetransfer::{Builder, FileTransferParams, SymlinkPolicy};
 use crate::host::Localhost;
 use crate::system::config_client::ConfigClient;
 use crate::system::watcher::FsWatcher;
+use crate::utils::checksum::ChecksumAlgorithm;
 pub(self) use lib::browser;
 use lib::browser::Browser;
+use lib::capabilities::RemoteCapabilities;
+use lib::command_history::CommandHistory;
+use lib::dir_marks::DirMarks;
+use lib::file_metadata::RichMetadataCache;
+use lib::free_space::DiskSpace;
+use lib::listing_cache::ListingCache;
+use lib::summary::SessionSummary;
+use lib::tail::TailStates;
 use lib::transfer::{TransferOpts, TransferStates};
+use lib::watcher_log::WatcherLog;
 pub(self) use session::TransferPayload;
 
 // Includes
@@ -33,15 +43,29 @@ use std::time::Duration;
 use tempfile::TempDir;
 use tuirealm::{Application, EventListenerCfg, NoUserEvent};
 
+/// Time after which a cached remote directory listing must be read again
+const REMOTE_CACHE_TTL: Duration = Duration::from_secs(60);
+/// Idle time after which the listing of a subdirectory is read ahead
+const REMOTE_PREFETCH_INTERVAL: Duration = Duration::from_secs(1);
+
 // -- components
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 enum Id {
+    BucketPopup,
+    ChecksumPopup,
+    ChecksumResultPopup,
     CopyPopup,
+    DeleteConfirmPopup,
     DeletePopup,
+    DirHistoryPopup,
+    DirMarksPopup,
     DisconnectPopup,
+    DuplicatesPopup,
     ErrorPopup,
     ExecPopup,
+    ExecResultPopup,
+    ExportFindPopup,
     ExplorerFind,
     ExplorerLocal,
     ExplorerRemote,
@@ -49,6 +73,7 @@ enum Id {
     FileInfoPopup,
     FindPopup,
     FooterBar,
+    FreeSpacePopup,
     GlobalListener,
     GotoPopup,
     KeybindingsPopup,
@@ -63,13 +88,21 @@ enum Id {
     ReplacePopup,
     ReplacingFilesListPopup,
     SaveAsPopup,
+    SessionSummaryPopup,
     SortingPopup,
+    StartTransferPopup,
     StatusBarLocal,
     StatusBarRemote,
+    SymlinkPolicyPopup,
     SymlinkPopup,
     SyncBrowsingMkdirPopup,
+    TailPopup,
+    TransferFilterPopup,
+    TransferSummaryPopup,
+    TypeAheadPopup,
     WaitPopup,
     WatchedPathsList,
+    WatcherLogPopup,
     WatcherPopup,
 }
 
@@ -83,7 +116,9 @@ enum Msg {
 
 #[derive(Debug, PartialEq)]
 enum PendingActionMsg {
+    CloseFreeSpacePopup,
     CloseReplacePopups,
+    CloseStartTransferPopup,
     CloseSyncBrowsingMkdirPopup,
     MakePendingDirectory,
     TransferPendingFile,
@@ -92,12 +127,18 @@ enum PendingActionMsg {
 #[derive(Debug, PartialEq)]
 enum TransferMsg {
     AbortTransfer,
+    ComputeChecksum(ChecksumAlgorithm),
     CopyFileTo(String),
     CreateSymlink(String),
     DeleteFile,
     EnterDirectory,
     ExecuteCmd(String),
+    ExportFindResults(String),
+    FindDuplicates(bool),
     GoTo(String),
+    GoToHistoryDirectory(usize),
+    GoToMark(usize),
+    GoToNextDirectory,
     GoToParentDirectory,
     GoToPreviousDirectory,
     Mkdir(String),
@@ -109,20 +150,34 @@ enum TransferMsg {
     RenameFile(String),
     SaveFileAs(String),
     SearchFile(String),
+    SetMark(usize),
+    SwitchBucket(String),
     ToggleWatch,
     ToggleWatchFor(usize),
+    TransferAllFound,
     TransferFile,
+    TransferFileWithFilter(String),
+    TransferFileWithSymlinkPolicy(SymlinkPolicy),
 }
 
 #[derive(Debug, PartialEq)]
 enum UiMsg {
     ChangeFileSorting(FileSorting),
     ChangeTransferWindow,
+    CloseBucketPopup,
+    CloseChecksumPopup,
+    CloseChecksumResultPopup,
     CloseCopyPopup,
+    CloseDeleteConfirmPopup,
     CloseDeletePopup,
+    CloseDirHistoryPopup,
+    CloseDirMarksPopup,
     CloseDisconnectPopup,
+    CloseDuplicatesPopup,
     CloseErrorPopup,
     CloseExecPopup,
+    CloseExecResultPopup,
+    CloseExportFindPopup,
     CloseFatalPopup,
     CloseFileInfoPopup,
     CloseFileSortingPopup,
@@ -136,17 +191,30 @@ enum UiMsg {
     CloseQuitPopup,
     CloseRenamePopup,
     CloseSaveAsPopup,
+    CloseSymlinkPolicyPopup,
     CloseSymlinkPopup,
+    CloseTailPopup,
+    CloseTransferFilterPopup,
+    CloseTypeAheadPopup,
     CloseWatchedPathsList,
+    CloseWatcherLogPopup,
     CloseWatcherPopup,
+    CompleteGotoPath(String),
+    CopyToClipboard(String),
     Disconnect,
     LogBackTabbed,
     Quit,
     ReplacePopupTabbed,
+    ShowBucketPopup,
+    ShowChecksumPopup,
     ShowCopyPopup,
     ShowDeletePopup,
+    ShowDirHistoryPopup,
+    ShowDirMarksPopup,
     ShowDisconnectPopup,
+    ShowDuplicatesPopup,
     ShowExecPopup,
+    ShowExportFindPopup,
     ShowFileInfoPopup,
     ShowFileSortingPopup,
     ShowFindPopup,
@@ -159,11 +227,19 @@ enum UiMsg {
     ShowQuitPopup,
     ShowRenamePopup,
     ShowSaveAsPopup,
+    ShowSymlinkPolicyPopup,
     ShowSymlinkPopup,
+    ShowTailPopup,
+    ShowTransferFilterPopup,
+    ShowTypeAheadPopup,
     ShowWatchedPathsList,
+    ShowWatcherLogPopup,
     ShowWatcherPopup,
+    ToggleDirectoryView,
     ToggleHiddenFiles,
+    ToggleReverseSorting,
     ToggleSyncBrowsing,
+    TypeAhead(String),
     WindowResized,
 }
 
@@ -215,6 +291,30 @@ pub struct FileTransferActivity {
     cache: Option<TempDir>,
     /// Fs watcher
     fswatcher: Option<FsWatcher>,
+    /// Remote file being followed
+    tail: Option<TailStates>,
+    /// Operations supported by the remote host
+    capabilities: RemoteCapabilities,
+    /// Remote directory listings read during the session
+    remote_cache: ListingCache,
+    /// Statistics of the transfers made during the session
+    summary: SessionSummary,
+    /// Disk space of the local working directory
+    local_disk_space: Option<DiskSpace>,
+    /// Disk space of the remote working directory
+    remote_disk_space: Option<DiskSpace>,
+    /// Commands executed on the local host
+    local_commands: CommandHistory,
+    /// Commands executed on the remote host
+    remote_commands: CommandHistory,
+    /// Directories marked in the local explorer
+    local_marks: DirMarks,
+    /// Directories marked in the remote explorer
+    remote_marks: DirMarks,
+    /// Changes synchronized by the file watcher
+    watcher_log: WatcherLog,
+    /// Metadata read from the content of the files shown in the file info popup
+    file_metadata: RichMetadataCache,
 }
 
 impl FileTransferActivity {
@@ -247,6 +347,18 @@ impl FileTransferActivity {
                     None
                 }
             },
+            tail: None,
+            capabilities: RemoteCapabilities::default(),
+            remote_cache: ListingCache::new(REMOTE_CACHE_TTL, REMOTE_PREFETCH_INTERVAL),
+            summary: SessionSummary::default(),
+            local_disk_space: None,
+            remote_disk_space: None,
+            local_commands: CommandHistory::default(),
+            remote_commands: CommandHistory::default(),
+            local_marks: DirMarks::default(),
+            remote_marks: DirMarks::default(),
+            watcher_log: WatcherLog::default(),
+            file_metadata: RichMetadataCache::default(),
         }
     }
 
@@ -307,9 +419,12 @@ impl FileTransferActivity {
         self.context().config()
     }
 
-    /// Get a reference to `Theme`
+    /// Get a reference to `Theme`; the high contrast one in accessibility mode
     fn theme(&self) -> &Theme {
-        self.context().theme_provider().theme()
+        match self.config().get_accessibility() {
+            true => self.context().theme_provider().high_contrast_theme(),
+            false => self.context().theme_provider().theme(),
+        }
     }
 
     /// Map a function to fs watcher if any
@@ -383,9 +498,15 @@ impl Activity for FileTransferActivity {
         self.tick();
         // poll
         self.poll_watcher();
+        self.poll_tail();
         // View
         if self.redraw {
+            // Input has been handled; don't block the ui with prefetch while the user is active
+            self.remote_cache.postpone_prefetch();
             self.view();
+        } else {
+            // Idle; read ahead the listing of a subdirectory
+            self.prefetch_remote_listing();
         }
     }
 
@@ -416,6 +537,7 @@ impl Activity for FileTransferActivity {
         if self.client.is_connected() {
             let _ = self.client.disconnect();
         }
+        self.save_view_settings();
         self.context.take()
     }
 }
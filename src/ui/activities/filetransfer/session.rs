@@ -3,18 +3,33 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 // Locals
-use super::{FileTransferActivity, LogLevel};
+use super::lib::capabilities;
+use super::lib::command_history::CommandHistory;
+use super::lib::dir_marks::DirMarks;
+use super::lib::free_space::{self, DiskSpace};
+use super::lib::links;
+use super::lib::sparse::{self, SparseWriter};
+use super::lib::split::{ChunkReader, SplitManifest, MANIFEST_EXTENSION};
+use super::lib::throttle::Throttle;
+use super::lib::transfer::{self, TransferSize, TransferSummary};
+use super::{FileTransferActivity, LogLevel, RemoteCapabilities};
+use crate::filetransfer::{Builder, FileTransferProtocol, SymlinkPolicy, TransferFilter};
 use crate::host::HostError;
+use crate::ui::i18n::tr_args;
 use crate::utils::fmt::fmt_millis;
+use crate::utils::path::{absolutize, is_child_of, normalize};
+use crate::utils::string::shell_quote;
 
 // Ext
 use bytesize::ByteSize;
-use remotefs::fs::{File, ReadStream, UnixPex, Welcome, WriteStream};
-use remotefs::{RemoteError, RemoteErrorType};
+use remotefs::fs::{File, Metadata, ReadStream, UnixPex, Welcome, WriteStream};
+use remotefs::{RemoteError, RemoteErrorType, RemoteResult};
+use std::collections::HashSet;
 use std::fs::File as StdFile;
-use std::io::{Read, Seek, Write};
+use std::io::{Cursor, Read, Seek, Write};
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Buffer size for remote I/O
@@ -53,8 +68,13 @@ impl FileTransferActivity {
     pub(super) fn connect(&mut self) {
         let ft_params = self.context().ft_params().unwrap().clone();
         let entry_dir: Option<PathBuf> = ft_params.entry_directory;
+        let explicit_local_dir = ft_params.local_directory.is_some();
         // Connect to remote
-        match self.client.connect() {
+        match self
+            .client
+            .connect()
+            .or_else(|err| self.connect_with_fallback_protocol(err))
+        {
             Ok(Welcome { banner, .. }) => {
                 if let Some(banner) = banner {
                     // Log welcome
@@ -67,10 +87,25 @@ impl FileTransferActivity {
                         ),
                     );
                 }
-                // Try to change directory to entry directory
-                let mut remote_chdir: Option<PathBuf> = None;
-                if let Some(entry_directory) = &entry_dir {
-                    remote_chdir = Some(entry_directory.clone());
+                self.probe_capabilities();
+                // Restore the last session; directories given by the user take precedence over
+                // the ones of the last session
+                let last_session = self.get_last_session();
+                let restore_local = last_session.is_some() && !explicit_local_dir;
+                let restore_remote = last_session.is_some() && entry_dir.is_none();
+                let mut remote_chdir: Option<PathBuf> = entry_dir;
+                if let Some(session) = &last_session {
+                    self.local_commands = CommandHistory::new(session.local_commands.clone());
+                    self.remote_commands = CommandHistory::new(session.remote_commands.clone());
+                    self.local_marks = DirMarks::new(&session.local_marks);
+                    self.remote_marks = DirMarks::new(&session.remote_marks);
+                    if restore_remote {
+                        remote_chdir = Some(session.remote_directory.clone());
+                    }
+                    if restore_local {
+                        self.local_changedir(session.local_directory.as_path(), false);
+                        self.reload_local_dir();
+                    }
                 }
                 if let Some(entry_directory) = remote_chdir {
                     self.remote_changedir(entry_directory.as_path(), false);
@@ -81,6 +116,9 @@ impl FileTransferActivity {
                 // Update file lists
                 self.update_local_filelist();
                 self.update_remote_filelist();
+                if let Some(session) = &last_session {
+                    self.restore_last_session_entries(session, restore_local, restore_remote);
+                }
             }
             Err(err) => {
                 // Set popup fatal error
@@ -90,11 +128,138 @@ impl FileTransferActivity {
         }
     }
 
+    /// If connection with SFTP/SCP failed after establishing the SSH session (e.g. the SFTP subsystem is disabled),
+    /// try to connect with the other protocol. If the fallback fails too, the original error is returned.
+    fn connect_with_fallback_protocol(&mut self, err: RemoteError) -> RemoteResult<Welcome> {
+        let mut ft_params = self.context().ft_params().unwrap().clone();
+        let fallback = match ft_params.protocol {
+            FileTransferProtocol::Sftp if Self::is_transfer_channel_error(&err) => {
+                FileTransferProtocol::Scp
+            }
+            FileTransferProtocol::Scp if Self::is_transfer_channel_error(&err) => {
+                FileTransferProtocol::Sftp
+            }
+            _ => return Err(err),
+        };
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "Could not connect using {}: {}; trying with {}…",
+                ft_params.protocol.to_string(),
+                err,
+                fallback.to_string()
+            ),
+        );
+        let mut client = Builder::build(fallback, ft_params.params.clone(), self.config());
+        match client.connect() {
+            Ok(welcome) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Connected using {} ({} is not available on remote host)",
+                        fallback.to_string(),
+                        ft_params.protocol.to_string()
+                    ),
+                );
+                self.client = client;
+                ft_params.protocol = fallback;
+                self.context_mut().set_ftparams(ft_params);
+                Ok(welcome)
+            }
+            Err(fallback_err) => {
+                error!(
+                    "Fallback to {} failed: {}",
+                    fallback.to_string(),
+                    fallback_err
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// Returns whether `err` was raised opening the SFTP subsystem or the SCP shell channel.
+    /// Both are opened once the SSH session is established and authenticated, while handshake and
+    /// algorithms negotiation failures are reported with the same error kind
+    fn is_transfer_channel_error(err: &RemoteError) -> bool {
+        // NOTE: libssh2 channel errors (failure, request denied, closed) and SFTP status codes
+        const CHANNEL_ERRORS: [&str; 6] = [
+            "[Session(-21)]",
+            "[Session(-22)]",
+            "[Session(-26)]",
+            "[SFTP(",
+            "Could not open channel",
+            "Could not execute command",
+        ];
+        err.kind == RemoteErrorType::ProtocolError
+            && err
+                .msg
+                .as_deref()
+                .map(|msg| CHANNEL_ERRORS.iter().any(|x| msg.contains(x)))
+                .unwrap_or(false)
+    }
+
+    /// Probe which optional operations are supported by the remote host, looking for the commands
+    /// they require when commands can be executed on it
+    fn probe_capabilities(&mut self) {
+        let protocol = self.context().ft_params().unwrap().protocol;
+        let probe = match protocol {
+            FileTransferProtocol::Scp | FileTransferProtocol::Sftp => {
+                match self.client.exec(capabilities::PROBE_COMMAND) {
+                    Ok((0, output)) => Some(output),
+                    Ok((rc, output)) => {
+                        debug!("capabilities probe exited with {}: {}", rc, output);
+                        None
+                    }
+                    Err(err) => {
+                        debug!("could not probe capabilities: {}", err);
+                        None
+                    }
+                }
+            }
+            FileTransferProtocol::Ftp(_) | FileTransferProtocol::AwsS3 => None,
+        };
+        self.capabilities = RemoteCapabilities::new(protocol, probe.as_deref());
+        debug!("remote capabilities: {:?}", self.capabilities);
+        let unsupported = self.capabilities.unsupported();
+        if !unsupported.is_empty() {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "These operations are not supported by the remote host: {}",
+                    unsupported.join(", ")
+                ),
+            );
+        }
+        let emulated = self.capabilities.emulated();
+        if !emulated.is_empty() {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "These operations will be performed downloading and uploading files again: {}",
+                    emulated.join(", ")
+                ),
+            );
+        }
+        if self.config().get_partial_uploads() && !self.capabilities.rename {
+            self.log(
+                LogLevel::Info,
+                String::from(
+                    "Partial uploads are disabled, since files can't be renamed on the remote host",
+                ),
+            );
+        }
+    }
+
     /// disconnect from remote
     pub(super) fn disconnect(&mut self) {
-        let msg: String = format!("Disconnecting from {}…", self.get_remote_hostname());
+        let msg = tr_args(
+            "wait-disconnecting",
+            &[("host", &self.get_remote_hostname())],
+        );
         // Show popup disconnecting
         self.mount_wait(msg.as_str());
+        self.save_last_session();
+        self.log_session_summary();
         // Disconnect
         let _ = self.client.disconnect();
         // Quit
@@ -107,13 +272,29 @@ impl FileTransferActivity {
         self.exit_reason = Some(super::ExitReason::Quit);
     }
 
+    /// Write the summary of the transfers made during the session to the log
+    fn log_session_summary(&mut self) {
+        let msg = format!(
+            "Session summary for {}: {}",
+            self.get_remote_hostname(),
+            self.summary.lines().join("; ")
+        );
+        self.log(LogLevel::Info, msg);
+    }
+
     /// Reload remote directory entries and update browser
     pub(super) fn reload_remote_dir(&mut self) {
         // Get current entries
         if let Ok(wrkdir) = self.client.pwd() {
             self.remote_scan(wrkdir.as_path());
+            // Reading the disk space takes two commands, so it's refreshed only when the working
+            // directory changes, and not after each entry of a transfer
+            if wrkdir != self.remote().wrkdir {
+                self.remote_disk_space = self.remote_disk_space(wrkdir.as_path());
+            }
             // Set wrkdir
             self.remote_mut().wrkdir = wrkdir;
+            self.apply_remote_directory_view();
         }
     }
 
@@ -121,7 +302,9 @@ impl FileTransferActivity {
     pub(super) fn reload_local_dir(&mut self) {
         let wrkdir: PathBuf = self.host.pwd();
         self.local_scan(wrkdir.as_path());
+        self.local_disk_space = free_space::local_disk_space(wrkdir.as_path());
         self.local_mut().wrkdir = wrkdir;
+        self.apply_local_directory_view();
     }
 
     /// Scan current local directory
@@ -142,8 +325,17 @@ impl FileTransferActivity {
 
     /// Scan current remote directory
     fn remote_scan(&mut self, path: &Path) {
+        if let Some(files) = self.remote_cache.get(path) {
+            trace!("Using cached listing for {}", path.display());
+            let files = files.to_vec();
+            self.prefetch_subdirs(files.as_slice());
+            self.remote_mut().set_files(files);
+            return;
+        }
         match self.client.list_dir(path) {
             Ok(files) => {
+                self.remote_cache.insert(path, files.clone());
+                self.prefetch_subdirs(files.as_slice());
                 // Set files and sort (sorting is implicit)
                 self.remote_mut().set_files(files);
             }
@@ -156,6 +348,30 @@ impl FileTransferActivity {
         }
     }
 
+    /// Queue the subdirectories in `files` for prefetch
+    fn prefetch_subdirs(&mut self, files: &[File]) {
+        self.remote_cache.prefetch(
+            files
+                .iter()
+                .filter(|x| x.is_dir())
+                .map(|x| x.path().to_path_buf()),
+        );
+    }
+
+    /// Read the listing of the next directory queued for prefetch and store it in the cache
+    pub(super) fn prefetch_remote_listing(&mut self) {
+        if !self.client.is_connected() {
+            return;
+        }
+        if let Some(path) = self.remote_cache.next_prefetch() {
+            trace!("Prefetching listing of {}", path.display());
+            match self.client.list_dir(path.as_path()) {
+                Ok(files) => self.remote_cache.insert(path.as_path(), files),
+                Err(err) => debug!("Could not prefetch {}: {}", path.display(), err),
+            }
+        }
+    }
+
     /// Send fs entry to remote.
     /// If dst_name is Some, entry will be saved with a different name.
     /// If entry is a directory, this applies to directory only
@@ -186,9 +402,16 @@ impl FileTransferActivity {
                 self.notify_transfer_error(e.as_str());
             }
         }
+        self.refresh_remote_disk_space();
         result
     }
 
+    /// Read again the disk space of the remote working directory
+    pub(super) fn refresh_remote_disk_space(&mut self) {
+        let wrkdir = self.remote().wrkdir.clone();
+        self.remote_disk_space = self.remote_disk_space(wrkdir.as_path());
+    }
+
     /// Send one file to remote at specified path.
     fn filetransfer_send_file(
         &mut self,
@@ -201,8 +424,13 @@ impl FileTransferActivity {
         // Calculate total size of transfer
         let total_transfer_size: usize = file.metadata.size as usize;
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, curr_remote_path, true)?;
         // Mount progress bar
-        self.mount_progress_bar(format!("Uploading {}…", file.path.display()));
+        self.mount_progress_bar(tr_args(
+            "progress-uploading",
+            &[("file", &file.path.display())],
+        ));
         // Get remote path
         let file_name: String = file.name();
         let mut remote_path: PathBuf = PathBuf::from(curr_remote_path);
@@ -229,10 +457,16 @@ impl FileTransferActivity {
         // Reset states
         self.transfer.reset();
         // Calculate total size of transfer
-        let total_transfer_size: usize = self.get_total_transfer_size_local(entry);
+        let total_transfer_size: usize = self.get_total_transfer_size_local(entry).bytes;
+        self.transfer.clear_visited();
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, curr_remote_path, true)?;
         // Mount progress bar
-        self.mount_progress_bar(format!("Uploading {}…", entry.path().display()));
+        self.mount_progress_bar(tr_args(
+            "progress-uploading",
+            &[("file", &entry.path().display())],
+        ));
         // Send recurse
         let result = self.filetransfer_send_recurse(entry, curr_remote_path, dst_name);
         // Umount progress bar
@@ -246,16 +480,28 @@ impl FileTransferActivity {
         entries: &[File],
         curr_remote_path: &Path,
     ) -> Result<(), String> {
-        // Reset states
-        self.transfer.reset();
-        // Calculate total size of transfer
-        let total_transfer_size: usize = entries
-            .iter()
-            .map(|x| self.get_total_transfer_size_local(x))
-            .sum();
+        // Calculate total size of transfer, unless computed by `should_start_transfer_of`
+        let sizes = match self.take_planned_sizes(entries) {
+            Some(sizes) => sizes,
+            None => {
+                self.transfer.reset();
+                let sizes: Vec<TransferSize> = entries
+                    .iter()
+                    .map(|x| self.get_total_transfer_size_local(x))
+                    .collect();
+                self.transfer.clear_visited();
+                sizes
+            }
+        };
+        let total_transfer_size: usize = sizes.iter().map(|x| x.bytes).sum();
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, curr_remote_path, true)?;
         // Mount progress bar
-        self.mount_progress_bar(format!("Uploading {} entries…", entries.len()));
+        self.mount_progress_bar(tr_args(
+            "progress-uploading-entries",
+            &[("count", &entries.len())],
+        ));
         // Send recurse
         let result = entries
             .iter()
@@ -282,8 +528,41 @@ impl FileTransferActivity {
             None => PathBuf::from(file_name.as_str()),
         };
         remote_path.push(remote_file_name);
+        // Handle symlinks according to policy
+        let is_dir: bool = if entry.is_symlink() {
+            match self.symlink_policy() {
+                SymlinkPolicy::Skip => {
+                    self.log(
+                        LogLevel::Info,
+                        format!("Skipped symlink \"{}\"", entry.path().display()),
+                    );
+                    return Ok(());
+                }
+                SymlinkPolicy::CopyAsLink => {
+                    self.filetransfer_send_symlink(entry, remote_path.as_path());
+                    return Ok(());
+                }
+                SymlinkPolicy::Follow => match Self::local_symlink_is_dir(entry) {
+                    Ok(is_dir) => is_dir,
+                    Err(err) => {
+                        self.log(
+                            LogLevel::Warn,
+                            format!("Skipped symlink \"{}\": {}", entry.path().display(), err),
+                        );
+                        return Ok(());
+                    }
+                },
+            }
+        } else {
+            entry.is_dir()
+        };
+        // Symlinks may lead to a directory which has already been sent
+        if is_dir && !self.transfer.visit_local_dir(entry.path()) {
+            self.log_skipped_visited_dir(entry.path());
+            return Ok(());
+        }
         // Match entry
-        let result: Result<(), String> = if entry.is_dir() {
+        let result: Result<(), String> = if is_dir {
             // Create directory on remote first
             match self
                 .client
@@ -294,6 +573,7 @@ impl FileTransferActivity {
                         LogLevel::Info,
                         format!("Created directory \"{}\"", remote_path.display()),
                     );
+                    self.apply_remote_ownership(remote_path.as_path(), entry);
                 }
                 Err(err) if err.kind == RemoteErrorType::DirectoryAlreadyExists => {
                     self.log(
@@ -319,12 +599,16 @@ impl FileTransferActivity {
             // Get files in dir
             match self.host.scan_dir(entry.path()) {
                 Ok(entries) => {
+                    let filter = self.transfer_filter();
                     // Iterate over files
                     for entry in entries.iter() {
                         // If aborted; break
                         if self.transfer.aborted() {
                             break;
                         }
+                        if self.skip_filtered_entry(filter.as_ref(), entry) {
+                            continue;
+                        }
                         // Send entry; name is always None after first call
                         self.filetransfer_send_recurse(entry, remote_path.as_path(), None)?
                     }
@@ -342,14 +626,25 @@ impl FileTransferActivity {
                     Err(err.to_string())
                 }
             }
+        } else if links::is_local_special_file(entry.path()) {
+            self.log(
+                LogLevel::Warn,
+                format!("Skipped special file \"{}\"", entry.path().display()),
+            );
+            Ok(())
+        } else if self.filetransfer_send_hard_link(entry, remote_path.as_path()) {
+            Ok(())
         } else {
             match self.filetransfer_send_one(entry, remote_path.as_path(), file_name) {
                 Err(err) => {
-                    // If transfer was abrupted or there was an IO error on remote, remove file
+                    // If transfer was abrupted or there was an IO error on remote, remove file.
+                    // Partial uploads handle their temporary file themselves, and the destination
+                    // still holds the previous version of the file, so it must not be removed
                     if matches!(
                         err,
                         TransferErrorReason::Abrupted | TransferErrorReason::RemoteIoError(_)
-                    ) {
+                    ) && !self.partial_uploads()
+                    {
                         // Stat file on remote and remove it if exists
                         match self.client.stat(remote_path.as_path()) {
                             Err(err) => self.log(
@@ -379,6 +674,11 @@ impl FileTransferActivity {
                 Ok(_) => Ok(()),
             }
         };
+        // The destination has been written: its listing and its parent's can't be used anymore
+        self.remote_cache.invalidate(remote_path.as_path());
+        if let Some(parent) = remote_path.parent() {
+            self.remote_cache.invalidate(parent);
+        }
         // Scan dir on remote
         self.reload_remote_dir();
         // If aborted; show popup
@@ -398,6 +698,51 @@ impl FileTransferActivity {
         local: &File,
         remote: &Path,
         file_name: String,
+    ) -> Result<(), TransferErrorReason> {
+        // Existing files can't be overwritten in read-only mode
+        if self.read_only() && self.remote_file_exists(remote) {
+            self.log(
+                LogLevel::Warn,
+                format!(
+                    "Skipped \"{}\": files can't be overwritten in read-only mode",
+                    remote.display()
+                ),
+            );
+            return Ok(());
+        }
+        let split_size = self
+            .config()
+            .get_split_size()
+            .filter(|x| local.metadata().size > *x);
+        let result = match split_size {
+            Some(chunk_size) => {
+                self.filetransfer_send_one_split(local, remote, file_name, chunk_size)
+            }
+            None if self.partial_uploads() => {
+                self.filetransfer_send_one_partial(local, remote, file_name)
+            }
+            None => self.filetransfer_send_one_file(local, remote, file_name),
+        };
+        match result {
+            Ok(_) => {
+                self.summary.uploaded(local.metadata().size);
+                self.summary
+                    .rate(self.transfer.partial.calc_bytes_per_second());
+                // Chunks are not meant to be used as they are, so ownership is not preserved
+                if split_size.is_none() {
+                    self.apply_remote_ownership(remote, local);
+                }
+            }
+            Err(_) => self.summary.failed(),
+        }
+        result
+    }
+
+    fn filetransfer_send_one_file(
+        &mut self,
+        local: &File,
+        remote: &Path,
+        file_name: String,
     ) -> Result<(), TransferErrorReason> {
         // Sync file size and attributes before transfer
         let metadata = self
@@ -413,7 +758,7 @@ impl FileTransferActivity {
                     self.filetransfer_send_one_with_stream(local, remote, file_name, fhnd, rhnd)
                 }
                 Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => {
-                    self.filetransfer_send_one_wno_stream(local, remote, file_name, fhnd)
+                    self.filetransfer_send_one_wno_stream(local, remote, file_name, &metadata, fhnd)
                 }
                 Err(err) => Err(TransferErrorReason::FileTransferError(err)),
             },
@@ -421,15 +766,200 @@ impl FileTransferActivity {
         }
     }
 
+    /// Returns whether files must be uploaded to a temporary file, renamed once complete.
+    /// Requires the remote host to support renaming files
+    fn partial_uploads(&self) -> bool {
+        self.config().get_partial_uploads() && self.capabilities.rename
+    }
+
+    /// Send local file to a temporary `.part` file next to `remote`, then rename it to `remote`,
+    /// so that the file appears at `remote` only once it has been completely written
+    fn filetransfer_send_one_partial(
+        &mut self,
+        local: &File,
+        remote: &Path,
+        file_name: String,
+    ) -> Result<(), TransferErrorReason> {
+        let partial = transfer::partial_path(remote);
+        if let Err(err) = self.filetransfer_send_one_file(local, partial.as_path(), file_name) {
+            self.remove_partial_file(partial.as_path());
+            return Err(err);
+        }
+        let err = match self.client.mov(partial.as_path(), remote) {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+        if !self.remote_file_exists(remote) {
+            self.remove_partial_file(partial.as_path());
+            return Err(TransferErrorReason::FileTransferError(err));
+        }
+        // Some servers refuse to rename a file over an existing one; replace it then
+        if let Err(err) = self.client.remove_file(remote) {
+            self.remove_partial_file(partial.as_path());
+            return Err(TransferErrorReason::FileTransferError(err));
+        }
+        if let Err(err) = self.client.mov(partial.as_path(), remote) {
+            // The destination has been removed already: the uploaded file is the only copy left
+            self.log_and_alert(
+                LogLevel::Error,
+                format!(
+                    "Could not rename \"{}\" to \"{}\" after removing the existing file; the uploaded file has been kept at \"{}\"",
+                    partial.display(),
+                    remote.display(),
+                    partial.display()
+                ),
+            );
+            return Err(TransferErrorReason::FileTransferError(err));
+        }
+        Ok(())
+    }
+
+    /// Remove a partially uploaded file
+    fn remove_partial_file(&mut self, partial: &Path) {
+        if let Err(err) = self.client.remove_file(partial) {
+            self.log(
+                LogLevel::Warn,
+                format!(
+                    "Could not remove partial file \"{}\": {}",
+                    partial.display(),
+                    err
+                ),
+            );
+        }
+    }
+
+    /// Send local file to remote as chunks of `chunk_size` bytes, along with the manifest
+    /// describing how to join them back
+    fn filetransfer_send_one_split(
+        &mut self,
+        local: &File,
+        remote: &Path,
+        file_name: String,
+        chunk_size: u64,
+    ) -> Result<(), TransferErrorReason> {
+        let metadata = self
+            .host
+            .stat(local.path.as_path())
+            .map_err(TransferErrorReason::HostError)
+            .map(|x| x.metadata().clone())?;
+        let remote_dir = remote.parent().unwrap_or_else(|| Path::new("/"));
+        let remote_name = remote
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_else(|| local.name());
+        let manifest = SplitManifest::new(remote_name, metadata.size, chunk_size);
+        let chunks: Vec<PathBuf> = manifest.chunks.iter().map(|x| remote_dir.join(x)).collect();
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Splitting \"{}\" into {} chunks of {}",
+                local.path.display(),
+                chunks.len(),
+                ByteSize(chunk_size)
+            ),
+        );
+        for (i, (chunk, (offset, len))) in chunks.iter().zip(manifest.chunk_ranges()).enumerate() {
+            let result = match self.transfer.aborted() {
+                true => Err(TransferErrorReason::Abrupted),
+                false => self.filetransfer_send_one_chunk(
+                    local,
+                    chunk,
+                    format!("{} ({}/{})", file_name, i + 1, chunks.len()),
+                    &metadata,
+                    offset,
+                    len,
+                ),
+            };
+            if let Err(err) = result {
+                self.remove_remote_chunks(&chunks[..=i]);
+                return Err(err);
+            }
+        }
+        // Write manifest
+        let manifest_path = remote_dir.join(manifest.manifest_name());
+        let data = manifest.to_string().into_bytes();
+        let mut manifest_metadata = metadata;
+        manifest_metadata.size = data.len() as u64;
+        if let Err(err) = self.client.create_file(
+            &manifest_path,
+            &manifest_metadata,
+            Box::new(Cursor::new(data)),
+        ) {
+            self.remove_remote_chunks(&chunks);
+            return Err(TransferErrorReason::FileTransferError(err));
+        }
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Saved split manifest for \"{}\" to \"{}\"",
+                local.path.display(),
+                manifest_path.display()
+            ),
+        );
+        Ok(())
+    }
+
+    /// Send `len` bytes starting at `offset` of the local file to the remote chunk file
+    fn filetransfer_send_one_chunk(
+        &mut self,
+        local: &File,
+        remote: &Path,
+        file_name: String,
+        metadata: &Metadata,
+        offset: u64,
+        len: u64,
+    ) -> Result<(), TransferErrorReason> {
+        let mut metadata = metadata.clone();
+        metadata.size = len;
+        let reader = self
+            .host
+            .open_file_read(local.path.as_path())
+            .map_err(TransferErrorReason::HostError)
+            .and_then(|x| {
+                ChunkReader::new(x, offset, len).map_err(TransferErrorReason::LocalIoError)
+            })?;
+        match self.client.create(remote, &metadata) {
+            Ok(rhnd) => {
+                self.filetransfer_send_one_with_stream(local, remote, file_name, reader, rhnd)
+            }
+            Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => {
+                self.filetransfer_send_one_wno_stream(local, remote, file_name, &metadata, reader)
+            }
+            Err(err) => Err(TransferErrorReason::FileTransferError(err)),
+        }
+    }
+
+    /// Remove the chunks uploaded before a split upload failed
+    fn remove_remote_chunks(&mut self, chunks: &[PathBuf]) {
+        for chunk in chunks.iter() {
+            if let Err(err) = self.client.remove_file(chunk.as_path()) {
+                self.log(
+                    LogLevel::Warn,
+                    format!("Could not remove chunk \"{}\": {}", chunk.display(), err),
+                );
+            }
+        }
+    }
+
     /// Send file to remote using stream
-    fn filetransfer_send_one_with_stream(
+    fn filetransfer_send_one_with_stream<R: Read + Seek>(
         &mut self,
         local: &File,
         remote: &Path,
         file_name: String,
-        mut reader: StdFile,
-        mut writer: WriteStream,
+        mut reader: R,
+        writer: WriteStream,
     ) -> Result<(), TransferErrorReason> {
+        // Preserve holes if the file is sparse and the remote stream allows to seek
+        let sparse = writer.seekable() && sparse::is_sparse(local.path());
+        if sparse {
+            debug!(
+                "{} is sparse; holes will be preserved",
+                local.path().display()
+            );
+        }
+        let mut writer = SparseWriter::new(writer, sparse);
+        let mut throttle = Throttle::new(self.context().ft_params().and_then(|x| x.upload_limit));
         // Write file
         let file_size: usize = reader.seek(std::io::SeekFrom::End(0)).unwrap_or(0) as usize;
         // Init transfer
@@ -441,6 +971,7 @@ impl FileTransferActivity {
         // Write remote file
         let mut total_bytes_written: usize = 0;
         let mut last_progress_val: f64 = 0.0;
+        let redraw_step = self.progress_redraw_step();
         let mut last_input_event_fetch: Option<Instant> = None;
         // While the entire file hasn't been completely written,
         // Or filetransfer has been aborted
@@ -488,15 +1019,19 @@ impl FileTransferActivity {
             // Increase progress
             self.transfer.partial.update_progress(delta);
             self.transfer.full.update_progress(delta);
+            self.throttle_transfer(&mut throttle, delta);
             // Draw only if a significant progress has been made (performance improvement)
-            if last_progress_val < self.transfer.partial.calc_progress() - 0.01 {
+            if last_progress_val < self.transfer.partial.calc_progress() - redraw_step {
                 // Draw
-                self.update_progress_bar(format!("Uploading \"{}\"…", file_name));
+                self.update_progress_bar(tr_args("progress-uploading", &[("file", &file_name)]));
                 self.view();
                 last_progress_val = self.transfer.partial.calc_progress();
             }
         }
         // Finalize stream
+        let writer = writer
+            .finish()
+            .map_err(TransferErrorReason::RemoteIoError)?;
         if let Err(err) = self.client.on_written(writer) {
             self.log(
                 LogLevel::Warn,
@@ -521,19 +1056,14 @@ impl FileTransferActivity {
     }
 
     /// Send an `File` to remote without using streams.
-    fn filetransfer_send_one_wno_stream(
+    fn filetransfer_send_one_wno_stream<R: Read + Seek + 'static>(
         &mut self,
         local: &File,
         remote: &Path,
         file_name: String,
-        mut reader: StdFile,
+        metadata: &Metadata,
+        mut reader: R,
     ) -> Result<(), TransferErrorReason> {
-        // Sync file size and attributes before transfer
-        let metadata = self
-            .host
-            .stat(local.path.as_path())
-            .map_err(TransferErrorReason::HostError)
-            .map(|x| x.metadata().clone())?;
         // Write file
         let file_size: usize = reader.seek(std::io::SeekFrom::End(0)).unwrap_or(0) as usize;
         // Init transfer
@@ -543,17 +1073,17 @@ impl FileTransferActivity {
             return Err(TransferErrorReason::CouldNotRewind(err));
         }
         // Draw before
-        self.update_progress_bar(format!("Uploading \"{}\"…", file_name));
+        self.update_progress_bar(tr_args("progress-uploading", &[("file", &file_name)]));
         self.view();
         // Send file
-        if let Err(err) = self.client.create_file(remote, &metadata, Box::new(reader)) {
+        if let Err(err) = self.client.create_file(remote, metadata, Box::new(reader)) {
             return Err(TransferErrorReason::FileTransferError(err));
         }
         // Set transfer size ok
         self.transfer.partial.update_progress(file_size);
         self.transfer.full.update_progress(file_size);
         // Draw again after
-        self.update_progress_bar(format!("Uploading \"{}\"…", file_name));
+        self.update_progress_bar(tr_args("progress-uploading", &[("file", &file_name)]));
         self.view();
         // log and return Ok
         self.log(
@@ -609,10 +1139,18 @@ impl FileTransferActivity {
         // Reset states
         self.transfer.reset();
         // Calculate total transfer size
-        let total_transfer_size: usize = self.get_total_transfer_size_remote(entry);
+        let total_transfer_size: usize = self.get_total_transfer_size_remote(entry).bytes;
+        self.transfer.clear_visited();
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, local_path, false)?;
+        // Look for hard links and special files
+        self.scan_remote_links(entry);
         // Mount progress bar
-        self.mount_progress_bar(format!("Downloading {}…", entry.path().display()));
+        self.mount_progress_bar(tr_args(
+            "progress-downloading",
+            &[("file", &entry.path().display())],
+        ));
         // Receive
         let result = self.filetransfer_recv_recurse(entry, local_path, dst_name);
         // Umount progress bar
@@ -627,8 +1165,13 @@ impl FileTransferActivity {
         // Calculate total transfer size
         let total_transfer_size: usize = entry.metadata.size as usize;
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, local_path, false)?;
         // Mount progress bar
-        self.mount_progress_bar(format!("Downloading {}…", entry.path.display()));
+        self.mount_progress_bar(tr_args(
+            "progress-downloading",
+            &[("file", &entry.path.display())],
+        ));
         // Receive
         let result = self.filetransfer_recv_one(local_path, entry, entry.name());
         // Umount progress bar
@@ -643,16 +1186,30 @@ impl FileTransferActivity {
         entries: &[File],
         curr_remote_path: &Path,
     ) -> Result<(), String> {
-        // Reset states
-        self.transfer.reset();
-        // Calculate total size of transfer
-        let total_transfer_size: usize = entries
-            .iter()
-            .map(|x| self.get_total_transfer_size_remote(x))
-            .sum();
+        // Calculate total size of transfer, unless computed by `should_start_transfer_of`
+        let sizes = match self.take_planned_sizes(entries) {
+            Some(sizes) => sizes,
+            None => {
+                self.transfer.reset();
+                let sizes: Vec<TransferSize> = entries
+                    .iter()
+                    .map(|x| self.get_total_transfer_size_remote(x))
+                    .collect();
+                self.transfer.clear_visited();
+                sizes
+            }
+        };
+        let total_transfer_size: usize = sizes.iter().map(|x| x.bytes).sum();
         self.transfer.full.init(total_transfer_size);
+        // Check free space on destination
+        self.check_free_space(total_transfer_size, curr_remote_path, false)?;
+        // Look for hard links and special files
+        entries.iter().for_each(|x| self.scan_remote_links(x));
         // Mount progress bar
-        self.mount_progress_bar(format!("Downloading {} entries…", entries.len()));
+        self.mount_progress_bar(tr_args(
+            "progress-downloading-entries",
+            &[("count", &entries.len())],
+        ));
         // Send recurse
         let result = entries
             .iter()
@@ -672,8 +1229,43 @@ impl FileTransferActivity {
     ) -> Result<(), String> {
         // Write popup
         let file_name = entry.name();
+        // Handle symlinks according to policy; followed links are listed at their target
+        let (is_dir, dir_path): (bool, PathBuf) = if entry.is_symlink() {
+            match self.symlink_policy() {
+                SymlinkPolicy::Skip => {
+                    self.log(
+                        LogLevel::Info,
+                        format!("Skipped symlink \"{}\"", entry.path().display()),
+                    );
+                    return Ok(());
+                }
+                SymlinkPolicy::CopyAsLink => {
+                    let mut local_link_path: PathBuf = PathBuf::from(local_path);
+                    local_link_path.push(dst_name.unwrap_or_else(|| entry.name()));
+                    self.filetransfer_recv_symlink(entry, local_link_path.as_path());
+                    return Ok(());
+                }
+                SymlinkPolicy::Follow => match self.remote_symlink_target(entry) {
+                    Ok(target) => (target.is_dir(), target.path),
+                    Err(err) => {
+                        self.log(
+                            LogLevel::Warn,
+                            format!("Skipped symlink \"{}\": {}", entry.path().display(), err),
+                        );
+                        return Ok(());
+                    }
+                },
+            }
+        } else {
+            (entry.is_dir(), entry.path().to_path_buf())
+        };
+        // Symlinks may lead to a directory which has already been received
+        if is_dir && !self.transfer.visit_remote_dir(dir_path.as_path()) {
+            self.log_skipped_visited_dir(entry.path());
+            return Ok(());
+        }
         // Match entry
-        let result: Result<(), String> = if entry.is_dir() {
+        let result: Result<(), String> = if is_dir {
             // Get dir name
             let mut local_dir_path: PathBuf = PathBuf::from(local_path);
             match dst_name {
@@ -698,19 +1290,37 @@ impl FileTransferActivity {
                             );
                         }
                     }
+                    self.apply_local_ownership(local_dir_path.as_path(), entry);
                     self.log(
                         LogLevel::Info,
                         format!("Created directory \"{}\"", local_dir_path.display()),
                     );
-                    // Get files in dir
-                    match self.client.list_dir(entry.path()) {
+                    // Get files in dir; use the listing read while computing the transfer size if any
+                    let entries = match self.transfer.take_listing(dir_path.as_path()) {
+                        Some(entries) => Ok(entries),
+                        None => self.client.list_dir(dir_path.as_path()),
+                    };
+                    match entries {
                         Ok(entries) => {
+                            let filter = self.transfer_filter();
+                            // Chunks are joined when receiving their manifest
+                            let manifests: HashSet<String> = entries
+                                .iter()
+                                .map(|x| x.name())
+                                .filter(|x| SplitManifest::is_manifest(x))
+                                .collect();
                             // Iterate over files
                             for entry in entries.iter() {
                                 // If transfer has been aborted; break
                                 if self.transfer.aborted() {
                                     break;
                                 }
+                                if self.skip_filtered_entry(filter.as_ref(), entry) {
+                                    continue;
+                                }
+                                if Self::is_split_chunk(&manifests, entry) {
+                                    continue;
+                                }
                                 // Receive entry; name is always None after first call
                                 // Local path becomes local_dir_path
                                 self.filetransfer_recv_recurse(
@@ -754,15 +1364,24 @@ impl FileTransferActivity {
                 None => entry.name(),
             };
             local_file_path.push(local_file_name.as_str());
-            // Download file
-            if let Err(err) =
+            if self.transfer.links.is_remote_special_file(entry.path()) {
+                self.log(
+                    LogLevel::Warn,
+                    format!("Skipped special file \"{}\"", entry.path().display()),
+                );
+                Ok(())
+            } else if self.filetransfer_recv_hard_link(entry, local_file_path.as_path()) {
+                Ok(())
+            } else if let Err(err) =
                 self.filetransfer_recv_one(local_file_path.as_path(), entry, file_name)
             {
-                // If transfer was abrupted or there was an IO error on remote, remove file
+                // If transfer was abrupted or there was an IO error on remote, remove file.
+                // Split downloads remove the joined file on their own
                 if matches!(
                     err,
                     TransferErrorReason::Abrupted | TransferErrorReason::LocalIoError(_)
-                ) {
+                ) && !SplitManifest::is_manifest(entry.name().as_str())
+                {
                     // Stat file
                     match self.host.stat(local_file_path.as_path()) {
                         Err(err) => self.log(
@@ -811,6 +1430,168 @@ impl FileTransferActivity {
         local: &Path,
         remote: &File,
         file_name: String,
+    ) -> Result<(), TransferErrorReason> {
+        let split = SplitManifest::is_manifest(remote.name().as_str());
+        let result = match split {
+            true => self.filetransfer_recv_one_split(local, remote, file_name),
+            false => self
+                .filetransfer_recv_one_file(local, remote, file_name)
+                .map(|_| remote.metadata().size),
+        };
+        match result {
+            Ok(size) => {
+                self.summary.downloaded(size);
+                self.summary
+                    .rate(self.transfer.partial.calc_bytes_per_second());
+                if !split {
+                    self.apply_local_ownership(local, remote);
+                }
+            }
+            Err(_) => self.summary.failed(),
+        }
+        result.map(|_| ())
+    }
+
+    /// Receive the chunks listed in the split manifest `remote` and join them into the file it describes.
+    /// The joined file is written to `local` without the manifest extension.
+    /// Returns the size of the joined file
+    fn filetransfer_recv_one_split(
+        &mut self,
+        local: &Path,
+        remote: &File,
+        file_name: String,
+    ) -> Result<u64, TransferErrorReason> {
+        let manifest = self.read_split_manifest(remote)?;
+        let local = match local
+            .to_string_lossy()
+            .strip_suffix(format!(".{}", MANIFEST_EXTENSION).as_str())
+        {
+            Some(path) => PathBuf::from(path),
+            None => local.to_path_buf(),
+        };
+        let writer = self
+            .host
+            .open_file_write(local.as_path())
+            .map_err(TransferErrorReason::HostError)?;
+        let remote_dir = remote.path().parent().unwrap_or_else(|| Path::new("/"));
+        let result = self.filetransfer_recv_chunks(
+            local.as_path(),
+            remote_dir,
+            &manifest,
+            file_name,
+            writer,
+        );
+        if result.is_err() {
+            // Remove the partially joined file
+            if let Err(err) = self
+                .host
+                .stat(local.as_path())
+                .and_then(|x| self.host.remove(&x))
+            {
+                self.log(
+                    LogLevel::Error,
+                    format!("Could not remove created file {}: {}", local.display(), err),
+                );
+            }
+        }
+        result.map(|_| manifest.size)
+    }
+
+    /// Read the split manifest at `remote`
+    fn read_split_manifest(&mut self, remote: &File) -> Result<SplitManifest, TransferErrorReason> {
+        let mut tmp = tempfile::tempfile().map_err(TransferErrorReason::LocalIoError)?;
+        let writer = tmp.try_clone().map_err(TransferErrorReason::LocalIoError)?;
+        self.client
+            .open_file(remote.path(), Box::new(writer))
+            .map_err(TransferErrorReason::FileTransferError)?;
+        let mut data = String::new();
+        tmp.rewind()
+            .and_then(|_| tmp.read_to_string(&mut data))
+            .map_err(TransferErrorReason::LocalIoError)?;
+        SplitManifest::from_str(data.as_str()).map_err(|err| {
+            TransferErrorReason::FileTransferError(RemoteError::new_ex(
+                RemoteErrorType::BadFile,
+                err,
+            ))
+        })
+    }
+
+    /// Receive the chunks listed in `manifest`, located in `remote_dir`, appending them to `writer`
+    fn filetransfer_recv_chunks(
+        &mut self,
+        local: &Path,
+        remote_dir: &Path,
+        manifest: &SplitManifest,
+        file_name: String,
+        writer: StdFile,
+    ) -> Result<(), TransferErrorReason> {
+        for (i, chunk) in manifest.chunks.iter().enumerate() {
+            if self.transfer.aborted() {
+                return Err(TransferErrorReason::Abrupted);
+            }
+            let chunk_name = format!("{} ({}/{})", file_name, i + 1, manifest.chunks.len());
+            let chunk = self
+                .client
+                .stat(remote_dir.join(chunk).as_path())
+                .map_err(TransferErrorReason::FileTransferError)?;
+            // Clones share the cursor, so each chunk is written after the previous one
+            let writer = writer
+                .try_clone()
+                .map_err(TransferErrorReason::LocalIoError)?;
+            match self.client.open(chunk.path()) {
+                Ok(rhnd) => {
+                    self.filetransfer_recv_one_with_stream(local, &chunk, chunk_name, rhnd, writer)?
+                }
+                Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => {
+                    self.transfer.partial.init(chunk.metadata().size as usize);
+                    self.update_progress_bar(tr_args(
+                        "progress-downloading",
+                        &[("file", &chunk_name)],
+                    ));
+                    self.view();
+                    self.client
+                        .open_file(chunk.path(), Box::new(writer))
+                        .map_err(TransferErrorReason::FileTransferError)?;
+                    self.transfer
+                        .partial
+                        .update_progress(chunk.metadata().size as usize);
+                    self.transfer
+                        .full
+                        .update_progress(chunk.metadata().size as usize);
+                }
+                Err(err) => return Err(TransferErrorReason::FileTransferError(err)),
+            }
+        }
+        // Make sure the joined file is complete
+        let size = writer
+            .metadata()
+            .map(|x| x.len())
+            .map_err(TransferErrorReason::LocalIoError)?;
+        if size != manifest.size {
+            return Err(TransferErrorReason::FileTransferError(RemoteError::new_ex(
+                RemoteErrorType::BadFile,
+                format!(
+                    "joined file is {} bytes long, but {} bytes were expected",
+                    size, manifest.size
+                ),
+            )));
+        }
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Joined {} chunks into \"{}\"",
+                manifest.chunks.len(),
+                local.display()
+            ),
+        );
+        Ok(())
+    }
+
+    fn filetransfer_recv_one_file(
+        &mut self,
+        local: &Path,
+        remote: &File,
+        file_name: String,
     ) -> Result<(), TransferErrorReason> {
         // Try to open local file
         match self.host.open_file_write(local) {
@@ -837,13 +1618,17 @@ impl FileTransferActivity {
         remote: &File,
         file_name: String,
         mut reader: ReadStream,
-        mut writer: StdFile,
+        writer: StdFile,
     ) -> Result<(), TransferErrorReason> {
+        // Holes can't be detected on remote files, so blocks of zeros are skipped
+        let mut writer = SparseWriter::new(writer, true);
+        let mut throttle = Throttle::new(self.context().ft_params().and_then(|x| x.download_limit));
         let mut total_bytes_written: usize = 0;
         // Init transfer
         self.transfer.partial.init(remote.metadata.size as usize);
         // Write local file
         let mut last_progress_val: f64 = 0.0;
+        let redraw_step = self.progress_redraw_step();
         let mut last_input_event_fetch: Option<Instant> = None;
         // While the entire file hasn't been completely read,
         // Or filetransfer has been aborted
@@ -889,10 +1674,11 @@ impl FileTransferActivity {
             // Set progress
             self.transfer.partial.update_progress(delta);
             self.transfer.full.update_progress(delta);
+            self.throttle_transfer(&mut throttle, delta);
             // Draw only if a significant progress has been made (performance improvement)
-            if last_progress_val < self.transfer.partial.calc_progress() - 0.01 {
+            if last_progress_val < self.transfer.partial.calc_progress() - redraw_step {
                 // Draw
-                self.update_progress_bar(format!("Downloading \"{}\"", file_name));
+                self.update_progress_bar(tr_args("progress-downloading", &[("file", &file_name)]));
                 self.view();
                 last_progress_val = self.transfer.partial.calc_progress();
             }
@@ -908,6 +1694,7 @@ impl FileTransferActivity {
         if self.transfer.aborted() {
             return Err(TransferErrorReason::Abrupted);
         }
+        writer.finish().map_err(TransferErrorReason::LocalIoError)?;
         // Apply file mode to file
         #[cfg(target_family = "unix")]
         if let Some(mode) = remote.metadata.mode {
@@ -953,7 +1740,7 @@ impl FileTransferActivity {
         // Init transfer
         self.transfer.partial.init(remote.metadata.size as usize);
         // Draw before transfer
-        self.update_progress_bar(format!("Downloading \"{}\"", file_name));
+        self.update_progress_bar(tr_args("progress-downloading", &[("file", &file_name)]));
         self.view();
         // recv wno stream
         if let Err(err) = self.client.open_file(remote.path.as_path(), reader) {
@@ -967,7 +1754,7 @@ impl FileTransferActivity {
             .full
             .update_progress(remote.metadata.size as usize);
         // Draw after transfer
-        self.update_progress_bar(format!("Downloading \"{}\"", file_name));
+        self.update_progress_bar(tr_args("progress-downloading", &[("file", &file_name)]));
         self.view();
         // Apply file mode to file
         #[cfg(target_family = "unix")]
@@ -1080,15 +1867,506 @@ impl FileTransferActivity {
         }
     }
 
+    /// Returns the progress which must be made before redrawing the progress bars.
+    /// In accessibility mode the progress bars are redrawn less often, to reduce motion
+    fn progress_redraw_step(&self) -> f64 {
+        match self.config().get_accessibility() {
+            true => 0.1,
+            false => 0.01,
+        }
+    }
+
+    /// Wait as long as required by `throttle` after transferring `bytes`.
+    /// Input events are still handled while waiting, so the transfer can be aborted
+    fn throttle_transfer(&mut self, throttle: &mut Throttle, bytes: usize) {
+        let mut wait = throttle.consume(bytes);
+        while !wait.is_zero() && !self.transfer.aborted() {
+            let slice = wait.min(Duration::from_millis(500));
+            std::thread::sleep(slice);
+            wait -= slice;
+            self.tick();
+        }
+    }
+
+    /// Compute the size of the local `entries` to upload, if `upload` is true, or of the remote
+    /// entries to download otherwise. Then show the amount of files, the total size and the largest
+    /// entries of the transfer and ask the user whether to start it.
+    /// The sizes and the listings read are kept for the transfer of the entries, which must follow
+    pub(super) fn should_start_transfer_of(&mut self, entries: &[File], upload: bool) -> bool {
+        self.transfer.reset();
+        let sizes: Vec<TransferSize> = entries
+            .iter()
+            .map(|x| match upload {
+                true => self.get_total_transfer_size_local(x),
+                false => self.get_total_transfer_size_remote(x),
+            })
+            .collect();
+        self.transfer.clear_visited();
+        let summary = TransferSummary::new(
+            entries
+                .iter()
+                .map(|x| x.name())
+                .zip(sizes.iter().copied())
+                .collect(),
+        );
+        if !self.should_start_transfer(&summary) {
+            self.transfer.reset();
+            return false;
+        }
+        entries
+            .iter()
+            .zip(sizes)
+            .for_each(|(x, size)| self.transfer.plan(x.path(), size));
+        true
+    }
+
+    /// Take the sizes of `entries` computed by `should_start_transfer_of`.
+    /// Returns `None` if any of them hasn't been computed
+    fn take_planned_sizes(&mut self, entries: &[File]) -> Option<Vec<TransferSize>> {
+        entries
+            .iter()
+            .map(|x| self.transfer.take_planned(x.path()))
+            .collect()
+    }
+
+    /// Check whether the destination has enough space to store `size` bytes.
+    /// If it doesn't, the user is asked whether to transfer anyway.
+    /// If the available space can't be determined, the transfer is allowed
+    fn check_free_space(&mut self, size: usize, dest: &Path, remote: bool) -> Result<(), String> {
+        let space = if remote {
+            self.remote_disk_space(dest)
+        } else {
+            free_space::local_disk_space(dest)
+        };
+        let (available, quota) = match space {
+            Some(space) if (size as u64) > space.available => (space.available, space.quota),
+            _ => return Ok(()),
+        };
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "Not enough space on {}: {} required, {} available{}",
+                dest.display(),
+                ByteSize(size as u64),
+                ByteSize(available),
+                if quota { " within your quota" } else { "" }
+            ),
+        );
+        if self.should_transfer_without_space(size as u64, available) {
+            Ok(())
+        } else {
+            Err(format!(
+                "not enough space on destination ({} required, {} available)",
+                ByteSize(size as u64),
+                ByteSize(available)
+            ))
+        }
+    }
+
+    /// Get the disk space on the remote host for `path` running `df`, if exec is supported.
+    /// If the user has a disk quota on the filesystem, the space is limited to it
+    fn remote_disk_space(&mut self, path: &Path) -> Option<DiskSpace> {
+        if !self.capabilities.exec {
+            return None;
+        }
+        let cmd = free_space::df_command(path);
+        let output = match self.client.exec(cmd.as_str()) {
+            Ok((0, output)) => output,
+            Ok((rc, output)) => {
+                debug!("\"{}\" exited with {}: {}", cmd, rc, output);
+                return None;
+            }
+            Err(err) => {
+                debug!("could not execute \"{}\": {}", cmd, err);
+                return None;
+            }
+        };
+        let space = free_space::parse_df_output(output.as_str())?;
+        let filesystem = match free_space::parse_df_filesystem(output.as_str()) {
+            Some(filesystem) => filesystem,
+            None => return Some(space),
+        };
+        // quota exits with an error when the quota is exceeded, so the output is always parsed
+        match self.client.exec(free_space::QUOTA_COMMAND) {
+            Ok((_, quota)) => match free_space::parse_quota_output(quota.as_str(), filesystem) {
+                Some(quota) => Some(space.with_quota(quota)),
+                None => Some(space),
+            },
+            Err(err) => {
+                debug!(
+                    "could not execute \"{}\": {}",
+                    free_space::QUOTA_COMMAND,
+                    err
+                );
+                Some(space)
+            }
+        }
+    }
+
+    // -- symlinks
+
+    /// Get the policy for the symlinks met during the transfer
+    fn symlink_policy(&self) -> SymlinkPolicy {
+        self.transfer
+            .symlink_policy
+            .unwrap_or_else(|| self.config().get_symlink_policy())
+    }
+
+    // -- filter
+
+    /// Get the include/exclude patterns for the entries met while recursing
+    fn transfer_filter(&self) -> Option<TransferFilter> {
+        self.transfer
+            .filter
+            .clone()
+            .or_else(|| self.config().get_transfer_filter())
+    }
+
+    /// Returns whether `entry` is a chunk of a split file, whose manifest is in `manifests`
+    fn is_split_chunk(manifests: &HashSet<String>, entry: &File) -> bool {
+        let name = entry.name();
+        entry.is_file()
+            && SplitManifest::chunk_of(name.as_str())
+                .map(|x| manifests.contains(&format!("{}.{}", x, MANIFEST_EXTENSION)))
+                .unwrap_or(false)
+    }
+
+    /// Returns whether `entry` is excluded by `filter`
+    fn is_filtered_out(filter: Option<&TransferFilter>, entry: &File) -> bool {
+        filter
+            .map(|x| x.excludes(entry.name().as_str(), entry.is_dir()))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether `entry` is excluded by `filter` and must be skipped; skipped entries are logged
+    fn skip_filtered_entry(&mut self, filter: Option<&TransferFilter>, entry: &File) -> bool {
+        let skip = Self::is_filtered_out(filter, entry);
+        if skip {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "Skipped \"{}\" (excluded by filter)",
+                    entry.path().display()
+                ),
+            );
+        }
+        skip
+    }
+
+    /// Returns whether the local symlink `entry` points to a directory.
+    /// Returns error if the link is broken or if it points to one of its parent directories,
+    /// since following it would make the transfer loop
+    fn local_symlink_is_dir(entry: &File) -> Result<bool, String> {
+        let target = std::fs::canonicalize(entry.path()).map_err(|e| e.to_string())?;
+        let link_dir = entry
+            .path()
+            .parent()
+            .and_then(|x| std::fs::canonicalize(x).ok())
+            .unwrap_or_default();
+        if is_child_of(link_dir.as_path(), target.as_path()) {
+            return Err(String::from("link points to a parent directory"));
+        }
+        Ok(target.is_dir())
+    }
+
+    /// Returns the target of the remote symlink `entry`, at its absolute and normalized path.
+    /// Returns error if the link is broken or if it points to one of its parent directories,
+    /// since following it would make the transfer loop
+    fn remote_symlink_target(&mut self, entry: &File) -> Result<File, String> {
+        let link_dir = entry.path().parent().unwrap_or_else(|| Path::new("/"));
+        let target = match entry.metadata().symlink.as_deref() {
+            Some(target) => normalize(absolutize(link_dir, target).as_path()),
+            None => return Err(String::from("unknown link target")),
+        };
+        if is_child_of(link_dir, target.as_path()) {
+            return Err(String::from("link points to a parent directory"));
+        }
+        self.client
+            .stat(target.as_path())
+            .map(|x| File {
+                path: target,
+                metadata: x.metadata,
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    /// Log that the directory at `path` is skipped, since it has already been transferred
+    fn log_skipped_visited_dir(&mut self, path: &Path) {
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "Skipped directory \"{}\": already transferred (symlink loop)",
+                path.display()
+            ),
+        );
+    }
+
+    /// Create a symlink on remote at `remote_path` pointing to the target of the local symlink `entry`
+    fn filetransfer_send_symlink(&mut self, entry: &File, remote_path: &Path) {
+        let target = match entry.metadata().symlink.as_deref() {
+            Some(target) => target.to_path_buf(),
+            None => return,
+        };
+        if !self.capabilities.symlink {
+            self.log(
+                LogLevel::Warn,
+                format!(
+                    "Skipped symlink \"{}\": symlinks are not supported by this protocol",
+                    entry.path().display()
+                ),
+            );
+            return;
+        }
+        match self.client.symlink(remote_path, target.as_path()) {
+            Ok(_) => self.log(
+                LogLevel::Info,
+                format!(
+                    "Created symlink \"{}\" -> \"{}\"",
+                    remote_path.display(),
+                    target.display()
+                ),
+            ),
+            Err(err) => self.log(
+                LogLevel::Error,
+                format!(
+                    "Could not create symlink \"{}\": {}",
+                    remote_path.display(),
+                    err
+                ),
+            ),
+        }
+    }
+
+    /// Create a symlink on localhost at `local_path` pointing to the target of the remote symlink `entry`
+    fn filetransfer_recv_symlink(&mut self, entry: &File, local_path: &Path) {
+        let target = match entry.metadata().symlink.as_deref() {
+            Some(target) => target.to_path_buf(),
+            None => return,
+        };
+        #[cfg(target_family = "unix")]
+        match self.host.symlink(local_path, target.as_path()) {
+            Ok(_) => self.log(
+                LogLevel::Info,
+                format!(
+                    "Created symlink \"{}\" -> \"{}\"",
+                    local_path.display(),
+                    target.display()
+                ),
+            ),
+            Err(err) => self.log(
+                LogLevel::Error,
+                format!(
+                    "Could not create symlink \"{}\": {}",
+                    local_path.display(),
+                    err
+                ),
+            ),
+        }
+        #[cfg(target_os = "windows")]
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "Skipped symlink \"{}\": symlinks are not supported on this system",
+                entry.path().display()
+            ),
+        );
+    }
+
+    /// Set the owner of the remote file at `remote_path`, uploaded from `local`, according to the
+    /// configured ownership mapping. Requires the protocol to support command execution
+    fn apply_remote_ownership(&mut self, remote_path: &Path, local: &File) {
+        let map = match self.config().get_ownership_map() {
+            Some(map) if self.capabilities.exec => map,
+            _ => return,
+        };
+        let owner = match (
+            map.map_uid(local.metadata().uid),
+            map.map_gid(local.metadata().gid),
+        ) {
+            (Some(uid), Some(gid)) => format!("{}:{}", uid, gid),
+            (Some(uid), None) => uid.to_string(),
+            (None, Some(gid)) => format!(":{}", gid),
+            (None, None) => return,
+        };
+        let cmd = format!(
+            "chown {} {}",
+            owner,
+            shell_quote(remote_path.to_string_lossy().as_ref())
+        );
+        match self.client.exec(cmd.as_str()) {
+            Ok((0, _)) => {}
+            Ok((_, output)) => self.log(
+                LogLevel::Error,
+                format!(
+                    "Could not change owner of \"{}\" to {}: {}",
+                    remote_path.display(),
+                    owner,
+                    output.trim()
+                ),
+            ),
+            Err(err) => self.log(
+                LogLevel::Error,
+                format!(
+                    "Could not change owner of \"{}\" to {}: {}",
+                    remote_path.display(),
+                    owner,
+                    err
+                ),
+            ),
+        }
+    }
+
+    /// Set the owner of the local file at `local_path`, downloaded from `remote`, according to the
+    /// configured ownership mapping
+    #[cfg(target_family = "unix")]
+    fn apply_local_ownership(&mut self, local_path: &Path, remote: &File) {
+        let map = match self.config().get_ownership_map() {
+            Some(map) => map,
+            None => return,
+        };
+        let uid = map.map_uid(remote.metadata().uid);
+        let gid = map.map_gid(remote.metadata().gid);
+        if uid.is_none() && gid.is_none() {
+            return;
+        }
+        if let Err(err) = std::os::unix::fs::chown(local_path, uid, gid) {
+            self.log(
+                LogLevel::Error,
+                format!(
+                    "Could not change owner of \"{}\": {}",
+                    local_path.display(),
+                    err
+                ),
+            );
+        }
+    }
+
+    /// Ownership is not preserved on Windows
+    #[cfg(target_os = "windows")]
+    fn apply_local_ownership(&mut self, _local_path: &Path, _remote: &File) {}
+
+    /// Look for hard links and special files under the remote directory `entry`.
+    /// Requires `find` on the remote host; otherwise no hard link is preserved
+    fn scan_remote_links(&mut self, entry: &File) {
+        if !entry.is_dir() || !self.capabilities.hard_links {
+            return;
+        }
+        let cmd = links::remote_scan_command(entry.path());
+        match self.client.exec(cmd.as_str()) {
+            Ok((_, output)) => self.transfer.links.store_remote_scan(output.as_str()),
+            Err(err) => debug!("could not execute \"{}\": {}", cmd, err),
+        }
+    }
+
+    /// If the local file `entry` is a hard link to a file which has already been uploaded,
+    /// create a hard link to it at `remote_path` and return true.
+    /// Returns false if the file must be uploaded
+    fn filetransfer_send_hard_link(&mut self, entry: &File, remote_path: &Path) -> bool {
+        let first = match links::local_file_id(entry.path())
+            .and_then(|id| self.transfer.links.register(id, remote_path))
+        {
+            Some(first) => first,
+            None => return false,
+        };
+        // `ln -f` would replace an existing file, which is not allowed in read-only mode;
+        // the file is uploaded instead, which checks whether it exists first
+        if !self.capabilities.hard_links || self.read_only() {
+            return false;
+        }
+        let cmd = format!(
+            "ln -f {} {}",
+            shell_quote(first.to_string_lossy().as_ref()),
+            shell_quote(remote_path.to_string_lossy().as_ref())
+        );
+        match self.client.exec(cmd.as_str()) {
+            Ok((0, _)) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Created hard link \"{}\" => \"{}\"",
+                        remote_path.display(),
+                        first.display()
+                    ),
+                );
+                self.transfer
+                    .full
+                    .update_progress(entry.metadata().size as usize);
+                true
+            }
+            Ok((rc, output)) => {
+                debug!("\"{}\" exited with {}: {}", cmd, rc, output);
+                false
+            }
+            Err(err) => {
+                debug!("could not execute \"{}\": {}", cmd, err);
+                false
+            }
+        }
+    }
+
+    /// If the remote file `entry` is a hard link to a file which has already been downloaded,
+    /// create a hard link to it at `local_path` and return true.
+    /// Returns false if the file must be downloaded
+    fn filetransfer_recv_hard_link(&mut self, entry: &File, local_path: &Path) -> bool {
+        let first = match self
+            .transfer
+            .links
+            .remote_file_id(entry.path())
+            .and_then(|id| self.transfer.links.register(id, local_path))
+        {
+            Some(first) => first,
+            None => return false,
+        };
+        if local_path.exists() {
+            let _ = std::fs::remove_file(local_path);
+        }
+        match std::fs::hard_link(first.as_path(), local_path) {
+            Ok(_) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Created hard link \"{}\" => \"{}\"",
+                        local_path.display(),
+                        first.display()
+                    ),
+                );
+                self.transfer
+                    .full
+                    .update_progress(entry.metadata().size as usize);
+                true
+            }
+            Err(err) => {
+                debug!(
+                    "could not create hard link {}: {}",
+                    local_path.display(),
+                    err
+                );
+                false
+            }
+        }
+    }
+
     // -- transfer sizes
 
-    /// Get total size of transfer for localhost
-    fn get_total_transfer_size_local(&mut self, entry: &File) -> usize {
-        if entry.is_dir() {
+    /// Get total size and amount of files of transfer for localhost
+    fn get_total_transfer_size_local(&mut self, entry: &File) -> TransferSize {
+        let is_dir = match entry.is_symlink() {
+            true if self.symlink_policy() != SymlinkPolicy::Follow => {
+                return TransferSize::default()
+            }
+            true => Self::local_symlink_is_dir(entry).unwrap_or(false),
+            false => entry.is_dir(),
+        };
+        if is_dir && !self.transfer.visit_local_dir(entry.path()) {
+            return TransferSize::default();
+        }
+        if is_dir {
             // List dir
+            let filter = self.transfer_filter();
             match self.host.scan_dir(entry.path()) {
                 Ok(files) => files
                     .iter()
+                    .filter(|x| !Self::is_filtered_out(filter.as_ref(), x))
                     .map(|x| self.get_total_transfer_size_local(x))
                     .sum(),
                 Err(err) => {
@@ -1100,23 +2378,43 @@ impl FileTransferActivity {
                             err
                         ),
                     );
-                    0
+                    TransferSize::default()
                 }
             }
         } else {
-            entry.metadata.size as usize
+            TransferSize::file(entry.metadata.size as usize)
         }
     }
 
-    /// Get total size of transfer for remote host
-    fn get_total_transfer_size_remote(&mut self, entry: &File) -> usize {
-        if entry.is_dir() {
+    /// Get total size and amount of files of transfer for remote host
+    fn get_total_transfer_size_remote(&mut self, entry: &File) -> TransferSize {
+        let (is_dir, dir_path) = match entry.is_symlink() {
+            true if self.symlink_policy() != SymlinkPolicy::Follow => {
+                return TransferSize::default()
+            }
+            true => match self.remote_symlink_target(entry) {
+                Ok(target) => (target.is_dir(), target.path),
+                Err(_) => return TransferSize::default(),
+            },
+            false => (entry.is_dir(), entry.path().to_path_buf()),
+        };
+        if is_dir && !self.transfer.visit_remote_dir(dir_path.as_path()) {
+            return TransferSize::default();
+        }
+        if is_dir {
             // List directory
-            match self.client.list_dir(entry.path()) {
-                Ok(files) => files
-                    .iter()
-                    .map(|x| self.get_total_transfer_size_remote(x))
-                    .sum(),
+            let filter = self.transfer_filter();
+            match self.client.list_dir(dir_path.as_path()) {
+                Ok(files) => {
+                    let size = files
+                        .iter()
+                        .filter(|x| !Self::is_filtered_out(filter.as_ref(), x))
+                        .map(|x| self.get_total_transfer_size_remote(x))
+                        .sum();
+                    // Keep listing for the transfer, in order not to read the directory twice
+                    self.transfer.store_listing(dir_path.as_path(), files);
+                    size
+                }
                 Err(err) => {
                     self.log(
                         LogLevel::Error,
@@ -1126,11 +2424,11 @@ impl FileTransferActivity {
                             err
                         ),
                     );
-                    0
+                    TransferSize::default()
                 }
             }
         } else {
-            entry.metadata.size as usize
+            TransferSize::file(entry.metadata.size as usize)
         }
     }
 
@@ -8,8 +8,12 @@ use super::{
     browser::{FileExplorerTab, FoundExplorerTab},
     ExitReason, FileTransferActivity, Id, Msg, TransferMsg, TransferOpts, UiMsg,
 };
+use crate::filetransfer::TransferFilter;
+use crate::ui::i18n::{tr, tr_args};
 // externals
 use remotefs::fs::File;
+use std::path::Path;
+use std::str::FromStr;
 use tuirealm::{
     props::{AttrValue, Attribute},
     State, StateValue, Update,
@@ -31,13 +35,41 @@ impl Update<Msg> for FileTransferActivity {
 
 impl FileTransferActivity {
     fn update_transfer(&mut self, msg: TransferMsg) -> Option<Msg> {
+        // Cached listings can be kept only while browsing
+        if !matches!(
+            msg,
+            TransferMsg::EnterDirectory
+                | TransferMsg::GoTo(_)
+                | TransferMsg::GoToHistoryDirectory(_)
+                | TransferMsg::GoToMark(_)
+                | TransferMsg::GoToNextDirectory
+                | TransferMsg::GoToParentDirectory
+                | TransferMsg::GoToPreviousDirectory
+        ) {
+            self.remote_cache.clear();
+        }
         match msg {
             TransferMsg::AbortTransfer => {
                 self.transfer.abort();
             }
+            TransferMsg::ComputeChecksum(algorithm) => {
+                self.umount_checksum();
+                self.mount_blocking_wait(
+                    tr_args("wait-computing", &[("algorithm", &algorithm.name())]).as_str(),
+                );
+                self.action_checksum(algorithm);
+                self.umount_wait();
+            }
             TransferMsg::CopyFileTo(dest) => {
+                let dest = match self.expand_path_input(dest.as_str(), false) {
+                    Ok(dest) => dest,
+                    Err(err) => {
+                        self.mount_error(err);
+                        return None;
+                    }
+                };
                 self.umount_copy();
-                self.mount_blocking_wait("Copying file(s)…");
+                self.mount_blocking_wait(tr("wait-copying").as_str());
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_copy(dest),
                     FileExplorerTab::Remote => self.action_remote_copy(dest),
@@ -49,7 +81,7 @@ impl FileTransferActivity {
             }
             TransferMsg::CreateSymlink(name) => {
                 self.umount_symlink();
-                self.mount_blocking_wait("Creating symlink…");
+                self.mount_blocking_wait(tr("wait-creating-symlink").as_str());
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_symlink(name),
                     FileExplorerTab::Remote => self.action_remote_symlink(name),
@@ -61,7 +93,8 @@ impl FileTransferActivity {
             }
             TransferMsg::DeleteFile => {
                 self.umount_radio_delete();
-                self.mount_blocking_wait("Removing file(s)…");
+                self.umount_delete_confirm();
+                self.mount_blocking_wait(tr("wait-removing").as_str());
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_delete(),
                     FileExplorerTab::Remote => self.action_remote_delete(),
@@ -75,12 +108,17 @@ impl FileTransferActivity {
                                 self.found_mut().unwrap().del_entry(idx);
                             }
                             Ok(State::Vec(values)) => {
-                                values
+                                let mut indexes: Vec<usize> = values
                                     .iter()
                                     .map(|x| match x {
                                         StateValue::Usize(v) => *v,
                                         _ => 0,
                                     })
+                                    .collect();
+                                // Remove from the last one, otherwise indexes would shift
+                                indexes.sort_unstable_by(|a, b| b.cmp(a));
+                                indexes
+                                    .into_iter()
                                     .for_each(|x| self.found_mut().unwrap().del_entry(x));
                             }
                             _ => {}
@@ -131,7 +169,7 @@ impl FileTransferActivity {
             TransferMsg::ExecuteCmd(cmd) => {
                 // Exex command
                 self.umount_exec();
-                self.mount_blocking_wait(format!("Executing '{}'…", cmd).as_str());
+                self.mount_blocking_wait(tr_args("wait-executing", &[("command", &cmd)]).as_str());
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_exec(cmd),
                     FileExplorerTab::Remote => self.action_remote_exec(cmd),
@@ -141,7 +179,34 @@ impl FileTransferActivity {
                 // Reload files
                 self.update_browser_file_list()
             }
+            TransferMsg::ExportFindResults(dest) => {
+                let dest = match self.expand_local_path(dest.as_str()) {
+                    Ok(dest) => self.local_to_abs_path(Path::new(dest.as_str())),
+                    Err(err) => {
+                        self.mount_error(err);
+                        return None;
+                    }
+                };
+                self.umount_export_find();
+                self.action_find_export(dest.as_path());
+            }
+            TransferMsg::FindDuplicates(recursive) => {
+                self.umount_duplicates();
+                self.action_find_duplicates(recursive);
+            }
             TransferMsg::GoTo(dir) => {
+                // Keep the popup open if the path is not a directory
+                let dir = match self.expand_path_input(dir.as_str(), false) {
+                    Ok(dir) => dir,
+                    Err(err) => {
+                        self.mount_error(err);
+                        return None;
+                    }
+                };
+                if let Err(err) = self.check_goto_dir(dir.as_str()) {
+                    self.mount_error(err);
+                    return None;
+                }
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_change_local_dir(dir),
                     FileExplorerTab::Remote => self.action_change_remote_dir(dir),
@@ -156,6 +221,43 @@ impl FileTransferActivity {
                 // Reload files
                 self.update_browser_file_list()
             }
+            TransferMsg::GoToHistoryDirectory(index) => {
+                match self.browser.tab() {
+                    FileExplorerTab::Local => self.action_go_to_local_history_dir(index),
+                    FileExplorerTab::Remote => self.action_go_to_remote_history_dir(index),
+                    _ => {}
+                }
+                self.umount_dir_history();
+                // Reload files
+                self.update_browser_file_list()
+            }
+            TransferMsg::GoToMark(slot) => {
+                self.umount_dir_marks();
+                self.action_go_to_dir_mark(slot);
+                // Reload files
+                self.update_browser_file_list()
+            }
+            TransferMsg::GoToNextDirectory => {
+                match self.browser.tab() {
+                    FileExplorerTab::Local => {
+                        self.action_go_to_next_local_dir();
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_remote_filelist();
+                        }
+                        // Reload file list component
+                        self.update_local_filelist()
+                    }
+                    FileExplorerTab::Remote => {
+                        self.action_go_to_next_remote_dir();
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_local_filelist();
+                        }
+                        // Reload file list component
+                        self.update_remote_filelist()
+                    }
+                    _ => {}
+                }
+            }
             TransferMsg::GoToParentDirectory => {
                 match self.browser.tab() {
                     FileExplorerTab::Local => {
@@ -174,7 +276,13 @@ impl FileTransferActivity {
                         // Reload file list component
                         self.update_remote_filelist()
                     }
-                    _ => {}
+                    FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => {
+                        // Jump to the directory containing the selected result
+                        self.action_find_goto_parent_dir();
+                        self.umount_find();
+                        self.finalize_find();
+                        self.update_browser_file_list()
+                    }
                 }
             }
             TransferMsg::GoToPreviousDirectory => {
@@ -234,6 +342,9 @@ impl FileTransferActivity {
                 self.umount_openwith();
             }
             TransferMsg::OpenTextFile => {
+                if !self.is_operation_allowed("Editing files") {
+                    return None;
+                }
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_edit_local_file(),
                     FileExplorerTab::Remote => self.action_edit_remote_file(),
@@ -241,10 +352,15 @@ impl FileTransferActivity {
                 }
                 self.update_browser_file_list()
             }
-            TransferMsg::ReloadDir => self.update_browser_file_list(),
+            TransferMsg::ReloadDir => {
+                if matches!(self.browser.tab(), FileExplorerTab::Remote) {
+                    self.refresh_remote_disk_space();
+                }
+                self.update_browser_file_list()
+            }
             TransferMsg::RenameFile(dest) => {
                 self.umount_rename();
-                self.mount_blocking_wait("Moving file(s)…");
+                self.mount_blocking_wait(tr("wait-moving").as_str());
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_rename(dest),
                     FileExplorerTab::Remote => self.action_remote_rename(dest),
@@ -255,6 +371,13 @@ impl FileTransferActivity {
                 self.update_browser_file_list()
             }
             TransferMsg::SaveFileAs(dest) => {
+                let dest = match self.expand_path_input(dest.as_str(), true) {
+                    Ok(dest) => dest,
+                    Err(err) => {
+                        self.mount_error(err);
+                        return None;
+                    }
+                };
                 self.umount_saveas();
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_saveas(dest),
@@ -271,7 +394,9 @@ impl FileTransferActivity {
             TransferMsg::SearchFile(search) => {
                 self.umount_find_input();
                 // Mount wait
-                self.mount_blocking_wait(format!(r#"Searching for "{}"…"#, search).as_str());
+                self.mount_blocking_wait(
+                    tr_args("wait-searching", &[("search", &search)]).as_str(),
+                );
                 // Find
                 let res: Result<Vec<File>, String> = match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_find(search.clone()),
@@ -308,7 +433,7 @@ impl FileTransferActivity {
                             wrkdir.as_path(),
                         );
                         // Mount result widget
-                        self.mount_find(&search);
+                        self.mount_find(format!(r#"Search results for "{}""#, search));
                         self.update_find_list();
                         // Initialize tab
                         self.browser.change_tab(match self.browser.tab() {
@@ -319,8 +444,19 @@ impl FileTransferActivity {
                     }
                 }
             }
+            TransferMsg::SetMark(slot) => {
+                self.action_set_dir_mark(slot);
+            }
+            TransferMsg::SwitchBucket(bucket) => {
+                self.umount_bucket();
+                self.action_switch_bucket(bucket);
+            }
             TransferMsg::ToggleWatch => self.action_toggle_watch(),
             TransferMsg::ToggleWatchFor(index) => self.action_toggle_watch_for(index),
+            TransferMsg::TransferAllFound => {
+                self.action_find_transfer_all();
+                self.update_browser_file_list_swapped();
+            }
             TransferMsg::TransferFile => {
                 match self.browser.tab() {
                     FileExplorerTab::Local => self.action_local_send(),
@@ -331,6 +467,35 @@ impl FileTransferActivity {
                 }
                 self.update_browser_file_list_swapped();
             }
+            TransferMsg::TransferFileWithFilter(filter) => {
+                let filter = match TransferFilter::from_str(filter.as_str()) {
+                    Ok(filter) => filter,
+                    Err(err) => {
+                        self.mount_error(format!("Invalid transfer filter: {}", err));
+                        return None;
+                    }
+                };
+                self.umount_transfer_filter();
+                match self.browser.tab() {
+                    FileExplorerTab::Local => self.action_local_send_with_filter(filter),
+                    FileExplorerTab::Remote => self.action_remote_recv_with_filter(filter),
+                    FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => {
+                        self.action_find_transfer(TransferOpts::default().filter(filter))
+                    }
+                }
+                self.update_browser_file_list_swapped();
+            }
+            TransferMsg::TransferFileWithSymlinkPolicy(policy) => {
+                self.umount_symlink_policy();
+                match self.browser.tab() {
+                    FileExplorerTab::Local => self.action_local_send_with_symlink_policy(policy),
+                    FileExplorerTab::Remote => self.action_remote_recv_with_symlink_policy(policy),
+                    FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => {
+                        self.action_find_transfer(TransferOpts::default().symlink_policy(policy))
+                    }
+                }
+                self.update_browser_file_list_swapped();
+            }
         }
         // Force redraw
         self.redraw = true;
@@ -350,6 +515,7 @@ impl FileTransferActivity {
                         self.refresh_remote_status_bar()
                     }
                 }
+                self.update_directory_view();
                 self.update_browser_file_list();
             }
             UiMsg::ChangeTransferWindow => {
@@ -375,11 +541,20 @@ impl FileTransferActivity {
                 }
                 self.browser.change_tab(new_tab);
             }
+            UiMsg::CloseBucketPopup => self.umount_bucket(),
+            UiMsg::CloseChecksumPopup => self.umount_checksum(),
+            UiMsg::CloseChecksumResultPopup => self.umount_checksum_result(),
             UiMsg::CloseCopyPopup => self.umount_copy(),
+            UiMsg::CloseDeleteConfirmPopup => self.umount_delete_confirm(),
             UiMsg::CloseDeletePopup => self.umount_radio_delete(),
+            UiMsg::CloseDirHistoryPopup => self.umount_dir_history(),
+            UiMsg::CloseDirMarksPopup => self.umount_dir_marks(),
             UiMsg::CloseDisconnectPopup => self.umount_disconnect(),
+            UiMsg::CloseDuplicatesPopup => self.umount_duplicates(),
             UiMsg::CloseErrorPopup => self.umount_error(),
             UiMsg::CloseExecPopup => self.umount_exec(),
+            UiMsg::CloseExecResultPopup => self.umount_exec_result(),
+            UiMsg::CloseExportFindPopup => self.umount_export_find(),
             UiMsg::CloseFatalPopup => {
                 self.umount_fatal();
                 self.exit_reason = Some(ExitReason::Disconnect);
@@ -399,9 +574,19 @@ impl FileTransferActivity {
             UiMsg::CloseQuitPopup => self.umount_quit(),
             UiMsg::CloseRenamePopup => self.umount_rename(),
             UiMsg::CloseSaveAsPopup => self.umount_saveas(),
+            UiMsg::CloseSymlinkPolicyPopup => self.umount_symlink_policy(),
             UiMsg::CloseSymlinkPopup => self.umount_symlink(),
+            UiMsg::CloseTailPopup => self.action_stop_tail(),
+            UiMsg::CloseTransferFilterPopup => self.umount_transfer_filter(),
+            UiMsg::CloseTypeAheadPopup => self.umount_type_ahead(),
             UiMsg::CloseWatchedPathsList => self.umount_watched_paths_list(),
+            UiMsg::CloseWatcherLogPopup => self.umount_watcher_log(),
             UiMsg::CloseWatcherPopup => self.umount_radio_watcher(),
+            UiMsg::CompleteGotoPath(input) => self.action_complete_goto_path(input),
+            UiMsg::CopyToClipboard(text) => {
+                self.umount_checksum_result();
+                self.action_copy_to_clipboard(text);
+            }
             UiMsg::Disconnect => {
                 self.disconnect();
                 self.umount_disconnect();
@@ -425,23 +610,42 @@ impl FileTransferActivity {
                     assert!(self.app.active(&Id::ReplacePopup).is_ok());
                 }
             }
-            UiMsg::ShowCopyPopup => self.mount_copy(),
-            UiMsg::ShowDeletePopup => self.mount_radio_delete(),
-            UiMsg::ShowDisconnectPopup => self.mount_disconnect(),
-            UiMsg::ShowExecPopup => self.mount_exec(),
-            UiMsg::ShowFileInfoPopup if self.browser.tab() == FileExplorerTab::Local => {
-                if let SelectedFile::One(file) = self.get_local_selected_entries() {
-                    self.mount_file_info(&file);
+            UiMsg::ShowBucketPopup => self.action_show_bucket_popup(),
+            UiMsg::ShowChecksumPopup => self.action_show_checksum_popup(),
+            UiMsg::ShowCopyPopup => {
+                if self.is_operation_allowed("Copy") {
+                    self.mount_copy();
                 }
             }
-            UiMsg::ShowFileInfoPopup if self.browser.tab() == FileExplorerTab::Remote => {
-                if let SelectedFile::One(file) = self.get_remote_selected_entries() {
-                    self.mount_file_info(&file);
+            UiMsg::ShowDeletePopup => {
+                if self.is_operation_allowed("Delete") {
+                    self.action_show_delete_popup();
                 }
             }
+            UiMsg::ShowDirHistoryPopup => self.action_show_dir_history(),
+            UiMsg::ShowDirMarksPopup => self.action_show_dir_marks(),
+            UiMsg::ShowDisconnectPopup => self.mount_disconnect(),
+            UiMsg::ShowDuplicatesPopup => self.mount_duplicates(),
+            UiMsg::ShowExecPopup => {
+                if self.is_operation_allowed("Executing commands")
+                    && self.is_operation_supported(self.capabilities.exec, "Executing commands")
+                {
+                    self.mount_exec();
+                }
+            }
+            UiMsg::ShowExportFindPopup => self.mount_export_find(),
             UiMsg::ShowFileInfoPopup => {
-                if let SelectedFile::One(file) = self.get_found_selected_entries() {
-                    self.mount_file_info(&file);
+                let selected = match self.browser.tab() {
+                    FileExplorerTab::Local => self.get_local_selected_entries(),
+                    FileExplorerTab::Remote => self.get_remote_selected_entries(),
+                    FileExplorerTab::FindLocal | FileExplorerTab::FindRemote => {
+                        self.get_found_selected_entries()
+                    }
+                };
+                match selected {
+                    SelectedFile::One(file) => self.action_show_file_info(&file),
+                    SelectedFile::Many(files) => self.mount_files_info(&files),
+                    SelectedFile::None => {}
                 }
             }
             UiMsg::ShowFileSortingPopup => self.mount_file_sorting(),
@@ -452,9 +656,19 @@ impl FileTransferActivity {
             UiMsg::ShowNewFilePopup => self.mount_newfile(),
             UiMsg::ShowOpenWithPopup => self.mount_openwith(),
             UiMsg::ShowQuitPopup => self.mount_quit(),
-            UiMsg::ShowRenamePopup => self.mount_rename(),
+            UiMsg::ShowRenamePopup => {
+                if self.is_operation_allowed("Rename") {
+                    self.mount_rename();
+                }
+            }
             UiMsg::ShowSaveAsPopup => self.mount_saveas(),
+            UiMsg::ShowSymlinkPolicyPopup => self.mount_symlink_policy(),
             UiMsg::ShowSymlinkPopup => {
+                if !self.is_operation_allowed("Creating symlinks")
+                    || !self.is_operation_supported(self.capabilities.symlink, "Symlink")
+                {
+                    return None;
+                }
                 if match self.browser.tab() {
                     FileExplorerTab::Local => self.is_local_selected_one(),
                     FileExplorerTab::Remote => self.is_remote_selected_one(),
@@ -468,24 +682,59 @@ impl FileTransferActivity {
                     );
                 }
             }
+            UiMsg::ShowTailPopup => self.action_remote_tail(),
+            UiMsg::ShowTransferFilterPopup => self.mount_transfer_filter(),
+            UiMsg::ShowTypeAheadPopup => self.mount_type_ahead(),
             UiMsg::ShowWatchedPathsList => self.action_show_watched_paths_list(),
-            UiMsg::ShowWatcherPopup => self.action_show_radio_watch(),
-            UiMsg::ToggleHiddenFiles => match self.browser.tab() {
-                FileExplorerTab::FindLocal | FileExplorerTab::Local => {
-                    self.browser.local_mut().toggle_hidden_files();
-                    self.refresh_local_status_bar();
-                    self.update_browser_file_list();
-                }
-                FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
-                    self.browser.remote_mut().toggle_hidden_files();
-                    self.refresh_remote_status_bar();
-                    self.update_browser_file_list();
+            UiMsg::ShowWatcherLogPopup => self.mount_watcher_log(),
+            UiMsg::ShowWatcherPopup => {
+                if self.is_operation_allowed("Synchronizing") {
+                    self.action_show_radio_watch();
                 }
-            },
+            }
+            UiMsg::ToggleDirectoryView => {
+                self.toggle_directory_view();
+                self.refresh_local_status_bar();
+                self.refresh_remote_status_bar();
+                self.update_browser_file_list();
+                // Remount popup to update its title
+                self.mount_file_sorting();
+            }
+            UiMsg::ToggleHiddenFiles => {
+                match self.browser.tab() {
+                    FileExplorerTab::FindLocal | FileExplorerTab::Local => {
+                        self.browser.local_mut().toggle_hidden_files();
+                        self.refresh_local_status_bar();
+                    }
+                    FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
+                        self.browser.remote_mut().toggle_hidden_files();
+                        self.refresh_remote_status_bar();
+                    }
+                }
+                self.update_directory_view();
+                self.update_browser_file_list();
+            }
+            UiMsg::ToggleReverseSorting => {
+                match self.browser.tab() {
+                    FileExplorerTab::Local | FileExplorerTab::FindLocal => {
+                        self.local_mut().toggle_reverse_sorting();
+                        self.refresh_local_status_bar();
+                    }
+                    FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                        self.remote_mut().toggle_reverse_sorting();
+                        self.refresh_remote_status_bar()
+                    }
+                }
+                self.update_directory_view();
+                self.update_browser_file_list();
+                // Remount popup to update its title
+                self.mount_file_sorting();
+            }
             UiMsg::ToggleSyncBrowsing => {
                 self.browser.toggle_sync_browsing();
                 self.refresh_remote_status_bar();
             }
+            UiMsg::TypeAhead(query) => self.action_type_ahead(query),
             UiMsg::WindowResized => {
                 self.redraw = true;
             }
@@ -5,14 +5,19 @@
 // locals
 use super::{
     browser::{FileExplorerTab, FoundExplorerTab},
-    components, Context, FileTransferActivity, Id,
+    components,
+    lib::{dir_marks::DirMarks, file_metadata::RichMetadata, transfer::TransferSummary},
+    Context, FileTransferActivity, Id,
 };
 use crate::explorer::FileSorting;
+use crate::ui::i18n::tr;
+use crate::utils::checksum::ChecksumAlgorithm;
 use crate::utils::ui::draw_area_in;
 // Ext
 use remotefs::fs::File;
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::tui::layout::{Constraint, Direction, Layout};
+use tuirealm::props::{AttrValue, Attribute, Color};
+use tuirealm::tui::layout::{Constraint, Direction, Layout, Rect};
 use tuirealm::tui::widgets::Clear;
 use tuirealm::{Sub, SubClause, SubEventClause};
 use unicode_width::UnicodeWidthStr;
@@ -36,7 +41,7 @@ impl FileTransferActivity {
             .app
             .mount(
                 Id::FooterBar,
-                Box::new(components::FooterBar::new(key_color)),
+                Box::new(components::FooterBar::new(key_color, self.read_only())),
                 vec![]
             )
             .is_ok());
@@ -76,6 +81,8 @@ impl FileTransferActivity {
                 vec![]
             )
             .is_ok());
+        self.set_text_markers(&Id::ExplorerLocal);
+        self.set_text_markers(&Id::ExplorerRemote);
         // Load status bar
         self.refresh_local_status_bar();
         self.refresh_remote_status_bar();
@@ -154,7 +161,12 @@ impl FileTransferActivity {
             self.app.view(&Id::StatusBarLocal, f, status_bar_chunks[0]);
             self.app.view(&Id::StatusBarRemote, f, status_bar_chunks[1]);
             // @! Draw popups
-            if self.app.mounted(&Id::CopyPopup) {
+            if self.app.mounted(&Id::BucketPopup) {
+                let popup = draw_area_in(f.size(), 40, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::BucketPopup, f, popup);
+            } else if self.app.mounted(&Id::CopyPopup) {
                 let popup = draw_area_in(f.size(), 40, 10);
                 f.render_widget(Clear, popup);
                 // make popup
@@ -164,6 +176,11 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::FindPopup, f, popup);
+            } else if self.app.mounted(&Id::TypeAheadPopup) {
+                let popup = draw_area_in(f.size(), 30, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::TypeAheadPopup, f, popup);
             } else if self.app.mounted(&Id::GotoPopup) {
                 let popup = draw_area_in(f.size(), 40, 10);
                 f.render_widget(Clear, popup);
@@ -204,6 +221,48 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::ExecPopup, f, popup);
+            } else if self.app.mounted(&Id::ChecksumPopup) {
+                let popup = draw_area_in(f.size(), 50, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::ChecksumPopup, f, popup);
+            } else if self.app.mounted(&Id::ChecksumResultPopup) {
+                let popup = draw_area_in(f.size(), 60, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::ChecksumResultPopup, f, popup);
+            } else if self.app.mounted(&Id::FreeSpacePopup) {
+                let popup = draw_area_in(f.size(), 60, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::FreeSpacePopup, f, popup);
+            } else if self.app.mounted(&Id::StartTransferPopup) {
+                let popup_chunks = Self::session_summary_popup_chunks(f.size());
+                f.render_widget(Clear, popup_chunks[0]);
+                f.render_widget(Clear, popup_chunks[1]);
+                // make popup
+                self.app.view(&Id::TransferSummaryPopup, f, popup_chunks[0]);
+                self.app.view(&Id::StartTransferPopup, f, popup_chunks[1]);
+            } else if self.app.mounted(&Id::SymlinkPolicyPopup) {
+                let popup = draw_area_in(f.size(), 50, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::SymlinkPolicyPopup, f, popup);
+            } else if self.app.mounted(&Id::DuplicatesPopup) {
+                let popup = draw_area_in(f.size(), 50, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::DuplicatesPopup, f, popup);
+            } else if self.app.mounted(&Id::ExportFindPopup) {
+                let popup = draw_area_in(f.size(), 50, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::ExportFindPopup, f, popup);
+            } else if self.app.mounted(&Id::TransferFilterPopup) {
+                let popup = draw_area_in(f.size(), 50, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::TransferFilterPopup, f, popup);
             } else if self.app.mounted(&Id::FileInfoPopup) {
                 let popup = draw_area_in(f.size(), 50, 50);
                 f.render_widget(Clear, popup);
@@ -230,6 +289,11 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::DeletePopup, f, popup);
+            } else if self.app.mounted(&Id::DeleteConfirmPopup) {
+                let popup = draw_area_in(f.size(), 40, 10);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::DeleteConfirmPopup, f, popup);
             } else if self.app.mounted(&Id::ReplacePopup) {
                 // NOTE: handle extended / normal modes
                 if self.is_radio_replace_extended() {
@@ -255,27 +319,56 @@ impl FileTransferActivity {
                     self.app.view(&Id::ReplacePopup, f, popup);
                 }
             } else if self.app.mounted(&Id::DisconnectPopup) {
-                let popup = draw_area_in(f.size(), 30, 10);
-                f.render_widget(Clear, popup);
+                let popup_chunks = Self::session_summary_popup_chunks(f.size());
+                f.render_widget(Clear, popup_chunks[0]);
+                f.render_widget(Clear, popup_chunks[1]);
                 // make popup
-                self.app.view(&Id::DisconnectPopup, f, popup);
+                self.app.view(&Id::SessionSummaryPopup, f, popup_chunks[0]);
+                self.app.view(&Id::DisconnectPopup, f, popup_chunks[1]);
             } else if self.app.mounted(&Id::QuitPopup) {
-                let popup = draw_area_in(f.size(), 30, 10);
+                let popup_chunks = Self::session_summary_popup_chunks(f.size());
+                f.render_widget(Clear, popup_chunks[0]);
+                f.render_widget(Clear, popup_chunks[1]);
+                // make popup
+                self.app.view(&Id::SessionSummaryPopup, f, popup_chunks[0]);
+                self.app.view(&Id::QuitPopup, f, popup_chunks[1]);
+            } else if self.app.mounted(&Id::DirHistoryPopup) {
+                let popup = draw_area_in(f.size(), 60, 50);
                 f.render_widget(Clear, popup);
                 // make popup
-                self.app.view(&Id::QuitPopup, f, popup);
+                self.app.view(&Id::DirHistoryPopup, f, popup);
+            } else if self.app.mounted(&Id::DirMarksPopup) {
+                let popup = draw_area_in(f.size(), 60, 50);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::DirMarksPopup, f, popup);
             } else if self.app.mounted(&Id::WatchedPathsList) {
                 let popup = draw_area_in(f.size(), 60, 50);
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::WatchedPathsList, f, popup);
+            } else if self.app.mounted(&Id::WatcherLogPopup) {
+                let popup = draw_area_in(f.size(), 80, 50);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::WatcherLogPopup, f, popup);
             } else if self.app.mounted(&Id::WatcherPopup) {
                 let popup = draw_area_in(f.size(), 60, 10);
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::WatcherPopup, f, popup);
+            } else if self.app.mounted(&Id::ExecResultPopup) {
+                let popup = draw_area_in(f.size(), 80, 70);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::ExecResultPopup, f, popup);
+            } else if self.app.mounted(&Id::TailPopup) {
+                let popup = draw_area_in(f.size(), 80, 70);
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::TailPopup, f, popup);
             } else if self.app.mounted(&Id::SortingPopup) {
-                let popup = draw_area_in(f.size(), 50, 10);
+                let popup = draw_area_in(f.size(), 90, 10);
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::SortingPopup, f, popup);
@@ -400,6 +493,7 @@ impl FileTransferActivity {
     pub(super) fn mount_quit(&mut self) {
         // Protocol
         let quit_color = self.theme().misc_quit_dialog;
+        self.mount_session_summary(quit_color);
         assert!(self
             .app
             .remount(
@@ -414,12 +508,14 @@ impl FileTransferActivity {
     /// Umount quit popup
     pub(super) fn umount_quit(&mut self) {
         let _ = self.app.umount(&Id::QuitPopup);
+        let _ = self.app.umount(&Id::SessionSummaryPopup);
     }
 
     /// Mount disconnect popup
     pub(super) fn mount_disconnect(&mut self) {
         // Protocol
         let quit_color = self.theme().misc_quit_dialog;
+        self.mount_session_summary(quit_color);
         assert!(self
             .app
             .remount(
@@ -434,6 +530,54 @@ impl FileTransferActivity {
     /// Umount disconnect popup
     pub(super) fn umount_disconnect(&mut self) {
         let _ = self.app.umount(&Id::DisconnectPopup);
+        let _ = self.app.umount(&Id::SessionSummaryPopup);
+    }
+
+    /// Mount the summary of the session, shown along with the disconnect and quit popups
+    fn mount_session_summary(&mut self, color: Color) {
+        let lines = self.summary.lines();
+        assert!(self
+            .app
+            .remount(
+                Id::SessionSummaryPopup,
+                Box::new(components::SessionSummaryPopup::new(&lines, color)),
+                vec![],
+            )
+            .is_ok());
+    }
+
+    pub(super) fn mount_bucket(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::BucketPopup,
+                Box::new(components::BucketPopup::new(input_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::BucketPopup).is_ok());
+    }
+
+    pub(super) fn umount_bucket(&mut self) {
+        let _ = self.app.umount(&Id::BucketPopup);
+    }
+
+    pub(super) fn mount_type_ahead(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::TypeAheadPopup,
+                Box::new(components::TypeAheadPopup::new(input_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::TypeAheadPopup).is_ok());
+    }
+
+    pub(super) fn umount_type_ahead(&mut self) {
+        let _ = self.app.umount(&Id::TypeAheadPopup);
     }
 
     pub(super) fn mount_copy(&mut self) {
@@ -453,13 +597,183 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::CopyPopup);
     }
 
+    pub(super) fn mount_checksum(&mut self, file: &str) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::ChecksumPopup,
+                Box::new(components::ChecksumPopup::new(file, input_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::ChecksumPopup).is_ok());
+    }
+
+    pub(super) fn umount_checksum(&mut self) {
+        let _ = self.app.umount(&Id::ChecksumPopup);
+    }
+
+    pub(super) fn mount_checksum_result(
+        &mut self,
+        file: &str,
+        algorithm: ChecksumAlgorithm,
+        digest: String,
+    ) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::ChecksumResultPopup,
+                Box::new(components::ChecksumResultPopup::new(
+                    file, algorithm, digest, info_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::ChecksumResultPopup).is_ok());
+    }
+
+    pub(super) fn umount_checksum_result(&mut self) {
+        let _ = self.app.umount(&Id::ChecksumResultPopup);
+    }
+
+    pub(super) fn mount_free_space(&mut self, required: u64, available: u64) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::FreeSpacePopup,
+                Box::new(components::FreeSpacePopup::new(
+                    required, available, warn_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::FreeSpacePopup).is_ok());
+    }
+
+    pub(super) fn umount_free_space(&mut self) {
+        let _ = self.app.umount(&Id::FreeSpacePopup);
+    }
+
+    pub(super) fn mount_start_transfer(&mut self, summary: &TransferSummary) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::TransferSummaryPopup,
+                Box::new(components::TransferSummaryPopup::new(summary, info_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self
+            .app
+            .remount(
+                Id::StartTransferPopup,
+                Box::new(components::StartTransferPopup::new(info_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::StartTransferPopup).is_ok());
+    }
+
+    pub(super) fn umount_start_transfer(&mut self) {
+        let _ = self.app.umount(&Id::StartTransferPopup);
+        let _ = self.app.umount(&Id::TransferSummaryPopup);
+    }
+
+    pub(super) fn mount_symlink_policy(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        let policy = self.config().get_symlink_policy();
+        assert!(self
+            .app
+            .remount(
+                Id::SymlinkPolicyPopup,
+                Box::new(components::SymlinkPolicyPopup::new(policy, input_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::SymlinkPolicyPopup).is_ok());
+    }
+
+    pub(super) fn umount_symlink_policy(&mut self) {
+        let _ = self.app.umount(&Id::SymlinkPolicyPopup);
+    }
+
+    pub(super) fn mount_duplicates(&mut self) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::DuplicatesPopup,
+                Box::new(components::DuplicatesPopup::new(info_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::DuplicatesPopup).is_ok());
+    }
+
+    pub(super) fn umount_duplicates(&mut self) {
+        let _ = self.app.umount(&Id::DuplicatesPopup);
+    }
+
+    pub(super) fn mount_export_find(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::ExportFindPopup,
+                Box::new(components::ExportFindPopup::new(input_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::ExportFindPopup).is_ok());
+    }
+
+    pub(super) fn umount_export_find(&mut self) {
+        let _ = self.app.umount(&Id::ExportFindPopup);
+    }
+
+    pub(super) fn mount_transfer_filter(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        let filter = self
+            .config()
+            .get_transfer_filter()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        assert!(self
+            .app
+            .remount(
+                Id::TransferFilterPopup,
+                Box::new(components::TransferFilterPopup::new(
+                    filter.as_str(),
+                    input_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::TransferFilterPopup).is_ok());
+    }
+
+    pub(super) fn umount_transfer_filter(&mut self) {
+        let _ = self.app.umount(&Id::TransferFilterPopup);
+    }
+
     pub(super) fn mount_exec(&mut self) {
         let input_color = self.theme().misc_input_dialog;
+        let history = match self.browser.tab() {
+            FileExplorerTab::Local | FileExplorerTab::FindLocal => self.local_commands.commands(),
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                self.remote_commands.commands()
+            }
+        }
+        .to_vec();
         assert!(self
             .app
             .remount(
                 Id::ExecPopup,
-                Box::new(components::ExecPopup::new(input_color)),
+                Box::new(components::ExecPopup::new(input_color, &history)),
                 vec![],
             )
             .is_ok());
@@ -470,7 +784,38 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::ExecPopup);
     }
 
-    pub(super) fn mount_find(&mut self, search: &str) {
+    pub(super) fn mount_exec_result(
+        &mut self,
+        cmd: &str,
+        exit_code: Option<i32>,
+        stdout: &str,
+        stderr: &str,
+    ) {
+        let info_color = self.theme().misc_info_dialog;
+        let error_color = self.theme().misc_error_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::ExecResultPopup,
+                Box::new(components::ExecResultPopup::new(
+                    cmd,
+                    exit_code,
+                    stdout,
+                    stderr,
+                    info_color,
+                    error_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::ExecResultPopup).is_ok());
+    }
+
+    pub(super) fn umount_exec_result(&mut self) {
+        let _ = self.app.umount(&Id::ExecResultPopup);
+    }
+
+    pub(super) fn mount_find(&mut self, title: String) {
         // Get color
         let (bg, fg, hg) = match self.browser.tab() {
             FileExplorerTab::Local | FileExplorerTab::FindLocal => (
@@ -489,19 +834,28 @@ impl FileTransferActivity {
             .app
             .remount(
                 Id::ExplorerFind,
-                Box::new(components::ExplorerFind::new(
-                    format!(r#"Search results for "{}""#, search),
-                    &[],
-                    bg,
-                    fg,
-                    hg
-                )),
+                Box::new(components::ExplorerFind::new(title, &[], bg, fg, hg)),
                 vec![],
             )
             .is_ok());
+        self.set_text_markers(&Id::ExplorerFind);
         assert!(self.app.active(&Id::ExplorerFind).is_ok());
     }
 
+    /// Mark the selected files and the cursor of the explorer `id` with text in accessibility mode
+    fn set_text_markers(&mut self, id: &Id) {
+        if self.config().get_accessibility() {
+            assert!(self
+                .app
+                .attr(
+                    id,
+                    Attribute::Custom(components::FILE_LIST_TEXT_MARKERS),
+                    AttrValue::Flag(true)
+                )
+                .is_ok());
+        }
+    }
+
     pub(super) fn umount_find(&mut self) {
         let _ = self.app.umount(&Id::ExplorerFind);
     }
@@ -525,12 +879,17 @@ impl FileTransferActivity {
     }
 
     pub(super) fn mount_goto(&mut self) {
+        self.mount_goto_completion("", &[]);
+    }
+
+    /// Mount the goto popup with `value` in the input field and the completion `candidates` in the title
+    pub(super) fn mount_goto_completion(&mut self, value: &str, candidates: &[String]) {
         let input_color = self.theme().misc_input_dialog;
         assert!(self
             .app
             .remount(
                 Id::GotoPopup,
-                Box::new(components::GoToPopup::new(input_color)),
+                Box::new(components::GoToPopup::new(input_color, value, candidates)),
                 vec![],
             )
             .is_ok());
@@ -649,7 +1008,7 @@ impl FileTransferActivity {
                 Box::new(components::ProgressBarPartial::new(
                     0.0,
                     "",
-                    "Please wait",
+                    tr("please-wait").as_str(),
                     prog_color_partial
                 )),
                 vec![],
@@ -665,16 +1024,28 @@ impl FileTransferActivity {
 
     pub(super) fn mount_file_sorting(&mut self) {
         let sorting_color = self.theme().transfer_status_sorting;
-        let sorting: FileSorting = match self.browser.tab() {
-            FileExplorerTab::Local => self.local().get_file_sorting(),
-            FileExplorerTab::Remote => self.remote().get_file_sorting(),
+        let (sorting, reversed): (FileSorting, bool) = match self.browser.tab() {
+            FileExplorerTab::Local => (
+                self.local().get_file_sorting(),
+                self.local().reverse_sorting(),
+            ),
+            FileExplorerTab::Remote => (
+                self.remote().get_file_sorting(),
+                self.remote().reverse_sorting(),
+            ),
             _ => return,
         };
+        let remembered = self.has_directory_view();
         assert!(self
             .app
             .remount(
                 Id::SortingPopup,
-                Box::new(components::SortingPopup::new(sorting, sorting_color)),
+                Box::new(components::SortingPopup::new(
+                    sorting,
+                    reversed,
+                    remembered,
+                    sorting_color,
+                )),
                 vec![],
             )
             .is_ok());
@@ -702,6 +1073,23 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::DeletePopup);
     }
 
+    pub(super) fn mount_delete_confirm(&mut self, count: usize) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::DeleteConfirmPopup,
+                Box::new(components::DeleteConfirmPopup::new(count, warn_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::DeleteConfirmPopup).is_ok());
+    }
+
+    pub(super) fn umount_delete_confirm(&mut self) {
+        let _ = self.app.umount(&Id::DeleteConfirmPopup);
+    }
+
     pub(super) fn mount_radio_watch(&mut self, watch: bool, local: &str, remote: &str) {
         let info_color = self.theme().misc_info_dialog;
         assert!(self
@@ -721,6 +1109,42 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::WatcherPopup);
     }
 
+    pub(super) fn mount_dir_history(&mut self, history: &[std::path::PathBuf], current: usize) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::DirHistoryPopup,
+                Box::new(components::DirHistoryPopup::new(
+                    history, current, info_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::DirHistoryPopup).is_ok());
+    }
+
+    pub(super) fn umount_dir_history(&mut self) {
+        let _ = self.app.umount(&Id::DirHistoryPopup);
+    }
+
+    pub(super) fn mount_dir_marks(&mut self, marks: &DirMarks) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::DirMarksPopup,
+                Box::new(components::DirMarksPopup::new(marks, info_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::DirMarksPopup).is_ok());
+    }
+
+    pub(super) fn umount_dir_marks(&mut self) {
+        let _ = self.app.umount(&Id::DirMarksPopup);
+    }
+
     pub(super) fn mount_watched_paths_list(&mut self, paths: &[std::path::PathBuf]) {
         let info_color = self.theme().misc_info_dialog;
         assert!(self
@@ -738,6 +1162,46 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::WatchedPathsList);
     }
 
+    pub(super) fn mount_watcher_log(&mut self) {
+        let info_color = self.theme().misc_info_dialog;
+        let error_color = self.theme().misc_error_dialog;
+        let events: Vec<_> = self.watcher_log.events().cloned().collect();
+        assert!(self
+            .app
+            .remount(
+                Id::WatcherLogPopup,
+                Box::new(components::WatcherLogPopup::new(
+                    &events,
+                    info_color,
+                    error_color
+                )),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::WatcherLogPopup).is_ok());
+    }
+
+    pub(super) fn umount_watcher_log(&mut self) {
+        let _ = self.app.umount(&Id::WatcherLogPopup);
+    }
+
+    pub(super) fn mount_tail(&mut self, path: &std::path::Path) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(self
+            .app
+            .remount(
+                Id::TailPopup,
+                Box::new(components::TailPopup::new(path, info_color)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::TailPopup).is_ok());
+    }
+
+    pub(super) fn umount_tail(&mut self) {
+        let _ = self.app.umount(&Id::TailPopup);
+    }
+
     pub(super) fn mount_radio_replace(&mut self, file_name: &str) {
         let warn_color = self.theme().misc_warn_dialog;
         assert!(self
@@ -782,12 +1246,24 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::ReplacingFilesListPopup); // NOTE: replace anyway
     }
 
-    pub(super) fn mount_file_info(&mut self, file: &File) {
+    pub(super) fn mount_file_info(&mut self, file: &File, metadata: Option<&RichMetadata>) {
+        assert!(self
+            .app
+            .remount(
+                Id::FileInfoPopup,
+                Box::new(components::FileInfoPopup::new(file, metadata)),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::FileInfoPopup).is_ok());
+    }
+
+    pub(super) fn mount_files_info(&mut self, files: &[File]) {
         assert!(self
             .app
             .remount(
                 Id::FileInfoPopup,
-                Box::new(components::FileInfoPopup::new(file)),
+                Box::new(components::FileInfoPopup::many(files)),
                 vec![],
             )
             .is_ok());
@@ -801,14 +1277,26 @@ impl FileTransferActivity {
     pub(super) fn refresh_local_status_bar(&mut self) {
         let sorting_color = self.theme().transfer_status_sorting;
         let hidden_color = self.theme().transfer_status_hidden;
+        let disk_space_color = self.theme().transfer_status_sync_browsing;
+        let info = components::StatusBarInfo {
+            disk_space: self.local_disk_space,
+            host: Self::get_local_hostname(),
+            rate: self.summary.last_rate(),
+            watched_paths: self.watched_paths_count(),
+            last_sync: self.watcher_log.last_sync(),
+        };
+        let fmt = self.config().get_status_bar_fmt();
         assert!(self
             .app
             .remount(
                 Id::StatusBarLocal,
                 Box::new(components::StatusBarLocal::new(
                     &self.browser,
+                    &info,
+                    fmt.as_ref(),
                     sorting_color,
-                    hidden_color
+                    hidden_color,
+                    disk_space_color
                 )),
                 vec![],
             )
@@ -819,15 +1307,27 @@ impl FileTransferActivity {
         let sorting_color = self.theme().transfer_status_sorting;
         let hidden_color = self.theme().transfer_status_hidden;
         let sync_color = self.theme().transfer_status_sync_browsing;
+        let disk_space_color = self.theme().transfer_status_hidden;
+        let info = components::StatusBarInfo {
+            disk_space: self.remote_disk_space,
+            host: self.get_remote_hostname(),
+            rate: self.summary.last_rate(),
+            watched_paths: self.watched_paths_count(),
+            last_sync: self.watcher_log.last_sync(),
+        };
+        let fmt = self.config().get_status_bar_fmt();
         assert!(self
             .app
             .remount(
                 Id::StatusBarRemote,
                 Box::new(components::StatusBarRemote::new(
                     &self.browser,
+                    &info,
+                    fmt.as_ref(),
                     sorting_color,
                     hidden_color,
-                    sync_color
+                    sync_color,
+                    disk_space_color
                 )),
                 vec![],
             )
@@ -875,7 +1375,11 @@ impl FileTransferActivity {
             .app
             .remount(
                 Id::KeybindingsPopup,
-                Box::new(components::KeybindingsPopup::new(key_color)),
+                Box::new(components::KeybindingsPopup::new(
+                    key_color,
+                    self.read_only(),
+                    self.capabilities
+                )),
                 vec![],
             )
             .is_ok());
@@ -888,6 +1392,27 @@ impl FileTransferActivity {
 
     // -- dynamic size
 
+    /// Returns the areas of the session summary and of the disconnect/quit radio below it,
+    /// centered in `area`
+    fn session_summary_popup_chunks(area: Rect) -> Vec<Rect> {
+        let height = 10.min(area.height);
+        let popup = Rect {
+            y: area.y + (area.height - height) / 2,
+            height,
+            ..draw_area_in(area, 40, 10)
+        };
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Min(0),    // Summary
+                    Constraint::Length(3), // Radio
+                ]
+                .as_ref(),
+            )
+            .split(popup)
+    }
+
     /// Given the id of the component to display and the width and height of the total area,
     /// returns the height in percentage to the entire area height, that the popup should have
     fn calc_popup_height(&self, id: Id, width: u16, height: u16) -> u16 {
@@ -1069,9 +1594,89 @@ impl FileTransferActivity {
                                                                                                             Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
                                                                                                                 Id::WatchedPathsList,
                                                                                                             )))),
-                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
-                                                                                                                Id::WaitPopup,
-                                                                                                            )))),
+                                                                                                            Box::new(SubClause::And(
+                                                                                                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                    Id::TailPopup,
+                                                                                                                )))),
+                                                                                                                Box::new(SubClause::And(
+                                                                                                                    Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                        Id::BucketPopup,
+                                                                                                                    )))),
+                                                                                                                    Box::new(SubClause::And(
+                                                                                                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                            Id::TypeAheadPopup,
+                                                                                                                        )))),
+                                                                                                                        Box::new(SubClause::And(
+                                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                Id::ChecksumPopup,
+                                                                                                                            )))),
+                                                                                                                            Box::new(SubClause::And(
+                                                                                                                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                    Id::ChecksumResultPopup,
+                                                                                                                                )))),
+                                                                                                                                Box::new(SubClause::And(
+                                                                                                                                    Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                        Id::FreeSpacePopup,
+                                                                                                                                    )))),
+                                                                                                                                    Box::new(SubClause::And(
+                                                                                                                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                            Id::SymlinkPolicyPopup,
+                                                                                                                                        )))),
+                                                                                                                                        Box::new(SubClause::And(
+                                                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                Id::DirHistoryPopup,
+                                                                                                                                            )))),
+                                                                                                                                            Box::new(SubClause::And(
+                                                                                                                                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                    Id::TransferFilterPopup,
+                                                                                                                                                )))),
+                                                                                                                                                Box::new(SubClause::And(
+                                                                                                                                                    Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                        Id::ExportFindPopup,
+                                                                                                                                                    )))),
+                                                                                                                                                    Box::new(SubClause::And(
+                                                                                                                                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                            Id::DuplicatesPopup,
+                                                                                                                                                        )))),
+                                                                                                                                                        Box::new(SubClause::And(
+                                                                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                Id::ExecResultPopup,
+                                                                                                                                                            )))),
+                                                                                                                                                            Box::new(SubClause::And(
+                                                                                                                                                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                    Id::DeleteConfirmPopup,
+                                                                                                                                                                )))),
+                                                                                                                                                                Box::new(SubClause::And(
+                                                                                                                                                                    Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                        Id::StartTransferPopup,
+                                                                                                                                                                    )))),
+                                                                                                                                                                    Box::new(SubClause::And(
+                                                                                                                                                                        Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                            Id::WatcherLogPopup,
+                                                                                                                                                                        )))),
+                                                                                                                                                                        Box::new(SubClause::And(
+                                                                                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                                Id::DirMarksPopup,
+                                                                                                                                                                            )))),
+                                                                                                                                                                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(
+                                                                                                                                                                                Id::WaitPopup,
+                                                                                                                                                                            )))),
+                                                                                                                                                                        )),
+                                                                                                                                                                    )),
+                                                                                                                                                                )),
+                                                                                                                                                            )),
+                                                                                                                                                        )),
+                                                                                                                                                    )),
+                                                                                                                                                )),
+                                                                                                                                            )),
+                                                                                                                                        )),
+                                                                                                                                    )),
+                                                                                                                                )),
+                                                                                                                            )),
+                                                                                                                        )),
+                                                                                                                    )),
+                                                                                                                )),
+                                                                                                            )),
                                                                                                         )),
                                                                                                     )),
                                                                                                 )),
@@ -17,6 +17,7 @@ pub enum ExitReason {
     Connect,
     Disconnect,
     EnterSetup,
+    SwitchProfile,
 }
 
 // -- Activity trait
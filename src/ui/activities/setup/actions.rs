@@ -7,6 +7,7 @@
 use super::{Id, IdSsh, IdTheme, SetupActivity, ViewLayout};
 // Ext
 use crate::config::themes::Theme;
+use crate::system::environment;
 use std::env;
 use tuirealm::tui::style::Color;
 use tuirealm::{State, StateValue};
@@ -22,6 +23,29 @@ impl SetupActivity {
         }
     }
 
+    /// Switch to the configuration profile named `profile`; `default` or an empty name selects the default profile.
+    /// Unsaved changes must be saved or reverted before switching profile
+    pub(super) fn action_switch_profile(&mut self, profile: &str) -> Result<(), String> {
+        if self.config_changed() {
+            return Err(String::from(
+                "There are unsaved changes! Save or revert them before switching profile",
+            ));
+        }
+        let profile = match profile {
+            "" | "default" => None,
+            name if environment::is_valid_profile_name(name) => Some(name),
+            _ => {
+                return Err(String::from(
+                    "Profile names may contain only alphanumeric characters, '-' and '_'",
+                ))
+            }
+        };
+        info!("Switching to profile {:?}", profile);
+        environment::set_profile(profile);
+        self.exit_reason = Some(super::ExitReason::SwitchProfile);
+        Ok(())
+    }
+
     /// Save all configurations. If current tab can load values, they will be loaded, otherwise they'll just be saved.
     /// Once all the configuration has been changed, set config_changed to false
     pub(super) fn action_save_all(&mut self) -> Result<(), String> {
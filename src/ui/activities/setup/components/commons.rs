@@ -3,11 +3,14 @@
 //! config tab components
 
 use super::{CommonMsg, Msg, ViewLayout};
+use crate::ui::i18n::{tr, tr_args, tr_indented};
 
-use tui_realm_stdlib::{List, Paragraph, Radio, Span};
+use tui_realm_stdlib::{Input, List, Paragraph, Radio, Span};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, TableBuilder, TextSpan};
+use tuirealm::props::{
+    Alignment, BorderSides, BorderType, Borders, Color, InputType, Style, TableBuilder, TextSpan,
+};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
 #[derive(MockComponent)]
@@ -54,15 +57,15 @@ impl Default for Footer {
         Self {
             component: Span::default().spans(&[
                 TextSpan::new("<F1|CTRL+H>").bold().fg(Color::Cyan),
-                TextSpan::new(" Help "),
+                TextSpan::new(format!(" {} ", tr("footer-help"))),
                 TextSpan::new("<F4|CTRL+S>").bold().fg(Color::Cyan),
-                TextSpan::new(" Save parameters "),
+                TextSpan::new(format!(" {} ", tr("footer-save-parameters"))),
                 TextSpan::new("<F10|ESC>").bold().fg(Color::Cyan),
-                TextSpan::new(" Exit "),
+                TextSpan::new(format!(" {} ", tr("footer-exit"))),
                 TextSpan::new("<TAB>").bold().fg(Color::Cyan),
-                TextSpan::new(" Change panel "),
+                TextSpan::new(format!(" {} ", tr("footer-change-panel"))),
                 TextSpan::new("<UP/DOWN>").bold().fg(Color::Cyan),
-                TextSpan::new(" Change field "),
+                TextSpan::new(format!(" {} ", tr("footer-change-field"))),
             ]),
         }
     }
@@ -80,7 +83,7 @@ pub struct Header {
 }
 
 impl Header {
-    pub fn new(layout: ViewLayout) -> Self {
+    pub fn new(layout: ViewLayout, profile: &str) -> Self {
         Self {
             component: Radio::default()
                 .borders(
@@ -88,8 +91,12 @@ impl Header {
                         .color(Color::Yellow)
                         .sides(BorderSides::BOTTOM),
                 )
-                .choices(&["Configuration parameters", "SSH Keys", "Theme"])
+                .choices(&[tr("setup-config"), tr("setup-ssh-keys"), tr("setup-theme")])
                 .foreground(Color::Yellow)
+                .title(
+                    tr_args("setup-profile", &[("profile", &profile)]),
+                    Alignment::Left,
+                )
                 .value(match layout {
                     ViewLayout::SetupForm => 0,
                     ViewLayout::SshKeys => 1,
@@ -115,37 +122,40 @@ impl Default for Keybindings {
         Self {
             component: List::default()
                 .borders(Borders::default().modifiers(BorderType::Rounded))
-                .title("Keybindings", Alignment::Center)
+                .title(tr("keybindings"), Alignment::Center)
                 .scroll(true)
                 .highlighted_str("? ")
                 .rows(
                     TableBuilder::default()
                         .add_col(TextSpan::new("<ESC>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("           Exit setup"))
+                        .add_col(TextSpan::from(tr_indented(11, "help-exit-setup")))
                         .add_row()
                         .add_col(TextSpan::new("<TAB>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("           Change setup page"))
+                        .add_col(TextSpan::from(tr_indented(11, "help-change-setup-page")))
                         .add_row()
                         .add_col(TextSpan::new("<RIGHT/LEFT>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("    Change cursor"))
+                        .add_col(TextSpan::from(tr_indented(4, "help-change-cursor")))
                         .add_row()
                         .add_col(TextSpan::new("<UP/DOWN>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("       Change input field"))
+                        .add_col(TextSpan::from(tr_indented(7, "help-change-field")))
                         .add_row()
                         .add_col(TextSpan::new("<ENTER>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("         Select / Dismiss popup"))
+                        .add_col(TextSpan::from(tr_indented(9, "help-select-dismiss")))
                         .add_row()
                         .add_col(TextSpan::new("<DEL|E>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("         Delete SSH key"))
+                        .add_col(TextSpan::from(tr_indented(9, "help-delete-ssh-key")))
                         .add_row()
                         .add_col(TextSpan::new("<CTRL+N>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        New SSH key"))
+                        .add_col(TextSpan::from(tr_indented(8, "help-new-ssh-key")))
+                        .add_row()
+                        .add_col(TextSpan::new("<CTRL+P>").bold().fg(Color::Cyan))
+                        .add_col(TextSpan::from(tr_indented(8, "help-switch-profile")))
                         .add_row()
                         .add_col(TextSpan::new("<CTRL+R>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        Revert changes"))
+                        .add_col(TextSpan::from(tr_indented(8, "help-revert-changes")))
                         .add_row()
                         .add_col(TextSpan::new("<CTRL+S>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        Save configuration"))
+                        .add_col(TextSpan::from(tr_indented(8, "help-save-config")))
                         .build(),
                 ),
         }
@@ -197,6 +207,93 @@ impl Component<Msg, NoUserEvent> for Keybindings {
     }
 }
 
+#[derive(MockComponent)]
+pub struct ProfilePopup {
+    component: Input,
+}
+
+impl ProfilePopup {
+    pub fn new(profiles: &[String]) -> Self {
+        let available = std::iter::once("default")
+            .chain(profiles.iter().map(|x| x.as_str()))
+            .collect::<Vec<&str>>()
+            .join(", ");
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Yellow)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::Yellow)
+                .input_type(InputType::Text)
+                .placeholder(available, Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("switch-profile"), Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ProfilePopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(profile)) => {
+                    Some(Msg::Common(CommonMsg::SwitchProfile(profile)))
+                }
+                _ => Some(Msg::Common(CommonMsg::CloseProfilePopup)),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Common(CommonMsg::CloseProfilePopup))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct QuitPopup {
     component: Radio,
@@ -212,12 +309,9 @@ impl Default for QuitPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(Color::Red)
-                .title(
-                    "There are unsaved changes! Save changes before leaving?",
-                    Alignment::Center,
-                )
+                .title(tr("unsaved-changes"), Alignment::Center)
                 .rewind(true)
-                .choices(&["Save", "Don't save", "Cancel"]),
+                .choices(&[tr("save"), tr("dont-save"), tr("cancel")]),
         }
     }
 }
@@ -271,9 +365,9 @@ impl Default for SavePopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(Color::Yellow)
-                .title("Save changes?", Alignment::Center)
+                .title(tr("save-changes"), Alignment::Center)
                 .rewind(true)
-                .choices(&["Yes", "No"]),
+                .choices(&[tr("yes"), tr("no")]),
         }
     }
 }
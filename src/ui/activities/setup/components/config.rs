@@ -3,10 +3,17 @@
 //! config tab components
 
 use super::{ConfigMsg, Msg};
-use crate::explorer::GroupDirs as GroupDirsEnum;
-use crate::filetransfer::FileTransferProtocol;
+use crate::config::status_bar::StatusBarFmt as StatusBarFmtType;
+use crate::explorer::{FileSorting as FileSortingEnum, GroupDirs as GroupDirsEnum};
+use crate::filetransfer::{
+    FileTransferProtocol, OwnershipMap as OwnershipMapType, SymlinkPolicy as SymlinkPolicyEnum,
+    TransferFilter as TransferFilterType,
+};
+use crate::ui::i18n::tr;
 use crate::utils::parser::parse_bytesize;
 
+use std::str::FromStr;
+
 use tui_realm_stdlib::{Input, Radio};
 use tuirealm::command::{Cmd, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
@@ -15,6 +22,40 @@ use tuirealm::{Component, Event, MockComponent, NoUserEvent};
 
 // -- components
 
+#[derive(MockComponent)]
+pub struct Accessibility {
+    component: Radio,
+}
+
+impl Accessibility {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::White)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("yes"), tr("no")])
+                .foreground(Color::White)
+                .rewind(true)
+                .title(tr("accessibility-mode"), Alignment::Left)
+                .value(if enabled { 0 } else { 1 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for Accessibility {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::AccessibilityBlurDown),
+            Msg::Config(ConfigMsg::AccessibilityBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct CheckUpdates {
     component: Radio,
@@ -29,10 +70,10 @@ impl CheckUpdates {
                         .color(Color::LightYellow)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(Color::LightYellow)
                 .rewind(true)
-                .title("Check for updates?", Alignment::Left)
+                .title(tr("check-for-updates"), Alignment::Left)
                 .value(if enabled { 0 } else { 1 }),
         }
     }
@@ -66,7 +107,7 @@ impl DefaultProtocol {
                 .choices(&["SFTP", "SCP", "FTP", "FTPS", "S3"])
                 .foreground(Color::Cyan)
                 .rewind(true)
-                .title("Default protocol", Alignment::Left)
+                .title(tr("default-protocol"), Alignment::Left)
                 .value(match protocol {
                     FileTransferProtocol::AwsS3 => 4,
                     FileTransferProtocol::Ftp(true) => 3,
@@ -89,6 +130,202 @@ impl Component<Msg, NoUserEvent> for DefaultProtocol {
     }
 }
 
+#[derive(MockComponent)]
+pub struct DeleteConfirmation {
+    component: Input,
+}
+
+impl DeleteConfirmation {
+    pub fn new(value: &str) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightRed)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightRed)
+                .input_type(InputType::UnsignedInteger)
+                .placeholder("100", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("delete-confirmation-threshold"), Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DeleteConfirmation {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::DeleteConfirmationBlurDown),
+            Msg::Config(ConfigMsg::DeleteConfirmationBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct FileSorting {
+    component: Radio,
+}
+
+impl FileSorting {
+    pub fn new(sorting: FileSortingEnum) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightYellow)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[
+                    tr("sorting-name"),
+                    tr("sorting-natural"),
+                    tr("sorting-modify-time"),
+                    tr("sorting-creation-time"),
+                    tr("sorting-size"),
+                    tr("sorting-extension"),
+                    tr("sorting-owner"),
+                    tr("sorting-permissions"),
+                ])
+                .foreground(Color::LightYellow)
+                .rewind(true)
+                .title(tr("default-file-sorting"), Alignment::Left)
+                .value(match sorting {
+                    FileSortingEnum::Name => 0,
+                    FileSortingEnum::NaturalName => 1,
+                    FileSortingEnum::ModifyTime => 2,
+                    FileSortingEnum::CreationTime => 3,
+                    FileSortingEnum::Size => 4,
+                    FileSortingEnum::Extension => 5,
+                    FileSortingEnum::Owner => 6,
+                    FileSortingEnum::Permissions => 7,
+                }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FileSorting {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::FileSortingBlurDown),
+            Msg::Config(ConfigMsg::FileSortingBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct SymlinkPolicy {
+    component: Radio,
+}
+
+impl SymlinkPolicy {
+    pub fn new(policy: SymlinkPolicyEnum) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightCyan)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[
+                    tr("symlink-follow"),
+                    tr("symlink-copy-as-link"),
+                    tr("symlink-skip"),
+                ])
+                .foreground(Color::LightCyan)
+                .rewind(true)
+                .title(tr("symlinks-in-transfers"), Alignment::Left)
+                .value(match policy {
+                    SymlinkPolicyEnum::Follow => 0,
+                    SymlinkPolicyEnum::CopyAsLink => 1,
+                    SymlinkPolicyEnum::Skip => 2,
+                }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SymlinkPolicy {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::SymlinkPolicyBlurDown),
+            Msg::Config(ConfigMsg::SymlinkPolicyBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct FtpMode {
+    component: Radio,
+}
+
+impl FtpMode {
+    pub fn new(active: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightMagenta)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("ftp-passive"), tr("ftp-active")])
+                .foreground(Color::LightMagenta)
+                .rewind(true)
+                .title(tr("ftp-data-connection-mode"), Alignment::Left)
+                .value(if active { 1 } else { 0 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FtpMode {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::FtpModeBlurDown),
+            Msg::Config(ConfigMsg::FtpModeBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct SshCompression {
+    component: Radio,
+}
+
+impl SshCompression {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightGreen)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("yes"), tr("no")])
+                .foreground(Color::LightGreen)
+                .rewind(true)
+                .title(tr("compress-ssh"), Alignment::Left)
+                .value(if enabled { 0 } else { 1 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SshCompression {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::SshCompressionBlurDown),
+            Msg::Config(ConfigMsg::SshCompressionBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct GroupDirs {
     component: Radio,
@@ -103,10 +340,10 @@ impl GroupDirs {
                         .color(Color::LightMagenta)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Display first", "Display last", "No"])
+                .choices(&[tr("group-dirs-first"), tr("group-dirs-last"), tr("no")])
                 .foreground(Color::LightMagenta)
                 .rewind(true)
-                .title("Group directories", Alignment::Left)
+                .title(tr("group-dirs"), Alignment::Left)
                 .value(match opt {
                     Some(GroupDirsEnum::First) => 0,
                     Some(GroupDirsEnum::Last) => 1,
@@ -141,10 +378,10 @@ impl HiddenFiles {
                         .color(Color::LightRed)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(Color::LightRed)
                 .rewind(true)
-                .title("Show hidden files? (by default)", Alignment::Left)
+                .title(tr("show-hidden-files"), Alignment::Left)
                 .value(if enabled { 0 } else { 1 }),
         }
     }
@@ -175,10 +412,10 @@ impl NotificationsEnabled {
                         .color(Color::LightRed)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(Color::LightRed)
                 .rewind(true)
-                .title("Enable notifications?", Alignment::Left)
+                .title(tr("enable-notifications"), Alignment::Left)
                 .value(if enabled { 0 } else { 1 }),
         }
     }
@@ -195,6 +432,40 @@ impl Component<Msg, NoUserEvent> for NotificationsEnabled {
     }
 }
 
+#[derive(MockComponent)]
+pub struct PartialUploads {
+    component: Radio,
+}
+
+impl PartialUploads {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightGreen)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(&[tr("yes"), tr("no")])
+                .foreground(Color::LightGreen)
+                .rewind(true)
+                .title(tr("partial-uploads"), Alignment::Left)
+                .value(if enabled { 0 } else { 1 }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for PartialUploads {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::PartialUploadsBlurDown),
+            Msg::Config(ConfigMsg::PartialUploadsBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct PromptOnFileReplace {
     component: Radio,
@@ -209,10 +480,10 @@ impl PromptOnFileReplace {
                         .color(Color::LightBlue)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(Color::LightBlue)
                 .rewind(true)
-                .title("Prompt when replacing existing files?", Alignment::Left)
+                .title(tr("prompt-on-replace"), Alignment::Left)
                 .value(if enabled { 0 } else { 1 }),
         }
     }
@@ -249,7 +520,7 @@ impl LocalFileFmt {
                     "{NAME:36} {PEX} {SIZE} {MTIME:17:%b %d %Y %H:%M}",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("File formatter syntax (local)", Alignment::Left)
+                .title(tr("local-file-fmt"), Alignment::Left)
                 .value(value),
         }
     }
@@ -291,7 +562,7 @@ impl NotificationsThreshold {
                 .invalid_style(Style::default().fg(Color::Red))
                 .input_type(InputType::Custom(validate, char_valid))
                 .placeholder("64 MB", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Notifications: minimum transfer size", Alignment::Left)
+                .title(tr("notifications-threshold"), Alignment::Left)
                 .value(value),
         }
     }
@@ -308,6 +579,51 @@ impl Component<Msg, NoUserEvent> for NotificationsThreshold {
     }
 }
 
+#[derive(MockComponent)]
+pub struct OwnershipMap {
+    component: Input,
+}
+
+impl OwnershipMap {
+    pub fn new(value: &str) -> Self {
+        // -- validators
+        fn validate(map: &str) -> bool {
+            OwnershipMapType::from_str(map).is_ok()
+        }
+        fn char_valid(_input: &str, _incoming: char) -> bool {
+            true
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightMagenta)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightMagenta)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder(
+                    "preserve, uid:1000=1001, gid:100=50",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title(tr("preserve-ownership"), Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for OwnershipMap {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::OwnershipMapBlurDown),
+            Msg::Config(ConfigMsg::OwnershipMapBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct RemoteFileFmt {
     component: Input,
@@ -328,7 +644,7 @@ impl RemoteFileFmt {
                     "{NAME:36} {PEX} {SIZE} {MTIME:17:%b %d %Y %H:%M}",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("File formatter syntax (remote)", Alignment::Left)
+                .title(tr("remote-file-fmt"), Alignment::Left)
                 .value(value),
         }
     }
@@ -345,6 +661,48 @@ impl Component<Msg, NoUserEvent> for RemoteFileFmt {
     }
 }
 
+#[derive(MockComponent)]
+pub struct SplitSize {
+    component: Input,
+}
+
+impl SplitSize {
+    pub fn new(value: &str) -> Self {
+        // -- validators
+        fn validate(bytes: &str) -> bool {
+            bytes.is_empty() || parse_bytesize(bytes).is_some()
+        }
+        fn char_valid(_input: &str, incoming: char) -> bool {
+            incoming.is_ascii_digit() || ['B', 'K', 'M', 'G', 'T', 'P'].contains(&incoming)
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightBlue)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightBlue)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder("4 GB", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title(tr("split-files"), Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SplitSize {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::SplitSizeBlurDown),
+            Msg::Config(ConfigMsg::SplitSizeBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct SshConfig {
     component: Input,
@@ -365,7 +723,7 @@ impl SshConfig {
                     "~/.ssh/config",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("SSH configuration path", Alignment::Left)
+                .title(tr("ssh-config-path"), Alignment::Left)
                 .value(value),
         }
     }
@@ -382,6 +740,96 @@ impl Component<Msg, NoUserEvent> for SshConfig {
     }
 }
 
+#[derive(MockComponent)]
+pub struct StatusBarFmt {
+    component: Input,
+}
+
+impl StatusBarFmt {
+    pub fn new(value: &str) -> Self {
+        // -- validators
+        fn validate(fmt: &str) -> bool {
+            StatusBarFmtType::from_str(fmt).is_ok()
+        }
+        fn char_valid(_input: &str, _incoming: char) -> bool {
+            true
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightCyan)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightCyan)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder(
+                    "{PATH} | {SORTING} | {DISK}",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title(tr("status-bar-format"), Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for StatusBarFmt {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::StatusBarFmtBlurDown),
+            Msg::Config(ConfigMsg::StatusBarFmtBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct TransferFilter {
+    component: Input,
+}
+
+impl TransferFilter {
+    pub fn new(value: &str) -> Self {
+        // -- validators
+        fn validate(filter: &str) -> bool {
+            TransferFilterType::from_str(filter).is_ok()
+        }
+        fn char_valid(_input: &str, _incoming: char) -> bool {
+            true
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Red)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::Red)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder(
+                    "target/, .git/, *.o",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title(tr("default-transfer-filter"), Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for TransferFilter {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::TransferFilterBlurDown),
+            Msg::Config(ConfigMsg::TransferFilterBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct TextEditor {
     component: Input,
@@ -399,7 +847,7 @@ impl TextEditor {
                 .foreground(Color::LightGreen)
                 .input_type(InputType::Text)
                 .placeholder("vim", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Text editor", Alignment::Left)
+                .title(tr("text-editor"), Alignment::Left)
                 .value(value),
         }
     }
@@ -9,10 +9,14 @@ mod config;
 mod ssh;
 mod theme;
 
-pub(super) use commons::{ErrorPopup, Footer, Header, Keybindings, QuitPopup, SavePopup};
+pub(super) use commons::{
+    ErrorPopup, Footer, Header, Keybindings, ProfilePopup, QuitPopup, SavePopup,
+};
 pub(super) use config::{
-    CheckUpdates, DefaultProtocol, GroupDirs, HiddenFiles, LocalFileFmt, NotificationsEnabled,
-    NotificationsThreshold, PromptOnFileReplace, RemoteFileFmt, SshConfig, TextEditor,
+    Accessibility, CheckUpdates, DefaultProtocol, DeleteConfirmation, FileSorting, FtpMode,
+    GroupDirs, HiddenFiles, LocalFileFmt, NotificationsEnabled, NotificationsThreshold,
+    OwnershipMap, PartialUploads, PromptOnFileReplace, RemoteFileFmt, SplitSize, SshCompression,
+    SshConfig, StatusBarFmt, SymlinkPolicy, TextEditor, TransferFilter,
 };
 pub(super) use ssh::{DelSshKeyPopup, SshHost, SshKeys, SshUsername};
 pub(super) use theme::*;
@@ -46,6 +50,10 @@ impl Component<Msg, NoUserEvent> for GlobalListener {
                 code: Key::Function(1),
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::Common(CommonMsg::ShowKeybindings)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('p'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Common(CommonMsg::ShowProfilePopup)),
             Event::Keyboard(KeyEvent {
                 code: Key::Char('r'),
                 modifiers: KeyModifiers::CONTROL,
@@ -3,6 +3,7 @@
 //! ssh components
 
 use super::{Msg, SshMsg};
+use crate::ui::i18n::tr;
 
 use tui_realm_stdlib::{Input, List, Radio};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
@@ -31,10 +32,10 @@ impl Default for DelSshKeyPopup {
                         .color(Color::Red)
                         .modifiers(BorderType::Rounded),
                 )
-                .choices(&["Yes", "No"])
+                .choices(&[tr("yes"), tr("no")])
                 .foreground(Color::Red)
                 .rewind(true)
-                .title("Delete key?", Alignment::Center)
+                .title(tr("delete-ssh-key"), Alignment::Center)
                 .value(1),
         }
     }
@@ -103,7 +104,7 @@ impl SshKeys {
                 .rows(keys.iter().map(|x| vec![TextSpan::from(x)]).collect())
                 .step(4)
                 .scroll(true)
-                .title("SSH Keys", Alignment::Left),
+                .title(tr("setup-ssh-keys"), Alignment::Left),
         }
     }
 }
@@ -181,7 +182,7 @@ impl Default for SshHost {
                     "192.168.1.2",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Hostname or address", Alignment::Center),
+                .title(tr("ssh-host"), Alignment::Center),
         }
     }
 }
@@ -265,7 +266,7 @@ impl Default for SshUsername {
                 )
                 .input_type(InputType::Text)
                 .placeholder("root", Style::default().fg(Color::Rgb(128, 128, 128)))
-                .title("Username", Alignment::Center),
+                .title(tr("username"), Alignment::Center),
         }
     }
 }
@@ -3,14 +3,22 @@
 //! theme tab components
 
 use super::{Msg, ThemeMsg};
+use crate::config::themes::Theme;
 use crate::ui::activities::setup::IdTheme;
+use crate::ui::i18n::tr;
 
 use tui_realm_stdlib::{Input, Label};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{Alignment, BorderType, Borders, Color, InputType, Style, TextModifiers};
+use tuirealm::tui::layout::{Constraint, Direction as LayoutDirection, Layout, Rect};
+use tuirealm::tui::text::{Span, Spans};
+use tuirealm::tui::widgets::{
+    Block, Borders as TuiBorders, Clear, Gauge, List as TuiList, ListItem, Paragraph,
+};
 use tuirealm::{
-    AttrValue, Attribute, Component, Event, MockComponent, NoUserEvent, State, StateValue,
+    AttrValue, Attribute, Component, Event, Frame, MockComponent, NoUserEvent, Props, State,
+    StateValue,
 };
 
 // -- components
@@ -25,7 +33,7 @@ impl Default for AuthTitle {
         Self {
             component: Label::default()
                 .modifiers(TextModifiers::BOLD)
-                .text("Authentication styles"),
+                .text(tr("theme-auth")),
         }
     }
 }
@@ -46,7 +54,7 @@ impl Default for MiscTitle {
         Self {
             component: Label::default()
                 .modifiers(TextModifiers::BOLD)
-                .text("Misc styles"),
+                .text(tr("theme-misc")),
         }
     }
 }
@@ -67,7 +75,7 @@ impl Default for TransferTitle {
         Self {
             component: Label::default()
                 .modifiers(TextModifiers::BOLD)
-                .text("Transfer styles"),
+                .text(tr("theme-transfer")),
         }
     }
 }
@@ -88,7 +96,7 @@ impl Default for TransferTitle2 {
         Self {
             component: Label::default()
                 .modifiers(TextModifiers::BOLD)
-                .text("Transfer styles (2)"),
+                .text(tr("theme-transfer-2")),
         }
     }
 }
@@ -99,6 +107,223 @@ impl Component<Msg, NoUserEvent> for TransferTitle2 {
     }
 }
 
+/// Miniature of the file transfer activity, drawn with the colors of the theme being edited
+pub struct ThemePreview {
+    props: Props,
+    theme: Theme,
+}
+
+impl ThemePreview {
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            props: Props::default(),
+            theme,
+        }
+    }
+
+    fn view_explorer(&self, frame: &mut Frame, area: Rect, title: &str, colors: [Color; 3]) {
+        let [bg, fg, hg] = colors;
+        let files: Vec<ListItem> = ["../", "docs/", "Cargo.toml", "README.md"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let style = match i {
+                    0 => Style::default()
+                        .fg(hg)
+                        .add_modifier(TextModifiers::REVERSED),
+                    _ => Style::default().fg(fg),
+                };
+                ListItem::new(Span::styled(*name, style))
+            })
+            .collect();
+        let list = TuiList::new(files).style(Style::default().bg(bg)).block(
+            Block::default()
+                .borders(TuiBorders::ALL)
+                .border_style(Style::default().fg(hg))
+                .title(title),
+        );
+        frame.render_widget(list, area);
+    }
+
+    fn view_progress_bar(&self, frame: &mut Frame, area: Rect, progress: u16, color: Color) {
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .borders(TuiBorders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .gauge_style(Style::default().fg(color))
+            .percent(progress);
+        frame.render_widget(gauge, area);
+    }
+
+    fn view_popup(&self, frame: &mut Frame, area: Rect) {
+        let popup = Rect {
+            x: area.x + area.width / 6,
+            y: area.y + area.height / 2,
+            width: area.width - area.width / 3,
+            height: 3.min(area.height / 2),
+        };
+        let color = self.theme.misc_info_dialog;
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(Span::styled(tr("connecting"), Style::default().fg(color))).block(
+                Block::default()
+                    .borders(TuiBorders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(color)),
+            ),
+            popup,
+        );
+    }
+}
+
+impl MockComponent for ThemePreview {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = &self.theme;
+        let div = Block::default()
+            .borders(TuiBorders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(tr("preview"));
+        let inner = div.inner(area);
+        frame.render_widget(div, area);
+        let chunks = Layout::default()
+            .direction(LayoutDirection::Vertical)
+            .constraints(
+                [
+                    Constraint::Min(6),    // Local explorer
+                    Constraint::Min(6),    // Remote explorer
+                    Constraint::Length(1), // Status bar
+                    Constraint::Length(3), // Progress bar (full)
+                    Constraint::Length(3), // Progress bar (partial)
+                    Constraint::Length(4), // Log
+                    Constraint::Length(1), // Dialogs
+                    Constraint::Length(1), // Keys
+                ]
+                .as_ref(),
+            )
+            .split(inner);
+        self.view_explorer(
+            frame,
+            chunks[0],
+            &tr("localhost"),
+            [
+                theme.transfer_local_explorer_background,
+                theme.transfer_local_explorer_foreground,
+                theme.transfer_local_explorer_highlighted,
+            ],
+        );
+        self.view_explorer(
+            frame,
+            chunks[1],
+            &tr("remote-host"),
+            [
+                theme.transfer_remote_explorer_background,
+                theme.transfer_remote_explorer_foreground,
+                theme.transfer_remote_explorer_highlighted,
+            ],
+        );
+        frame.render_widget(
+            Paragraph::new(Spans::from(vec![
+                Span::styled(
+                    tr("sorted-by-name"),
+                    Style::default()
+                        .fg(theme.transfer_status_sorting)
+                        .add_modifier(TextModifiers::REVERSED),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    tr("preview-hidden"),
+                    Style::default()
+                        .fg(theme.transfer_status_hidden)
+                        .add_modifier(TextModifiers::REVERSED),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    tr("preview-sync"),
+                    Style::default()
+                        .fg(theme.transfer_status_sync_browsing)
+                        .add_modifier(TextModifiers::REVERSED),
+                ),
+            ])),
+            chunks[2],
+        );
+        self.view_progress_bar(frame, chunks[3], 60, theme.transfer_progress_bar_full);
+        self.view_progress_bar(frame, chunks[4], 30, theme.transfer_progress_bar_partial);
+        frame.render_widget(
+            Paragraph::new(vec![
+                Spans::from("[INFO] Connected"),
+                Spans::from("[INFO] Uploaded"),
+            ])
+            .style(Style::default().bg(theme.transfer_log_background))
+            .block(
+                Block::default()
+                    .borders(TuiBorders::ALL)
+                    .border_style(Style::default().fg(theme.transfer_log_window))
+                    .title(tr("log")),
+            ),
+            chunks[5],
+        );
+        let dialogs: Vec<Span> = [
+            (tr("preview-error"), theme.misc_error_dialog),
+            (tr("theme-info"), theme.misc_info_dialog),
+            (tr("theme-input"), theme.misc_input_dialog),
+            (tr("footer-quit"), theme.misc_quit_dialog),
+            (tr("save"), theme.misc_save_dialog),
+            (tr("preview-warn"), theme.misc_warn_dialog),
+        ]
+        .iter()
+        .flat_map(|(label, color)| {
+            [
+                Span::styled(
+                    label.clone(),
+                    Style::default()
+                        .fg(*color)
+                        .add_modifier(TextModifiers::REVERSED),
+                ),
+                Span::raw(" "),
+            ]
+        })
+        .collect();
+        frame.render_widget(Paragraph::new(Spans::from(dialogs)), chunks[6]);
+        frame.render_widget(
+            Paragraph::new(Spans::from(vec![
+                Span::styled(
+                    "<ENTER>",
+                    Style::default()
+                        .fg(theme.misc_keys)
+                        .add_modifier(TextModifiers::BOLD),
+                ),
+                Span::raw(format!(" {}", tr("footer-enter-dir"))),
+            ])),
+            chunks[7],
+        );
+        self.view_popup(frame, chunks[0]);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ThemePreview {
+    fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+        None
+    }
+}
+
 #[derive(MockComponent)]
 pub struct AuthAddress {
     component: InputColor,
@@ -108,7 +333,7 @@ impl AuthAddress {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Ip Address",
+                &tr("ip-address"),
                 IdTheme::AuthAddress,
                 value,
                 Msg::Theme(ThemeMsg::AuthAddressBlurDown),
@@ -133,7 +358,7 @@ impl AuthBookmarks {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Bookmarks",
+                &tr("bookmarks"),
                 IdTheme::AuthBookmarks,
                 value,
                 Msg::Theme(ThemeMsg::AuthBookmarksBlurDown),
@@ -158,7 +383,7 @@ impl AuthPassword {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Password",
+                &tr("password"),
                 IdTheme::AuthPassword,
                 value,
                 Msg::Theme(ThemeMsg::AuthPasswordBlurDown),
@@ -183,7 +408,7 @@ impl AuthPort {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Port",
+                &tr("port"),
                 IdTheme::AuthPort,
                 value,
                 Msg::Theme(ThemeMsg::AuthPortBlurDown),
@@ -208,7 +433,7 @@ impl AuthProtocol {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Protocol",
+                &tr("protocol"),
                 IdTheme::AuthProtocol,
                 value,
                 Msg::Theme(ThemeMsg::AuthProtocolBlurDown),
@@ -233,7 +458,7 @@ impl AuthRecentHosts {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Recent connections",
+                &tr("recent-connections"),
                 IdTheme::AuthRecentHosts,
                 value,
                 Msg::Theme(ThemeMsg::AuthRecentHostsBlurDown),
@@ -257,7 +482,7 @@ impl AuthUsername {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Username",
+                &tr("username"),
                 IdTheme::AuthUsername,
                 value,
                 Msg::Theme(ThemeMsg::AuthUsernameBlurDown),
@@ -282,7 +507,7 @@ impl ExplorerLocalBg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Local explorer background",
+                &tr("theme-local-explorer-background"),
                 IdTheme::ExplorerLocalBg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerLocalBgBlurDown),
@@ -307,7 +532,7 @@ impl ExplorerLocalFg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Local explorer foreground",
+                &tr("theme-local-explorer-foreground"),
                 IdTheme::ExplorerLocalFg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerLocalFgBlurDown),
@@ -332,7 +557,7 @@ impl ExplorerLocalHg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Local explorer highlighted",
+                &tr("theme-local-explorer-highlighted"),
                 IdTheme::ExplorerLocalHg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerLocalHgBlurDown),
@@ -357,7 +582,7 @@ impl ExplorerRemoteBg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Remote explorer background",
+                &tr("theme-remote-explorer-background"),
                 IdTheme::ExplorerRemoteBg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerRemoteBgBlurDown),
@@ -382,7 +607,7 @@ impl ExplorerRemoteFg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Remote explorer foreground",
+                &tr("theme-remote-explorer-foreground"),
                 IdTheme::ExplorerRemoteFg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerRemoteFgBlurDown),
@@ -407,7 +632,7 @@ impl ExplorerRemoteHg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Remote explorer highlighted",
+                &tr("theme-remote-explorer-highlighted"),
                 IdTheme::ExplorerRemoteHg,
                 value,
                 Msg::Theme(ThemeMsg::ExplorerRemoteHgBlurDown),
@@ -432,7 +657,7 @@ impl LogBg {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Log window background",
+                &tr("theme-log-background"),
                 IdTheme::LogBg,
                 value,
                 Msg::Theme(ThemeMsg::LogBgBlurDown),
@@ -457,7 +682,7 @@ impl LogWindow {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Log window",
+                &tr("theme-log-window"),
                 IdTheme::LogWindow,
                 value,
                 Msg::Theme(ThemeMsg::LogWindowBlurDown),
@@ -482,7 +707,7 @@ impl MiscError {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Error",
+                &tr("theme-error"),
                 IdTheme::MiscError,
                 value,
                 Msg::Theme(ThemeMsg::MiscErrorBlurDown),
@@ -507,7 +732,7 @@ impl MiscInfo {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Info",
+                &tr("theme-info"),
                 IdTheme::MiscInfo,
                 value,
                 Msg::Theme(ThemeMsg::MiscInfoBlurDown),
@@ -532,7 +757,7 @@ impl MiscInput {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Input",
+                &tr("theme-input"),
                 IdTheme::MiscInput,
                 value,
                 Msg::Theme(ThemeMsg::MiscInputBlurDown),
@@ -557,7 +782,7 @@ impl MiscKeys {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Key strokes",
+                &tr("theme-keys"),
                 IdTheme::MiscKeys,
                 value,
                 Msg::Theme(ThemeMsg::MiscKeysBlurDown),
@@ -582,7 +807,7 @@ impl MiscQuit {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Quit dialogs",
+                &tr("theme-quit"),
                 IdTheme::MiscQuit,
                 value,
                 Msg::Theme(ThemeMsg::MiscQuitBlurDown),
@@ -607,7 +832,7 @@ impl MiscSave {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Save confirmations",
+                &tr("theme-save"),
                 IdTheme::MiscSave,
                 value,
                 Msg::Theme(ThemeMsg::MiscSaveBlurDown),
@@ -632,7 +857,7 @@ impl MiscWarn {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Warnings",
+                &tr("theme-warn"),
                 IdTheme::MiscWarn,
                 value,
                 Msg::Theme(ThemeMsg::MiscWarnBlurDown),
@@ -657,7 +882,7 @@ impl ProgBarFull {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "'Full transfer' Progress bar",
+                &tr("theme-progress-full"),
                 IdTheme::ProgBarFull,
                 value,
                 Msg::Theme(ThemeMsg::ProgBarFullBlurDown),
@@ -682,7 +907,7 @@ impl ProgBarPartial {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "'Partial transfer' Progress bar",
+                &tr("theme-progress-partial"),
                 IdTheme::ProgBarPartial,
                 value,
                 Msg::Theme(ThemeMsg::ProgBarPartialBlurDown),
@@ -707,7 +932,7 @@ impl StatusHidden {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Hidden files",
+                &tr("status-hidden-files"),
                 IdTheme::StatusHidden,
                 value,
                 Msg::Theme(ThemeMsg::StatusHiddenBlurDown),
@@ -732,7 +957,7 @@ impl StatusSorting {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "File sorting",
+                &tr("status-file-sorting"),
                 IdTheme::StatusSorting,
                 value,
                 Msg::Theme(ThemeMsg::StatusSortingBlurDown),
@@ -757,7 +982,7 @@ impl StatusSync {
     pub fn new(value: Color) -> Self {
         Self {
             component: InputColor::new(
-                "Synchronized browsing",
+                &tr("theme-sync-browsing"),
                 IdTheme::StatusSync,
                 value,
                 Msg::Theme(ThemeMsg::StatusSyncBlurDown),
@@ -37,23 +37,35 @@ enum IdCommon {
     GlobalListener,
     Header,
     Keybindings,
+    ProfilePopup,
     QuitPopup,
     SavePopup,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 enum IdConfig {
+    Accessibility,
     CheckUpdates,
     DefaultProtocol,
+    DeleteConfirmation,
+    FileSorting,
+    FtpMode,
     GroupDirs,
     HiddenFiles,
     LocalFileFmt,
     NotificationsEnabled,
     NotificationsThreshold,
+    OwnershipMap,
+    PartialUploads,
     PromptOnFileReplace,
     RemoteFileFmt,
+    SplitSize,
+    SshCompression,
     SshConfig,
+    StatusBarFmt,
+    SymlinkPolicy,
     TextEditor,
+    TransferFilter,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -90,6 +102,7 @@ pub enum IdTheme {
     MiscSave,
     MiscTitle,
     MiscWarn,
+    Preview,
     ProgBarFull,
     ProgBarPartial,
     StatusHidden,
@@ -113,6 +126,7 @@ pub enum CommonMsg {
     ChangeLayout,
     CloseErrorPopup,
     CloseKeybindingsPopup,
+    CloseProfilePopup,
     CloseQuitPopup,
     CloseSavePopup,
     Quit,
@@ -120,18 +134,28 @@ pub enum CommonMsg {
     SaveAndQuit,
     SaveConfig,
     ShowKeybindings,
+    ShowProfilePopup,
     ShowQuitPopup,
     ShowSavePopup,
+    SwitchProfile(String),
     WindowResized,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigMsg {
+    AccessibilityBlurDown,
+    AccessibilityBlurUp,
     CheckUpdatesBlurDown,
     CheckUpdatesBlurUp,
     ConfigChanged,
     DefaultProtocolBlurDown,
     DefaultProtocolBlurUp,
+    DeleteConfirmationBlurDown,
+    DeleteConfirmationBlurUp,
+    FileSortingBlurDown,
+    FileSortingBlurUp,
+    FtpModeBlurDown,
+    FtpModeBlurUp,
     GroupDirsBlurDown,
     GroupDirsBlurUp,
     HiddenFilesBlurDown,
@@ -142,14 +166,28 @@ pub enum ConfigMsg {
     NotificationsEnabledBlurUp,
     NotificationsThresholdBlurDown,
     NotificationsThresholdBlurUp,
+    OwnershipMapBlurDown,
+    OwnershipMapBlurUp,
+    PartialUploadsBlurDown,
+    PartialUploadsBlurUp,
     PromptOnFileReplaceBlurDown,
     PromptOnFileReplaceBlurUp,
     RemoteFileFmtBlurDown,
     RemoteFileFmtBlurUp,
+    SplitSizeBlurDown,
+    SplitSizeBlurUp,
+    SshCompressionBlurDown,
+    SshCompressionBlurUp,
     SshConfigBlurDown,
     SshConfigBlurUp,
+    StatusBarFmtBlurDown,
+    StatusBarFmtBlurUp,
+    SymlinkPolicyBlurDown,
+    SymlinkPolicyBlurUp,
     TextEditorBlurDown,
     TextEditorBlurUp,
+    TransferFilterBlurDown,
+    TransferFilterBlurUp,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,6 +45,9 @@ impl SetupActivity {
             CommonMsg::CloseKeybindingsPopup => {
                 self.umount_help();
             }
+            CommonMsg::CloseProfilePopup => {
+                self.umount_profile_popup();
+            }
             CommonMsg::CloseQuitPopup => {
                 self.umount_quit();
             }
@@ -87,12 +90,21 @@ impl SetupActivity {
             CommonMsg::ShowKeybindings => {
                 self.mount_help();
             }
+            CommonMsg::ShowProfilePopup => {
+                self.mount_profile_popup();
+            }
             CommonMsg::ShowQuitPopup => {
                 self.action_on_esc();
             }
             CommonMsg::ShowSavePopup => {
                 self.mount_save_popup();
             }
+            CommonMsg::SwitchProfile(profile) => {
+                self.umount_profile_popup();
+                if let Err(err) = self.action_switch_profile(profile.trim()) {
+                    self.mount_error(err);
+                }
+            }
             CommonMsg::WindowResized => {
                 self.redraw = true;
             }
@@ -102,6 +114,15 @@ impl SetupActivity {
 
     fn config_update(&mut self, msg: ConfigMsg) -> Option<Msg> {
         match msg {
+            ConfigMsg::AccessibilityBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::SplitSize)).is_ok());
+            }
+            ConfigMsg::AccessibilityBlurUp => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::SymlinkPolicy))
+                    .is_ok());
+            }
             ConfigMsg::CheckUpdatesBlurDown => {
                 assert!(self
                     .app
@@ -117,8 +138,35 @@ impl SetupActivity {
             ConfigMsg::DefaultProtocolBlurUp => {
                 assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
             }
+            ConfigMsg::DeleteConfirmationBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
+            }
+            ConfigMsg::DeleteConfirmationBlurUp => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::TransferFilter))
+                    .is_ok());
+            }
+            ConfigMsg::FileSortingBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::SymlinkPolicy))
+                    .is_ok());
+            }
+            ConfigMsg::FileSortingBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::GroupDirs)).is_ok());
+            }
+            ConfigMsg::FtpModeBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::SshCompression))
+                    .is_ok());
+            }
+            ConfigMsg::FtpModeBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::SshConfig)).is_ok());
+            }
             ConfigMsg::GroupDirsBlurDown => {
-                assert!(self.app.active(&Id::Config(IdConfig::LocalFileFmt)).is_ok());
+                assert!(self.app.active(&Id::Config(IdConfig::FileSorting)).is_ok());
             }
             ConfigMsg::GroupDirsBlurUp => {
                 assert!(self
@@ -142,7 +190,10 @@ impl SetupActivity {
                     .is_ok());
             }
             ConfigMsg::LocalFileFmtBlurUp => {
-                assert!(self.app.active(&Id::Config(IdConfig::GroupDirs)).is_ok());
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::PartialUploads))
+                    .is_ok());
             }
             ConfigMsg::NotificationsEnabledBlurDown => {
                 assert!(self
@@ -165,6 +216,21 @@ impl SetupActivity {
                     .active(&Id::Config(IdConfig::NotificationsEnabled))
                     .is_ok());
             }
+            ConfigMsg::OwnershipMapBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::StatusBarFmt)).is_ok());
+            }
+            ConfigMsg::OwnershipMapBlurUp => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::SshCompression))
+                    .is_ok());
+            }
+            ConfigMsg::PartialUploadsBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::LocalFileFmt)).is_ok());
+            }
+            ConfigMsg::PartialUploadsBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::SplitSize)).is_ok());
+            }
             ConfigMsg::PromptOnFileReplaceBlurDown => {
                 assert!(self.app.active(&Id::Config(IdConfig::GroupDirs)).is_ok());
             }
@@ -187,10 +253,49 @@ impl SetupActivity {
                     .is_ok());
             }
             ConfigMsg::TextEditorBlurUp => {
-                assert!(self.app.active(&Id::Config(IdConfig::SshConfig)).is_ok());
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::DeleteConfirmation))
+                    .is_ok());
+            }
+            ConfigMsg::SshCompressionBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::OwnershipMap)).is_ok());
+            }
+            ConfigMsg::SshCompressionBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::FtpMode)).is_ok());
+            }
+            ConfigMsg::StatusBarFmtBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::TransferFilter))
+                    .is_ok());
+            }
+            ConfigMsg::StatusBarFmtBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::OwnershipMap)).is_ok());
+            }
+            ConfigMsg::TransferFilterBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::DeleteConfirmation))
+                    .is_ok());
+            }
+            ConfigMsg::TransferFilterBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::StatusBarFmt)).is_ok());
+            }
+            ConfigMsg::SplitSizeBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::PartialUploads))
+                    .is_ok());
+            }
+            ConfigMsg::SplitSizeBlurUp => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::Accessibility))
+                    .is_ok());
             }
             ConfigMsg::SshConfigBlurDown => {
-                assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
+                assert!(self.app.active(&Id::Config(IdConfig::FtpMode)).is_ok());
             }
             ConfigMsg::SshConfigBlurUp => {
                 assert!(self
@@ -198,6 +303,15 @@ impl SetupActivity {
                     .active(&Id::Config(IdConfig::NotificationsThreshold))
                     .is_ok());
             }
+            ConfigMsg::SymlinkPolicyBlurDown => {
+                assert!(self
+                    .app
+                    .active(&Id::Config(IdConfig::Accessibility))
+                    .is_ok());
+            }
+            ConfigMsg::SymlinkPolicyBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::FileSorting)).is_ok());
+            }
             ConfigMsg::ConfigChanged => {
                 self.set_config_changed(true);
             }
@@ -453,6 +567,7 @@ impl SetupActivity {
             }
             ThemeMsg::ColorChanged(id, color) => {
                 self.action_save_color(id, color);
+                self.load_preview();
                 // Set unsaved changes to true
                 self.set_config_changed(true);
             }
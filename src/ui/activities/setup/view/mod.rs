@@ -8,6 +8,7 @@ pub mod ssh_keys;
 pub mod theme;
 
 use super::*;
+use crate::system::environment;
 use crate::utils::ui::draw_area_in;
 pub use setup::*;
 pub use ssh_keys::*;
@@ -97,6 +98,24 @@ impl SetupActivity {
         let _ = self.app.umount(&Id::Common(IdCommon::SavePopup));
     }
 
+    /// Mount profile popup
+    pub(super) fn mount_profile_popup(&mut self) {
+        assert!(self
+            .app
+            .remount(
+                Id::Common(IdCommon::ProfilePopup),
+                Box::new(components::ProfilePopup::new(&environment::list_profiles())),
+                vec![],
+            )
+            .is_ok());
+        assert!(self.app.active(&Id::Common(IdCommon::ProfilePopup)).is_ok());
+    }
+
+    /// Umount profile popup
+    pub(super) fn umount_profile_popup(&mut self) {
+        let _ = self.app.umount(&Id::Common(IdCommon::ProfilePopup));
+    }
+
     /// Mount help
     pub(super) fn mount_help(&mut self) {
         assert!(self
@@ -136,6 +155,11 @@ impl SetupActivity {
             let popup = draw_area_in(f.size(), 30, 10);
             f.render_widget(Clear, popup);
             self.app.view(&Id::Common(IdCommon::SavePopup), f, popup);
+        } else if self.app.mounted(&Id::Common(IdCommon::ProfilePopup)) {
+            // make popup
+            let popup = draw_area_in(f.size(), 50, 10);
+            f.render_widget(Clear, popup);
+            self.app.view(&Id::Common(IdCommon::ProfilePopup), f, popup);
         }
     }
 
@@ -153,7 +177,10 @@ impl SetupActivity {
             .app
             .remount(
                 Id::Common(IdCommon::Header),
-                Box::new(components::Header::new(layout)),
+                Box::new(components::Header::new(
+                    layout,
+                    environment::get_profile().as_deref().unwrap_or("default"),
+                )),
                 vec![],
             )
             .is_ok());
@@ -211,6 +238,13 @@ impl SetupActivity {
                         }),
                         Self::no_popup_mounted_clause(),
                     ),
+                    Sub::new(
+                        SubEventClause::Keyboard(KeyEvent {
+                            code: Key::Char('p'),
+                            modifiers: KeyModifiers::CONTROL,
+                        }),
+                        Self::no_popup_mounted_clause(),
+                    ),
                     Sub::new(
                         SubEventClause::Keyboard(KeyEvent {
                             code: Key::Char('r'),
@@ -257,12 +291,17 @@ impl SetupActivity {
                             IdCommon::SavePopup,
                         ))))),
                         Box::new(SubClause::And(
-                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(Id::Ssh(
-                                IdSsh::DelSshKeyPopup,
-                            ))))),
-                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(Id::Ssh(
-                                IdSsh::SshHost,
+                            Box::new(SubClause::Not(Box::new(SubClause::IsMounted(Id::Common(
+                                IdCommon::ProfilePopup,
                             ))))),
+                            Box::new(SubClause::And(
+                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(Id::Ssh(
+                                    IdSsh::DelSshKeyPopup,
+                                ))))),
+                                Box::new(SubClause::Not(Box::new(SubClause::IsMounted(Id::Ssh(
+                                    IdSsh::SshHost,
+                                ))))),
+                            )),
                         )),
                     )),
                 )),
@@ -5,15 +5,48 @@
 
 // Locals
 use super::{components, Context, Id, IdCommon, IdConfig, SetupActivity, ViewLayout};
-use crate::explorer::GroupDirs;
-use crate::filetransfer::FileTransferProtocol;
+use crate::config::status_bar::StatusBarFmt;
+use crate::explorer::{FileSorting, GroupDirs};
+use crate::filetransfer::{FileTransferProtocol, OwnershipMap, SymlinkPolicy, TransferFilter};
 use crate::utils::fmt::fmt_bytes;
+use crate::utils::parser::parse_bytesize;
 
 // Ext
 use std::path::PathBuf;
+use std::str::FromStr;
 use tuirealm::tui::layout::{Constraint, Direction, Layout};
 use tuirealm::{State, StateValue};
 
+/// Fields of the first column of the setup form, from the top
+const SETUP_COLUMN_1: [IdConfig; 11] = [
+    IdConfig::TextEditor,
+    IdConfig::DefaultProtocol,
+    IdConfig::HiddenFiles,
+    IdConfig::CheckUpdates,
+    IdConfig::PromptOnFileReplace,
+    IdConfig::GroupDirs,
+    IdConfig::FileSorting,
+    IdConfig::SymlinkPolicy,
+    IdConfig::Accessibility,
+    IdConfig::SplitSize,
+    IdConfig::PartialUploads,
+];
+
+/// Fields of the second column of the setup form, from the top
+const SETUP_COLUMN_2: [IdConfig; 11] = [
+    IdConfig::LocalFileFmt,
+    IdConfig::RemoteFileFmt,
+    IdConfig::NotificationsEnabled,
+    IdConfig::NotificationsThreshold,
+    IdConfig::SshConfig,
+    IdConfig::FtpMode,
+    IdConfig::SshCompression,
+    IdConfig::OwnershipMap,
+    IdConfig::StatusBarFmt,
+    IdConfig::TransferFilter,
+    IdConfig::DeleteConfirmation,
+];
+
 impl SetupActivity {
     // -- view
 
@@ -29,6 +62,7 @@ impl SetupActivity {
 
     pub(super) fn view_setup(&mut self) {
         let mut ctx: Context = self.context.take().unwrap();
+        let focus = self.app.focus().cloned();
         let _ = ctx.terminal().raw_mut().draw(|f| {
             // Prepare main chunks
             let chunks = Layout::default()
@@ -52,79 +86,31 @@ impl SetupActivity {
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
                 .split(chunks[1]);
             // Column 1
+            let col1_fields = Self::visible_config_fields(
+                &SETUP_COLUMN_1,
+                focus.as_ref(),
+                ui_cfg_chunks[0].height,
+            );
             let ui_cfg_chunks_col1 = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints(
-                    [
-                        Constraint::Length(3), // Text editor
-                        Constraint::Length(3), // Protocol tab
-                        Constraint::Length(3), // Hidden files
-                        Constraint::Length(3), // Updates tab
-                        Constraint::Length(3), // Prompt file replace
-                        Constraint::Length(3), // Group dirs
-                        Constraint::Length(1), // Prevent overflow
-                    ]
-                    .as_ref(),
-                )
+                .constraints(Self::config_fields_constraints(col1_fields))
                 .split(ui_cfg_chunks[0]);
-            self.app
-                .view(&Id::Config(IdConfig::TextEditor), f, ui_cfg_chunks_col1[0]);
-            self.app.view(
-                &Id::Config(IdConfig::DefaultProtocol),
-                f,
-                ui_cfg_chunks_col1[1],
-            );
-            self.app
-                .view(&Id::Config(IdConfig::HiddenFiles), f, ui_cfg_chunks_col1[2]);
-            self.app.view(
-                &Id::Config(IdConfig::CheckUpdates),
-                f,
-                ui_cfg_chunks_col1[3],
-            );
-            self.app.view(
-                &Id::Config(IdConfig::PromptOnFileReplace),
-                f,
-                ui_cfg_chunks_col1[4],
-            );
-            self.app
-                .view(&Id::Config(IdConfig::GroupDirs), f, ui_cfg_chunks_col1[5]);
+            for (field, chunk) in col1_fields.iter().zip(ui_cfg_chunks_col1) {
+                self.app.view(&Id::Config(field.clone()), f, chunk);
+            }
             // Column 2
+            let col2_fields = Self::visible_config_fields(
+                &SETUP_COLUMN_2,
+                focus.as_ref(),
+                ui_cfg_chunks[1].height,
+            );
             let ui_cfg_chunks_col2 = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints(
-                    [
-                        Constraint::Length(3), // Local Format input
-                        Constraint::Length(3), // Remote Format input
-                        Constraint::Length(3), // Notifications enabled
-                        Constraint::Length(3), // Notifications threshold
-                        Constraint::Length(3), // Ssh config
-                        Constraint::Length(1), // Prevent overflow
-                    ]
-                    .as_ref(),
-                )
+                .constraints(Self::config_fields_constraints(col2_fields))
                 .split(ui_cfg_chunks[1]);
-            self.app.view(
-                &Id::Config(IdConfig::LocalFileFmt),
-                f,
-                ui_cfg_chunks_col2[0],
-            );
-            self.app.view(
-                &Id::Config(IdConfig::RemoteFileFmt),
-                f,
-                ui_cfg_chunks_col2[1],
-            );
-            self.app.view(
-                &Id::Config(IdConfig::NotificationsEnabled),
-                f,
-                ui_cfg_chunks_col2[2],
-            );
-            self.app.view(
-                &Id::Config(IdConfig::NotificationsThreshold),
-                f,
-                ui_cfg_chunks_col2[3],
-            );
-            self.app
-                .view(&Id::Config(IdConfig::SshConfig), f, ui_cfg_chunks_col2[4]);
+            for (field, chunk) in col2_fields.iter().zip(ui_cfg_chunks_col2) {
+                self.app.view(&Id::Config(field.clone()), f, chunk);
+            }
             // Popups
             self.view_popups(f);
         });
@@ -132,6 +118,32 @@ impl SetupActivity {
         self.context = Some(ctx);
     }
 
+    /// Get the fields of a setup form column which fit in `height` rows.
+    /// If the focused field is below them, the column is scrolled so that it is the last field shown
+    fn visible_config_fields<'a>(
+        fields: &'a [IdConfig],
+        focus: Option<&Id>,
+        height: u16,
+    ) -> &'a [IdConfig] {
+        // Each field takes 3 rows; keep one row to prevent overflow
+        let rows = ((height.saturating_sub(1) / 3) as usize).max(1);
+        let focus = fields
+            .iter()
+            .position(|x| focus == Some(&Id::Config(x.clone())))
+            .unwrap_or(0);
+        let start = (focus + 1).saturating_sub(rows);
+        &fields[start..fields.len().min(start + rows)]
+    }
+
+    /// Get the layout constraints of the visible `fields` of a setup form column
+    fn config_fields_constraints(fields: &[IdConfig]) -> Vec<Constraint> {
+        fields
+            .iter()
+            .map(|_| Constraint::Length(3))
+            .chain(std::iter::once(Constraint::Length(1))) // Prevent overflow
+            .collect()
+    }
+
     /// Load values from configuration into input fields
     pub(crate) fn load_input_values(&mut self) {
         // Text editor
@@ -198,6 +210,65 @@ impl SetupActivity {
                 vec![]
             )
             .is_ok());
+        // File sorting
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::FileSorting),
+                Box::new(components::FileSorting::new(
+                    self.config().get_file_sorting()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Symlink policy
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::SymlinkPolicy),
+                Box::new(components::SymlinkPolicy::new(
+                    self.config().get_symlink_policy()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Accessibility
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::Accessibility),
+                Box::new(components::Accessibility::new(
+                    self.config().get_accessibility()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Split size
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::SplitSize),
+                Box::new(components::SplitSize::new(
+                    &self
+                        .config()
+                        .get_split_size()
+                        .map(fmt_bytes)
+                        .unwrap_or_default()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Partial uploads
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::PartialUploads),
+                Box::new(components::PartialUploads::new(
+                    self.config().get_partial_uploads()
+                )),
+                vec![]
+            )
+            .is_ok());
         // Local File Fmt
         assert!(self
             .app
@@ -253,6 +324,86 @@ impl SetupActivity {
                 vec![]
             )
             .is_ok());
+        // Ownership map
+        let ownership_map: String = self
+            .config()
+            .get_ownership_map()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::OwnershipMap),
+                Box::new(components::OwnershipMap::new(ownership_map.as_str())),
+                vec![]
+            )
+            .is_ok());
+        // Status bar format
+        let status_bar_fmt: String = self
+            .config()
+            .get_status_bar_fmt()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::StatusBarFmt),
+                Box::new(components::StatusBarFmt::new(status_bar_fmt.as_str())),
+                vec![]
+            )
+            .is_ok());
+        // Transfer filter
+        let transfer_filter: String = self
+            .config()
+            .get_transfer_filter()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::TransferFilter),
+                Box::new(components::TransferFilter::new(transfer_filter.as_str())),
+                vec![]
+            )
+            .is_ok());
+        // Delete confirmation
+        let delete_confirmation: String = self
+            .config()
+            .get_delete_confirmation_threshold()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::DeleteConfirmation),
+                Box::new(components::DeleteConfirmation::new(
+                    delete_confirmation.as_str()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Ftp mode
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::FtpMode),
+                Box::new(components::FtpMode::new(
+                    self.config().get_ftp_active_mode()
+                )),
+                vec![]
+            )
+            .is_ok());
+        // Ssh compression
+        assert!(self
+            .app
+            .remount(
+                Id::Config(IdConfig::SshCompression),
+                Box::new(components::SshCompression::new(
+                    self.config().get_ssh_compression()
+                )),
+                vec![]
+            )
+            .is_ok());
     }
 
     /// Collect values from input and put them into the configuration
@@ -279,6 +430,10 @@ impl SetupActivity {
             self.app.state(&Id::Config(IdConfig::HiddenFiles))
         {
             let show: bool = matches!(opt, 0);
+            if show != self.config().get_show_hidden_files() {
+                // The new default must apply to the explorers
+                self.config_mut().clear_last_views();
+            }
             self.config_mut().set_show_hidden_files(show);
         }
         if let Ok(State::One(StateValue::Usize(opt))) =
@@ -313,6 +468,54 @@ impl SetupActivity {
             };
             self.config_mut().set_group_dirs(dirs);
         }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::FileSorting))
+        {
+            let sorting: FileSorting = match opt {
+                1 => FileSorting::NaturalName,
+                2 => FileSorting::ModifyTime,
+                3 => FileSorting::CreationTime,
+                4 => FileSorting::Size,
+                5 => FileSorting::Extension,
+                6 => FileSorting::Owner,
+                7 => FileSorting::Permissions,
+                _ => FileSorting::Name,
+            };
+            if sorting != self.config().get_file_sorting() {
+                // The new default must apply to the explorers
+                self.config_mut().clear_last_views();
+            }
+            self.config_mut().set_file_sorting(sorting);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::SymlinkPolicy))
+        {
+            let policy: SymlinkPolicy = match opt {
+                1 => SymlinkPolicy::CopyAsLink,
+                2 => SymlinkPolicy::Skip,
+                _ => SymlinkPolicy::Follow,
+            };
+            self.config_mut().set_symlink_policy(policy);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::Accessibility))
+        {
+            self.config_mut().set_accessibility(opt == 0);
+        }
+        if let Ok(State::One(StateValue::String(size))) =
+            self.app.state(&Id::Config(IdConfig::SplitSize))
+        {
+            let size: Option<u64> = match size.trim().is_empty() {
+                true => None,
+                false => parse_bytesize(size.as_str()).map(|x| x.as_u64()),
+            };
+            self.config_mut().set_split_size(size);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::PartialUploads))
+        {
+            self.config_mut().set_partial_uploads(opt == 0);
+        }
         if let Ok(State::One(StateValue::Usize(opt))) =
             self.app.state(&Id::Config(IdConfig::NotificationsEnabled))
         {
@@ -338,5 +541,48 @@ impl SetupActivity {
                 self.config_mut().set_ssh_config(Some(path));
             }
         }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::FtpMode))
+        {
+            self.config_mut().set_ftp_active_mode(opt == 1);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::SshCompression))
+        {
+            self.config_mut().set_ssh_compression(opt == 0);
+        }
+        if let Ok(State::One(StateValue::String(map))) =
+            self.app.state(&Id::Config(IdConfig::OwnershipMap))
+        {
+            let map: Option<OwnershipMap> = match map.trim().is_empty() {
+                true => None,
+                false => OwnershipMap::from_str(map.as_str()).ok(),
+            };
+            self.config_mut().set_ownership_map(map);
+        }
+        if let Ok(State::One(StateValue::String(fmt))) =
+            self.app.state(&Id::Config(IdConfig::StatusBarFmt))
+        {
+            let fmt: Option<StatusBarFmt> = match fmt.trim().is_empty() {
+                true => None,
+                false => StatusBarFmt::from_str(fmt.as_str()).ok(),
+            };
+            self.config_mut().set_status_bar_fmt(fmt);
+        }
+        if let Ok(State::One(StateValue::String(filter))) =
+            self.app.state(&Id::Config(IdConfig::TransferFilter))
+        {
+            let filter: Option<TransferFilter> = match filter.trim().is_empty() {
+                true => None,
+                false => TransferFilter::from_str(filter.as_str()).ok(),
+            };
+            self.config_mut().set_transfer_filter(filter);
+        }
+        if let Ok(State::One(StateValue::String(threshold))) =
+            self.app.state(&Id::Config(IdConfig::DeleteConfirmation))
+        {
+            self.config_mut()
+                .set_delete_confirmation_threshold(threshold.trim().parse::<usize>().ok());
+        }
     }
 }
@@ -48,10 +48,11 @@ impl SetupActivity {
                 .direction(Direction::Horizontal)
                 .constraints(
                     [
-                        Constraint::Percentage(25),
-                        Constraint::Percentage(25),
-                        Constraint::Percentage(25),
-                        Constraint::Percentage(25),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
                     ]
                     .as_ref(),
                 )
@@ -234,6 +235,9 @@ impl SetupActivity {
                 f,
                 transfer_colors_layout_col2[7],
             );
+            // Preview
+            self.app
+                .view(&Id::Theme(IdTheme::Preview), f, colors_layout[4]);
             // Popups
             self.view_popups(f);
         });
@@ -276,6 +280,19 @@ impl SetupActivity {
             .is_ok());
     }
 
+    /// Mount the preview of the file transfer activity with the current theme
+    pub(crate) fn load_preview(&mut self) {
+        let theme: Theme = self.theme().clone();
+        assert!(self
+            .app
+            .remount(
+                Id::Theme(IdTheme::Preview),
+                Box::new(components::ThemePreview::new(theme)),
+                vec![]
+            )
+            .is_ok());
+    }
+
     /// Load values from theme into input fields
     pub(crate) fn load_styles(&mut self) {
         let theme: Theme = self.theme().clone();
@@ -515,5 +532,6 @@ impl SetupActivity {
                 vec![]
             )
             .is_ok());
+        self.load_preview();
     }
 }
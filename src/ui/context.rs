@@ -89,6 +89,18 @@ impl Context {
         self.ft_params = Some(params);
     }
 
+    /// Replace bookmarks, configuration and theme (e.g. after the configuration profile has changed)
+    pub fn reload(
+        &mut self,
+        bookmarks_client: Option<BookmarksClient>,
+        config_client: ConfigClient,
+        theme_provider: ThemeProvider,
+    ) {
+        self.bookmarks_client = bookmarks_client;
+        self.config_client = config_client;
+        self.theme_provider = theme_provider;
+    }
+
     // -- error
 
     /// Set context error
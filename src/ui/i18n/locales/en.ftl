@@ -0,0 +1,339 @@
+# termscp - English messages
+#
+# Each message is written as `id = text`. Arguments are written as `{ $name }`.
+# Keep this file sorted by section; translations must use the same ids and arguments.
+
+## Common
+yes = Yes
+no = No
+current = current
+default = Default
+off = OFF
+on = ON
+
+## File transfer popups
+are-you-sure-to-disconnect = Are you sure you want to disconnect?
+are-you-sure-to-quit = Are you sure you want to quit termscp?
+checksum-of = { $algorithm } of "{ $file }" (<C> copy to clipboard)
+compute-checksum-of = Compute checksum of "{ $file }"
+copy-files-to = Copy file(s) to…
+create-symlink = Create a symlink pointing to the selected entry
+delete-files = Delete file(s)?
+delete-files-confirm = Type { $count } to delete { $count } file(s)
+directory-history = Directory history
+directory-marks = Marked directories
+find-duplicates-recursive = Look for duplicates in subdirectories too?
+execute-command = Execute command
+go-to = Go to…
+jump-to = Jump to
+keybindings = Keybindings
+move-files-to = Move file(s) to…
+new-directory-name = New directory name
+new-file-name = New file name
+not-enough-space = Not enough space on destination ({ $required } required, { $available } available). Transfer anyway?
+open-file-with = Open file with…
+overwrite-file = File "{ $file }" already exists. Overwrite file?
+overwrite-files = Overwrite files?
+export-search-results = Export search results to… (.csv for CSV)
+files-to-replace = The following files are going to be replaced
+save-as = Save as…
+search-files-by-name = Search files by name
+session-summary = Session summary
+sort-files-by = Sort files by… (<R> reverse: { $reversed }, <D> keep for this directory: { $remembered })
+start-transfer = Start transfer?
+switch-bucket = Switch bucket
+symlink-name = Symlink name
+sync-browsing-create-dir = Sync browsing: directory "{ $dir }" doesn't exist. Do you want to create it?
+synchronized-files = These files are currently synched with the remote host
+transfer-filter = Transfer excluding (e.g. target/, *.o, +*.rs)
+transfer-symlinks = Transfer symlinks in selection
+transfer-summary = { $files } file(s), { $size } in total
+type-program-to-open-with = Type the program to open the file with
+watch-start = Synchronize changes from "{ $local }" to "{ $remote }"?
+watch-stop = Stop synchronizing changes at "{ $local }"?
+watcher-log = Changes synchronized by the file watcher
+command-exit-code = { $command } (exit code: { $code })
+command-terminated-by-signal = { $command } (terminated by a signal)
+disk-space-free = { $available } free of { $total }
+disk-space-free-quota = { $available } free of { $total } (quota)
+file-encoding-extended = 8-bit, not UTF-8
+file-encoding-utf8-bom = UTF-8 with BOM
+log = Log
+no-output = (no output)
+not-supported-by-remote = (not supported by the remote host)
+selected-files = { $count } selected files
+watcher-error = ERROR
+watcher-log-empty = (no changes synchronized yet)
+watcher-move = Move
+watcher-ok = OK
+watcher-remove = Remove
+watcher-upload = Upload
+
+## Symlink policies
+symlink-copy-as-link = Copy as link
+symlink-follow = Follow
+symlink-skip = Skip
+
+## File info
+file-camera = Camera
+file-creation-time = Creation time
+file-date-taken = Date taken
+file-dimensions = Dimensions
+file-duration = Duration
+file-encoding = Encoding
+file-entries = Entries
+file-group = Group
+file-last-access-time = Last access time
+file-last-modified-time = Last modified time
+file-path = Path
+file-selected = Selected
+file-selected-count = { $count } ({ $files } files, { $dirs } directories)
+file-size = Size
+file-type = File type
+file-user = User
+
+## File sorting
+sorting-creation-time = Creation time
+sorting-extension = Extension
+sorting-modify-time = Modify time
+sorting-name = Name
+sorting-natural = Natural
+sorting-owner = Owner
+sorting-permissions = Permissions
+sorting-size = Size
+sorted-by-creation-time = By creation time
+sorted-by-extension = By extension
+sorted-by-modify-time = By modify time
+sorted-by-name = By name
+sorted-by-natural-name = By name (natural)
+sorted-by-owner = By owner
+sorted-by-permissions = By permissions
+sorted-by-size = By size
+sorted-reversed = { $sorting } (reversed)
+
+## Status bar
+status-disk = Disk
+status-file-sorting = File sorting
+status-hidden-files = Hidden files
+status-hidden-files-hide = Hide
+status-hidden-files-show = Show
+status-sync-browsing = Sync browsing
+status-watcher = Watching
+status-watcher-last-sync = { $paths } path(s), last sync at { $time }
+status-watcher-paths = { $paths } path(s)
+status-not-available = n/a
+
+## Keybindings
+help-auth-switch-tab = Switch from form and bookmarks
+help-bookmark-hotkey = Assign hotkey to selected bookmark
+help-change-cursor = Change cursor
+help-change-explorer-tab = Change explorer tab
+help-change-field = Change input field
+help-change-setup-page = Change setup page
+help-checksum = Compute file checksum (MD5, SHA-1, SHA-256)
+help-connect = Connect/Load bookmark
+help-copy = Copy
+help-create-symlink = Create symlink pointing to the current selected entry
+help-delete-bookmark = Delete selected bookmark
+help-delete-file = Delete selected file
+help-delete-ssh-key = Delete SSH key
+help-dir-history = Show directory history
+help-disconnect = Disconnect
+help-edit-file = Open text file with preferred editor
+help-enter-dir = Enter directory
+help-enter-setup = Enter setup
+help-exec = Execute shell command (find: export results)
+help-exit-setup = Exit setup
+help-file-info = Show info about selected file
+help-find-duplicates = Find duplicates in local and remote directories
+help-follow-file = Follow remote file (tail -f)
+help-go-to-mark = Go to directory marked at slot
+help-go-to-parent-dir = Go to parent directory
+help-go-to-path = Go to path
+help-go-to-previous-dir = Go to previous directory
+help-history-back-forward = Go back / forward in directory history
+help-interrupt-transfer = Interrupt file transfer
+help-jump-to = Jump to file by name
+help-make-dir = Make directory
+help-mark-dir = Mark working directory at slot
+help-move-in-list = Move up/down in list
+help-move-in-tab = Move up/down in current tab
+help-new-file = Create new file
+help-new-ssh-key = New SSH key
+help-open-file = Open file with default application for file type
+help-open-file-with = Open file with specified application
+help-quit = Quit termscp
+help-reload-dir = Reload directory content
+help-rename-file = Rename file
+help-revert-changes = Revert changes
+help-save-as = Save file as
+help-save-bookmark = Save bookmark
+help-save-config = Save configuration
+help-search-files = Search files
+help-select-all = Select all files
+help-select-dismiss = Select / Dismiss popup
+help-select-file = Select file
+help-show-help = Show help
+help-show-marks = Show marked directories
+help-show-watched = Show watched paths
+help-sort-files = Change file sorting mode
+help-switch-bookmark-tab = Switch bookmark tab
+help-switch-bucket = Switch S3 bucket
+help-switch-log = Switch between explorer and log window
+help-switch-profile = Switch profile
+help-sync-browsing = Toggle synchronized browsing
+help-toggle-hidden-files = Toggle hidden files
+help-transfer-file = Upload/Download file
+help-transfer-filter = Transfer excluding files matching patterns
+help-transfer-symlinks = Transfer choosing how to handle symlinks
+help-watch = Watch/unwatch file changes (find: transfer all results)
+help-watcher-log = Show changes synchronized by the file watcher
+
+## Footers
+footer-change-field = Change field
+footer-change-panel = Change panel
+footer-change-tab = Change tab
+footer-copy = Copy
+footer-delete = Delete
+footer-edit = Edit
+footer-enter-dir = Enter dir
+footer-enter-setup = Enter setup
+footer-exit = Exit
+footer-help = Help
+footer-install-update = Install update
+footer-make-dir = Make dir
+footer-quit = Quit
+footer-rename = Rename
+footer-save-as = Save as
+footer-save-parameters = Save parameters
+footer-submit-form = Submit form
+footer-switch-tab = Switch tab
+footer-transfer = Transfer
+footer-view = View
+
+## Wait and progress messages
+please-wait = Please wait
+progress-downloading = Downloading "{ $file }"…
+progress-downloading-entries = Downloading { $count } entries…
+progress-uploading = Uploading "{ $file }"…
+progress-uploading-entries = Uploading { $count } entries…
+wait-computing = Computing { $algorithm }…
+wait-connecting = Connecting to { $host }…
+wait-copying = Copying file(s)…
+wait-counting-files = Counting files to delete…
+wait-creating-symlink = Creating symlink…
+wait-disconnecting = Disconnecting from { $host }…
+wait-duplicates = Looking for duplicates…
+wait-executing = Executing '{ $command }'…
+wait-moving = Moving file(s)…
+wait-removing = Removing file(s)…
+wait-searching = Searching for "{ $search }"…
+
+## Authentication
+access-key = Access key
+auth-quit = Quit termscp?
+bookmark-name = Bookmark name
+bookmarks = Bookmarks
+bucket-name = Bucket name
+compression = Compression
+connect-anyway = Connect anyway?
+default-remote-directory = Default remote directory
+delete-bookmark = Delete selected bookmark?
+delete-recent = Delete selected recent host?
+download-limit = Download limit (per second)
+endpoint = Endpoint
+filename-encoding = Filename encoding
+install-update = Install update?
+new-path-style = New path style
+new-version-available = is NOW available! Install update and view release notes with <CTRL+R>
+password = Password
+port-number = Port number
+production-host = Production host
+profile = Profile
+protocol = Protocol
+read-only = Read-only
+recent-connections = Recent connections
+region = Region
+release-notes = Release notes
+remote-host = Remote host
+save-secrets = Save secrets?
+secret-access-key = Secret access key
+security-token = Security token
+session-token = Session token
+unlimited = unlimited
+upload-limit = Upload limit (per second)
+username = Username
+window-too-small = termscp requires at least 24 lines of height to run
+
+## Setup
+accessibility-mode = Accessibility mode?
+cancel = Cancel
+check-for-updates = Check for updates?
+compress-ssh = Compress SSH transport
+default-file-sorting = Default file sorting
+default-protocol = Default protocol
+default-transfer-filter = Transfer filter
+delete-confirmation-threshold = Type to confirm deletes of more files than
+delete-ssh-key = Delete key?
+dont-save = Don't save
+enable-notifications = Enable notifications?
+ftp-active = Active
+ftp-data-connection-mode = FTP data connection mode
+ftp-passive = Passive
+group-dirs = Group directories
+group-dirs-first = Display first
+group-dirs-last = Display last
+local-file-fmt = File formatter syntax (local)
+notifications-threshold = Notifications: minimum transfer size
+partial-uploads = Upload to temporary .part files?
+preserve-ownership = Preserve ownership (uid/gid mapping)
+prompt-on-replace = Prompt when replacing existing files?
+remote-file-fmt = File formatter syntax (remote)
+save = Save
+save-changes = Save changes?
+setup-config = Configuration parameters
+setup-profile = Profile: { $profile }
+setup-ssh-keys = SSH Keys
+setup-theme = Theme
+show-hidden-files = Show hidden files? (by default)
+split-files = Split files larger than
+ssh-config-path = SSH configuration path
+ssh-host = Hostname or address
+status-bar-format = Status bar format
+switch-profile = Switch to profile (a new one is created if missing)
+symlinks-in-transfers = Symlinks in recursive transfers
+text-editor = Text editor
+unsaved-changes = There are unsaved changes! Save changes before leaving?
+
+## Theme
+connecting = Connecting...
+ip-address = Ip Address
+localhost = Localhost
+port = Port
+preview = Preview
+preview-error = Err
+preview-hidden = Hidden
+preview-sync = Sync
+preview-warn = Warn
+theme-auth = Authentication styles
+theme-error = Error
+theme-info = Info
+theme-input = Input
+theme-keys = Key strokes
+theme-local-explorer-background = Local explorer background
+theme-local-explorer-foreground = Local explorer foreground
+theme-local-explorer-highlighted = Local explorer highlighted
+theme-log-background = Log window background
+theme-log-window = Log window
+theme-misc = Misc styles
+theme-progress-full = 'Full transfer' Progress bar
+theme-progress-partial = 'Partial transfer' Progress bar
+theme-quit = Quit dialogs
+theme-remote-explorer-background = Remote explorer background
+theme-remote-explorer-foreground = Remote explorer foreground
+theme-remote-explorer-highlighted = Remote explorer highlighted
+theme-save = Save confirmations
+theme-sync-browsing = Synchronized browsing
+theme-transfer = Transfer styles
+theme-transfer-2 = Transfer styles (2)
+theme-warn = Warnings
@@ -0,0 +1,338 @@
+# termscp - Messaggi in italiano
+#
+# Ogni messaggio è scritto come `id = testo`. Gli argomenti sono scritti come `{ $nome }`.
+
+## Common
+yes = Sì
+no = No
+current = attuale
+default = Predefinito
+off = NO
+on = SÌ
+
+## File transfer popups
+are-you-sure-to-disconnect = Sei sicuro di volerti disconnettere?
+are-you-sure-to-quit = Sei sicuro di voler uscire da termscp?
+checksum-of = { $algorithm } di "{ $file }" (<C> copia negli appunti)
+compute-checksum-of = Calcola il checksum di "{ $file }"
+copy-files-to = Copia file in…
+create-symlink = Crea un collegamento simbolico all'elemento selezionato
+delete-files = Eliminare i file?
+delete-files-confirm = Digita { $count } per eliminare { $count } file
+directory-history = Cronologia delle cartelle
+directory-marks = Cartelle contrassegnate
+find-duplicates-recursive = Cercare i duplicati anche nelle sottocartelle?
+execute-command = Esegui comando
+go-to = Vai a…
+jump-to = Salta a
+keybindings = Scorciatoie da tastiera
+move-files-to = Sposta file in…
+new-directory-name = Nome della nuova cartella
+new-file-name = Nome del nuovo file
+not-enough-space = Spazio insufficiente sulla destinazione ({ $required } richiesti, { $available } disponibili). Trasferire comunque?
+open-file-with = Apri file con…
+overwrite-file = Il file "{ $file }" esiste già. Sovrascriverlo?
+overwrite-files = Sovrascrivere i file?
+export-search-results = Esporta risultati della ricerca in… (.csv per CSV)
+files-to-replace = I seguenti file verranno sostituiti
+save-as = Salva come…
+search-files-by-name = Cerca file per nome
+session-summary = Riepilogo della sessione
+sort-files-by = Ordina file per… (<R> inverti: { $reversed }, <D> mantieni per questa cartella: { $remembered })
+start-transfer = Avviare il trasferimento?
+switch-bucket = Cambia bucket
+symlink-name = Nome del collegamento
+sync-browsing-create-dir = Navigazione sincronizzata: la cartella "{ $dir }" non esiste. Vuoi crearla?
+synchronized-files = Questi file sono attualmente sincronizzati con l'host remoto
+transfer-filter = Trasferisci escludendo (es. target/, *.o, +*.rs)
+transfer-symlinks = Trasferimento dei collegamenti simbolici nella selezione
+transfer-summary = { $files } file, { $size } in totale
+type-program-to-open-with = Digita il programma con cui aprire il file
+watch-start = Sincronizzare le modifiche da "{ $local }" a "{ $remote }"?
+watch-stop = Interrompere la sincronizzazione delle modifiche a "{ $local }"?
+watcher-log = Modifiche sincronizzate dal file watcher
+command-exit-code = { $command } (codice di uscita: { $code })
+command-terminated-by-signal = { $command } (terminato da un segnale)
+disk-space-free = { $available } liberi su { $total }
+disk-space-free-quota = { $available } liberi su { $total } (quota)
+file-encoding-extended = 8 bit, non UTF-8
+file-encoding-utf8-bom = UTF-8 con BOM
+log = Log
+no-output = (nessun output)
+not-supported-by-remote = (non supportato dall'host remoto)
+selected-files = { $count } file selezionati
+watcher-error = ERRORE
+watcher-log-empty = (nessuna modifica ancora sincronizzata)
+watcher-move = Sposta
+watcher-ok = OK
+watcher-remove = Rimuovi
+watcher-upload = Carica
+
+## Symlink policies
+symlink-copy-as-link = Copia come collegamento
+symlink-follow = Segui
+symlink-skip = Salta
+
+## File info
+file-camera = Fotocamera
+file-creation-time = Data di creazione
+file-date-taken = Data dello scatto
+file-dimensions = Dimensioni
+file-duration = Durata
+file-encoding = Codifica
+file-entries = Elementi
+file-group = Gruppo
+file-last-access-time = Ultimo accesso
+file-last-modified-time = Ultima modifica
+file-path = Percorso
+file-selected = Selezionati
+file-selected-count = { $count } ({ $files } file, { $dirs } cartelle)
+file-size = Dimensione
+file-type = Tipo di file
+file-user = Utente
+
+## File sorting
+sorting-creation-time = Data di creazione
+sorting-extension = Estensione
+sorting-modify-time = Data di modifica
+sorting-name = Nome
+sorting-natural = Naturale
+sorting-owner = Proprietario
+sorting-permissions = Permessi
+sorting-size = Dimensione
+sorted-by-creation-time = Per data di creazione
+sorted-by-extension = Per estensione
+sorted-by-modify-time = Per data di modifica
+sorted-by-name = Per nome
+sorted-by-natural-name = Per nome (naturale)
+sorted-by-owner = Per proprietario
+sorted-by-permissions = Per permessi
+sorted-by-size = Per dimensione
+sorted-reversed = { $sorting } (inverso)
+
+## Status bar
+status-disk = Disco
+status-file-sorting = Ordinamento
+status-hidden-files = File nascosti
+status-hidden-files-hide = Nascondi
+status-hidden-files-show = Mostra
+status-sync-browsing = Navigazione sincronizzata
+status-watcher = Sincronizzazione
+status-watcher-last-sync = { $paths } percorso/i, ultima alle { $time }
+status-watcher-paths = { $paths } percorso/i
+status-not-available = n/d
+
+## Keybindings
+help-auth-switch-tab = Passa dal form ai segnalibri e viceversa
+help-bookmark-hotkey = Assegna una scorciatoia al segnalibro selezionato
+help-change-cursor = Sposta il cursore
+help-change-explorer-tab = Cambia scheda dell'explorer
+help-change-field = Cambia campo
+help-change-setup-page = Cambia pagina
+help-checksum = Calcola il checksum del file (MD5, SHA-1, SHA-256)
+help-connect = Connetti/carica il segnalibro
+help-copy = Copia
+help-create-symlink = Crea un symlink che punta all'elemento selezionato
+help-delete-bookmark = Elimina il segnalibro selezionato
+help-delete-file = Elimina il file selezionato
+help-delete-ssh-key = Elimina la chiave SSH
+help-dir-history = Mostra la cronologia delle directory
+help-disconnect = Disconnetti
+help-edit-file = Apri file di testo con l'editor preferito
+help-enter-dir = Entra nella directory
+help-enter-setup = Apri la configurazione
+help-exec = Esegui comando shell (ricerca: esporta i risultati)
+help-exit-setup = Esci dalla configurazione
+help-file-info = Mostra informazioni sul file selezionato
+help-find-duplicates = Trova i duplicati nelle directory locale e remota
+help-follow-file = Segui file remoto (tail -f)
+help-go-to-mark = Vai alla directory segnata nello slot
+help-go-to-parent-dir = Vai alla directory superiore
+help-go-to-path = Vai al percorso
+help-go-to-previous-dir = Vai alla directory precedente
+help-history-back-forward = Vai indietro / avanti nella cronologia delle directory
+help-interrupt-transfer = Interrompi il trasferimento
+help-jump-to = Salta al file per nome
+help-make-dir = Crea directory
+help-mark-dir = Segna la directory corrente nello slot
+help-move-in-list = Spostati su/giù nella lista
+help-move-in-tab = Spostati su/giù nella scheda corrente
+help-new-file = Crea nuovo file
+help-new-ssh-key = Nuova chiave SSH
+help-open-file = Apri file con l'applicazione predefinita per il tipo di file
+help-open-file-with = Apri file con l'applicazione specificata
+help-quit = Esci da termscp
+help-reload-dir = Ricarica il contenuto della directory
+help-rename-file = Rinomina file
+help-revert-changes = Annulla le modifiche
+help-save-as = Salva file come
+help-save-bookmark = Salva segnalibro
+help-save-config = Salva la configurazione
+help-search-files = Cerca file
+help-select-all = Seleziona tutti i file
+help-select-dismiss = Seleziona / chiudi il popup
+help-select-file = Seleziona file
+help-show-help = Mostra l'aiuto
+help-show-marks = Mostra le directory segnate
+help-show-watched = Mostra i percorsi sincronizzati
+help-sort-files = Cambia l'ordinamento dei file
+help-switch-bookmark-tab = Cambia scheda dei segnalibri
+help-switch-bucket = Cambia bucket S3
+help-switch-log = Passa dall'explorer alla finestra di log e viceversa
+help-switch-profile = Cambia profilo
+help-sync-browsing = Attiva/disattiva la navigazione sincronizzata
+help-toggle-hidden-files = Mostra/nascondi i file nascosti
+help-transfer-file = Carica/scarica file
+help-transfer-filter = Trasferisci escludendo i file corrispondenti ai pattern
+help-transfer-symlinks = Trasferisci scegliendo come gestire i symlink
+help-watch = Sincronizza/smetti di sincronizzare le modifiche (ricerca: trasferisci tutti i risultati)
+help-watcher-log = Mostra le modifiche sincronizzate dal file watcher
+
+## Footers
+footer-change-field = Cambia campo
+footer-change-panel = Cambia pannello
+footer-change-tab = Cambia scheda
+footer-copy = Copia
+footer-delete = Elimina
+footer-edit = Modifica
+footer-enter-dir = Entra
+footer-enter-setup = Configurazione
+footer-exit = Esci
+footer-help = Aiuto
+footer-install-update = Installa aggiornamento
+footer-make-dir = Crea dir
+footer-quit = Esci
+footer-rename = Rinomina
+footer-save-as = Salva come
+footer-save-parameters = Salva parametri
+footer-submit-form = Connetti
+footer-switch-tab = Cambia scheda
+footer-transfer = Trasferisci
+footer-view = Visualizza
+
+## Wait and progress messages
+please-wait = Attendere
+progress-downloading = Download di "{ $file }"…
+progress-downloading-entries = Download di { $count } elementi…
+progress-uploading = Caricamento di "{ $file }"…
+progress-uploading-entries = Caricamento di { $count } elementi…
+wait-computing = Calcolo di { $algorithm }…
+wait-connecting = Connessione a { $host }…
+wait-copying = Copia dei file…
+wait-counting-files = Conteggio dei file da eliminare…
+wait-creating-symlink = Creazione del symlink…
+wait-disconnecting = Disconnessione da { $host }…
+wait-duplicates = Ricerca dei duplicati…
+wait-executing = Esecuzione di '{ $command }'…
+wait-moving = Spostamento dei file…
+wait-removing = Rimozione dei file…
+wait-searching = Ricerca di "{ $search }"…
+
+## Authentication
+access-key = Chiave di accesso
+auth-quit = Uscire da termscp?
+bookmark-name = Nome del segnalibro
+bookmarks = Segnalibri
+bucket-name = Nome del bucket
+compression = Compressione
+connect-anyway = Connettersi comunque?
+default-remote-directory = Directory remota predefinita
+delete-bookmark = Eliminare il segnalibro selezionato?
+delete-recent = Eliminare l'host recente selezionato?
+download-limit = Limite di download (al secondo)
+endpoint = Endpoint
+filename-encoding = Codifica dei nomi dei file
+install-update = Installare l'aggiornamento?
+new-path-style = Nuovo stile dei percorsi
+new-version-available = è ORA disponibile! Installa l'aggiornamento e leggi le note di rilascio con <CTRL+R>
+password = Password
+port-number = Numero di porta
+production-host = Host di produzione
+profile = Profilo
+protocol = Protocollo
+read-only = Sola lettura
+recent-connections = Connessioni recenti
+region = Regione
+release-notes = Note di rilascio
+remote-host = Host remoto
+save-secrets = Salvare le credenziali?
+secret-access-key = Chiave di accesso segreta
+security-token = Token di sicurezza
+session-token = Token di sessione
+unlimited = illimitato
+upload-limit = Limite di upload (al secondo)
+username = Nome utente
+window-too-small = termscp richiede un'altezza di almeno 24 righe per funzionare
+
+## Setup
+accessibility-mode = Modalità accessibilità?
+cancel = Annulla
+check-for-updates = Controllare gli aggiornamenti?
+compress-ssh = Comprimi il trasporto SSH
+default-file-sorting = Ordinamento predefinito dei file
+default-protocol = Protocollo predefinito
+default-transfer-filter = Filtro dei trasferimenti
+delete-confirmation-threshold = Digita per confermare l'eliminazione di più file di
+delete-ssh-key = Eliminare la chiave?
+dont-save = Non salvare
+enable-notifications = Attivare le notifiche?
+ftp-active = Attiva
+ftp-data-connection-mode = Modalità della connessione dati FTP
+ftp-passive = Passiva
+group-dirs = Raggruppa le directory
+group-dirs-first = Mostra prima
+group-dirs-last = Mostra dopo
+local-file-fmt = Sintassi del formato dei file (locale)
+notifications-threshold = Notifiche: dimensione minima del trasferimento
+partial-uploads = Caricare su file .part temporanei?
+preserve-ownership = Mantieni il proprietario (mappatura uid/gid)
+prompt-on-replace = Chiedere prima di sostituire i file esistenti?
+remote-file-fmt = Sintassi del formato dei file (remoto)
+save = Salva
+save-changes = Salvare le modifiche?
+setup-config = Parametri di configurazione
+setup-profile = Profilo: { $profile }
+setup-ssh-keys = Chiavi SSH
+setup-theme = Tema
+show-hidden-files = Mostrare i file nascosti? (predefinito)
+split-files = Dividi i file più grandi di
+ssh-config-path = Percorso della configurazione SSH
+ssh-host = Nome host o indirizzo
+status-bar-format = Formato della barra di stato
+switch-profile = Passa al profilo (se non esiste viene creato)
+symlinks-in-transfers = Symlink nei trasferimenti ricorsivi
+text-editor = Editor di testo
+unsaved-changes = Ci sono modifiche non salvate! Salvarle prima di uscire?
+
+## Theme
+connecting = Connessione...
+ip-address = Indirizzo IP
+localhost = Host locale
+port = Porta
+preview = Anteprima
+preview-error = Errore
+preview-hidden = Nascosti
+preview-sync = Sincr.
+preview-warn = Avviso
+theme-auth = Stili di autenticazione
+theme-error = Errore
+theme-info = Info
+theme-input = Input
+theme-keys = Tasti
+theme-local-explorer-background = Sfondo dell'explorer locale
+theme-local-explorer-foreground = Testo dell'explorer locale
+theme-local-explorer-highlighted = Evidenziazione dell'explorer locale
+theme-log-background = Sfondo della finestra di log
+theme-log-window = Finestra di log
+theme-misc = Stili vari
+theme-progress-full = Barra di avanzamento 'trasferimento totale'
+theme-progress-partial = Barra di avanzamento 'trasferimento parziale'
+theme-quit = Finestre di uscita
+theme-remote-explorer-background = Sfondo dell'explorer remoto
+theme-remote-explorer-foreground = Testo dell'explorer remoto
+theme-remote-explorer-highlighted = Evidenziazione dell'explorer remoto
+theme-save = Conferme di salvataggio
+theme-sync-browsing = Navigazione sincronizzata
+theme-transfer = Stili di trasferimento
+theme-transfer-2 = Stili di trasferimento (2)
+theme-warn = Avvisi
@@ -0,0 +1,211 @@
+//! ## I18n
+//!
+//! `i18n` provides the translations of the user interface strings.
+//!
+//! Messages are stored in bundles written with a subset of the Fluent syntax (`id = text`, with
+//! `{ $arg }` placeholders), one per language. The language is detected from the locale
+//! environment variables; messages missing in a bundle fall back to English.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+
+/// Default language, which must provide all the messages
+const DEFAULT_LANG: &str = "en";
+/// Available bundles
+const BUNDLES: [(&str, &str); 2] = [
+    ("en", include_str!("locales/en.ftl")),
+    ("it", include_str!("locales/it.ftl")),
+];
+
+lazy_static! {
+    static ref TRANSLATOR: Translator = Translator::new(current_language().as_deref());
+}
+
+/// Get the message with `id` in the current language
+pub fn tr(id: &str) -> String {
+    TRANSLATOR.message(id, &[])
+}
+
+/// Get the message with `id` in the current language, replacing the placeholders with `args`
+pub fn tr_args(id: &str, args: &[(&str, &dyn Display)]) -> String {
+    TRANSLATOR.message(id, args)
+}
+
+/// Get the message with `id` in the current language, preceded by `indent` spaces
+pub fn tr_indented(indent: usize, id: &str) -> String {
+    format!("{:indent$}{}", "", tr(id), indent = indent)
+}
+
+/// Holds the messages of the current language and the default one
+struct Translator {
+    messages: HashMap<&'static str, &'static str>,
+    fallback: HashMap<&'static str, &'static str>,
+}
+
+impl Translator {
+    fn new(lang: Option<&str>) -> Self {
+        let bundle = |lang: &str| {
+            BUNDLES
+                .iter()
+                .find(|(name, _)| *name == lang)
+                .map(|(_, source)| parse_bundle(source))
+        };
+        Self {
+            messages: lang.and_then(bundle).unwrap_or_default(),
+            fallback: bundle(DEFAULT_LANG).unwrap_or_default(),
+        }
+    }
+
+    fn message(&self, id: &str, args: &[(&str, &dyn Display)]) -> String {
+        let text = match self.messages.get(id).or_else(|| self.fallback.get(id)) {
+            Some(text) => *text,
+            None => {
+                error!("Missing translation for \"{}\"", id);
+                return id.to_string();
+            }
+        };
+        args.iter().fold(text.to_string(), |text, (name, value)| {
+            text.replace(format!("{{ ${} }}", name).as_str(), &value.to_string())
+        })
+    }
+}
+
+/// Parse the messages of a bundle. Empty lines and comments (`#`) are ignored
+fn parse_bundle(source: &'static str) -> HashMap<&'static str, &'static str> {
+    source
+        .lines()
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty() && !x.starts_with('#'))
+        .filter_map(|x| x.split_once('='))
+        .map(|(id, text)| (id.trim(), text.trim()))
+        .collect()
+}
+
+/// Get the language of the user interface. Tests always run with the default language
+fn current_language() -> Option<String> {
+    match cfg!(test) {
+        true => None,
+        false => detect_language(),
+    }
+}
+
+/// Detect the language from the locale environment variables (e.g. `it_IT.UTF-8` => `it`)
+fn detect_language() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|x| env::var(x).ok())
+        .find(|x| !x.is_empty())
+        .and_then(|x| language_of(x.as_str()))
+}
+
+/// Get the language of a locale name. Returns `None` for the `C` and `POSIX` locales
+fn language_of(locale: &str) -> Option<String> {
+    let lang = locale
+        .split(['_', '-', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match lang.as_str() {
+        "" | "c" | "posix" => None,
+        _ => Some(lang),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_get_language_of_locale() {
+        assert_eq!(language_of("it_IT.UTF-8").as_deref(), Some("it"));
+        assert_eq!(language_of("en-US").as_deref(), Some("en"));
+        assert_eq!(language_of("DE").as_deref(), Some("de"));
+        assert!(language_of("C").is_none());
+        assert!(language_of("POSIX").is_none());
+        assert!(language_of("").is_none());
+    }
+
+    #[test]
+    fn should_parse_bundle() {
+        let bundle = parse_bundle("# comment\n\nfoo = Foo\n  bar=Bar { $baz } \ninvalid\n");
+        assert_eq!(bundle.len(), 2);
+        assert_eq!(bundle.get("foo"), Some(&"Foo"));
+        assert_eq!(bundle.get("bar"), Some(&"Bar { $baz }"));
+    }
+
+    #[test]
+    fn should_translate_messages() {
+        let translator = Translator::new(Some("it"));
+        assert_eq!(translator.message("yes", &[]).as_str(), "Sì");
+        assert_eq!(
+            translator
+                .message("overwrite-file", &[("file", &"a.txt")])
+                .as_str(),
+            "Il file \"a.txt\" esiste già. Sovrascriverlo?"
+        );
+        assert_eq!(translator.message("foo-bar", &[]).as_str(), "foo-bar");
+        // Fallback to english
+        let translator = Translator::new(Some("tlh"));
+        assert_eq!(translator.message("yes", &[]).as_str(), "Yes");
+        let translator = Translator::new(None);
+        assert_eq!(translator.message("no", &[]).as_str(), "No");
+    }
+
+    #[test]
+    fn should_indent_messages() {
+        assert_eq!(tr_indented(4, "yes").as_str(), "    Yes");
+        assert_eq!(tr_indented(0, "no").as_str(), "No");
+    }
+
+    #[test]
+    fn bundles_should_have_all_messages() {
+        let default = parse_bundle(BUNDLES[0].1);
+        for (lang, source) in BUNDLES.iter().skip(1) {
+            let bundle = parse_bundle(source);
+            let mut missing: Vec<&str> = default
+                .keys()
+                .filter(|id| !bundle.contains_key(*id))
+                .copied()
+                .collect();
+            missing.sort_unstable();
+            assert!(
+                missing.is_empty(),
+                "{}: missing messages {:?}",
+                lang,
+                missing
+            );
+        }
+    }
+
+    #[test]
+    fn bundles_should_match_default_bundle() {
+        let default = parse_bundle(BUNDLES[0].1);
+        for (lang, source) in BUNDLES.iter().skip(1) {
+            for (id, text) in parse_bundle(source) {
+                let default_text = default
+                    .get(id)
+                    .unwrap_or_else(|| panic!("{}: unknown message \"{}\"", lang, id));
+                let placeholders = |text: &str| {
+                    let mut args: Vec<String> = text
+                        .split("{ $")
+                        .skip(1)
+                        .filter_map(|x| x.split_once(" }").map(|(arg, _)| arg.to_string()))
+                        .collect();
+                    args.sort();
+                    args
+                };
+                assert_eq!(
+                    placeholders(text),
+                    placeholders(default_text),
+                    "{}: placeholders of \"{}\" don't match",
+                    lang,
+                    id
+                );
+            }
+        }
+    }
+}
@@ -5,4 +5,5 @@
 // Modules
 pub mod activities;
 pub mod context;
+pub mod i18n;
 pub(crate) mod store;
@@ -0,0 +1,129 @@
+//! ## Checksum
+//!
+//! `checksum` is the module which provides utilities to compute file digests
+
+// Ext
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+
+/// Buffer size used to stream data into the hasher
+const BUFFER_SIZE: usize = 65536;
+
+/// Hash algorithm used to compute a file checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Returns the name of the algorithm
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Sha1 => "SHA-1",
+            Self::Sha256 => "SHA-256",
+        }
+    }
+
+    /// Returns the coreutils command which computes the digest
+    pub fn command(&self) -> &'static str {
+        match self {
+            Self::Md5 => "md5sum",
+            Self::Sha1 => "sha1sum",
+            Self::Sha256 => "sha256sum",
+        }
+    }
+
+    /// Returns the length of the digest as an hex string
+    fn hex_len(&self) -> usize {
+        match self {
+            Self::Md5 => 32,
+            Self::Sha1 => 40,
+            Self::Sha256 => 64,
+        }
+    }
+
+    /// Compute the digest of the data read from `reader` and return it as a lowercase hex string
+    pub fn digest<R: Read>(&self, reader: R) -> io::Result<String> {
+        match self {
+            Self::Md5 => digest_reader::<Md5, R>(reader),
+            Self::Sha1 => digest_reader::<Sha1, R>(reader),
+            Self::Sha256 => digest_reader::<Sha256, R>(reader),
+        }
+    }
+
+    /// Get the digest from the output of `command()` (`<digest>  <file>`).
+    /// Returns `None` if the output doesn't contain a valid digest
+    pub fn parse_command_output(&self, output: &str) -> Option<String> {
+        output
+            .split_whitespace()
+            .next()
+            .map(|x| x.trim_start_matches('\\').to_lowercase())
+            .filter(|x| x.len() == self.hex_len() && x.chars().all(|c| c.is_ascii_hexdigit()))
+    }
+}
+
+fn digest_reader<D: Digest, R: Read>(mut reader: R) -> io::Result<String> {
+    let mut hasher = D::new();
+    let mut buffer = vec![0; BUFFER_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(bytes) => hasher.update(&buffer[..bytes]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|x| format!("{:02x}", x))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_compute_digest() {
+        let data: &[u8] = b"termscp";
+        assert_eq!(
+            ChecksumAlgorithm::Md5.digest(data).unwrap().as_str(),
+            "f12e86b30fa5ab854dc2f18cde15fc38"
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Sha1.digest(data).unwrap().as_str(),
+            "89aa394d036af663b1bffaa3f2da3c406e6ab5a8"
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Sha256.digest(data).unwrap().as_str(),
+            "89b3d20d5e08b4f4ba1da7eedfff040e29a2706ed926dd82ecd3112b67c7f99b"
+        );
+    }
+
+    #[test]
+    fn should_parse_command_output() {
+        let alg = ChecksumAlgorithm::Md5;
+        assert_eq!(
+            alg.parse_command_output("F12E86B30FA5AB854DC2F18CDE15FC38  /tmp/a.txt\n")
+                .unwrap()
+                .as_str(),
+            "f12e86b30fa5ab854dc2f18cde15fc38"
+        );
+        assert!(alg
+            .parse_command_output("md5sum: /tmp/a.txt: No such file or directory")
+            .is_none());
+        assert!(alg.parse_command_output("").is_none());
+        assert!(ChecksumAlgorithm::Sha256
+            .parse_command_output("f12e86b30fa5ab854dc2f18cde15fc38  /tmp/a.txt")
+            .is_none());
+    }
+}
@@ -48,6 +48,17 @@ pub fn fmt_millis(duration: Duration) -> String {
     format!("{}.{:0width$}", seconds, millis, width = 3)
 }
 
+/// ### fmt_duration
+///
+/// Format duration as {hours}:{minutes}:{seconds}, omitting hours if zero
+pub fn fmt_duration(duration: Duration) -> String {
+    let seconds: u64 = duration.as_secs();
+    match seconds / 3600 {
+        0 => format!("{}:{:02}", seconds / 60, seconds % 60),
+        hours => format!("{}:{:02}:{:02}", hours, (seconds / 60) % 60, seconds % 60),
+    }
+}
+
 /// ### elide_path
 ///
 /// Elide a path if longer than width
@@ -323,6 +334,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_utils_fmt_duration() {
+        assert_eq!(fmt_duration(Duration::from_secs(5)), String::from("0:05"));
+        assert_eq!(
+            fmt_duration(Duration::from_millis(754_900)),
+            String::from("12:34")
+        );
+        assert_eq!(
+            fmt_duration(Duration::from_secs(3723)),
+            String::from("1:02:03")
+        );
+    }
+
     #[test]
     #[cfg(target_family = "unix")]
     fn test_utils_fmt_path_elide() {
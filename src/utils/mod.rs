@@ -3,6 +3,7 @@
 //! `utils` is the module which provides utilities of different kind
 
 // modules
+pub mod checksum;
 pub mod crypto;
 pub mod file;
 pub mod fmt;
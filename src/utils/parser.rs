@@ -15,6 +15,7 @@ use crate::system::environment;
 // Ext
 use bytesize::ByteSize;
 use lazy_regex::{Lazy, Regex};
+use std::net::IpAddr;
 use std::path::PathBuf;
 use std::str::FromStr;
 use tuirealm::tui::style::Color;
@@ -66,6 +67,14 @@ static SEMVER_REGEX: Lazy<Regex> = lazy_regex!(r".*(:?[0-9]\.[0-9]\.[0-9])");
  */
 static BYTESIZE_REGEX: Lazy<Regex> = lazy_regex!(r"(:?([0-9])+)( )*(:?[KMGTP])?B$");
 
+/**
+ * Regex matches a host name: labels of letters, digits, hyphens and underscores separated by dots,
+ * not starting or ending with an hyphen
+ */
+static HOSTNAME_REGEX: Lazy<Regex> = lazy_regex!(
+    r"^[a-zA-Z0-9_](?:[a-zA-Z0-9_-]{0,61}[a-zA-Z0-9_])?(?:\.[a-zA-Z0-9_](?:[a-zA-Z0-9_-]{0,61}[a-zA-Z0-9_])?)*\.?$"
+);
+
 // -- remote opts
 
 /// ### parse_remote_opt
@@ -300,6 +309,17 @@ impl FromStr for ByteUnit {
     }
 }
 
+/// ### is_valid_host
+///
+/// Returns whether `host` is a valid host name or IP address (IPv6 addresses may be enclosed in brackets)
+pub fn is_valid_host(host: &str) -> bool {
+    let ip = host
+        .strip_prefix('[')
+        .and_then(|x| x.strip_suffix(']'))
+        .unwrap_or(host);
+    ip.parse::<IpAddr>().is_ok() || (host.len() <= 253 && HOSTNAME_REGEX.is_match(host))
+}
+
 /// ### parse_bytesize
 ///
 /// Parse bytes repr (e.g. `24 MB`) into `ByteSize`
@@ -543,6 +563,23 @@ mod tests {
         assert!(parse_color("redd").is_none());
     }
 
+    #[test]
+    fn should_validate_host() {
+        assert!(is_valid_host("localhost"));
+        assert!(is_valid_host("files.example.com"));
+        assert!(is_valid_host("my-host_01.lan."));
+        assert!(is_valid_host("192.168.1.1"));
+        assert!(is_valid_host("::1"));
+        assert!(is_valid_host("[fe80::1]"));
+        assert!(!is_valid_host(""));
+        assert!(!is_valid_host("my host"));
+        assert!(!is_valid_host("-example.com"));
+        assert!(!is_valid_host("example..com"));
+        assert!(!is_valid_host("user@example.com"));
+        assert!(!is_valid_host("example.com:22"));
+        assert!(!is_valid_host(&format!("{}.com", "a".repeat(64))));
+    }
+
     #[test]
     fn parse_byteunit() {
         assert_eq!(ByteUnit::from_str("B").ok().unwrap(), ByteUnit::Byte);
@@ -3,6 +3,7 @@
 //! Path related utilities
 
 use std::path::{Component, Path, PathBuf};
+use wildmatch::WildMatch;
 
 /// ### absolutize
 ///
@@ -81,11 +82,106 @@ where
     }
 }
 
+/// Resolve the `.` and `..` components of `path` lexically, without accessing the file system.
+/// `..` at the root is discarded.
+///
+/// ```rust
+/// assert_eq!(normalize(&Path::new("/home/omar/../pi/./src")).as_path(), Path::new("/home/pi/src"));
+/// ```
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
 /// Returns whether `p` is child (direct/indirect) of ancestor `ancestor`
 pub fn is_child_of<P: AsRef<Path>>(p: P, ancestor: P) -> bool {
     p.as_ref().ancestors().any(|x| x == ancestor.as_ref())
 }
 
+/// Expand a leading `~` with `home` and the `$NAME` and `${NAME}` variables with the value returned by `var`,
+/// as a shell would do. Variables which are not set and `~`, if `home` is `None`, are left as they are
+pub fn expand_vars<F>(input: &str, home: Option<&Path>, mut var: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut expanded = String::with_capacity(input.len());
+    let mut rest = input;
+    if let Some(home) = home {
+        if rest == "~" || rest.starts_with("~/") {
+            expanded.push_str(home.to_string_lossy().as_ref());
+            rest = &rest[1..];
+        }
+    }
+    while let Some(pos) = rest.find('$') {
+        expanded.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let (name, next) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], &braced[end + 1..]),
+                None => ("", after),
+            },
+            None => {
+                let end = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            }
+        };
+        match (name.is_empty(), var(name)) {
+            (false, Some(value)) => expanded.push_str(value.as_str()),
+            _ => expanded.push_str(&rest[pos..rest.len() - next.len()]),
+        }
+        rest = next;
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Returns whether `path` contains wildcards (`*` or `?`)
+pub fn has_wildcards(path: &str) -> bool {
+    path.contains(['*', '?'])
+}
+
+/// Returns the paths matching the absolute `pattern`, where any component may contain wildcards.
+/// `list_dir` must return the names of the entries in the provided directory.
+/// Hidden entries are matched only if the pattern component starts with `.`
+pub fn glob_matches<F>(pattern: &Path, mut list_dir: F) -> Vec<PathBuf>
+where
+    F: FnMut(&Path) -> Vec<String>,
+{
+    let mut matches: Vec<PathBuf> = vec![PathBuf::new()];
+    for component in pattern.components() {
+        let component = component.as_os_str().to_string_lossy();
+        if !has_wildcards(component.as_ref()) {
+            matches.iter_mut().for_each(|x| x.push(component.as_ref()));
+            continue;
+        }
+        let filter = WildMatch::new(component.as_ref());
+        let hidden = component.starts_with('.');
+        matches = matches
+            .iter()
+            .flat_map(|dir| {
+                let mut names: Vec<String> = list_dir(dir.as_path())
+                    .into_iter()
+                    .filter(|x| (hidden || !x.starts_with('.')) && filter.matches(x))
+                    .collect();
+                names.sort();
+                names.into_iter().map(move |x| dir.join(x))
+            })
+            .collect();
+    }
+    matches
+}
+
 #[cfg(test)]
 mod test {
 
@@ -103,6 +199,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn normalize_path() {
+        assert_eq!(
+            normalize(Path::new("/home/omar/../pi/./src")).as_path(),
+            Path::new("/home/pi/src")
+        );
+        assert_eq!(
+            normalize(Path::new("/home/omar/.")),
+            Path::new("/home/omar")
+        );
+        assert_eq!(normalize(Path::new("/home/omar/..")), Path::new("/home"));
+        assert_eq!(normalize(Path::new("/../..")), Path::new("/"));
+    }
+
     #[test]
     fn calc_diff_paths() {
         assert_eq!(
@@ -125,6 +235,67 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_expand_vars() {
+        let home = Path::new("/home/omar");
+        let var = |name: &str| match name {
+            "HOME" => Some(String::from("/home/omar")),
+            "PROJECT" => Some(String::from("termscp")),
+            _ => None,
+        };
+        assert_eq!(expand_vars("~", Some(home), var).as_str(), "/home/omar");
+        assert_eq!(
+            expand_vars("~/Downloads", Some(home), var).as_str(),
+            "/home/omar/Downloads"
+        );
+        assert_eq!(
+            expand_vars("~/Downloads", None, var).as_str(),
+            "~/Downloads"
+        );
+        assert_eq!(expand_vars("~omar", Some(home), var).as_str(), "~omar");
+        assert_eq!(
+            expand_vars("$HOME/src/${PROJECT}-main", None, var).as_str(),
+            "/home/omar/src/termscp-main"
+        );
+        assert_eq!(
+            expand_vars("/tmp/$UNSET/${UNSET}/$/${", None, var).as_str(),
+            "/tmp/$UNSET/${UNSET}/$/${"
+        );
+        assert_eq!(expand_vars("/tmp/a b", None, var).as_str(), "/tmp/a b");
+    }
+
+    #[test]
+    fn should_get_glob_matches() {
+        let list_dir = |dir: &Path| match dir.to_string_lossy().as_ref() {
+            "/" => vec![String::from("home"), String::from("tmp")],
+            "/home" => vec![
+                String::from("omar"),
+                String::from("olivia"),
+                String::from(".cache"),
+            ],
+            "/home/omar" | "/home/olivia" => vec![String::from("Documents")],
+            _ => vec![],
+        };
+        assert!(has_wildcards("/home/o*"));
+        assert!(!has_wildcards("/home/omar"));
+        assert_eq!(
+            glob_matches(Path::new("/home/o*/Doc*"), list_dir),
+            vec![
+                PathBuf::from("/home/olivia/Documents"),
+                PathBuf::from("/home/omar/Documents")
+            ]
+        );
+        assert_eq!(
+            glob_matches(Path::new("/t?p/file.txt"), list_dir),
+            vec![PathBuf::from("/tmp/file.txt")]
+        );
+        assert_eq!(
+            glob_matches(Path::new("/home/.c*"), list_dir),
+            vec![PathBuf::from("/home/.cache")]
+        );
+        assert!(glob_matches(Path::new("/home/x*"), list_dir).is_empty());
+    }
+
     #[test]
     fn should_tell_whether_path_is_child_of() {
         assert_eq!(
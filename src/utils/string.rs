@@ -2,12 +2,81 @@
 //!
 //! String related utilities
 
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
 /// Get a substring considering utf8 characters
 pub fn secure_substring(string: &str, start: usize, end: usize) -> String {
     assert!(end >= start);
     string.chars().take(end).skip(start).collect()
 }
 
+/// Compare two strings in natural order: sequences of digits are compared by their numeric value,
+/// while other characters are compared ignoring case (e.g. `file2` comes before `file10`)
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        let ordering = match (a_chars.peek(), b_chars.peek()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_digits(&mut a_chars);
+                let y = take_digits(&mut b_chars);
+                let (x_trim, y_trim) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                x_trim
+                    .len()
+                    .cmp(&y_trim.len())
+                    .then_with(|| x_trim.cmp(y_trim))
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                a_chars.next();
+                b_chars.next();
+                ordering
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Quote `s` to be used as a single argument in a POSIX shell command
+pub fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Returns the longest prefix shared by all the strings in `strings`
+pub fn common_prefix<S: AsRef<str>>(strings: &[S]) -> String {
+    let mut iter = strings.iter();
+    let mut prefix: String = match iter.next() {
+        Some(first) => first.as_ref().to_string(),
+        None => return String::new(),
+    };
+    for s in iter {
+        let len: usize = prefix
+            .chars()
+            .zip(s.as_ref().chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        prefix.truncate(len);
+    }
+    prefix
+}
+
+/// Consume the sequence of digits at the beginning of `chars`
+fn take_digits(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(ch) = chars.next_if(|x| x.is_ascii_digit()) {
+        digits.push(ch);
+    }
+    digits
+}
+
 #[cfg(test)]
 mod test {
 
@@ -18,4 +87,42 @@ mod test {
         assert_eq!(secure_substring("christian", 2, 5).as_str(), "ris");
         assert_eq!(secure_substring("россия", 3, 5).as_str(), "си");
     }
+
+    #[test]
+    fn should_compare_strings_in_natural_order() {
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file2"), Ordering::Greater);
+        assert_eq!(natural_cmp("v1.9", "v1.10"), Ordering::Less);
+        assert_eq!(natural_cmp("v1.10.1", "v1.10"), Ordering::Greater);
+        assert_eq!(natural_cmp("File02", "file2"), Ordering::Less);
+        assert_eq!(natural_cmp("README", "readme"), Ordering::Less);
+        assert_eq!(natural_cmp("abc", "abc"), Ordering::Equal);
+        assert_eq!(natural_cmp("abc", "abd"), Ordering::Less);
+        assert_eq!(natural_cmp("", "a"), Ordering::Less);
+        assert_eq!(
+            natural_cmp(
+                "img_99999999999999999999.png",
+                "img_100000000000000000000.png"
+            ),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn should_quote_shell_argument() {
+        assert_eq!(shell_quote("/tmp/a b.txt").as_str(), "'/tmp/a b.txt'");
+        assert_eq!(shell_quote("it's").as_str(), "'it'\\''s'");
+    }
+
+    #[test]
+    fn should_get_common_prefix() {
+        assert_eq!(
+            common_prefix(&["Documents", "Downloads", "Dotfiles"]).as_str(),
+            "Do"
+        );
+        assert_eq!(common_prefix(&["café", "cafè"]).as_str(), "caf");
+        assert_eq!(common_prefix(&["src"]).as_str(), "src");
+        assert_eq!(common_prefix(&["src", "tests"]).as_str(), "");
+        assert_eq!(common_prefix::<&str>(&[]).as_str(), "");
+    }
 }
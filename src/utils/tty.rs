@@ -10,3 +10,13 @@ pub fn read_secret_from_tty(prompt: &str) -> std::io::Result<Option<String>> {
         Err(err) => Err(err),
     }
 }
+
+/// Copy `text` to the system clipboard through the terminal, using the OSC 52 escape sequence.
+/// This works with most terminal emulators, even when termscp is running on a remote machine
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64::encode(text))?;
+    stdout.flush()
+}